@@ -0,0 +1,393 @@
+//! Asynchronous counterpart of [`Client`] for use within async runtimes.
+
+use std::fmt::Debug;
+
+use eyre::{eyre, Result, WrapErr};
+use futures_util::{stream, Stream};
+use iroha_config::client_api::{ConfigGetDTO, ConfigUpdateDTO};
+use iroha_telemetry::metrics::Status;
+use iroha_torii_const::uri as torii_uri;
+use iroha_version::prelude::*;
+use parity_scale_codec::DecodeAll;
+
+use crate::{
+    client::{
+        join_torii_url, Client, QueryResult, StatusResponseHandler, TransactionResponseHandler,
+        APPLICATION_JSON,
+    },
+    crypto::HashOf,
+    data_model::{
+        isi::Instruction,
+        prelude::*,
+        query::{
+            builder::{HasTypedBatchIter, QueryBuilder},
+            parameters::ForwardCursor,
+            Query, QueryBox, QueryRequest, QueryWithFilter, QueryWithParams, SingularQuery,
+            SingularQueryBox, SingularQueryOutputBox, SingularQueryWithParams,
+        },
+    },
+    http::{Method as HttpMethod, RequestBuilder, StatusCode},
+    http_default::AsyncRequestBuilder,
+    query::{decode_iterable_query_response, decode_singular_query_response},
+};
+
+/// Iroha client with an asynchronous API.
+///
+/// Shares its configuration with [`Client`], which can still be used to build and sign transactions.
+#[derive(Debug, Clone)]
+pub struct AsyncClient {
+    client: Client,
+    http: reqwest::Client,
+}
+
+impl From<Client> for AsyncClient {
+    fn from(client: Client) -> Self {
+        Self::new(client)
+    }
+}
+
+impl AsyncClient {
+    /// Construct an asynchronous client with the same configuration as `client`
+    ///
+    /// # Panics
+    /// If the TLS backend can't be initialized or doesn't support [`Client::tls_roots`],
+    /// the same as [`reqwest::Client::new`]
+    pub fn new(client: Client) -> Self {
+        let http = client
+            .tls_roots
+            .add_to_reqwest(reqwest::Client::builder())
+            .and_then(|builder| builder.build().wrap_err("Failed to build HTTP client"))
+            .expect("TLS backend should be initialized");
+
+        Self { client, http }
+    }
+
+    /// The underlying blocking client
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Instructions API entry point. Submits one Iroha Special Instruction to `Iroha` peers.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit<I: Instruction>(&self, isi: I) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all([isi]).await
+    }
+
+    /// Instructions API entry point. Submits several Iroha Special Instructions to `Iroha` peers.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self
+            .client
+            .build_transaction(instructions, Metadata::default());
+        self.submit_transaction(&transaction).await
+    }
+
+    /// Submit a prebuilt transaction.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        iroha_logger::trace!(tx=?transaction, "Submitting");
+        let (req, hash) = self
+            .client
+            .prepare_transaction_request::<AsyncRequestBuilder>(transaction);
+        let response = req
+            .build()?
+            .send(&self.http)
+            .await
+            .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle(&response)?;
+        Ok(hash)
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_blocking<I: Instruction>(
+        &self,
+        instruction: I,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_blocking([instruction]).await
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all_blocking<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self
+            .client
+            .build_transaction(instructions, Metadata::default());
+        self.submit_transaction_blocking(&transaction).await
+    }
+
+    /// Submit the prebuilt transaction and wait until it is either rejected or committed.
+    /// If rejected, return the rejection reason.
+    ///
+    /// # Errors
+    /// Fails if sending a transaction to a peer fails or there is an error in the response
+    pub async fn submit_transaction_blocking(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let hash = transaction.hash();
+        let deadline = tokio::time::Instant::now() + self.client.transaction_status_timeout;
+
+        // Subscribe before submitting so that no events are missed
+        let mut event_stream = tokio::time::timeout_at(
+            deadline,
+            self.client
+                .listen_for_events_async(Client::tx_confirmation_filters(hash)),
+        )
+        .await
+        .map_err(Into::into)
+        .and_then(std::convert::identity)
+        .wrap_err("Failed to establish event listener connection")?;
+
+        let result = async {
+            self.submit_transaction(transaction).await?;
+
+            tokio::time::timeout_at(
+                deadline,
+                Client::listen_for_tx_confirmation_loop(&mut event_stream, hash),
+            )
+            .await
+            .wrap_err_with(|| {
+                eyre!(
+                    "haven't got tx confirmation within {:?} (configured with `transaction_status_timeout`)",
+                    self.client.transaction_status_timeout
+                )
+            })
+            .and_then(std::convert::identity)
+        }
+        .await;
+        event_stream.close().await;
+        result
+    }
+
+    /// Execute the prebuilt transaction against the latest state of the peer without submitting it.
+    ///
+    /// # Errors
+    /// Fails if sending the transaction to the peer fails, the peer doesn't accept it
+    /// (e.g. because of an invalid signature) or the response can't be decoded
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionSimulation> {
+        let hash = transaction.hash();
+        let response = AsyncRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.client.torii_url, torii_uri::TRANSACTION_SIMULATE),
+        )
+        .headers(self.client.headers.clone())
+        .body(transaction.encode_versioned())
+        .build()?
+        .send(&self.http)
+        .await
+        .wrap_err_with(|| format!("Failed to simulate transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle(&response)?;
+        DecodeAll::decode_all(&mut response.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Execute a singular query and return the result
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub async fn query_single<Q>(&self, query: Q) -> QueryResult<Q::Output>
+    where
+        Q: SingularQuery,
+        SingularQueryBox: From<Q>,
+        Q::Output: TryFrom<SingularQueryOutputBox>,
+        <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
+    {
+        let request = QueryRequest::Singular(SingularQueryWithParams::new(query.into(), None));
+        let response = self.send_query(request).await?;
+        let output = decode_singular_query_response(&response)?;
+
+        Ok(output
+            .try_into()
+            .expect("BUG: iroha returned unexpected type in singular query"))
+    }
+
+    /// Build an iterable query and return a builder object.
+    ///
+    /// The query is executed with [`AsyncQueryBuilderExt::execute_stream`].
+    pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
+    where
+        Q: Query,
+    {
+        QueryBuilder::new(self, query)
+    }
+
+    async fn send_query(&self, request: QueryRequest) -> Result<http::Response<Vec<u8>>> {
+        self.client
+            .get_query_request_head()
+            .assemble::<AsyncRequestBuilder>(request)
+            .build()?
+            .send(&self.http)
+            .await
+    }
+
+    async fn fetch_batch<T: HasTypedBatchIter>(
+        &self,
+        request: QueryRequest,
+    ) -> QueryResult<(T::TypedBatchIter, Option<ForwardCursor>)> {
+        let response = self.send_query(request).await?;
+        let (batch, _remaining_items, cursor) =
+            decode_iterable_query_response(&response)?.into_parts();
+        let batch = T::downcast(batch).expect(
+            "INTERNAL BUG: iroha returned unexpected type in iterable query. Is there a schema mismatch?",
+        );
+
+        Ok((batch, cursor))
+    }
+
+    /// Start an iterable query and stream its results, fetching the next batch when the previous one is exhausted
+    fn query_stream<'client, T: HasTypedBatchIter + 'client>(
+        &'client self,
+        query: QueryWithParams,
+    ) -> impl Stream<Item = QueryResult<T>> + 'client {
+        enum State<I> {
+            Start(QueryWithParams),
+            Batch(I, Option<ForwardCursor>),
+            Done,
+        }
+
+        stream::unfold(State::Start(query), move |mut state| async move {
+            loop {
+                state = match state {
+                    State::Start(query) => {
+                        match self.fetch_batch::<T>(QueryRequest::Start(query)).await {
+                            Ok((batch, cursor)) => State::Batch(batch, cursor),
+                            Err(err) => return Some((Err(err), State::Done)),
+                        }
+                    }
+                    State::Batch(mut batch, cursor) => {
+                        if let Some(item) = batch.next() {
+                            return Some((Ok(item), State::Batch(batch, cursor)));
+                        }
+                        // no cursor means the query result is exhausted
+                        let cursor = cursor?;
+                        match self.fetch_batch::<T>(QueryRequest::Continue(cursor)).await {
+                            Ok((batch, cursor)) => State::Batch(batch, cursor),
+                            Err(err) => return Some((Err(err), State::Done)),
+                        }
+                    }
+                    State::Done => return None,
+                }
+            }
+        })
+    }
+
+    /// Get value of config on peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_config(&self) -> Result<ConfigGetDTO> {
+        let resp = AsyncRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.client.torii_url, torii_uri::CONFIGURATION),
+        )
+        .headers(&self.client.headers)
+        .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+        .build()?
+        .send(&self.http)
+        .await?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get configuration with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        serde_json::from_slice(resp.body()).wrap_err("Failed to decode body")
+    }
+
+    /// Send a request to change the configuration of a specified field.
+    ///
+    /// # Errors
+    /// If sending request or decoding fails
+    pub async fn set_config(&self, dto: &ConfigUpdateDTO) -> Result<()> {
+        let body = serde_json::to_vec(&dto).wrap_err(format!("Failed to serialize {dto:?}"))?;
+        let url = join_torii_url(&self.client.torii_url, torii_uri::CONFIGURATION);
+        let resp = AsyncRequestBuilder::new(HttpMethod::POST, url)
+            .headers(&self.client.headers)
+            .headers(self.client.operator_headers(
+                &HttpMethod::POST,
+                torii_uri::CONFIGURATION,
+                &body,
+            ))
+            .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+            .body(body)
+            .build()?
+            .send(&self.http)
+            .await?;
+
+        if resp.status() != StatusCode::ACCEPTED {
+            return Err(eyre!(
+                "Failed to post configuration with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        };
+
+        Ok(())
+    }
+
+    /// Gets network status seen from the peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_status(&self) -> Result<Status> {
+        let resp = self
+            .client
+            .prepare_status_request::<AsyncRequestBuilder>()
+            .header(http::header::ACCEPT, "application/x-parity-scale")
+            .build()?
+            .send(&self.http)
+            .await?;
+        let scaled_resp = StatusResponseHandler::handle(&resp)?;
+        DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
+    }
+}
+
+/// Execution of iterable queries built with [`AsyncClient::query`].
+pub trait AsyncQueryBuilderExt<'e, T> {
+    /// Execute the query, returning a stream of its results.
+    ///
+    /// Batches of results are fetched from the peer lazily, as the stream is polled.
+    fn execute_stream(self) -> impl Stream<Item = QueryResult<T>> + 'e;
+}
+
+impl<'e, Q, T, A> AsyncQueryBuilderExt<'e, T> for QueryBuilder<'e, AsyncClient, Q, T, A>
+where
+    Q: Query,
+    QueryBox: From<QueryWithFilter<Q>>,
+    T: HasTypedBatchIter + 'e,
+{
+    fn execute_stream(self) -> impl Stream<Item = QueryResult<T>> + 'e {
+        let (client, query) = self.into_parts();
+        client.query_stream(query)
+    }
+}
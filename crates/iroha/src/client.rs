@@ -6,36 +6,40 @@ use std::{
     fmt::Debug,
     num::{NonZeroU32, NonZeroU64},
     thread,
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use derive_more::{DebugCustom, Display};
 use eyre::{eyre, Result, WrapErr};
 use futures_util::StreamExt;
 use http_default::{AsyncWebSocketStream, WebSocketStream};
-pub use iroha_config::client_api::ConfigGetDTO;
-use iroha_config::client_api::ConfigUpdateDTO;
+pub use iroha_config::client_api::{ConfigGetDTO, ConfigUpdateDTO};
 use iroha_logger::prelude::*;
 pub use iroha_telemetry::metrics::{Status, Uptime};
-use iroha_torii_const::uri as torii_uri;
+use iroha_torii_const::{header as torii_header, uri as torii_uri};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
 use rand::Rng;
 use url::Url;
 
-use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
-pub use crate::query::QueryError;
+use self::{
+    blocks_api::AsyncBlockStream,
+    events_api::{AsyncEventReplayStream, AsyncEventStream},
+};
+pub use crate::{async_client::AsyncClient, http_default::TlsRoots, query::QueryError};
 use crate::{
     config::Config,
-    crypto::{HashOf, KeyPair},
+    crypto::{HashOf, KeyPair, Signature},
     data_model::{
-        block::SignedBlock,
+        block::{BlockHeader, SignedBlock},
         events::pipeline::{
             BlockEventFilter, BlockStatus, PipelineEventBox, PipelineEventFilterBox,
             TransactionEventFilter, TransactionStatus,
         },
         isi::Instruction,
+        operator::OperatorRequestPayload,
         prelude::*,
+        query::TransactionProof,
         transaction::TransactionBuilder,
         ChainId,
     },
@@ -43,17 +47,17 @@ use crate::{
     http_default::{self, DefaultRequestBuilder, WebSocketError, WebSocketMessage},
 };
 
-const APPLICATION_JSON: &str = "application/json";
+pub(crate) const APPLICATION_JSON: &str = "application/json";
 
 /// `Result` with [`QueryError`] as an error
 pub type QueryResult<T> = core::result::Result<T, QueryError>;
 
 /// Phantom struct that handles Transaction API HTTP response
 #[derive(Clone, Copy)]
-struct TransactionResponseHandler;
+pub(crate) struct TransactionResponseHandler;
 
 impl TransactionResponseHandler {
-    fn handle(resp: &Response<Vec<u8>>) -> Result<()> {
+    pub(crate) fn handle(resp: &Response<Vec<u8>>) -> Result<()> {
         if resp.status() == StatusCode::OK {
             Ok(())
         } else {
@@ -71,7 +75,7 @@ impl TransactionResponseHandler {
 pub struct StatusResponseHandler;
 
 impl StatusResponseHandler {
-    fn handle(resp: &Response<Vec<u8>>) -> Result<&Vec<u8>> {
+    pub(crate) fn handle(resp: &Response<Vec<u8>>) -> Result<&Vec<u8>> {
         if resp.status() != StatusCode::OK {
             return Err(ResponseReport::with_msg("Unexpected status response", resp)
                 .unwrap_or_else(core::convert::identity)
@@ -138,6 +142,8 @@ pub struct Client {
     /// If `true` add nonce, which makes different hashes for
     /// transactions which occur repeatedly and/or simultaneously
     pub add_transaction_nonce: bool,
+    /// CA certificates trusted in addition to the default roots if Torii is accessed over TLS
+    pub tls_roots: TlsRoots,
 }
 
 /// Representation of `Iroha` client.
@@ -157,6 +163,7 @@ impl Client {
             chain,
             account,
             torii_api_url,
+            torii_tls_roots,
             key_pair,
             basic_auth,
             transaction_add_nonce,
@@ -185,6 +192,7 @@ impl Client {
             account,
             headers,
             add_transaction_nonce: transaction_add_nonce,
+            tls_roots: torii_tls_roots,
         }
     }
 
@@ -225,6 +233,37 @@ impl Client {
         transaction.sign(self.key_pair.private_key())
     }
 
+    /// Headers authenticating a request to an operator endpoint at `path` with the key pair of this client.
+    ///
+    /// The peer accepts such requests only if the public key of this client is among its admin keys.
+    pub fn operator_headers(
+        &self,
+        method: &HttpMethod,
+        path: &str,
+        body: &[u8],
+    ) -> [(&'static str, String); 3] {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Failed to get the current system time");
+        let payload = OperatorRequestPayload::new(method.as_str(), path, body, timestamp);
+        let signature = Signature::from(payload.sign(&self.key_pair));
+
+        [
+            (
+                torii_header::OPERATOR_PUBLIC_KEY,
+                self.key_pair.public_key().to_string(),
+            ),
+            (
+                torii_header::OPERATOR_SIGNATURE,
+                hex::encode(signature.payload()),
+            ),
+            (
+                torii_header::OPERATOR_TIMESTAMP,
+                payload.timestamp_ms.to_string(),
+            ),
+        ]
+    }
+
     /// Instructions API entry point. Submits one Iroha Special Instruction to `Iroha` peers.
     /// Returns submitted transaction's hash or error string.
     ///
@@ -286,6 +325,7 @@ impl Client {
         iroha_logger::trace!(tx=?transaction, "Submitting");
         let (req, hash) = self.prepare_transaction_request::<DefaultRequestBuilder>(transaction);
         let response = req
+            .tls_roots(&self.tls_roots)
             .build()?
             .send()
             .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
@@ -293,6 +333,30 @@ impl Client {
         Ok(hash)
     }
 
+    /// Execute the prebuilt transaction against the latest state of the peer without submitting it.
+    ///
+    /// # Errors
+    /// Fails if sending the transaction to the peer fails, the peer doesn't accept it
+    /// (e.g. because of an invalid signature) or the response can't be decoded
+    pub fn simulate_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionSimulation> {
+        let hash = transaction.hash();
+        let response = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::TRANSACTION_SIMULATE),
+        )
+        .headers(self.headers.clone())
+        .body(transaction.encode_versioned())
+        .tls_roots(&self.tls_roots)
+        .build()?
+        .send()
+        .wrap_err_with(|| format!("Failed to simulate transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle(&response)?;
+        DecodeAll::decode_all(&mut response.body().as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
     /// If rejected, return the rejection reason.
     ///
@@ -339,12 +403,7 @@ impl Client {
 
         rt.block_on(async {
             let mut event_iterator = {
-                let filters = vec![
-                    TransactionEventFilter::default().for_hash(hash).into(),
-                    PipelineEventFilterBox::from(
-                        BlockEventFilter::default().for_status(BlockStatus::Applied),
-                    ),
-                ];
+                let filters = Self::tx_confirmation_filters(hash);
 
                 let event_iterator_result =
                     tokio::time::timeout_at(deadline, self.listen_for_events_async(filters))
@@ -374,7 +433,19 @@ impl Client {
         })
     }
 
-    async fn listen_for_tx_confirmation_loop(
+    /// Filters of the events required to track the status of the transaction with the given `hash`
+    pub(crate) fn tx_confirmation_filters(
+        hash: HashOf<SignedTransaction>,
+    ) -> [PipelineEventFilterBox; 2] {
+        [
+            TransactionEventFilter::default().for_hash(hash).into(),
+            PipelineEventFilterBox::from(
+                BlockEventFilter::default().for_status(BlockStatus::Applied),
+            ),
+        ]
+    }
+
+    pub(crate) async fn listen_for_tx_confirmation_loop(
         event_iterator: &mut AsyncEventStream,
         hash: HashOf<SignedTransaction>,
     ) -> Result<HashOf<SignedTransaction>> {
@@ -418,7 +489,7 @@ impl Client {
     /// it is better to use a response handler anyway. It allows to abstract from implementation details.
     ///
     /// For general usage example see [`Client::prepare_query_request`].
-    fn prepare_transaction_request<B: RequestBuilder>(
+    pub(crate) fn prepare_transaction_request<B: RequestBuilder>(
         &self,
         transaction: &SignedTransaction,
     ) -> (B, HashOf<SignedTransaction>) {
@@ -497,7 +568,7 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
     ) -> Result<impl Iterator<Item = Result<EventBox>>> {
-        events_api::EventIterator::new(self.events_handler(event_filters)?)
+        events_api::EventIterator::new(self.events_handler(event_filters)?, &self.tls_roots)
     }
 
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
@@ -509,7 +580,8 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
     ) -> Result<AsyncEventStream> {
-        events_api::AsyncEventStream::new(self.events_handler(event_filters)?).await
+        events_api::AsyncEventStream::new(self.events_handler(event_filters)?, &self.tls_roots)
+            .await
     }
 
     /// Constructs an Events API handler. With it, you can use any WS client you want.
@@ -528,6 +600,58 @@ impl Client {
         )
     }
 
+    /// Connect (through `WebSocket`) to replay `Iroha` `pipeline` and `data` events starting from the block at `height`
+    /// and then listen for the new ones.
+    ///
+    /// Each message is tagged with the height of the block the event belongs to,
+    /// so that it's possible to resume from the next height after reconnecting without receiving duplicates.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::events_replay_handler`]
+    /// - Forwards from [`events_api::EventReplayIterator::new`]
+    pub fn replay_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+        height: NonZeroU64,
+    ) -> Result<impl Iterator<Item = Result<EventMessage>>> {
+        events_api::EventReplayIterator::new(
+            self.events_replay_handler(event_filters, height)?,
+            &self.tls_roots,
+        )
+    }
+
+    /// Connect asynchronously (through `WebSocket`) to replay `Iroha` `pipeline` and `data` events starting from the block at `height`
+    /// and then listen for the new ones.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::events_replay_handler`]
+    /// - Forwards from [`events_api::AsyncEventReplayStream::new`]
+    pub async fn replay_events_async(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
+        height: NonZeroU64,
+    ) -> Result<AsyncEventReplayStream> {
+        events_api::AsyncEventReplayStream::new(
+            self.events_replay_handler(event_filters, height)?,
+            &self.tls_roots,
+        )
+        .await
+    }
+
+    /// Constructs an Events API handler replaying events from the given height. With it, you can use any WS client you want.
+    ///
+    /// # Errors
+    /// Fails if handler construction fails
+    #[inline]
+    pub fn events_replay_handler(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+        height: NonZeroU64,
+    ) -> Result<events_api::flow::ReplayInit> {
+        self.events_handler(event_filters)
+            .map(|init| init.with_from_height(height))
+    }
+
     /// Connect (through `WebSocket`) to listen for `Iroha` blocks
     ///
     /// # Errors
@@ -537,7 +661,7 @@ impl Client {
         &self,
         height: NonZeroU64,
     ) -> Result<impl Iterator<Item = Result<SignedBlock>>> {
-        blocks_api::BlockIterator::new(self.blocks_handler(height)?)
+        blocks_api::BlockIterator::new(self.blocks_handler(height)?, &self.tls_roots)
     }
 
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` blocks
@@ -546,7 +670,7 @@ impl Client {
     /// - Forwards from [`Self::events_handler`]
     /// - Forwards from [`blocks_api::BlockIterator::new`]
     pub async fn listen_for_blocks_async(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
-        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?).await
+        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?, &self.tls_roots).await
     }
 
     /// Construct a handler for Blocks API. With this handler you can use any WS client you want.
@@ -562,6 +686,34 @@ impl Client {
         )
     }
 
+    /// Get the proof of inclusion of the transaction with the given `hash` into a committed block.
+    ///
+    /// # Errors
+    /// Fails if the transaction is not committed or the query fails
+    pub fn get_transaction_proof(
+        &self,
+        hash: HashOf<SignedTransaction>,
+    ) -> QueryResult<TransactionProof> {
+        self.query_single(FindTransactionProof::new(hash))
+    }
+
+    /// Check that the transaction with the given `hash` is included into the block with the given `block_header`.
+    ///
+    /// Unlike the proof returned by the peer, `block_header` has to come from a trusted source,
+    /// e.g. a block whose signatures of a quorum of peers were checked by the caller.
+    ///
+    /// # Errors
+    /// Fails if the transaction is not committed or the query fails
+    pub fn verify_transaction_inclusion(
+        &self,
+        hash: HashOf<SignedTransaction>,
+        block_header: &BlockHeader,
+    ) -> QueryResult<bool> {
+        let proof = self.get_transaction_proof(hash)?;
+
+        Ok(proof.block_header() == block_header && proof.verify(hash))
+    }
+
     /// Get value of config on peer
     ///
     /// # Errors
@@ -573,6 +725,7 @@ impl Client {
         )
         .headers(&self.headers)
         .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+        .tls_roots(&self.tls_roots)
         .build()?
         .send()?;
 
@@ -595,8 +748,10 @@ impl Client {
         let url = join_torii_url(&self.torii_url, torii_uri::CONFIGURATION);
         let resp = DefaultRequestBuilder::new(HttpMethod::POST, url)
             .headers(&self.headers)
+            .headers(self.operator_headers(&HttpMethod::POST, torii_uri::CONFIGURATION, &body))
             .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
             .body(body)
+            .tls_roots(&self.tls_roots)
             .build()?
             .send()?;
 
@@ -618,7 +773,8 @@ impl Client {
     pub fn get_status(&self) -> Result<Status> {
         let req = self
             .prepare_status_request::<DefaultRequestBuilder>()
-            .header(http::header::ACCEPT, "application/x-parity-scale");
+            .header(http::header::ACCEPT, "application/x-parity-scale")
+            .tls_roots(&self.tls_roots);
         let resp = req.build()?.send()?;
         let scaled_resp = StatusResponseHandler::handle(&resp).cloned()?;
         DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
@@ -636,6 +792,7 @@ impl Client {
             join_torii_url(&self.torii_url, torii_uri::STATUS),
         )
         .headers(self.headers.clone())
+        .headers(self.operator_headers(&HttpMethod::GET, torii_uri::STATUS, &[]))
     }
 }
 
@@ -656,11 +813,12 @@ pub(crate) fn join_torii_url(url: &Url, path: &str) -> Url {
 /// Logic for `sync` and `async` Iroha websocket streams
 pub mod stream_api {
     use futures_util::{SinkExt, Stream, StreamExt};
+    use tungstenite::protocol::frame::coding::CloseCode;
 
     use super::*;
     use crate::{
         http::ws::conn_flow::{Events, Init, InitData},
-        http_default::DefaultWebSocketRequestBuilder,
+        http_default::{DefaultWebSocketRequestBuilder, TlsRoots},
     };
 
     /// Iterator for getting messages from the `WebSocket` stream.
@@ -680,6 +838,7 @@ pub mod stream_api {
         /// - Message is an error
         pub fn new<I: Init<DefaultWebSocketRequestBuilder>>(
             handler: I,
+            tls_roots: &TlsRoots,
         ) -> Result<SyncIterator<I::Next>> {
             trace!("Creating `SyncIterator`");
             let InitData {
@@ -688,7 +847,7 @@ pub mod stream_api {
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect()?;
+            let mut stream = req.build(tls_roots)?.connect()?;
             stream.send(WebSocketMessage::Binary(first_message))?;
 
             trace!("`SyncIterator` created successfully");
@@ -708,6 +867,9 @@ pub mod stream_api {
                     Ok(WebSocketMessage::Binary(message)) => {
                         return Some(self.handler.message(message))
                     }
+                    Ok(WebSocketMessage::Close(Some(frame))) if frame.code != CloseCode::Normal => {
+                        return Some(Err(eyre!("Connection closed by peer: {}", frame.reason)))
+                    }
                     Ok(_) => continue,
                     Err(WebSocketError::ConnectionClosed | WebSocketError::AlreadyClosed) => {
                         return None
@@ -764,6 +926,7 @@ pub mod stream_api {
         #[allow(clippy::future_not_send)]
         pub async fn new<I: Init<DefaultWebSocketRequestBuilder>>(
             handler: I,
+            tls_roots: &TlsRoots,
         ) -> Result<AsyncStream<I::Next>> {
             trace!("Creating `AsyncStream`");
             let InitData {
@@ -772,7 +935,7 @@ pub mod stream_api {
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect_async().await?;
+            let mut stream = req.build(tls_roots)?.connect_async().await?;
             stream.send(WebSocketMessage::Binary(first_message)).await?;
 
             trace!("`AsyncStream` created successfully");
@@ -812,6 +975,14 @@ pub mod stream_api {
                     Some(Ok(WebSocketMessage::Binary(message))) => {
                         std::task::Poll::Ready(Some(self.handler.message(message)))
                     }
+                    Some(Ok(WebSocketMessage::Close(Some(frame))))
+                        if frame.code != CloseCode::Normal =>
+                    {
+                        std::task::Poll::Ready(Some(Err(eyre!(
+                            "Connection closed by peer: {}",
+                            frame.reason
+                        ))))
+                    }
                     Some(Ok(_)) => continue,
                     Some(Err(err)) => std::task::Poll::Ready(Some(Err(err.into()))),
                     None => std::task::Poll::Ready(None),
@@ -878,6 +1049,45 @@ pub mod events_api {
             }
         }
 
+        impl Init {
+            /// Replay events starting from the block at `height` before the live ones.
+            pub fn with_from_height(self, height: NonZeroU64) -> ReplayInit {
+                ReplayInit { init: self, height }
+            }
+        }
+
+        /// Initialization struct for Events API flow replaying events from a block height.
+        pub struct ReplayInit {
+            init: Init,
+            /// Block height from which to start replaying events
+            height: NonZeroU64,
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for ReplayInit {
+            type Next = ReplayEvents;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let Self {
+                    init:
+                        Init {
+                            url,
+                            headers,
+                            filters,
+                        },
+                    height,
+                } = self;
+
+                let msg = EventSubscriptionRequest::new(filters)
+                    .with_from_height(height)
+                    .encode();
+                InitData::new(
+                    R::new(HttpMethod::GET, url).headers(headers),
+                    msg,
+                    ReplayEvents,
+                )
+            }
+        }
+
         /// Events handler for Events API flow
         #[derive(Debug, Copy, Clone)]
         pub struct Events;
@@ -890,6 +1100,18 @@ pub mod events_api {
                 Ok(event_socket_message.into())
             }
         }
+
+        /// Events handler for Events API flow replaying events from a block height
+        #[derive(Debug, Copy, Clone)]
+        pub struct ReplayEvents;
+
+        impl FlowEvents for ReplayEvents {
+            type Event = EventMessage;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                Ok(EventMessage::decode_all(&mut message.as_slice())?)
+            }
+        }
     }
 
     /// Iterator for getting events from the `WebSocket` stream.
@@ -897,6 +1119,12 @@ pub mod events_api {
 
     /// Async stream for getting events from the `WebSocket` stream.
     pub type AsyncEventStream = stream_api::AsyncStream<flow::Events>;
+
+    /// Iterator for getting replayed events from the `WebSocket` stream.
+    pub(super) type EventReplayIterator = stream_api::SyncIterator<flow::ReplayEvents>;
+
+    /// Async stream for getting replayed events from the `WebSocket` stream.
+    pub type AsyncEventReplayStream = stream_api::AsyncStream<flow::ReplayEvents>;
 }
 
 mod blocks_api {
@@ -999,6 +1227,7 @@ mod tests {
             key_pair,
             account: account_id,
             torii_api_url: "http://127.0.0.1:8080".parse().unwrap(),
+            torii_tls_roots: TlsRoots::default(),
             basic_auth: None,
             transaction_add_nonce: false,
             transaction_ttl: Duration::from_secs(5),
@@ -15,6 +15,7 @@ use url::Url;
 use crate::{
     crypto::KeyPair,
     data_model::{prelude::*, ChainId},
+    http_default::TlsRoots,
 };
 
 mod user;
@@ -68,6 +69,8 @@ pub struct Config {
     pub key_pair: KeyPair,
     pub basic_auth: Option<BasicAuth>,
     pub torii_api_url: Url,
+    #[serde(skip)]
+    pub torii_tls_roots: TlsRoots,
     pub transaction_ttl: Duration,
     pub transaction_status_timeout: Duration,
     pub transaction_add_nonce: bool,
@@ -176,6 +179,30 @@ mod tests {
         assert_eq!(config.torii_api_url.as_str(), "http://127.0.0.1/peer-1/");
     }
 
+    #[test]
+    fn torii_ca_cert_file_without_certificates() {
+        use std::io::Write;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"not a certificate").unwrap();
+
+        let err = ConfigReader::new()
+            .with_toml_source(TomlSource::inline(config_sample()))
+            .with_env(MockEnv::from([(
+                "TORII_CA_CERT_FILE",
+                file.path().display().to_string(),
+            )]))
+            .read_and_complete::<user::Root>()
+            .unwrap()
+            .parse()
+            .expect_err("file contains no certificates");
+
+        assert_contains!(
+            format!("{err:?}"),
+            "Failed to load CA certificates of Torii"
+        );
+    }
+
     #[test]
     fn invalid_toml_file_is_handled_properly() {
         use std::io::Write;
@@ -1,5 +1,7 @@
 //! User configuration view.
 
+use std::path::PathBuf;
+
 use error_stack::{Report, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
@@ -12,6 +14,7 @@ use crate::{
     config::BasicAuth,
     crypto::{KeyPair, PrivateKey, PublicKey},
     data_model::prelude::{AccountId, ChainId, DomainId},
+    http_default::TlsRoots,
 };
 
 /// Root of the user configuration
@@ -21,6 +24,9 @@ pub struct Root {
     pub chain: ChainId,
     #[config(env = "TORII_URL")]
     pub torii_url: WithOrigin<Url>,
+    /// PEM file with CA certificates trusted in addition to the default roots if `torii_url` is `https`
+    #[config(env = "TORII_CA_CERT_FILE")]
+    pub torii_ca_cert_file: Option<WithOrigin<PathBuf>>,
     pub basic_auth: Option<BasicAuth>,
     #[config(nested)]
     pub account: Account,
@@ -36,6 +42,8 @@ pub enum ParseError {
     KeyPair,
     #[error("Unsupported URL scheme: `{scheme}`")]
     UnsupportedUrlScheme { scheme: String },
+    #[error("Failed to load CA certificates of Torii")]
+    TlsRoots,
 }
 
 impl Root {
@@ -48,10 +56,12 @@ impl Root {
         let Self {
             chain: chain_id,
             torii_url,
+            torii_ca_cert_file,
             basic_auth,
             account:
                 Account {
                     domain: domain_id,
+                    signatory,
                     public_key,
                     private_key,
                 },
@@ -87,6 +97,22 @@ impl Root {
                 .attach_printable("Note: only `http` and `https` protocols are supported"),
             ),
         }
+        let torii_tls_roots = torii_ca_cert_file
+            .map(|file| {
+                let path = file.resolve_relative_path();
+                std::fs::read(&path)
+                    .map_err(eyre::Report::from)
+                    .and_then(|pem| TlsRoots::from_pem(&pem))
+                    .map_err(|err| {
+                        Report::new(ParseError::TlsRoots)
+                            .attach_printable(err.to_string())
+                            .attach_printable(file.into_attachment().display_path())
+                    })
+            })
+            .transpose()
+            .ok_or_emit(&mut emitter)
+            .flatten()
+            .unwrap_or_default();
         let torii_api_url = {
             let mut url = torii_url.into_value();
             let path = url.path();
@@ -100,7 +126,7 @@ impl Root {
 
         let (public_key, public_key_origin) = public_key.into_tuple();
         let (private_key, private_key_origin) = private_key.into_tuple();
-        let account_id = AccountId::new(domain_id, public_key.clone());
+        let account_id = AccountId::new(domain_id, signatory.unwrap_or_else(|| public_key.clone()));
         let key_pair = KeyPair::new(public_key, private_key)
             .attach_printable(ConfigValueAndOrigin::new("[REDACTED]", public_key_origin))
             .attach_printable(ConfigValueAndOrigin::new("[REDACTED]", private_key_origin))
@@ -114,6 +140,7 @@ impl Root {
             account: account_id,
             key_pair: key_pair.unwrap(),
             torii_api_url,
+            torii_tls_roots,
             basic_auth,
             transaction_ttl: tx_ttl.into_value().get(),
             transaction_status_timeout: tx_timeout.into_value().get(),
@@ -126,6 +153,8 @@ impl Root {
 #[allow(missing_docs)]
 pub struct Account {
     pub domain: DomainId,
+    /// Public key the account was created with, if it differs from `public_key` after a key rotation
+    pub signatory: Option<PublicKey>,
     pub public_key: WithOrigin<PublicKey>,
     pub private_key: WithOrigin<PrivateKey>,
 }
@@ -151,7 +180,10 @@ mod tests {
 
     #[test]
     fn parses_all_envs() {
-        let env = MockEnv::from([("TORII_URL", "http://localhost:8080")]);
+        let env = MockEnv::from([
+            ("TORII_URL", "http://localhost:8080"),
+            ("TORII_CA_CERT_FILE", "ca.pem"),
+        ]);
 
         let _ = ConfigReader::new()
             .with_env(env.clone())
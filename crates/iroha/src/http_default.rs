@@ -1,11 +1,12 @@
 //! Defaults for various items used in communication over http(s).
-use std::net::TcpStream;
+use std::{net::TcpStream, sync::Arc};
 
 use attohttpc::{
     body as atto_body, RequestBuilder as AttoHttpRequestBuilder, Response as AttoHttpResponse,
 };
 use eyre::{eyre, Error, Result, WrapErr};
-use http::header::HeaderName;
+use http::header::{HeaderName, HeaderValue};
+use rustls_pki_types::CertificateDer;
 use tungstenite::{client::IntoClientRequest, stream::MaybeTlsStream, WebSocket};
 pub use tungstenite::{Error as WebSocketError, Message as WebSocketMessage};
 use url::Url;
@@ -20,6 +21,160 @@ fn header_name_from_str(str: &str) -> Result<HeaderName> {
         .wrap_err_with(|| format!("Failed to parse header name {str}"))
 }
 
+/// CA certificates trusted in addition to the default roots when connecting to Torii over TLS.
+///
+/// Useful if Torii uses a certificate issued by a private CA, e.g. a self-signed one.
+/// Ignored if none of the `tls-*` features is enabled.
+#[derive(Debug, Clone, Default)]
+pub struct TlsRoots(Arc<[CertificateDer<'static>]>);
+
+impl TlsRoots {
+    /// Parse PEM-encoded CA certificates.
+    ///
+    /// # Errors
+    /// Fails if `pem` is malformed or contains no certificates
+    pub fn from_pem(pem: &[u8]) -> Result<Self> {
+        let certs = rustls_pemfile::certs(&mut &*pem)
+            .collect::<Result<Vec<_>, _>>()
+            .wrap_err("Failed to parse PEM certificates")?;
+        if certs.is_empty() {
+            return Err(eyre!("No certificates found"));
+        }
+
+        Ok(Self(certs.into()))
+    }
+
+    /// Whether there are no additional CA certificates
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Trust the certificates in requests made by the `reqwest` client being built.
+    ///
+    /// # Errors
+    /// Fails if a certificate isn't supported by the TLS backend
+    #[allow(unused_mut)]
+    pub fn add_to_reqwest(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder> {
+        #[cfg(any(
+            feature = "tls-native",
+            feature = "tls-native-vendored",
+            feature = "tls-rustls-native-roots",
+            feature = "tls-rustls-webpki-roots"
+        ))]
+        for cert in self.0.iter() {
+            builder = builder.add_root_certificate(
+                reqwest::Certificate::from_der(cert).wrap_err("Unsupported CA certificate")?,
+            );
+        }
+        Ok(builder)
+    }
+}
+
+/// Conversion of [`TlsRoots`] into configurations of the TLS backend selected by features.
+///
+/// `native-tls` takes precedence if both backends are enabled, the same as in `attohttpc`.
+#[cfg(any(
+    feature = "tls-native",
+    feature = "tls-native-vendored",
+    feature = "tls-rustls-native-roots",
+    feature = "tls-rustls-webpki-roots"
+))]
+mod tls {
+    use super::*;
+
+    #[cfg(any(feature = "tls-native", feature = "tls-native-vendored"))]
+    type Certificate = native_tls::Certificate;
+    #[cfg(not(any(feature = "tls-native", feature = "tls-native-vendored")))]
+    type Certificate = CertificateDer<'static>;
+
+    impl TlsRoots {
+        fn certificates(&self) -> Result<Vec<Certificate>> {
+            self.0
+                .iter()
+                .map(|cert| {
+                    #[cfg(any(feature = "tls-native", feature = "tls-native-vendored"))]
+                    {
+                        native_tls::Certificate::from_der(cert)
+                            .wrap_err("Unsupported CA certificate")
+                    }
+                    #[cfg(not(any(feature = "tls-native", feature = "tls-native-vendored")))]
+                    {
+                        Ok(cert.clone())
+                    }
+                })
+                .collect()
+        }
+
+        pub(super) fn add_to_attohttpc(
+            &self,
+            builder: AttoHttpRequestBuilder,
+        ) -> Result<AttoHttpRequestBuilder> {
+            Ok(self
+                .certificates()?
+                .into_iter()
+                .fold(builder, AttoHttpRequestBuilder::add_root_certificate))
+        }
+
+        #[cfg(any(feature = "tls-native", feature = "tls-native-vendored"))]
+        fn native_tls_connector(&self) -> Result<native_tls::TlsConnector> {
+            let mut builder = native_tls::TlsConnector::builder();
+            for cert in self.certificates()? {
+                builder.add_root_certificate(cert);
+            }
+            builder.build().wrap_err("Failed to configure TLS")
+        }
+
+        #[cfg(not(any(feature = "tls-native", feature = "tls-native-vendored")))]
+        fn rustls_config(&self) -> Result<Arc<tungstenite_rustls::ClientConfig>> {
+            let mut roots = tungstenite_rustls::RootCertStore::empty();
+            #[cfg(feature = "tls-rustls-native-roots")]
+            roots.add_parsable_certificates(
+                rustls_native_certs::load_native_certs()
+                    .wrap_err("Failed to load native root certificates")?,
+            );
+            #[cfg(feature = "tls-rustls-webpki-roots")]
+            roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+            for cert in self.certificates()? {
+                roots.add(cert).wrap_err("Unsupported CA certificate")?;
+            }
+
+            Ok(Arc::new(
+                tungstenite_rustls::ClientConfig::builder()
+                    .with_root_certificates(roots)
+                    .with_no_client_auth(),
+            ))
+        }
+
+        pub(super) fn websocket_connector(&self) -> Result<tungstenite::Connector> {
+            #[cfg(any(feature = "tls-native", feature = "tls-native-vendored"))]
+            {
+                self.native_tls_connector()
+                    .map(tungstenite::Connector::NativeTls)
+            }
+            #[cfg(not(any(feature = "tls-native", feature = "tls-native-vendored")))]
+            {
+                self.rustls_config().map(tungstenite::Connector::Rustls)
+            }
+        }
+
+        pub(super) fn async_websocket_connector(&self) -> Result<tokio_tungstenite::Connector> {
+            #[cfg(any(feature = "tls-native", feature = "tls-native-vendored"))]
+            {
+                self.native_tls_connector()
+                    .map(tokio_tungstenite::Connector::NativeTls)
+            }
+            #[cfg(not(any(feature = "tls-native", feature = "tls-native-vendored")))]
+            {
+                self.rustls_config()
+                    .map(tokio_tungstenite::Connector::Rustls)
+            }
+        }
+    }
+}
+
 /// Default request builder implemented on top of `attohttpc` crate.
 #[derive(Debug)]
 pub struct DefaultRequestBuilder {
@@ -39,6 +194,30 @@ impl DefaultRequestBuilder {
         }
     }
 
+    /// Trust `roots` in addition to the default CA certificates.
+    #[must_use]
+    pub fn tls_roots(self, roots: &TlsRoots) -> Self {
+        #[cfg(any(
+            feature = "tls-native",
+            feature = "tls-native-vendored",
+            feature = "tls-rustls-native-roots",
+            feature = "tls-rustls-webpki-roots"
+        ))]
+        {
+            self.and_then(|builder| roots.add_to_attohttpc(builder))
+        }
+        #[cfg(not(any(
+            feature = "tls-native",
+            feature = "tls-native-vendored",
+            feature = "tls-rustls-native-roots",
+            feature = "tls-rustls-webpki-roots"
+        )))]
+        {
+            let _ = roots;
+            self
+        }
+    }
+
     /// Build request by consuming self.
     pub fn build(self) -> Result<DefaultRequest> {
         self.inner
@@ -96,6 +275,99 @@ impl RequestBuilder for DefaultRequestBuilder {
     }
 }
 
+/// Asynchronous request builder implemented on top of `reqwest` crate.
+#[derive(Debug)]
+pub struct AsyncRequestBuilder {
+    inner: Result<reqwest::Request>,
+}
+
+impl AsyncRequestBuilder {
+    /// Same as [`DefaultRequestBuilder::and_then`].
+    fn and_then<F>(self, fun: F) -> Self
+    where
+        F: FnOnce(reqwest::Request) -> Result<reqwest::Request>,
+    {
+        Self {
+            inner: self.inner.and_then(fun),
+        }
+    }
+
+    /// Build request by consuming self.
+    pub fn build(self) -> Result<AsyncRequest> {
+        self.inner.map(AsyncRequest)
+    }
+}
+
+/// Request built by [`AsyncRequestBuilder`].
+#[derive(Debug)]
+pub struct AsyncRequest(reqwest::Request);
+
+impl AsyncRequest {
+    /// Sends itself with the given `client` and returns byte response
+    ///
+    /// # Errors
+    /// Fails if request sending fails or response transformation fails
+    pub async fn send(self, client: &reqwest::Client) -> Result<Response<Bytes>> {
+        let (method, url) = (self.0.method().clone(), self.0.url().clone());
+
+        let response = client
+            .execute(self.0)
+            .await
+            .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
+
+        let mut builder = Response::builder().status(response.status());
+        let headers = builder
+            .headers_mut()
+            .ok_or_else(|| eyre!("Failed to get headers map reference."))?;
+        for (key, value) in response.headers() {
+            headers.insert(key, value.clone());
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .wrap_err("Failed to get response as bytes")?;
+        builder
+            .body(bytes.to_vec())
+            .wrap_err("Failed to construct response bytes body")
+    }
+}
+
+impl RequestBuilder for AsyncRequestBuilder {
+    fn new(method: Method, url: Url) -> Self {
+        Self {
+            inner: Ok(reqwest::Request::new(method, url)),
+        }
+    }
+
+    fn header<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
+        self.and_then(|mut request| {
+            let value = HeaderValue::try_from(value.to_string())
+                .wrap_err_with(|| format!("Failed to parse value of header {}", key.as_ref()))?;
+            request
+                .headers_mut()
+                .insert(header_name_from_str(key.as_ref())?, value);
+            Ok(request)
+        })
+    }
+
+    fn param<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
+        self.and_then(|mut request| {
+            request
+                .url_mut()
+                .query_pairs_mut()
+                .append_pair(key.as_ref(), &value.to_string());
+            Ok(request)
+        })
+    }
+
+    fn body(self, data: Vec<u8>) -> Self {
+        self.and_then(|mut request| {
+            *request.body_mut() = Some(data.into());
+            Ok(request)
+        })
+    }
+}
+
 /// Request builder built on top of [`http::request::Builder`]. Used for `WebSocket` connections.
 pub struct DefaultWebSocketRequestBuilder(Result<http::request::Builder>);
 
@@ -109,7 +381,9 @@ impl DefaultWebSocketRequestBuilder {
     }
 
     /// Consumes itself to build request.
-    pub fn build(self) -> Result<DefaultWebSocketStreamRequest> {
+    ///
+    /// The connection will trust `tls_roots` in addition to the default CA certificates.
+    pub fn build(self, tls_roots: &TlsRoots) -> Result<DefaultWebSocketStreamRequest> {
         let builder = self.0?;
         let mut request = builder
             .uri_ref()
@@ -121,23 +395,84 @@ impl DefaultWebSocketRequestBuilder {
         {
             request.headers_mut().entry(header).or_insert(value.clone());
         }
-        Ok(DefaultWebSocketStreamRequest(request))
+        Ok(DefaultWebSocketStreamRequest {
+            request,
+            tls_roots: tls_roots.clone(),
+        })
     }
 }
 
 /// `WebSocket` request built by [`DefaultWebSocketRequestBuilder`]
-pub struct DefaultWebSocketStreamRequest(http::Request<()>);
+pub struct DefaultWebSocketStreamRequest {
+    request: http::Request<()>,
+    tls_roots: TlsRoots,
+}
 
 impl DefaultWebSocketStreamRequest {
     /// Open [`WebSocketStream`] synchronously.
     pub fn connect(self) -> Result<WebSocketStream> {
-        let (stream, _) = tungstenite::connect(self.0)?;
+        #[cfg(any(
+            feature = "tls-native",
+            feature = "tls-native-vendored",
+            feature = "tls-rustls-native-roots",
+            feature = "tls-rustls-webpki-roots"
+        ))]
+        if !self.tls_roots.is_empty() && self.request.uri().scheme_str() == Some("wss") {
+            return self.connect_with_tls_roots();
+        }
+
+        let (stream, _) = tungstenite::connect(self.request)?;
         Ok(stream)
     }
 
+    #[cfg(any(
+        feature = "tls-native",
+        feature = "tls-native-vendored",
+        feature = "tls-rustls-native-roots",
+        feature = "tls-rustls-webpki-roots"
+    ))]
+    fn connect_with_tls_roots(self) -> Result<WebSocketStream> {
+        let uri = self.request.uri();
+        let host = uri
+            .host()
+            .ok_or_else(|| eyre!("Missing host in {uri}"))?
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let port = uri.port_u16().unwrap_or(443);
+        let tcp_stream = TcpStream::connect((host, port))
+            .wrap_err_with(|| format!("Failed to connect to {host}:{port}"))?;
+        let connector = self.tls_roots.websocket_connector()?;
+
+        match tungstenite::client_tls_with_config(self.request, tcp_stream, None, Some(connector)) {
+            Ok((stream, _)) => Ok(stream),
+            Err(tungstenite::HandshakeError::Failure(error)) => Err(error.into()),
+            Err(tungstenite::HandshakeError::Interrupted(_)) => {
+                Err(eyre!("WebSocket handshake was interrupted"))
+            }
+        }
+    }
+
     /// Open [`AsyncWebSocketStream`].
     pub async fn connect_async(self) -> Result<AsyncWebSocketStream> {
-        let (stream, _) = tokio_tungstenite::connect_async(self.0).await?;
+        #[cfg(any(
+            feature = "tls-native",
+            feature = "tls-native-vendored",
+            feature = "tls-rustls-native-roots",
+            feature = "tls-rustls-webpki-roots"
+        ))]
+        if !self.tls_roots.is_empty() {
+            let connector = self.tls_roots.async_websocket_connector()?;
+            let (stream, _) = tokio_tungstenite::connect_async_tls_with_config(
+                self.request,
+                None,
+                false,
+                Some(connector),
+            )
+            .await?;
+            return Ok(stream);
+        }
+
+        let (stream, _) = tokio_tungstenite::connect_async(self.request).await?;
         Ok(stream)
     }
 }
@@ -1,5 +1,6 @@
 //! Crate contains client which talks to Iroha network via http
 
+pub mod async_client;
 pub mod client;
 pub mod config;
 pub mod http;
@@ -1,6 +1,6 @@
 //! Functions and types to make queries to the Iroha peer.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, num::NonZeroU64};
 
 use eyre::{eyre, Context, Result};
 use http::StatusCode;
@@ -18,30 +18,31 @@ use crate::{
             builder::{QueryBuilder, QueryExecutor},
             parameters::ForwardCursor,
             Query, QueryOutput, QueryRequest, QueryResponse, QueryWithParams, SingularQuery,
-            SingularQueryBox, SingularQueryOutputBox,
+            SingularQueryBox, SingularQueryOutputBox, SingularQueryWithParams,
         },
         ValidationFail,
     },
     http::{Method as HttpMethod, RequestBuilder},
-    http_default::DefaultRequestBuilder,
+    http_default::{DefaultRequestBuilder, TlsRoots},
 };
 
 #[derive(Debug)]
-struct ClientQueryRequestHead {
+pub(crate) struct ClientQueryRequestHead {
     torii_url: Url,
     headers: HashMap<String, String>,
     account_id: AccountId,
     key_pair: KeyPair,
+    tls_roots: TlsRoots,
 }
 
 impl ClientQueryRequestHead {
-    fn assemble(&self, query: QueryRequest) -> DefaultRequestBuilder {
+    pub(crate) fn assemble<B: RequestBuilder>(&self, query: QueryRequest) -> B {
         // authorize and sign the query
         let query = query
             .with_authority(self.account_id.clone())
             .sign(&self.key_pair);
 
-        DefaultRequestBuilder::new(
+        B::new(
             HttpMethod::POST,
             join_torii_url(&self.torii_url, torii_uri::QUERY),
         )
@@ -51,7 +52,7 @@ impl ClientQueryRequestHead {
 }
 
 /// Decode a raw response from the node's query endpoint
-fn decode_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryResponse> {
+pub(crate) fn decode_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryResponse> {
     match resp.status() {
         StatusCode::OK => {
             let res = QueryResponse::decode_all(&mut resp.body().as_slice());
@@ -89,7 +90,7 @@ fn decode_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryRes
     }
 }
 
-fn decode_singular_query_response(
+pub(crate) fn decode_singular_query_response(
     resp: &http::Response<Vec<u8>>,
 ) -> QueryResult<SingularQueryOutputBox> {
     let QueryResponse::Singular(resp) = decode_query_response(resp)? else {
@@ -101,7 +102,9 @@ fn decode_singular_query_response(
     Ok(resp)
 }
 
-fn decode_iterable_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryOutput> {
+pub(crate) fn decode_iterable_query_response(
+    resp: &http::Response<Vec<u8>>,
+) -> QueryResult<QueryOutput> {
     let QueryResponse::Iterable(resp) = decode_query_response(resp)? else {
         return Err(eyre!(
             "Got unexpected type of query response from the node (expected iterable)"
@@ -143,13 +146,17 @@ impl QueryExecutor for Client {
 
     fn execute_singular_query(
         &self,
-        query: SingularQueryBox,
+        query: SingularQueryWithParams,
     ) -> Result<SingularQueryOutputBox, Self::Error> {
         let request_head = self.get_query_request_head();
 
         let request = QueryRequest::Singular(query);
 
-        let response = request_head.assemble(request).build()?.send()?;
+        let response = request_head
+            .assemble::<DefaultRequestBuilder>(request)
+            .tls_roots(&request_head.tls_roots)
+            .build()?
+            .send()?;
         let response = decode_singular_query_response(&response)?;
 
         Ok(response)
@@ -163,7 +170,11 @@ impl QueryExecutor for Client {
 
         let request = QueryRequest::Start(query);
 
-        let response = request_head.assemble(request).build()?.send()?;
+        let response = request_head
+            .assemble::<DefaultRequestBuilder>(request)
+            .tls_roots(&request_head.tls_roots)
+            .build()?
+            .send()?;
         let response = decode_iterable_query_response(&response)?;
 
         let (batch, remaining_items, cursor) = response.into_parts();
@@ -186,7 +197,11 @@ impl QueryExecutor for Client {
 
         let request = QueryRequest::Continue(cursor);
 
-        let response = request_head.assemble(request).build()?.send()?;
+        let response = request_head
+            .assemble::<DefaultRequestBuilder>(request)
+            .tls_roots(&request_head.tls_roots)
+            .build()?
+            .send()?;
         let response = decode_iterable_query_response(&response)?;
 
         let (batch, remaining_items, cursor) = response.into_parts();
@@ -204,12 +219,13 @@ impl Client {
     /// Get a [`ClientQueryRequestHead`] - an object that can be used to make queries independently of the client.
     ///
     /// You probably do not want to use it directly, but rather use [`Client::query_single`] or [`Client::query`].
-    fn get_query_request_head(&self) -> ClientQueryRequestHead {
+    pub(crate) fn get_query_request_head(&self) -> ClientQueryRequestHead {
         ClientQueryRequestHead {
             torii_url: self.torii_url.clone(),
             headers: self.headers.clone(),
             account_id: self.account.clone(),
             key_pair: self.key_pair.clone(),
+            tls_roots: self.tls_roots.clone(),
         }
     }
 
@@ -225,7 +241,42 @@ impl Client {
         Q::Output: TryFrom<SingularQueryOutputBox>,
         <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
     {
-        let query = SingularQueryBox::from(query);
+        self.query_single_with_height(query, None)
+    }
+
+    /// Execute a singular query against the state right after the block at `height` was committed.
+    ///
+    /// How far back the state can be queried is limited by the peer configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn query_single_at_height<Q>(
+        &self,
+        query: Q,
+        height: NonZeroU64,
+    ) -> Result<Q::Output, QueryError>
+    where
+        Q: SingularQuery,
+        SingularQueryBox: From<Q>,
+        Q::Output: TryFrom<SingularQueryOutputBox>,
+        <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
+    {
+        self.query_single_with_height(query, Some(height))
+    }
+
+    fn query_single_with_height<Q>(
+        &self,
+        query: Q,
+        at_height: Option<NonZeroU64>,
+    ) -> Result<Q::Output, QueryError>
+    where
+        Q: SingularQuery,
+        SingularQueryBox: From<Q>,
+        Q::Output: TryFrom<SingularQueryOutputBox>,
+        <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
+    {
+        let query = SingularQueryWithParams::new(SingularQueryBox::from(query), at_height);
 
         let result = self.execute_singular_query(query)?;
 
@@ -257,7 +308,11 @@ impl Client {
 
         let request = QueryRequest::Continue(cursor);
 
-        let response = request_head.assemble(request).build()?.send()?;
+        let response = request_head
+            .assemble::<DefaultRequestBuilder>(request)
+            .tls_roots(&request_head.tls_roots)
+            .build()?
+            .send()?;
         let response = decode_query_response(&response)?;
 
         Ok(response)
@@ -7,6 +7,7 @@ use std::{
     fs,
     io::{self, Read, Write},
     path::PathBuf,
+    str::FromStr,
     time::Duration,
 };
 
@@ -14,7 +15,11 @@ use erased_serde::Serialize;
 use error_stack::{fmt::ColorMode, IntoReportCompat, ResultExt};
 use eyre::{eyre, Result, WrapErr};
 use futures::TryStreamExt;
-use iroha::{client::Client, config::Config, data_model::prelude::*};
+use iroha::{
+    client::Client,
+    config::Config,
+    data_model::{prelude::*, ParseError},
+};
 use thiserror::Error;
 use tokio::runtime::Runtime;
 
@@ -116,6 +121,28 @@ trait RunContext {
         Client::new(self.config().clone())
     }
 
+    /// Look up the ID of an account given by its alias
+    fn resolve_account(&self, account: AccountArg) -> Result<AccountId> {
+        match account {
+            AccountArg::Id(id) => Ok(id),
+            AccountArg::Alias(alias) => self
+                .client_from_config()
+                .query_single(FindAccountByAlias::new(alias.clone()))
+                .wrap_err_with(|| format!("Failed to resolve `{alias}` account alias")),
+        }
+    }
+
+    /// Look up the ID of an asset whose account is given by its alias
+    fn resolve_asset(&self, asset: AssetArg) -> Result<AssetId> {
+        match asset {
+            AssetArg::Id(id) => Ok(id),
+            AssetArg::Aliased { definition, alias } => {
+                let account = self.resolve_account(AccountArg::Alias(alias))?;
+                Ok(format!("{definition}#{account}").parse()?)
+            }
+        }
+    }
+
     /// Submit instructions or dump them to stdout depending on the flag
     fn finish(&mut self, instructions: impl Into<Executable>) -> Result<()> {
         let mut instructions = match instructions.into() {
@@ -213,6 +240,52 @@ impl<W: std::io::Write> RunContext for PrintJsonContext<W> {
     }
 }
 
+/// Account given either by its ID or by its alias
+#[derive(Debug, Clone)]
+enum AccountArg {
+    Id(AccountId),
+    Alias(AccountAlias),
+}
+
+impl FromStr for AccountArg {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(Self::Id)
+            .or_else(|_| s.parse().map(Self::Alias))
+    }
+}
+
+/// Asset given either by its ID or with its account given by alias
+#[derive(Debug, Clone)]
+enum AssetArg {
+    Id(AssetId),
+    Aliased {
+        /// The `asset#domain` or `asset#` part
+        definition: String,
+        alias: AccountAlias,
+    },
+}
+
+impl FromStr for AssetArg {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = match s.parse() {
+            Ok(id) => return Ok(Self::Id(id)),
+            Err(err) => err,
+        };
+        let Some((definition, alias)) = s.rsplit_once('#') else {
+            return Err(err);
+        };
+        Ok(Self::Aliased {
+            definition: definition.to_owned(),
+            alias: alias.parse()?,
+        })
+    }
+}
+
 /// Runs command
 trait Run {
     /// Runs command
@@ -386,6 +459,31 @@ mod events {
         TriggerExecute,
         /// Notify when a trigger execution is completed
         TriggerComplete,
+        /// Notify when a smart contract or trigger emits a custom event
+        Custom(CustomArgs),
+    }
+
+    #[derive(clap::Args, Debug)]
+    struct CustomArgs {
+        /// Only events emitted on behalf of this account, in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        emitter: Option<AccountArg>,
+        /// Only events with this name
+        #[arg(short, long)]
+        name: Option<Name>,
+    }
+
+    impl CustomArgs {
+        fn into_filter(self, context: &impl RunContext) -> Result<CustomEventFilter> {
+            let mut filter = CustomEventFilter::new();
+            if let Some(emitter) = self.emitter {
+                filter = filter.for_emitter(context.resolve_account(emitter)?);
+            }
+            if let Some(name) = self.name {
+                filter = filter.for_name(name);
+            }
+            Ok(filter)
+        }
     }
 
     impl Run for Args {
@@ -399,6 +497,10 @@ mod events {
                 Block => listen(BlockEventFilter::default(), context, timeout),
                 TriggerExecute => listen(ExecuteTriggerEventFilter::new(), context, timeout),
                 TriggerComplete => listen(TriggerCompletedEventFilter::new(), context, timeout),
+                Custom(args) => {
+                    let filter = args.into_filter(context)?;
+                    listen(filter, context, timeout)
+                }
             }
         }
     }
@@ -578,8 +680,9 @@ mod domain {
                         .wrap_err("Failed to unregister domain")
                 }
                 Transfer(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Transfer::domain(args.from, args.id, args.to);
+                    let from = context.resolve_account(args.from)?;
+                    let to = context.resolve_account(args.to)?;
+                    let instruction = iroha::data_model::isi::Transfer::domain(from, args.id, to);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to transfer domain")
@@ -594,12 +697,12 @@ mod domain {
         /// Domain name
         #[arg(short, long)]
         pub id: DomainId,
-        /// Source account, in the format "multihash@domain"
+        /// Source account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub from: AccountId,
-        /// Destination account, in the format "multihash@domain"
+        pub from: AccountArg,
+        /// Destination account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountArg,
     }
 
     #[derive(clap::Args, Debug)]
@@ -631,7 +734,7 @@ mod account {
         /// Retrieve details of a specific account
         Get(Id),
         /// Register an account
-        Register(Id),
+        Register(Register),
         /// Unregister an account
         Unregister(Id),
         /// Read and write metadata
@@ -647,10 +750,11 @@ mod account {
                 Permission(cmd) => cmd.run(context),
                 List(cmd) => cmd.run(context),
                 Get(args) => {
+                    let id = context.resolve_account(args.id)?;
                     let client = context.client_from_config();
                     let entry = client
                         .query(FindAccounts)
-                        .filter_with(|entry| entry.id.eq(args.id))
+                        .filter_with(|entry| entry.id.eq(id))
                         .execute_single()
                         .wrap_err("Failed to get account")?;
                     context.print_data(&entry)
@@ -663,7 +767,8 @@ mod account {
                         .wrap_err("Failed to register account")
                 }
                 Unregister(args) => {
-                    let instruction = iroha::data_model::isi::Unregister::account(args.id);
+                    let id = context.resolve_account(args.id)?;
+                    let instruction = iroha::data_model::isi::Unregister::account(id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to unregister account")
@@ -688,22 +793,21 @@ mod account {
             use self::RoleCommand::*;
             match self {
                 List(args) => {
+                    let id = context.resolve_account(args.id)?;
                     let client = context.client_from_config();
-                    let roles = client
-                        .query(FindRolesByAccountId::new(args.id))
-                        .execute_all()?;
+                    let roles = client.query(FindRolesByAccountId::new(id)).execute_all()?;
                     context.print_data(&roles)
                 }
                 Grant(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Grant::account_role(args.role, args.id);
+                    let id = context.resolve_account(args.id)?;
+                    let instruction = iroha::data_model::isi::Grant::account_role(args.role, id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to grant the role to the account")
                 }
                 Revoke(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Revoke::account_role(args.role, args.id);
+                    let id = context.resolve_account(args.id)?;
+                    let instruction = iroha::data_model::isi::Revoke::account_role(args.role, id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to revoke the role from the account")
@@ -727,24 +831,27 @@ mod account {
             use self::PermissionCommand::*;
             match self {
                 List(args) => {
+                    let id = context.resolve_account(args.id)?;
                     let client = context.client_from_config();
                     let permissions = client
-                        .query(FindPermissionsByAccountId::new(args.id))
+                        .query(FindPermissionsByAccountId::new(id))
                         .execute_all()?;
                     context.print_data(&permissions)
                 }
                 Grant(args) => {
+                    let id = context.resolve_account(args.id)?;
                     let permission: Permission = parse_json5_stdin(context)?;
                     let instruction =
-                        iroha::data_model::isi::Grant::account_permission(permission, args.id);
+                        iroha::data_model::isi::Grant::account_permission(permission, id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to grant the permission to the account")
                 }
                 Revoke(args) => {
+                    let id = context.resolve_account(args.id)?;
                     let permission: Permission = parse_json5_stdin(context)?;
                     let instruction =
-                        iroha::data_model::isi::Revoke::account_permission(permission, args.id);
+                        iroha::data_model::isi::Revoke::account_permission(permission, id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to revoke the permission from the account")
@@ -754,17 +861,24 @@ mod account {
     }
 
     #[derive(clap::Args, Debug)]
-    pub struct Id {
+    pub struct Register {
         /// Account in the format "multihash@domain"
         #[arg(short, long)]
         id: AccountId,
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct Id {
+        /// Account in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        id: AccountArg,
+    }
+
     #[derive(clap::Args, Debug)]
     pub struct IdRole {
-        /// Account in the format "multihash@domain"
+        /// Account in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub id: AccountId,
+        pub id: AccountArg,
         /// Role name
         #[arg(short, long)]
         pub role: RoleId,
@@ -800,35 +914,37 @@ mod asset {
             match self {
                 Definition(cmd) => cmd.run(context),
                 Get(args) => {
+                    let id = context.resolve_asset(args.id)?;
                     let client = context.client_from_config();
                     let entry = client
                         .query(FindAssets)
-                        .filter_with(|entry| entry.id.eq(args.id))
+                        .filter_with(|entry| entry.id.eq(id))
                         .execute_single()
                         .wrap_err("Failed to get asset")?;
                     context.print_data(&entry)
                 }
                 List(cmd) => cmd.run(context),
                 Mint(args) => {
+                    let id = context.resolve_asset(args.id)?;
                     let instruction =
-                        iroha::data_model::isi::Mint::asset_numeric(args.quantity, args.id);
+                        iroha::data_model::isi::Mint::asset_numeric(args.quantity, id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to mint numeric asset")
                 }
                 Burn(args) => {
+                    let id = context.resolve_asset(args.id)?;
                     let instruction =
-                        iroha::data_model::isi::Burn::asset_numeric(args.quantity, args.id);
+                        iroha::data_model::isi::Burn::asset_numeric(args.quantity, id);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to burn numeric asset")
                 }
                 Transfer(args) => {
-                    let instruction = iroha::data_model::isi::Transfer::asset_numeric(
-                        args.id,
-                        args.quantity,
-                        args.to,
-                    );
+                    let id = context.resolve_asset(args.id)?;
+                    let to = context.resolve_account(args.to)?;
+                    let instruction =
+                        iroha::data_model::isi::Transfer::asset_numeric(id, args.quantity, to);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to transfer numeric asset")
@@ -892,9 +1008,10 @@ mod asset {
                             .wrap_err("Failed to unregister asset")
                     }
                     Transfer(args) => {
-                        let instruction = iroha::data_model::isi::Transfer::asset_definition(
-                            args.from, args.id, args.to,
-                        );
+                        let from = context.resolve_account(args.from)?;
+                        let to = context.resolve_account(args.to)?;
+                        let instruction =
+                            iroha::data_model::isi::Transfer::asset_definition(from, args.id, to);
                         context
                             .finish([instruction])
                             .wrap_err("Failed to transfer asset definition")
@@ -922,12 +1039,12 @@ mod asset {
             /// Asset definition in the format "asset#domain"
             #[arg(short, long)]
             pub id: AssetDefinitionId,
-            /// Source account, in the format "multihash@domain"
+            /// Source account, in the format "multihash@domain" or "alias@domain"
             #[arg(short, long)]
-            pub from: AccountId,
-            /// Destination account, in the format "multihash@domain"
+            pub from: AccountArg,
+            /// Destination account, in the format "multihash@domain" or "alias@domain"
             #[arg(short, long)]
-            pub to: AccountId,
+            pub to: AccountArg,
         }
 
         #[derive(clap::Args, Debug)]
@@ -942,12 +1059,13 @@ mod asset {
 
     #[derive(clap::Args, Debug)]
     pub struct Transfer {
-        /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain",
+        /// where the account may also be given by its "alias@domain"
         #[arg(short, long)]
-        pub id: AssetId,
-        /// Destination account, in the format "multihash@domain"
+        pub id: AssetArg,
+        /// Destination account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountArg,
         /// Transfer amount (integer or decimal)
         #[arg(short, long)]
         pub quantity: Numeric,
@@ -955,16 +1073,18 @@ mod asset {
 
     #[derive(clap::Args, Debug)]
     pub struct Id {
-        /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain",
+        /// where the account may also be given by its "alias@domain"
         #[arg(short, long)]
-        pub id: AssetId,
+        pub id: AssetArg,
     }
 
     #[derive(clap::Args, Debug)]
     pub struct IdQuantity {
-        /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain",
+        /// where the account may also be given by its "alias@domain"
         #[arg(short, long)]
-        pub id: AssetId,
+        pub id: AssetArg,
         /// Amount of change (integer or decimal)
         #[arg(short, long)]
         pub quantity: Numeric,
@@ -1029,8 +1149,9 @@ mod nft {
                         .wrap_err("Failed to unregister NFT")
                 }
                 Transfer(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Transfer::nft(args.from, args.id, args.to);
+                    let from = context.resolve_account(args.from)?;
+                    let to = context.resolve_account(args.to)?;
+                    let instruction = iroha::data_model::isi::Transfer::nft(from, args.id, to);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to transfer NFT")
@@ -1065,12 +1186,12 @@ mod nft {
         /// NFT in the format "name$domain"
         #[arg(short, long)]
         pub id: NftId,
-        /// Source account, in the format "multihash@domain"
+        /// Source account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub from: AccountId,
-        /// Destination account, in the format "multihash@domain"
+        pub from: AccountArg,
+        /// Destination account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountArg,
     }
 
     #[derive(clap::Args, Debug)]
@@ -1104,6 +1225,10 @@ mod peer {
         Register(Id),
         /// Unregister a peer
         Unregister(Id),
+        /// Show the status of the connected peer. Requires the client key to be an admin key of the peer
+        Status,
+        /// Update the configuration of the connected peer using JSON5 input from stdin. Requires the client key to be an admin key of the peer
+        Configure,
     }
 
     impl Run for Command {
@@ -1123,6 +1248,18 @@ mod peer {
                         .finish([instruction])
                         .wrap_err("Failed to unregister peer")
                 }
+                Status => {
+                    let client = context.client_from_config();
+                    let status = client.get_status().wrap_err("Failed to get peer status")?;
+                    context.print_data(&status)
+                }
+                Configure => {
+                    let update: iroha::client::ConfigUpdateDTO = parse_json5_stdin(context)?;
+                    let client = context.client_from_config();
+                    client
+                        .set_config(&update)
+                        .wrap_err("Failed to update peer configuration")
+                }
             }
         }
     }
@@ -1187,9 +1324,9 @@ mod multisig {
         /// ID of the multisig account to be registered
         #[arg(short, long)]
         pub account: AccountId,
-        /// List of signatories for the multisig account
+        /// List of signatories for the multisig account, each either "multihash@domain" or "alias@domain"
         #[arg(short, long, num_args(2..))]
-        pub signatories: Vec<AccountId>,
+        pub signatories: Vec<AccountArg>,
         /// Relative weights of signatories' responsibilities
         #[arg(short, long, num_args(2..))]
         pub weights: Vec<u8>,
@@ -1211,10 +1348,15 @@ mod multisig {
             if self.signatories.len() != self.weights.len() {
                 return Err(eyre!("signatories and weights must be equal in length"));
             }
+            let signatories = self
+                .signatories
+                .into_iter()
+                .map(|signatory| context.resolve_account(signatory))
+                .collect::<Result<Vec<_>>>()?;
             let instruction = MultisigRegister::new(
                 self.account,
                 MultisigSpec::new(
-                    self.signatories.into_iter().zip(self.weights).collect(),
+                    signatories.into_iter().zip(self.weights).collect(),
                     NonZeroU16::new(self.quorum).expect("quorum should not be 0"),
                     self.transaction_ttl
                         .as_millis()
@@ -1233,9 +1375,9 @@ mod multisig {
 
     #[derive(clap::Args, Debug)]
     pub struct Propose {
-        /// Multisig authority managing the proposed transaction
+        /// Multisig authority managing the proposed transaction, either "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub account: AccountId,
+        pub account: AccountArg,
         /// Overrides the default time-to-live for this transaction.
         /// Example: "1y 6M 2w 3d 12h 30m 30s"
         #[arg(short, long)]
@@ -1244,6 +1386,7 @@ mod multisig {
 
     impl Run for Propose {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let account = context.resolve_account(self.account)?;
             let instructions: Vec<InstructionBox> = parse_json5_stdin(context)?;
             let transaction_ttl_ms = self.transaction_ttl.map(|duration| {
                 duration
@@ -1258,7 +1401,7 @@ mod multisig {
             println!("{instructions_hash}");
 
             let propose_multisig_transaction =
-                MultisigPropose::new(self.account, instructions, transaction_ttl_ms);
+                MultisigPropose::new(account, instructions, transaction_ttl_ms);
 
             context
                 .finish([propose_multisig_transaction])
@@ -1268,9 +1411,9 @@ mod multisig {
 
     #[derive(clap::Args, Debug)]
     pub struct Approve {
-        /// Multisig authority of the transaction
+        /// Multisig authority of the transaction, either "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub account: AccountId,
+        pub account: AccountArg,
         /// Hash of the instructions to approve
         #[arg(short, long)]
         pub instructions_hash: ProposalKey,
@@ -1278,8 +1421,9 @@ mod multisig {
 
     impl Run for Approve {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let account = context.resolve_account(self.account)?;
             let approve_multisig_transaction =
-                MultisigApprove::new(self.account, self.instructions_hash);
+                MultisigApprove::new(account, self.instructions_hash);
 
             context
                 .finish([approve_multisig_transaction])
@@ -1817,7 +1961,7 @@ mod trigger {
         /// Retrieve details of a specific trigger
         // TODO: For better readability and reusability, triggers should reference a Wasm executable instead of storing the blob itself.
         Get(Id),
-        /// TODO: Register a trigger
+        /// Register a time trigger
         Register(Register),
         /// Unregister a trigger
         Unregister(Id),
@@ -1906,11 +2050,112 @@ mod trigger {
     }
 
     #[derive(clap::Args, Debug)]
-    pub struct Register;
+    pub struct Register {
+        /// Trigger name
+        #[arg(short, long)]
+        pub id: TriggerId,
+        /// Path to the compiled Wasm file to execute. If omitted, reads JSON5 instructions from stdin
+        #[arg(short, long)]
+        pub path: Option<PathBuf>,
+        /// Number of executions. Unlimited if omitted
+        #[arg(short, long)]
+        pub repeats: Option<u32>,
+        /// Account to execute the trigger on behalf of, either "multihash@domain" or "alias@domain".
+        /// Defaults to the configured account
+        #[arg(short, long)]
+        pub authority: Option<AccountArg>,
+        /// When to execute the trigger
+        #[command(subcommand)]
+        pub time: Time,
+    }
+
+    #[derive(clap::Subcommand, Debug)]
+    pub enum Time {
+        /// Execute right before every block commit
+        PreCommit,
+        /// Execute at the given time, optionally repeating with a fixed period
+        Schedule(Schedule),
+        /// Execute on every occurrence of a cron expression, evaluated in UTC against block creation time
+        Cron(Cron),
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Schedule {
+        /// Unix time of the first execution in milliseconds
+        #[arg(short, long)]
+        pub start_ms: u64,
+        /// Period between executions in milliseconds. Executes once if omitted
+        #[arg(short, long)]
+        pub period_ms: Option<u64>,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Cron {
+        /// Cron expression with minute, hour, day of month, month and day of week fields,
+        /// e.g. "0 0 * * 1-5" for every weekday at midnight or "@monthly"
+        #[arg(short, long)]
+        pub expression: CronExpression,
+        /// Unix time in milliseconds before which occurrences are skipped
+        #[arg(short, long)]
+        pub start_ms: Option<u64>,
+        /// Unix time in milliseconds since which occurrences are skipped
+        #[arg(long)]
+        pub end_ms: Option<u64>,
+        /// Maximum number of occurrences since the start, which must not be in the past
+        #[arg(short, long)]
+        pub max_occurrences: Option<u32>,
+    }
+
+    impl From<Time> for ExecutionTime {
+        fn from(value: Time) -> Self {
+            match value {
+                Time::PreCommit => Self::PreCommit,
+                Time::Schedule(Schedule {
+                    start_ms,
+                    period_ms,
+                }) => Self::Schedule(TimeSchedule {
+                    start_ms,
+                    period_ms,
+                }),
+                Time::Cron(Cron {
+                    expression,
+                    start_ms,
+                    end_ms,
+                    max_occurrences,
+                }) => Self::Cron(CronSchedule {
+                    expression,
+                    start_ms: start_ms.unwrap_or_default(),
+                    end_ms,
+                    max_occurrences,
+                }),
+            }
+        }
+    }
 
     impl Run for Register {
-        fn run<C: RunContext>(self, _context: &mut C) -> Result<()> {
-            todo!()
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let executable: Executable = if let Some(path) = self.path {
+                fs::read(path)
+                    .map(WasmSmartContract::from_compiled)
+                    .wrap_err("Failed to read a Wasm from the file")?
+                    .into()
+            } else {
+                parse_json5_stdin::<Vec<InstructionBox>>(context)?.into()
+            };
+            let repeats = self.repeats.map_or(Repeats::Indefinitely, Repeats::Exactly);
+            let authority = match self.authority {
+                Some(authority) => context.resolve_account(authority)?,
+                None => context.config().account.clone(),
+            };
+            let filter = TimeEventFilter::new(self.time.into());
+
+            let instruction = iroha::data_model::isi::Register::trigger(Trigger::new(
+                self.id,
+                Action::new(executable, repeats, authority, filter),
+            ));
+            context
+                .finish([instruction])
+                .wrap_err("Failed to register trigger")
         }
     }
 }
@@ -1958,8 +2203,39 @@ mod executor {
 mod metadata {
     use super::*;
 
+    /// Command argument identifying an entity, possibly by an alias
+    trait ResolveId {
+        type Id;
+
+        fn resolve<C: RunContext>(self, context: &C) -> Result<Self::Id>;
+    }
+
+    impl ResolveId for DomainId {
+        type Id = Self;
+
+        fn resolve<C: RunContext>(self, _context: &C) -> Result<Self::Id> {
+            Ok(self)
+        }
+    }
+
+    impl ResolveId for AccountArg {
+        type Id = AccountId;
+
+        fn resolve<C: RunContext>(self, context: &C) -> Result<Self::Id> {
+            context.resolve_account(self)
+        }
+    }
+
+    impl ResolveId for AssetDefinitionId {
+        type Id = Self;
+
+        fn resolve<C: RunContext>(self, _context: &C) -> Result<Self::Id> {
+            Ok(self)
+        }
+    }
+
     macro_rules! impl_metadata_command {
-        ($entity:ty, $query:expr, $constructor:ident) => {
+        ($id:ty, $query:expr, $constructor:ident) => {
             pub mod $constructor {
                 use super::*;
 
@@ -1976,7 +2252,7 @@ mod metadata {
                 #[derive(clap::Args, Debug)]
                 pub struct IdKey {
                     #[arg(short, long)]
-                    pub id: <$entity as Identifiable>::Id,
+                    pub id: $id,
                     #[arg(short, long)]
                     pub key: Name,
                 }
@@ -1986,26 +2262,29 @@ mod metadata {
                         use self::Command::*;
                         match self {
                             Get(args) => {
+                                let id = args.id.resolve(context)?;
                                 let client = context.client_from_config();
                                 let value = client
                                     .query($query)
-                                    .filter_with(|entry| entry.id.eq(args.id))
+                                    .filter_with(|entry| entry.id.eq(id))
                                     .select_with(|entry| entry.metadata.key(args.key))
                                     .execute_single()
                                     .wrap_err("Failed to get value")?;
                                 context.print_data(&value)
                             }
                             Set(args) => {
+                                let id = args.id.resolve(context)?;
                                 let value: Json = parse_json5_stdin(context)?;
                                 let instruction = iroha::data_model::isi::SetKeyValue::$constructor(
-                                    args.id, args.key, value,
+                                    id, args.key, value,
                                 );
                                 context.finish([instruction])
                             }
                             Remove(args) => {
+                                let id = args.id.resolve(context)?;
                                 let instruction =
                                     iroha::data_model::isi::RemoveKeyValue::$constructor(
-                                        args.id, args.key,
+                                        id, args.key,
                                     );
                                 context.finish([instruction])
                             }
@@ -2016,9 +2295,9 @@ mod metadata {
         };
     }
 
-    impl_metadata_command!(Domain, FindDomains, domain);
-    impl_metadata_command!(Account, FindAccounts, account);
-    impl_metadata_command!(AssetDefinition, FindAssetsDefinitions, asset_definition);
+    impl_metadata_command!(DomainId, FindDomains, domain);
+    impl_metadata_command!(AccountArg, FindAccounts, account);
+    impl_metadata_command!(AssetDefinitionId, FindAssetsDefinitions, asset_definition);
 
     // TODO apply macro after trigger.action.metadata is relocated to trigger.metadata
     pub mod trigger {
@@ -3,17 +3,18 @@
 //! Intended usage:
 //!
 //! - Create [`ConfigGetDTO`] from [`crate::iroha::Configuration`] and serialize it for the client
-//! - Deserialize [`ConfigGetDTO`] from the client and use [`ConfigGetDTO::apply_update()`] to update the configuration
-// TODO: Currently logic here is not generalised and handles only `logger.level` parameter. In future, when
+//! - Deserialize [`ConfigUpdateDTO`] from the client and use [`ConfigUpdateDTO::apply()`] to update the configuration
+// TODO: Currently logic here is not generalised and handles only a hand-picked set of parameters. In future, when
 //       other parts of configuration are refactored and there is a solid foundation e.g. as a general
 //       configuration-related crate, this part should be re-written in a clean way.
 //       Track configuration refactoring here: https://github.com/hyperledger-iroha/iroha/issues/2585
 
-use std::num::NonZero;
+use std::{num::NonZero, time::Duration};
 
 use iroha_crypto::PublicKey;
 use iroha_data_model::Level;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::{logger::Directives, parameters::actual as base};
 
@@ -25,6 +26,9 @@ pub struct ConfigGetDTO {
     pub logger: Logger,
     pub network: Network,
     pub queue: Queue,
+    pub live_query_store: LiveQueryStore,
+    pub snapshot: Snapshot,
+    pub telemetry: Option<Telemetry>,
 }
 
 impl From<&'_ base::Root> for ConfigGetDTO {
@@ -34,15 +38,129 @@ impl From<&'_ base::Root> for ConfigGetDTO {
             logger: (&value.logger).into(),
             network: value.into(),
             queue: (&value.queue).into(),
+            live_query_store: (&value.live_query_store).into(),
+            snapshot: (&value.snapshot).into(),
+            telemetry: value.telemetry.as_ref().map(Into::into),
         }
     }
 }
 
 /// Subset of Iroha configuration that clients could update.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+///
+/// Only the present sections are updated.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(default)]
 #[allow(missing_docs)]
 pub struct ConfigUpdateDTO {
-    pub logger: Logger,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logger: Option<Logger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<Network>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<Queue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_query_store: Option<LiveQueryStore>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<Snapshot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telemetry: Option<Telemetry>,
+}
+
+/// Error returned when a [`ConfigUpdateDTO`] can't be applied
+#[derive(thiserror::Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UpdateError {
+    /// A period that drives a timer was set to zero
+    #[error("`{0}` must be greater than zero")]
+    ZeroPeriod(&'static str),
+}
+
+impl ConfigUpdateDTO {
+    /// Check that the update can be applied.
+    ///
+    /// # Errors
+    /// If any of the updated periods is zero.
+    pub fn validate(&self) -> Result<(), UpdateError> {
+        if let Some(network) = &self.network {
+            if network.block_gossip_period_ms == 0 {
+                return Err(UpdateError::ZeroPeriod("network.block_gossip_period_ms"));
+            }
+            if network.transaction_gossip_period_ms == 0 {
+                return Err(UpdateError::ZeroPeriod(
+                    "network.transaction_gossip_period_ms",
+                ));
+            }
+        }
+        if let Some(live_query_store) = &self.live_query_store {
+            if live_query_store.idle_time_ms == 0 {
+                return Err(UpdateError::ZeroPeriod("live_query_store.idle_time_ms"));
+            }
+        }
+        if let Some(snapshot) = &self.snapshot {
+            if snapshot.create_every_ms == 0 {
+                return Err(UpdateError::ZeroPeriod("snapshot.create_every_ms"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the update to the configuration.
+    ///
+    /// Nothing is changed if the update is invalid.
+    ///
+    /// # Errors
+    /// See [`Self::validate`].
+    pub fn apply(self, config: &mut base::Root) -> Result<(), UpdateError> {
+        self.validate()?;
+
+        let Self {
+            logger,
+            network,
+            queue,
+            live_query_store,
+            snapshot,
+            telemetry,
+        } = self;
+
+        if let Some(logger) = logger {
+            config.logger.level = logger.level;
+            config.logger.filter = logger.filter;
+        }
+        if let Some(network) = network {
+            config.block_sync.gossip_size = network.block_gossip_size;
+            config.block_sync.gossip_period =
+                Duration::from_millis(network.block_gossip_period_ms.into());
+            config.transaction_gossiper.gossip_size = network.transaction_gossip_size;
+            config.transaction_gossiper.gossip_period =
+                Duration::from_millis(network.transaction_gossip_period_ms.into());
+        }
+        if let Some(queue) = queue {
+            config.queue.capacity = queue.capacity;
+            config.queue.capacity_per_user = queue.capacity_per_user;
+            config.queue.transaction_time_to_live =
+                Duration::from_millis(queue.transaction_time_to_live_ms);
+        }
+        if let Some(live_query_store) = live_query_store {
+            config.live_query_store.idle_time =
+                Duration::from_millis(live_query_store.idle_time_ms);
+            config.live_query_store.capacity = live_query_store.capacity;
+            config.live_query_store.capacity_per_user = live_query_store.capacity_per_user;
+        }
+        if let Some(snapshot) = snapshot {
+            config.snapshot.create_every_ms =
+                Duration::from_millis(snapshot.create_every_ms).into();
+            config.snapshot.keep_last = snapshot.keep_last;
+        }
+        if let Some(telemetry) = telemetry {
+            config.telemetry = Some(base::Telemetry {
+                name: telemetry.name,
+                url: telemetry.url,
+                min_retry_period: Duration::from_millis(telemetry.min_retry_period_ms),
+                max_retry_delay_exponent: telemetry.max_retry_delay_exponent,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// Subset of [`super::logger`] configuration.
@@ -66,12 +184,70 @@ impl From<&'_ base::Logger> for Logger {
 #[allow(missing_docs)]
 pub struct Queue {
     pub capacity: NonZero<usize>,
+    pub capacity_per_user: NonZero<usize>,
+    pub transaction_time_to_live_ms: u64,
 }
 
 impl From<&'_ base::Queue> for Queue {
     fn from(value: &'_ base::Queue) -> Self {
         Self {
             capacity: value.capacity,
+            capacity_per_user: value.capacity_per_user,
+            transaction_time_to_live_ms: value.transaction_time_to_live.as_millis() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[allow(missing_docs)]
+pub struct LiveQueryStore {
+    pub idle_time_ms: u64,
+    pub capacity: NonZero<usize>,
+    pub capacity_per_user: NonZero<usize>,
+}
+
+impl From<&'_ base::LiveQueryStore> for LiveQueryStore {
+    fn from(value: &'_ base::LiveQueryStore) -> Self {
+        Self {
+            idle_time_ms: value.idle_time.as_millis() as u64,
+            capacity: value.capacity,
+            capacity_per_user: value.capacity_per_user,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[allow(missing_docs)]
+pub struct Snapshot {
+    pub create_every_ms: u64,
+    pub keep_last: NonZero<usize>,
+}
+
+impl From<&'_ base::Snapshot> for Snapshot {
+    fn from(value: &'_ base::Snapshot) -> Self {
+        Self {
+            create_every_ms: value.create_every_ms.get().as_millis() as u64,
+            keep_last: value.keep_last,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[allow(missing_docs)]
+pub struct Telemetry {
+    pub name: String,
+    pub url: Url,
+    pub min_retry_period_ms: u64,
+    pub max_retry_delay_exponent: u8,
+}
+
+impl From<&'_ base::Telemetry> for Telemetry {
+    fn from(value: &'_ base::Telemetry) -> Self {
+        Self {
+            name: value.name.clone(),
+            url: value.url.clone(),
+            min_retry_period_ms: value.min_retry_period.as_millis() as u64,
+            max_retry_delay_exponent: value.max_retry_delay_exponent,
         }
     }
 }
@@ -123,7 +299,19 @@ mod test {
             },
             queue: Queue {
                 capacity: nonzero!(656565usize),
+                capacity_per_user: nonzero!(100usize),
+                transaction_time_to_live_ms: 86_400_000,
+            },
+            live_query_store: LiveQueryStore {
+                idle_time_ms: 10_000,
+                capacity: nonzero!(128usize),
+                capacity_per_user: nonzero!(128usize),
+            },
+            snapshot: Snapshot {
+                create_every_ms: 60_000,
+                keep_last: nonzero!(2usize),
             },
+            telemetry: None,
         };
 
         let actual = serde_json::to_string_pretty(&value).expect("The value is a valid JSON");
@@ -145,9 +333,60 @@ mod test {
                 "transaction_gossip_period_ms": 1000
               },
               "queue": {
-                "capacity": 656565
-              }
+                "capacity": 656565,
+                "capacity_per_user": 100,
+                "transaction_time_to_live_ms": 86400000
+              },
+              "live_query_store": {
+                "idle_time_ms": 10000,
+                "capacity": 128,
+                "capacity_per_user": 128
+              },
+              "snapshot": {
+                "create_every_ms": 60000,
+                "keep_last": 2
+              },
+              "telemetry": null
             }"#]];
         expected.assert_eq(&actual);
     }
+
+    #[test]
+    fn absent_sections_are_not_updated() {
+        let update: ConfigUpdateDTO =
+            serde_json::from_str(r#"{ "logger": { "level": "ERROR", "filter": null } }"#)
+                .expect("Sections are optional");
+
+        assert_eq!(update.logger.map(|logger| logger.level), Some(Level::ERROR));
+        assert!(update.network.is_none());
+        assert!(update.queue.is_none());
+        assert!(update.live_query_store.is_none());
+        assert!(update.snapshot.is_none());
+        assert!(update.telemetry.is_none());
+    }
+
+    #[test]
+    fn zero_periods_are_rejected() {
+        for (update, field) in [
+            (
+                r#"{ "network": { "block_gossip_size": 1, "block_gossip_period_ms": 0, "transaction_gossip_size": 1, "transaction_gossip_period_ms": 1000 } }"#,
+                "network.block_gossip_period_ms",
+            ),
+            (
+                r#"{ "network": { "block_gossip_size": 1, "block_gossip_period_ms": 1000, "transaction_gossip_size": 1, "transaction_gossip_period_ms": 0 } }"#,
+                "network.transaction_gossip_period_ms",
+            ),
+            (
+                r#"{ "live_query_store": { "idle_time_ms": 0, "capacity": 1, "capacity_per_user": 1 } }"#,
+                "live_query_store.idle_time_ms",
+            ),
+            (
+                r#"{ "snapshot": { "create_every_ms": 0, "keep_last": 1 } }"#,
+                "snapshot.create_every_ms",
+            ),
+        ] {
+            let update: ConfigUpdateDTO = serde_json::from_str(update).expect("Valid JSON");
+            assert_eq!(update.validate(), Err(UpdateError::ZeroPeriod(field)));
+        }
+    }
 }
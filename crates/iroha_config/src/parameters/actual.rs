@@ -34,6 +34,7 @@ pub struct Root {
     pub kura: Kura,
     pub sumeragi: Sumeragi,
     pub block_sync: BlockSync,
+    pub state_sync: StateSync,
     pub transaction_gossiper: TransactionGossiper,
     pub live_query_store: LiveQueryStore,
     pub logger: Logger,
@@ -155,6 +156,7 @@ pub struct LiveQueryStore {
     pub idle_time: Duration,
     pub capacity: NonZeroUsize,
     pub capacity_per_user: NonZeroUsize,
+    pub history_depth: u64,
 }
 
 impl Default for LiveQueryStore {
@@ -163,6 +165,7 @@ impl Default for LiveQueryStore {
             idle_time: defaults::torii::QUERY_IDLE_TIME,
             capacity: defaults::torii::QUERY_STORE_CAPACITY,
             capacity_per_user: defaults::torii::QUERY_STORE_CAPACITY_PER_USER,
+            history_depth: defaults::torii::QUERY_HISTORY_DEPTH,
         }
     }
 }
@@ -174,6 +177,14 @@ pub struct BlockSync {
     pub gossip_size: NonZeroU32,
 }
 
+/// Bootstrapping of a peer with an empty block store from a snapshot of a trusted peer
+#[derive(Debug, Clone, Copy)]
+#[allow(missing_docs)]
+pub struct StateSync {
+    pub enabled: bool,
+    pub timeout: Duration,
+}
+
 #[derive(Debug, Clone, Copy)]
 #[allow(missing_docs)]
 pub struct TransactionGossiper {
@@ -186,6 +197,37 @@ pub struct TransactionGossiper {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len: Bytes<u64>,
+    pub admin_public_keys: Vec<PublicKey>,
+    pub public_metrics: bool,
+    pub rate_limits: RateLimits,
+    pub tls: Option<ToriiTls>,
+}
+
+/// PEM files of Torii TLS.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ToriiTls {
+    pub cert_file: WithOrigin<PathBuf>,
+    pub key_file: WithOrigin<PathBuf>,
+    /// If set, client certificates are verified against these CA certificates.
+    pub client_ca_file: Option<WithOrigin<PathBuf>>,
+}
+
+/// Token bucket limits of requests to Torii. `None` means not limited.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(missing_docs)]
+pub struct RateLimits {
+    pub transactions: Option<RateLimit>,
+    pub queries: Option<RateLimit>,
+    pub subscriptions: Option<RateLimit>,
+}
+
+/// Token bucket refilled with `per_second` tokens per second, holding at most `burst` tokens.
+#[derive(Debug, Clone, Copy)]
+#[allow(missing_docs)]
+pub struct RateLimit {
+    pub per_second: NonZeroU32,
+    pub burst: NonZeroU32,
 }
 
 /// Complete configuration needed to start regular telemetry.
@@ -38,6 +38,8 @@ pub mod network {
     pub const BLOCK_GOSSIP_SIZE: NonZeroU32 = nonzero!(4u32);
 
     pub const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+    pub const STATE_SYNC_TIMEOUT: Duration = Duration::from_secs(10);
 }
 
 pub mod snapshot {
@@ -46,10 +48,14 @@ pub mod snapshot {
     pub const STORE_DIR: &str = "./storage/snapshot";
     // 10 mins
     pub const CREATE_EVERY: Duration = Duration::from_secs(10 * 60);
+    pub const KEEP_LAST: NonZeroUsize = nonzero!(3_usize);
 }
 
 pub mod torii {
-    use std::{num::NonZeroUsize, time::Duration};
+    use std::{
+        num::{NonZeroU32, NonZeroUsize},
+        time::Duration,
+    };
 
     use iroha_config_base::util::Bytes;
     use nonzero_ext::nonzero;
@@ -58,6 +64,8 @@ pub mod torii {
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(10);
     pub const QUERY_STORE_CAPACITY: NonZeroUsize = nonzero!(128usize);
     pub const QUERY_STORE_CAPACITY_PER_USER: NonZeroUsize = nonzero!(128usize);
+    pub const QUERY_HISTORY_DEPTH: u64 = 1024;
+    pub const RATE_LIMIT_BURST: NonZeroU32 = nonzero!(100u32);
 }
 
 pub mod telemetry {
@@ -87,6 +87,8 @@ pub struct Root {
 pub enum ParseError {
     #[error("Failed to construct the key pair")]
     BadKeyPair,
+    #[error("Torii TLS requires both `cert_file` and `key_file`")]
+    BadToriiTls,
 }
 
 impl Root {
@@ -106,7 +108,7 @@ impl Root {
             .change_context(ParseError::BadKeyPair)
             .ok_or_emit(&mut emitter);
 
-        let (network, block_sync, transaction_gossiper) = self.network.parse();
+        let (network, block_sync, state_sync, transaction_gossiper) = self.network.parse();
         let Some((peer, trusted_peers)) = key_pair.as_ref().map(|key_pair| {
             let peer = Peer::new(
                 network.address.value().clone(),
@@ -132,7 +134,7 @@ impl Root {
         let queue = self.queue;
         let snapshot = self.snapshot;
         let dev_telemetry = self.dev_telemetry;
-        let (torii, live_query_store) = self.torii.parse();
+        let (torii, live_query_store) = self.torii.parse(&mut emitter);
         let telemetry = self.telemetry.map(actual::Telemetry::from);
 
         let sumeragi = self.sumeragi.parse();
@@ -155,6 +157,7 @@ impl Root {
             kura,
             sumeragi,
             block_sync,
+            state_sync,
             transaction_gossiper,
             live_query_store,
             logger,
@@ -292,6 +295,13 @@ pub struct Network {
     /// Duration of time after which connection with peer is terminated if peer is idle
     #[config(default = "defaults::network::IDLE_TIMEOUT.into()")]
     pub idle_timeout_ms: DurationMs,
+    /// Whether a peer with an empty block store should bootstrap from a snapshot of trusted peers
+    /// instead of replaying all blocks
+    #[config(default)]
+    pub state_sync: bool,
+    /// Duration of time to wait for trusted peers to respond during state sync
+    #[config(default = "defaults::network::STATE_SYNC_TIMEOUT.into()")]
+    pub state_sync_timeout_ms: DurationMs,
 }
 
 impl Network {
@@ -300,6 +310,7 @@ impl Network {
     ) -> (
         actual::Network,
         actual::BlockSync,
+        actual::StateSync,
         actual::TransactionGossiper,
     ) {
         let Self {
@@ -310,6 +321,8 @@ impl Network {
             transaction_gossip_size,
             transaction_gossip_period_ms: transaction_gossip_period,
             idle_timeout_ms: idle_timeout,
+            state_sync,
+            state_sync_timeout_ms: state_sync_timeout,
         } = self;
 
         (
@@ -322,6 +335,10 @@ impl Network {
                 gossip_period: block_gossip_period.get(),
                 gossip_size: block_gossip_size,
             },
+            actual::StateSync {
+                enabled: state_sync,
+                timeout: state_sync_timeout.get(),
+            },
             actual::TransactionGossiper {
                 gossip_period: transaction_gossip_period.get(),
                 gossip_size: transaction_gossip_size,
@@ -461,6 +478,8 @@ pub struct Snapshot {
     pub mode: SnapshotMode,
     #[config(default = "defaults::snapshot::CREATE_EVERY.into()")]
     pub create_every_ms: DurationMs,
+    #[config(default = "defaults::snapshot::KEEP_LAST")]
+    pub keep_last: NonZeroUsize,
     #[config(
         default = "PathBuf::from(defaults::snapshot::STORE_DIR)",
         env = "SNAPSHOT_STORE_DIR"
@@ -482,19 +501,112 @@ pub struct Torii {
     /// The upper limit of the number of live queries for a single user.
     #[config(default = "defaults::torii::QUERY_STORE_CAPACITY_PER_USER")]
     pub query_store_capacity_per_user: NonZeroUsize,
+    /// How many blocks behind the latest one the state can be queried with `at_height`.
+    /// Checkpoints of the state covering this depth are kept in the snapshot store directory.
+    #[config(default = "defaults::torii::QUERY_HISTORY_DEPTH")]
+    pub query_history_depth: u64,
+    /// Public keys allowed to sign requests to operator endpoints.
+    /// If empty, operator endpoints reject all requests.
+    #[config(default)]
+    pub admin_public_keys: Vec<PublicKey>,
+    /// Whether `/metrics` is served without an admin signature, e.g. to be scraped by Prometheus.
+    #[config(default)]
+    pub public_metrics: bool,
+    #[config(nested)]
+    pub rate_limit: ToriiRateLimit,
+    #[config(nested)]
+    pub tls: ToriiTls,
+}
+
+/// TLS of Torii, applied to both REST and WebSocket routes.
+/// Enabled if both `cert_file` and `key_file` are set.
+#[derive(Debug, ReadConfig)]
+pub struct ToriiTls {
+    /// PEM file with the certificate chain of the server, starting with the server certificate.
+    #[config(env = "TORII_TLS_CERT_FILE")]
+    pub cert_file: Option<WithOrigin<PathBuf>>,
+    /// PEM file with the private key of the server certificate.
+    #[config(env = "TORII_TLS_KEY_FILE")]
+    pub key_file: Option<WithOrigin<PathBuf>>,
+    /// PEM file with CA certificates. If set, clients must present a certificate signed by one of them.
+    #[config(env = "TORII_TLS_CLIENT_CA_FILE")]
+    pub client_ca_file: Option<WithOrigin<PathBuf>>,
+}
+
+impl ToriiTls {
+    fn parse(self) -> Result<Option<actual::ToriiTls>, ParseError> {
+        match (self.cert_file, self.key_file) {
+            (Some(cert_file), Some(key_file)) => Ok(Some(actual::ToriiTls {
+                cert_file,
+                key_file,
+                client_ca_file: self.client_ca_file,
+            })),
+            (None, None) if self.client_ca_file.is_none() => Ok(None),
+            (cert_file, key_file) => {
+                let mut report = error_stack::Report::new(ParseError::BadToriiTls);
+                for file in [cert_file, key_file, self.client_ca_file]
+                    .into_iter()
+                    .flatten()
+                {
+                    report = report.attach_printable(file.into_attachment().display_path());
+                }
+                Err(report)
+            }
+        }
+    }
+}
+
+/// Token bucket limits of requests to Torii.
+/// Every limit applies separately to each authority account and each remote IP address.
+#[derive(Debug, Clone, Copy, ReadConfig)]
+pub struct ToriiRateLimit {
+    /// Transactions accepted per second. Not limited if unset.
+    pub transactions_per_second: Option<NonZeroU32>,
+    /// How many transactions might be accepted at once after a period of inactivity.
+    #[config(default = "defaults::torii::RATE_LIMIT_BURST")]
+    pub transactions_burst: NonZeroU32,
+    /// Query requests accepted per second. Not limited if unset.
+    pub queries_per_second: Option<NonZeroU32>,
+    /// How many query requests might be accepted at once after a period of inactivity.
+    #[config(default = "defaults::torii::RATE_LIMIT_BURST")]
+    pub queries_burst: NonZeroU32,
+    /// Event and block stream subscriptions accepted per second. Not limited if unset.
+    pub subscriptions_per_second: Option<NonZeroU32>,
+    /// How many subscriptions might be accepted at once after a period of inactivity.
+    #[config(default = "defaults::torii::RATE_LIMIT_BURST")]
+    pub subscriptions_burst: NonZeroU32,
+}
+
+impl From<ToriiRateLimit> for actual::RateLimits {
+    fn from(value: ToriiRateLimit) -> Self {
+        let limit = |per_second: Option<NonZeroU32>, burst| {
+            per_second.map(|per_second| actual::RateLimit { per_second, burst })
+        };
+
+        Self {
+            transactions: limit(value.transactions_per_second, value.transactions_burst),
+            queries: limit(value.queries_per_second, value.queries_burst),
+            subscriptions: limit(value.subscriptions_per_second, value.subscriptions_burst),
+        }
+    }
 }
 
 impl Torii {
-    fn parse(self) -> (actual::Torii, actual::LiveQueryStore) {
+    fn parse(self, emitter: &mut Emitter<ParseError>) -> (actual::Torii, actual::LiveQueryStore) {
         let torii = actual::Torii {
             address: self.address,
             max_content_len: self.max_content_len,
+            admin_public_keys: self.admin_public_keys,
+            public_metrics: self.public_metrics,
+            rate_limits: self.rate_limit.into(),
+            tls: self.tls.parse().ok_or_emit(emitter).flatten(),
         };
 
         let query = actual::LiveQueryStore {
             idle_time: self.query_idle_time_ms.get(),
             capacity: self.query_store_capacity,
             capacity_per_user: self.query_store_capacity_per_user,
+            history_depth: self.query_history_depth,
         };
 
         (torii, query)
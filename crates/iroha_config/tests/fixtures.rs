@@ -142,6 +142,14 @@ fn minimal_config_snapshot() {
                 max_content_len: Bytes(
                     16777216,
                 ),
+                admin_public_keys: [],
+                public_metrics: false,
+                rate_limits: RateLimits {
+                    transactions: None,
+                    queries: None,
+                    subscriptions: None,
+                },
+                tls: None,
             },
             kura: Kura {
                 init_mode: Strict,
@@ -161,6 +169,10 @@ fn minimal_config_snapshot() {
                 gossip_period: 10s,
                 gossip_size: 4,
             },
+            state_sync: StateSync {
+                enabled: false,
+                timeout: 10s,
+            },
             transaction_gossiper: TransactionGossiper {
                 gossip_period: 1s,
                 gossip_size: 500,
@@ -169,6 +181,7 @@ fn minimal_config_snapshot() {
                 idle_time: 10s,
                 capacity: 128,
                 capacity_per_user: 128,
+                history_depth: 1024,
             },
             logger: Logger {
                 level: INFO,
@@ -185,6 +198,7 @@ fn minimal_config_snapshot() {
                 create_every_ms: DurationMs(
                     600s,
                 ),
+                keep_last: 3,
                 store_dir: WithOrigin {
                     value: "./storage/snapshot",
                     origin: Default {
@@ -229,6 +243,17 @@ fn missing_fields() {
     assert_contains!(format!("{error:?}"), "missing parameter: `network.address`");
 }
 
+#[test]
+fn torii_tls_requires_cert_and_key() {
+    let error = load_config_from_fixtures("bad.torii_tls_without_key.toml")
+        .expect_err("should fail without a private key");
+
+    assert_contains!(
+        format!("{error:?}"),
+        "Torii TLS requires both `cert_file` and `key_file`"
+    );
+}
+
 #[test]
 fn extra_fields() {
     let error = load_config_from_fixtures("bad.extra_fields.toml")
@@ -50,7 +50,11 @@ pub fn create_block<'a>(
         tx_limits,
     )
     .unwrap()])
-    .chain(0, state.view().latest_block().as_deref())
+    .chain(
+        0,
+        state.view().latest_block().as_deref(),
+        state.view().latest_state_root(),
+    )
     .sign(peer_private_key)
     .unpack(|_| {});
 
@@ -224,7 +228,11 @@ pub fn build_state(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State
             tx_limits,
         )
         .unwrap()])
-        .chain(0, state.view().latest_block().as_deref())
+        .chain(
+            0,
+            state.view().latest_block().as_deref(),
+            state.view().latest_state_root(),
+        )
         .sign(&private_key)
         .unpack(|_| {});
         let mut state_block = state.block(unverified_block.header());
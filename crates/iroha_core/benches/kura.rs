@@ -59,7 +59,11 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
     let topology = Topology::new(vec![peer_id]);
     let mut block = {
         let unverified_block = BlockBuilder::new(vec![tx])
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(peer_key_pair.private_key())
             .unpack(|_| {});
 
@@ -67,7 +67,11 @@ fn build_test_and_transient_state() -> State {
             tx_limits,
         )
         .unwrap()])
-        .chain(0, state.view().latest_block().as_deref())
+        .chain(
+            0,
+            state.view().latest_block().as_deref(),
+            state.view().latest_state_root(),
+        )
         .sign(key_pair.private_key())
         .unpack(|_| {});
         let mut state_block = state.block(unverified_block.header());
@@ -155,7 +159,11 @@ fn validate_transaction(criterion: &mut Criterion) {
         tx_limits,
     )
     .unwrap()])
-    .chain(0, state.view().latest_block().as_deref())
+    .chain(
+        0,
+        state.view().latest_block().as_deref(),
+        state.view().latest_state_root(),
+    )
     .sign(key_pair.private_key())
     .unpack(|_| {});
     let transaction = AcceptedTransaction::accept(
@@ -209,8 +217,11 @@ fn sign_blocks(criterion: &mut Criterion) {
 
     let mut count = 0;
 
-    let block =
-        BlockBuilder::new(vec![transaction]).chain(0, state.view().latest_block().as_deref());
+    let block = BlockBuilder::new(vec![transaction]).chain(
+        0,
+        state.view().latest_block().as_deref(),
+        state.view().latest_state_root(),
+    );
 
     let _ = criterion.bench_function("sign_block", |b| {
         b.iter_batched(
@@ -11,6 +11,7 @@ use iroha_data_model::{
     block::*,
     events::prelude::*,
     peer::PeerId,
+    state::StateRoot,
     transaction::{error::TransactionRejectionReason, SignedTransaction},
 };
 use thiserror::Error;
@@ -54,6 +55,13 @@ pub enum BlockValidationError {
         /// Actual value
         actual: usize,
     },
+    /// Mismatch between the actual and expected roots of the world state resulting from the previous block. Expected: {expected:?}, actual: {actual:?}
+    PrevStateRootMismatch {
+        /// Expected value
+        expected: Option<StateRoot>,
+        /// Actual value
+        actual: Option<StateRoot>,
+    },
     /// The transaction hash stored in the block header does not match the actual transaction hash
     TransactionHashMismatch,
     /// Error during transaction validation
@@ -154,6 +162,7 @@ mod pending {
         fn make_header(
             &self,
             prev_block: Option<&SignedBlock>,
+            prev_state_root: Option<StateRoot>,
             view_change_index: usize,
         ) -> BlockHeader {
             let prev_block_time =
@@ -200,6 +209,7 @@ mod pending {
                     .map(SignedTransaction::hash)
                     .collect::<MerkleTree<_>>()
                     .hash(),
+                prev_state_root,
                 creation_time_ms: creation_time
                     .as_millis()
                     .try_into()
@@ -212,14 +222,16 @@ mod pending {
 
         /// Chain the block with existing blockchain.
         ///
+        /// `latest_state_root` is the root of the world state resulting from `latest_block`.
         /// Upon executing this method current timestamp is stored in the block header.
         pub fn chain(
             self,
             view_change_index: usize,
             latest_block: Option<&SignedBlock>,
+            latest_state_root: Option<StateRoot>,
         ) -> BlockBuilder<Chained> {
             BlockBuilder(Chained {
-                header: self.make_header(latest_block, view_change_index),
+                header: self.make_header(latest_block, latest_state_root, view_change_index),
                 transactions: self.0.transactions,
             })
         }
@@ -457,6 +469,7 @@ mod valid {
         /// - Topology field is incorrect
         /// - Block has committed transactions
         /// - Error during validation of individual transactions
+        /// - Transaction is not signed by a signatory of its authority
         /// - Transaction in the genesis block is not signed by the genesis public key
         pub fn validate(
             mut block: SignedBlock,
@@ -571,6 +584,20 @@ mod valid {
                 });
             }
 
+            let expected_prev_state_root = if soft_fork {
+                state.prev_state_root()
+            } else {
+                state.latest_state_root()
+            };
+            let actual_prev_state_root = block.header().prev_state_root;
+
+            if expected_prev_state_root != actual_prev_state_root {
+                return Err(BlockValidationError::PrevStateRootMismatch {
+                    expected: expected_prev_state_root,
+                    actual: actual_prev_state_root,
+                });
+            }
+
             if block.header().is_genesis() {
                 check_genesis_block(block, genesis_account)?;
             } else {
@@ -616,14 +643,12 @@ mod valid {
                 (params.sumeragi().max_clock_drift(), params.transaction)
             };
 
-            let mut wasm_cache = WasmCache::new();
-            let errors = block
+            let accepted_txs = block
                 .transactions()
                 // FIXME: Redundant clone
                 .cloned()
-                .enumerate()
-                .try_fold(Vec::new(), |mut acc, (idx, tx)| {
-                    let accepted_tx = if block.header().is_genesis() {
+                .map(|tx| {
+                    if block.header().is_genesis() {
                         AcceptedTransaction::accept_genesis(
                             tx,
                             expected_chain_id,
@@ -637,8 +662,22 @@ mod valid {
                             max_clock_drift,
                             tx_limits,
                         )
-                    }?;
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // The leader only includes transactions signed by the signatories
+            // from the state before the block, so anything else makes the whole block invalid
+            for accepted_tx in &accepted_txs {
+                accepted_tx
+                    .verify_signature(state_block.world())
+                    .map_err(AcceptTransactionFail::SignatureVerification)?;
+            }
 
+            let mut wasm_cache = WasmCache::new();
+            let errors = accepted_txs.into_iter().enumerate().fold(
+                Vec::new(),
+                |mut acc, (idx, accepted_tx)| {
                     if let Err((rejected_tx, error)) =
                         state_block.validate(accepted_tx, &mut wasm_cache)
                     {
@@ -652,8 +691,9 @@ mod valid {
                         acc.push((idx, error));
                     }
 
-                    Ok::<_, TransactionValidationError>(acc)
-                })?;
+                    acc
+                },
+            );
 
             block.set_transaction_errors(errors);
 
@@ -801,6 +841,24 @@ mod valid {
             Ok(())
         }
 
+        /// Check that a non-genesis `block` is signed by a quorum of `topology`
+        /// without validating its contents against the state.
+        ///
+        /// # Errors
+        ///
+        /// - Block is not signed by the leader or the proxy tail
+        /// - Block has unknown signatories or incorrect signatures
+        /// - Block doesn't have enough signatures
+        pub fn verify_commit_signatures(
+            block: &SignedBlock,
+            topology: &Topology,
+        ) -> Result<(), BlockValidationError> {
+            Self::verify_leader_signature(block, topology)?;
+            Self::verify_validator_signatures(block, topology)?;
+            Self::verify_no_undefined_signatures(block, topology)?;
+            Self::is_commit(block, topology)
+        }
+
         /// Add additional signatures for [`Self`].
         pub fn sign(&mut self, key_pair: &KeyPair, topology: &Topology) {
             let signatory_idx = topology
@@ -826,6 +884,7 @@ mod valid {
                 height: nonzero_ext::nonzero!(2_u64),
                 prev_block_hash: None,
                 transactions_hash: Some(transactions_hash),
+                prev_state_root: None,
                 creation_time_ms: 0,
                 view_change_index: 0,
             };
@@ -883,9 +942,14 @@ mod valid {
     #[cfg(test)]
     mod tests {
         use iroha_crypto::SignatureOf;
+        use iroha_data_model::prelude::*;
+        use iroha_test_samples::gen_account_in;
 
         use super::*;
-        use crate::sumeragi::network_topology::test_topology_with_keys;
+        use crate::{
+            kura::Kura, query::store::LiveQueryStore, smartcontracts::isi::Registrable as _,
+            state::State, sumeragi::network_topology::test_topology_with_keys,
+        };
 
         #[test]
         fn signature_verification_ok() {
@@ -983,6 +1047,61 @@ mod valid {
                 SignatureVerificationError::ProxyTailMissing.into()
             )
         }
+
+        #[tokio::test]
+        async fn block_with_invalid_transaction_signature_is_rejected() {
+            let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+            // The key from the id of alice is rotated out
+            let (alice_id, alice_keypair) = gen_account_in("wonderland");
+            let new_keypair = KeyPair::random();
+            let mut account = Account::new(alice_id.clone()).build(&alice_id);
+            account.signatories = [new_keypair.public_key().clone()].into();
+            let domain = Domain::new(alice_id.domain.clone()).build(&alice_id);
+            let world = World::with([domain], [account], []);
+            let state = State::new(
+                world,
+                Kura::blank_kura_for_testing(),
+                LiveQueryStore::start_test(),
+            );
+            let (max_clock_drift, tx_limits) = {
+                let state_view = state.world.view();
+                let params = state_view.parameters();
+                (params.sumeragi().max_clock_drift(), params.transaction)
+            };
+
+            let transactions = [&new_keypair, &alice_keypair]
+                .into_iter()
+                .map(|key_pair| {
+                    let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                        .with_instructions([Log::new(
+                            iroha_data_model::Level::INFO,
+                            "signed".to_owned(),
+                        )])
+                        .sign(key_pair.private_key());
+                    AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+                        .expect("Valid")
+                })
+                .collect();
+            let mut block: SignedBlock = BlockBuilder::new(transactions)
+                .chain(
+                    0,
+                    state.view().latest_block().as_deref(),
+                    state.view().latest_state_root(),
+                )
+                .sign(new_keypair.private_key())
+                .unpack(|_| {})
+                .into();
+
+            let mut state_block = state.block(block.header());
+            let error = ValidBlock::categorize(&mut block, &chain_id, &alice_id, &mut state_block)
+                .unwrap_err();
+
+            assert!(matches!(
+                error,
+                TransactionValidationError::Accept(AcceptTransactionFail::SignatureVerification(_))
+            ));
+        }
     }
 }
 
@@ -994,6 +1113,15 @@ mod commit {
     #[derive(Debug, Clone)]
     pub struct CommittedBlock(pub(super) ValidBlock);
 
+    impl CommittedBlock {
+        /// Treat `block` as committed without validating it.
+        ///
+        /// Only for blocks which are already known to be committed, e.g. by their hashes.
+        pub(crate) fn new_unchecked(block: SignedBlock) -> Self {
+            Self(ValidBlock(block))
+        }
+    }
+
     impl From<CommittedBlock> for ValidBlock {
         fn from(source: CommittedBlock) -> Self {
             ValidBlock(source.0.into())
@@ -1041,7 +1169,10 @@ mod event {
     }
 
     impl<B: EventProducer, U> WithEvents<Result<B, (U, BlockValidationError)>> {
-        pub fn unpack<F: Fn(PipelineEventBox)>(self, f: F) -> Result<B, (U, BlockValidationError)> {
+        pub fn unpack<F: FnMut(PipelineEventBox)>(
+            self,
+            f: F,
+        ) -> Result<B, (U, BlockValidationError)> {
             match self.0 {
                 Ok(ok) => Ok(WithEvents(ok).unpack(f)),
                 Err(err) => Err(WithEvents(err).unpack(f)),
@@ -1051,7 +1182,7 @@ mod event {
     impl<'state, B: EventProducer, U>
         WithEvents<Result<(B, StateBlock<'state>), (U, BlockValidationError)>>
     {
-        pub fn unpack<F: Fn(PipelineEventBox)>(
+        pub fn unpack<F: FnMut(PipelineEventBox)>(
             self,
             f: F,
         ) -> Result<(B, StateBlock<'state>), (U, BlockValidationError)> {
@@ -1062,7 +1193,7 @@ mod event {
         }
     }
     impl WithEvents<Result<Vec<BlockSignature>, SignatureVerificationError>> {
-        pub fn unpack<F: Fn(PipelineEventBox)>(
+        pub fn unpack<F: FnMut(PipelineEventBox)>(
             self,
             f: F,
         ) -> Result<Vec<BlockSignature>, SignatureVerificationError> {
@@ -1073,14 +1204,14 @@ mod event {
         }
     }
     impl<B: EventProducer> WithEvents<B> {
-        pub fn unpack<F: Fn(PipelineEventBox)>(self, f: F) -> B {
+        pub fn unpack<F: FnMut(PipelineEventBox)>(self, f: F) -> B {
             self.0.produce_events().for_each(f);
             self.0
         }
     }
 
     impl<B, E: EventProducer> WithEvents<(B, E)> {
-        pub(crate) fn unpack<F: Fn(PipelineEventBox)>(self, f: F) -> (B, E) {
+        pub(crate) fn unpack<F: FnMut(PipelineEventBox)>(self, f: F) -> (B, E) {
             self.0 .1.produce_events().for_each(f);
             self.0
         }
@@ -1212,7 +1343,11 @@ mod tests {
         // Creating a block of two identical transactions and validating it
         let transactions = vec![tx.clone(), tx];
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(alice_keypair.private_key())
             .unpack(|_| {});
 
@@ -1279,7 +1414,11 @@ mod tests {
         // Creating a block of two identical transactions and validating it
         let transactions = vec![tx0, tx, tx2];
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(alice_keypair.private_key())
             .unpack(|_| {});
         let mut state_block = state.block(unverified_block.header);
@@ -1331,7 +1470,11 @@ mod tests {
         // Creating a block of where first transaction must fail and second one fully executed
         let transactions = vec![tx_fail, tx_accept];
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(alice_keypair.private_key())
             .unpack(|_| {});
 
@@ -1396,7 +1539,11 @@ mod tests {
         let transactions = vec![tx];
         let topology = test_topology(1);
         let unverified_block = BlockBuilder::new(transactions)
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(genesis_correct_key.private_key())
             .unpack(|_| {});
 
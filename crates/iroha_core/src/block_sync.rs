@@ -18,7 +18,7 @@ use iroha_logger::prelude::*;
 use iroha_macro::*;
 use iroha_p2p::Post;
 use parity_scale_codec::{Decode, Encode};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 use crate::{
     kura::Kura,
@@ -60,12 +60,18 @@ pub struct BlockSynchronizer {
 
 impl BlockSynchronizer {
     /// Start [`Self`] actor.
-    pub fn start(self, shutdown_signal: ShutdownSignal) -> (BlockSynchronizerHandle, Child) {
+    ///
+    /// Gossip settings are updated with the values received from `config_updates`.
+    pub fn start(
+        self,
+        config_updates: watch::Receiver<Config>,
+        shutdown_signal: ShutdownSignal,
+    ) -> (BlockSynchronizerHandle, Child) {
         let (message_sender, message_receiver) = mpsc::channel(1);
         (
             BlockSynchronizerHandle { message_sender },
             Child::new(
-                tokio::spawn(self.run(message_receiver, shutdown_signal)),
+                tokio::spawn(self.run(message_receiver, config_updates, shutdown_signal)),
                 OnShutdown::Abort,
             ),
         )
@@ -75,6 +81,7 @@ impl BlockSynchronizer {
     async fn run(
         mut self,
         mut message_receiver: mpsc::Receiver<message::Message>,
+        mut config_updates: watch::Receiver<Config>,
         shutdown_signal: ShutdownSignal,
     ) {
         let mut gossip_period = tokio::time::interval(self.gossip_period);
@@ -84,6 +91,14 @@ impl BlockSynchronizer {
                 Some(msg) = message_receiver.recv() => {
                     msg.handle_message(&mut self).await;
                 }
+                Ok(()) = config_updates.changed() => {
+                    let config = *config_updates.borrow_and_update();
+                    self.gossip_size = config.gossip_size;
+                    if config.gossip_period != self.gossip_period {
+                        self.gossip_period = config.gossip_period;
+                        gossip_period = tokio::time::interval(self.gossip_period);
+                    }
+                }
                 () = shutdown_signal.receive() => {
                     debug!("Shutting down block sync");
                     break;
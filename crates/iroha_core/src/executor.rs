@@ -110,6 +110,14 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Executor> {
 }
 
 impl Executor {
+    /// Original WASM blob of the executor, `None` for the [`Initial`](Executor::Initial) one
+    pub fn raw(&self) -> Option<&data_model_executor::Executor> {
+        match self {
+            Self::Initial => None,
+            Self::UserProvided(executor) => Some(&executor.raw_executor),
+        }
+    }
+
     /// Execute [`SignedTransaction`].
     ///
     /// # Errors
@@ -207,7 +215,7 @@ impl Executor {
         trace!("Running query validation");
 
         let query = match query {
-            QueryRequest::Singular(singular) => AnyQueryBox::Singular(singular.clone()),
+            QueryRequest::Singular(singular) => AnyQueryBox::Singular(singular.query.clone()),
             QueryRequest::Start(iterable) => AnyQueryBox::Iterable(iterable.clone()),
             QueryRequest::Continue(_) => {
                 // The iterable query was already validated when it started
@@ -7,7 +7,7 @@ use iroha_data_model::{transaction::SignedTransaction, ChainId};
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
 use iroha_p2p::Broadcast;
 use parity_scale_codec::{Decode, Encode};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 use crate::{
     queue::Queue, state::State, tx::AcceptedTransaction, IrohaNetwork, NetworkMessage,
@@ -47,12 +47,18 @@ pub struct TransactionGossiper {
 
 impl TransactionGossiper {
     /// Start [`Self`] actor.
-    pub fn start(self, shutdown_signal: ShutdownSignal) -> (TransactionGossiperHandle, Child) {
+    ///
+    /// Gossip settings are updated with the values received from `config_updates`.
+    pub fn start(
+        self,
+        config_updates: watch::Receiver<Config>,
+        shutdown_signal: ShutdownSignal,
+    ) -> (TransactionGossiperHandle, Child) {
         let (message_sender, message_receiver) = mpsc::channel(1);
         (
             TransactionGossiperHandle { message_sender },
             Child::new(
-                tokio::task::spawn(self.run(message_receiver, shutdown_signal)),
+                tokio::task::spawn(self.run(message_receiver, config_updates, shutdown_signal)),
                 OnShutdown::Abort,
             ),
         )
@@ -80,8 +86,9 @@ impl TransactionGossiper {
     }
 
     async fn run(
-        self,
+        mut self,
         mut message_receiver: mpsc::Receiver<TransactionGossip>,
+        mut config_updates: watch::Receiver<Config>,
         shutdown_signal: ShutdownSignal,
     ) {
         let mut gossip_period = tokio::time::interval(self.gossip_period);
@@ -91,6 +98,14 @@ impl TransactionGossiper {
                 Some(transaction_gossip) = message_receiver.recv() => {
                     self.handle_transaction_gossip(transaction_gossip);
                 }
+                Ok(()) = config_updates.changed() => {
+                    let config = *config_updates.borrow_and_update();
+                    self.gossip_size = config.gossip_size;
+                    if config.gossip_period != self.gossip_period {
+                        self.gossip_period = config.gossip_period;
+                        gossip_period = tokio::time::interval(self.gossip_period);
+                    }
+                }
                 () = shutdown_signal.receive() => {
                     iroha_logger::debug!("Shutting down transactions gossiper");
                     break;
@@ -125,7 +140,14 @@ impl TransactionGossiper {
                 (params.sumeragi().max_clock_drift(), params.transaction)
             };
 
-            match AcceptedTransaction::accept(tx, &self.chain_id, max_clock_drift, tx_limits) {
+            let accepted_tx =
+                AcceptedTransaction::accept(tx, &self.chain_id, max_clock_drift, tx_limits)
+                    .and_then(|tx| {
+                        tx.verify_signature(&self.state.world.view())?;
+                        Ok(tx)
+                    });
+
+            match accepted_tx {
                 Ok(tx) => match self.queue.push(tx, self.state.view()) {
                     Ok(()) => {}
                     Err(crate::queue::Failure {
@@ -4,13 +4,17 @@
 //! no any part of Iroha is interested in the whole state. However, the API could be extended
 //! in future.
 //!
-//! Updates mechanism is implemented via subscriptions to [`tokio::sync::watch`] channels. For now,
-//! only `logger.level` field is dynamic, which might be tracked with [`KisoHandle::subscribe_on_logger_updates()`].
+//! Updates mechanism is implemented via subscriptions to [`tokio::sync::watch`] channels, one per
+//! dynamic section of the configuration, e.g. [`KisoHandle::subscribe_on_logger_updates()`].
 
 use eyre::Result;
 use iroha_config::{
-    client_api::{ConfigGetDTO, ConfigUpdateDTO},
-    parameters::actual::{Logger as LoggerConfig, Root as Config},
+    client_api::{ConfigGetDTO, ConfigUpdateDTO, UpdateError},
+    parameters::actual::{
+        BlockSync as BlockSyncConfig, LiveQueryStore as LiveQueryStoreConfig,
+        Logger as LoggerConfig, Queue as QueueConfig, Root as Config, Snapshot as SnapshotConfig,
+        Telemetry as TelemetryConfig, TransactionGossiper as TransactionGossiperConfig,
+    },
 };
 use iroha_futures::supervisor::{Child, OnShutdown};
 use tokio::sync::{mpsc, oneshot, watch};
@@ -29,11 +33,11 @@ impl KisoHandle {
     /// Spawn a new actor
     pub fn start(state: Config) -> (Self, Child) {
         let (actor_sender, actor_receiver) = mpsc::channel(DEFAULT_CHANNEL_SIZE);
-        let (logger_update, _) = watch::channel(state.logger.clone());
+        let updates = Updates::new(&state);
         let mut actor = Actor {
             handle: actor_receiver,
             state,
-            logger_update,
+            updates,
         };
         (
             Self {
@@ -75,18 +79,80 @@ impl KisoHandle {
         rx.await?
     }
 
-    /// Subscribe on updates of `logger.level` parameter.
+    /// Subscribe on updates of `logger` section.
     ///
     /// # Errors
     /// If communication with actor fails.
     pub async fn subscribe_on_logger_updates(
         &self,
     ) -> Result<watch::Receiver<LoggerConfig>, Error> {
+        Ok(self.subscribe().await?.logger)
+    }
+
+    /// Subscribe on updates of `queue` section.
+    ///
+    /// # Errors
+    /// If communication with actor fails.
+    pub async fn subscribe_on_queue_updates(&self) -> Result<watch::Receiver<QueueConfig>, Error> {
+        Ok(self.subscribe().await?.queue)
+    }
+
+    /// Subscribe on updates of live query store limits.
+    ///
+    /// # Errors
+    /// If communication with actor fails.
+    pub async fn subscribe_on_live_query_store_updates(
+        &self,
+    ) -> Result<watch::Receiver<LiveQueryStoreConfig>, Error> {
+        Ok(self.subscribe().await?.live_query_store)
+    }
+
+    /// Subscribe on updates of block gossiping settings.
+    ///
+    /// # Errors
+    /// If communication with actor fails.
+    pub async fn subscribe_on_block_sync_updates(
+        &self,
+    ) -> Result<watch::Receiver<BlockSyncConfig>, Error> {
+        Ok(self.subscribe().await?.block_sync)
+    }
+
+    /// Subscribe on updates of transaction gossiping settings.
+    ///
+    /// # Errors
+    /// If communication with actor fails.
+    pub async fn subscribe_on_transaction_gossiper_updates(
+        &self,
+    ) -> Result<watch::Receiver<TransactionGossiperConfig>, Error> {
+        Ok(self.subscribe().await?.transaction_gossiper)
+    }
+
+    /// Subscribe on updates of `snapshot` section.
+    ///
+    /// # Errors
+    /// If communication with actor fails.
+    pub async fn subscribe_on_snapshot_updates(
+        &self,
+    ) -> Result<watch::Receiver<SnapshotConfig>, Error> {
+        Ok(self.subscribe().await?.snapshot)
+    }
+
+    /// Subscribe on updates of `telemetry` section.
+    ///
+    /// # Errors
+    /// If communication with actor fails.
+    pub async fn subscribe_on_telemetry_updates(
+        &self,
+    ) -> Result<watch::Receiver<Option<TelemetryConfig>>, Error> {
+        Ok(self.subscribe().await?.telemetry)
+    }
+
+    async fn subscribe(&self) -> Result<Subscriptions, Error> {
         let (tx, rx) = oneshot::channel();
-        let msg = Message::SubscribeOnLogLevel { respond_to: tx };
+        let msg = Message::Subscribe { respond_to: tx };
         let _ = self.actor.send(msg).await;
-        let receiver = rx.await?;
-        Ok(receiver)
+        let subscriptions = rx.await?;
+        Ok(subscriptions)
     }
 }
 
@@ -98,26 +164,99 @@ enum Message {
         dto: ConfigUpdateDTO,
         respond_to: oneshot::Sender<Result<(), Error>>,
     },
-    SubscribeOnLogLevel {
-        respond_to: oneshot::Sender<watch::Receiver<LoggerConfig>>,
+    Subscribe {
+        respond_to: oneshot::Sender<Subscriptions>,
     },
 }
 
+/// Receivers of updates of every dynamic section
+struct Subscriptions {
+    logger: watch::Receiver<LoggerConfig>,
+    queue: watch::Receiver<QueueConfig>,
+    live_query_store: watch::Receiver<LiveQueryStoreConfig>,
+    block_sync: watch::Receiver<BlockSyncConfig>,
+    transaction_gossiper: watch::Receiver<TransactionGossiperConfig>,
+    snapshot: watch::Receiver<SnapshotConfig>,
+    telemetry: watch::Receiver<Option<TelemetryConfig>>,
+}
+
+/// Senders of updates of every dynamic section
+struct Updates {
+    logger: watch::Sender<LoggerConfig>,
+    queue: watch::Sender<QueueConfig>,
+    live_query_store: watch::Sender<LiveQueryStoreConfig>,
+    block_sync: watch::Sender<BlockSyncConfig>,
+    transaction_gossiper: watch::Sender<TransactionGossiperConfig>,
+    snapshot: watch::Sender<SnapshotConfig>,
+    telemetry: watch::Sender<Option<TelemetryConfig>>,
+}
+
+impl Updates {
+    fn new(state: &Config) -> Self {
+        Self {
+            logger: watch::Sender::new(state.logger.clone()),
+            queue: watch::Sender::new(state.queue),
+            live_query_store: watch::Sender::new(state.live_query_store),
+            block_sync: watch::Sender::new(state.block_sync),
+            transaction_gossiper: watch::Sender::new(state.transaction_gossiper),
+            snapshot: watch::Sender::new(state.snapshot.clone()),
+            telemetry: watch::Sender::new(state.telemetry.clone()),
+        }
+    }
+
+    fn subscribe(&self) -> Subscriptions {
+        Subscriptions {
+            logger: self.logger.subscribe(),
+            queue: self.queue.subscribe(),
+            live_query_store: self.live_query_store.subscribe(),
+            block_sync: self.block_sync.subscribe(),
+            transaction_gossiper: self.transaction_gossiper.subscribe(),
+            snapshot: self.snapshot.subscribe(),
+            telemetry: self.telemetry.subscribe(),
+        }
+    }
+
+    /// Notify subscribers of the sections changed by `dto`
+    fn send(&self, state: &Config, dto: &ConfigUpdateDTO) {
+        // `send_replace` stores the value even if there are no subscribers yet
+        if dto.logger.is_some() {
+            self.logger.send_replace(state.logger.clone());
+        }
+        if dto.network.is_some() {
+            self.block_sync.send_replace(state.block_sync);
+            self.transaction_gossiper
+                .send_replace(state.transaction_gossiper);
+        }
+        if dto.queue.is_some() {
+            self.queue.send_replace(state.queue);
+        }
+        if dto.live_query_store.is_some() {
+            self.live_query_store.send_replace(state.live_query_store);
+        }
+        if dto.snapshot.is_some() {
+            self.snapshot.send_replace(state.snapshot.clone());
+        }
+        if dto.telemetry.is_some() {
+            self.telemetry.send_replace(state.telemetry.clone());
+        }
+    }
+}
+
 /// Possible errors might occur while working with [`KisoHandle`]
 #[derive(thiserror::Error, displaydoc::Display, Debug)]
 pub enum Error {
     /// Failed to get actor's response
     Communication(#[from] oneshot::error::RecvError),
+    /// Invalid configuration update
+    InvalidUpdate(#[from] UpdateError),
 }
 
 struct Actor {
     handle: mpsc::Receiver<Message>,
     state: Config,
-    // Current implementation is somewhat not scalable in terms of code writing: for any
-    // future dynamic parameter, it will require its own `subscribe_on_<field>` function in [`KisoHandle`],
-    // new channel here, and new [`Message`] variant. If boilerplate expands, a more general solution will be
-    // required. However, as of now a single manually written implementation seems optimal.
-    logger_update: watch::Sender<LoggerConfig>,
+    // Any future dynamic section requires its own `subscribe_on_<section>` function in [`KisoHandle`]
+    // and a new channel in [`Updates`].
+    updates: Updates,
 }
 
 impl Actor {
@@ -133,19 +272,16 @@ impl Actor {
                 let dto = ConfigGetDTO::from(&self.state);
                 let _ = respond_to.send(dto);
             }
-            Message::UpdateWithDTO {
-                dto: ConfigUpdateDTO { logger: update },
-                respond_to,
-            } => {
-                self.state.logger.level = update.level;
-                self.state.logger.filter = update.filter;
+            Message::UpdateWithDTO { dto, respond_to } => {
+                let result = dto.clone().apply(&mut self.state);
+                if result.is_ok() {
+                    self.updates.send(&self.state, &dto);
+                }
 
-                let _ = self.logger_update.send(self.state.logger.clone());
-
-                let _ = respond_to.send(Ok(()));
+                let _ = respond_to.send(result.map_err(Error::from));
             }
-            Message::SubscribeOnLogLevel { respond_to } => {
-                let _ = respond_to.send(self.logger_update.subscribe());
+            Message::Subscribe { respond_to } => {
+                let _ = respond_to.send(self.updates.subscribe());
             }
         }
     }
@@ -157,7 +293,7 @@ mod tests {
 
     use iroha_config::{
         base::{read::ConfigReader, toml::TomlSource},
-        client_api::Logger as LoggerDTO,
+        client_api::{Logger as LoggerDTO, Snapshot as SnapshotDTO},
         parameters::{actual::Root, user::Root as UserConfig},
     };
     use iroha_logger::Level;
@@ -196,10 +332,11 @@ mod tests {
             .expect_err("Watcher should not be active initially");
 
         kiso.update_with_dto(ConfigUpdateDTO {
-            logger: LoggerDTO {
+            logger: Some(LoggerDTO {
                 level: NEW_LOG_LEVEL.into(),
                 filter: Some("trace,trace,trace".parse().unwrap()),
-            },
+            }),
+            ..ConfigUpdateDTO::default()
         })
         .await
         .expect("Update should work fine");
@@ -213,4 +350,29 @@ mod tests {
         assert_eq!(value.level, NEW_LOG_LEVEL.into());
         assert_eq!(format!("{}", value.filter.unwrap()), "trace,trace,trace");
     }
+
+    #[tokio::test]
+    async fn zero_period_update_is_rejected() {
+        let (kiso, _) = KisoHandle::start(test_config());
+        let before = kiso.get_dto().await.unwrap().snapshot;
+
+        let error = kiso
+            .update_with_dto(ConfigUpdateDTO {
+                snapshot: Some(SnapshotDTO {
+                    create_every_ms: 0,
+                    keep_last: nonzero_ext::nonzero!(1usize),
+                }),
+                ..ConfigUpdateDTO::default()
+            })
+            .await
+            .expect_err("Zero period should be rejected");
+        assert!(matches!(
+            error,
+            Error::InvalidUpdate(UpdateError::ZeroPeriod("snapshot.create_every_ms"))
+        ));
+
+        let after = kiso.get_dto().await.unwrap().snapshot;
+        assert_eq!(after.create_every_ms, before.create_every_ms);
+        assert_eq!(after.keep_last, before.keep_last);
+    }
 }
@@ -281,6 +281,11 @@ impl Kura {
         }
     }
 
+    /// Number of blocks in the block store, including the ones not yet written to disk.
+    pub fn block_count(&self) -> BlockCount {
+        BlockCount(self.block_data.lock().len())
+    }
+
     /// Get the hash of the block at the provided height.
     pub fn get_block_hash(&self, block_height: NonZeroUsize) -> Option<HashOf<BlockHeader>> {
         let hash_data_guard = self.block_data.lock();
@@ -1157,7 +1162,11 @@ mod tests {
 
         {
             let unverified_block = BlockBuilder::new(vec![tx1.clone()])
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(
+                    0,
+                    state.view().latest_block().as_deref(),
+                    state.view().latest_state_root(),
+                )
                 .sign(&leader_private_key)
                 .unpack(|_| {});
 
@@ -1177,7 +1186,7 @@ mod tests {
 
         {
             let unverified_block_soft_fork = BlockBuilder::new(vec![tx1])
-                .chain(1, Some(&genesis.0))
+                .chain(1, Some(&genesis.0), state.view().prev_state_root())
                 .sign(&leader_private_key)
                 .unpack(|_| {});
 
@@ -1198,7 +1207,11 @@ mod tests {
 
         {
             let unverified_block_next = BlockBuilder::new(vec![tx2])
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(
+                    0,
+                    state.view().latest_block().as_deref(),
+                    state.view().latest_state_root(),
+                )
                 .sign(&leader_private_key)
                 .unpack(|_| {});
 
@@ -12,6 +12,7 @@ pub mod queue;
 pub mod smartcontracts;
 pub mod snapshot;
 pub mod state;
+pub mod state_sync;
 pub mod sumeragi;
 #[cfg(feature = "telemetry")]
 pub mod telemetry;
@@ -29,6 +30,7 @@ use crate::{
     block_sync::message::Message as BlockSyncMessage,
     peers_gossiper::PeersGossip,
     prelude::*,
+    state_sync::message::Message as StateSyncMessage,
     sumeragi::message::{BlockMessage, ControlFlowMessage},
 };
 
@@ -53,6 +55,8 @@ pub enum NetworkMessage {
     SumeragiControlFlow(Box<ControlFlowMessage>),
     /// Block sync message
     BlockSync(Box<BlockSyncMessage>),
+    /// State sync message
+    StateSync(Box<StateSyncMessage>),
     /// Transaction gossiper message
     TransactionGossiper(Box<TransactionGossip>),
     /// Peers addresses gossiper message
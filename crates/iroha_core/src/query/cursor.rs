@@ -1,6 +1,6 @@
 //! Module with cursor-based pagination functional like [`Batched`].
 
-use std::{fmt::Debug, num::NonZeroU64};
+use std::{collections::VecDeque, fmt::Debug, num::NonZeroU64};
 
 use iroha_data_model::{
     prelude::SelectorTuple,
@@ -122,6 +122,46 @@ where
     }
 }
 
+/// Results that were computed in full upfront (e.g. aggregates) and are already split into batches.
+struct PrecomputedBatches {
+    batches: VecDeque<QueryOutputBatchBoxTuple>,
+    cursor: Option<u64>,
+}
+
+impl BatchedTrait for PrecomputedBatches {
+    fn next_batch(
+        &mut self,
+        cursor: u64,
+    ) -> Result<(QueryOutputBatchBoxTuple, Option<NonZeroU64>), QueryExecutionFail> {
+        let Some(server_cursor) = self.cursor else {
+            return Err(QueryExecutionFail::CursorDone);
+        };
+        if cursor != server_cursor {
+            return Err(QueryExecutionFail::CursorMismatch);
+        }
+        let batch = self
+            .batches
+            .pop_front()
+            .ok_or(QueryExecutionFail::CursorDone)?;
+
+        self.cursor = (!self.batches.is_empty()).then(|| {
+            cursor
+                .checked_add(batch.len() as u64)
+                .expect("Cursor size should never reach the platform limit")
+        });
+
+        Ok((
+            batch,
+            self.cursor
+                .map(|cursor| NonZeroU64::new(cursor).expect("Cursor is never 0")),
+        ))
+    }
+
+    fn remaining(&self) -> u64 {
+        self.batches.iter().map(|batch| batch.len() as u64).sum()
+    }
+}
+
 /// A query output iterator that combines evaluating selectors, batching and type erasure.
 pub struct ErasedQueryIterator {
     inner: Box<dyn BatchedTrait + Send + Sync>,
@@ -153,6 +193,16 @@ impl ErasedQueryIterator {
         }
     }
 
+    /// Creates an erased query iterator yielding the already evaluated results batch by batch.
+    pub fn from_batches(batches: impl IntoIterator<Item = QueryOutputBatchBoxTuple>) -> Self {
+        Self {
+            inner: Box::new(PrecomputedBatches {
+                batches: batches.into_iter().collect(),
+                cursor: Some(0),
+            }),
+        }
+    }
+
     /// Gets the next batch of results.
     ///
     /// Checks if the cursor matches the server's cursor.
@@ -0,0 +1,381 @@
+//! This module contains [`StateHistory`], which reconstructs the [`State`] at past block heights.
+//!
+//! The state is restored from the latest checkpoint which is old enough and the remaining blocks are replayed from [`Kura`].
+//! Checkpoints are [snapshots](crate::snapshot) of the state written every [`CHECKPOINT_INTERVAL`] blocks
+//! and retained for the configured depth, so that only a few blocks have to be replayed.
+
+use std::{
+    num::{NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+    sync::{
+        mpsc::{self, RecvTimeoutError, TrySendError},
+        Arc,
+    },
+    time::Duration,
+};
+
+use iroha_data_model::{
+    account::{Account, AccountId},
+    domain::Domain,
+    events::EventBox,
+    peer::PeerId,
+    query::error::QueryExecutionFail,
+    ChainId,
+};
+use iroha_logger::prelude::*;
+use tokio::sync::oneshot;
+
+use super::store::LiveQueryStoreHandle;
+#[cfg(feature = "telemetry")]
+use crate::telemetry::StateTelemetry;
+use crate::{
+    kura::{BlockCount, Kura},
+    smartcontracts::isi::Registrable as _,
+    snapshot::{read_manifests, try_read_snapshot, try_write_snapshot, EntryChunks},
+    state::{State, StateReadOnly, World},
+    sumeragi::{network_topology::Topology, SumeragiHandle},
+};
+
+/// Number of requests which can wait for the state history thread before further requests are rejected
+const REQUESTS_CAPACITY: usize = 16;
+
+/// Number of blocks after which the next checkpoint of the state is written.
+/// At most this many blocks are replayed to reconstruct a state within the configured depth.
+pub const CHECKPOINT_INTERVAL: usize = 64;
+
+/// How often the state history thread checks whether the next checkpoint is due
+const CHECKPOINT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Name of the directory inside of the snapshot store directory where the checkpoints are kept
+const CHECKPOINTS_DIR_NAME: &str = "history";
+
+/// Reconstructs the [`State`] at past block heights to answer historical queries and to replay the events of past blocks.
+///
+/// States are reconstructed on a dedicated thread, one request at a time,
+/// so that historical queries never occupy the threads serving other requests.
+pub struct StateHistory {
+    /// How many blocks behind the latest one the state can be reconstructed
+    depth: u64,
+    requests: mpsc::SyncSender<Request>,
+}
+
+/// Request to the thread of [`StateHistory`]
+enum Request {
+    /// Call `f` with the state right after the block at `height` was committed
+    State {
+        height: usize,
+        f: Box<dyn FnOnce(&State) + Send>,
+    },
+    /// Send the events emitted while committing and applying the block at `height`, unless it isn't committed yet
+    BlockEvents {
+        height: NonZeroUsize,
+        sender: oneshot::Sender<Option<Vec<EventBox>>>,
+    },
+}
+
+/// Owner of the reconstructed states, living on the thread of [`StateHistory`]
+struct Replayer {
+    chain_id: ChainId,
+    genesis_account: AccountId,
+    trusted_peers: Vec<PeerId>,
+    kura: Arc<Kura>,
+    /// The latest state, of which the checkpoints are written
+    state: Arc<State>,
+    query_handle: LiveQueryStoreHandle,
+    snapshot_dir: PathBuf,
+    checkpoints: Checkpoints,
+}
+
+/// Checkpoints of the latest state, covering the configured depth
+struct Checkpoints {
+    store_dir: PathBuf,
+    keep_last: NonZeroUsize,
+    /// Height of the latest written checkpoint
+    latest_height: Option<usize>,
+    /// Chunks of the latest written checkpoint, reused by the next one
+    entry_chunks: EntryChunks,
+}
+
+impl StateHistory {
+    /// Construct [`StateHistory`] and start the thread reconstructing the states.
+    ///
+    /// The thread also writes the checkpoints of `state` into the `snapshot_dir` and stops once [`StateHistory`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the thread can't be spawned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: ChainId,
+        genesis_account: AccountId,
+        trusted_peers: Vec<PeerId>,
+        kura: Arc<Kura>,
+        state: Arc<State>,
+        query_handle: LiveQueryStoreHandle,
+        snapshot_dir: PathBuf,
+        depth: u64,
+    ) -> Self {
+        let (requests, receiver) = mpsc::sync_channel(REQUESTS_CAPACITY);
+        let checkpoints_dir = snapshot_dir.join(CHECKPOINTS_DIR_NAME);
+        let latest_checkpoint = read_manifests(&checkpoints_dir)
+            .ok()
+            .and_then(|manifests| manifests.last().map(|manifest| manifest.height))
+            .and_then(|height| usize::try_from(height).ok());
+        // the oldest retained checkpoint has to be at least `depth` blocks behind the latest one
+        let keep_last = usize::try_from(depth)
+            .unwrap_or(usize::MAX)
+            .div_ceil(CHECKPOINT_INTERVAL)
+            .saturating_add(1);
+        let replayer = Replayer {
+            chain_id,
+            genesis_account,
+            trusted_peers,
+            kura,
+            state,
+            query_handle,
+            snapshot_dir,
+            checkpoints: Checkpoints {
+                store_dir: checkpoints_dir,
+                keep_last: NonZeroUsize::new(keep_last).expect("at least one is kept"),
+                latest_height: latest_checkpoint,
+                entry_chunks: EntryChunks::default(),
+            },
+        };
+        std::thread::Builder::new()
+            .name("state_history".to_owned())
+            .spawn(move || replayer.run(receiver))
+            .expect("INTERNAL BUG: Failed to spawn the state history thread");
+
+        Self { depth, requests }
+    }
+
+    /// Call `f` with the state right after the block at `height` was committed.
+    ///
+    /// Historical states are reconstructed one at a time, so concurrent callers wait for each other.
+    ///
+    /// # Errors
+    ///
+    /// - [`QueryExecutionFail::HeightUnavailable`] if `height` is greater than `latest_height`
+    ///   or is more than the configured depth behind it
+    /// - [`QueryExecutionFail::CapacityLimit`] if too many requests are already waiting
+    pub async fn with_state_at<R: Send + 'static>(
+        &self,
+        height: NonZeroU64,
+        latest_height: usize,
+        f: impl FnOnce(&State) -> R + Send + 'static,
+    ) -> Result<R, QueryExecutionFail> {
+        let latest_height = latest_height as u64;
+        if height.get() > latest_height || latest_height - height.get() > self.depth {
+            return Err(QueryExecutionFail::HeightUnavailable);
+        }
+        let height = usize::try_from(height.get()).expect("INTERNAL BUG: Height exceeds usize");
+
+        let (sender, receiver) = oneshot::channel();
+        self.submit(Request::State {
+            height,
+            f: Box::new(move |state| {
+                let _ = sender.send(f(state));
+            }),
+        })?;
+
+        Ok(receiver
+            .await
+            .expect("INTERNAL BUG: State history thread has stopped"))
+    }
+
+    /// Events emitted while committing and applying the block at `height`, reconstructed by replaying the block.
+    ///
+    /// Returns [`None`] if the block isn't committed yet.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`QueryExecutionFail::CapacityLimit`] if too many requests are already waiting
+    pub async fn block_events(
+        &self,
+        height: NonZeroU64,
+    ) -> Result<Option<Vec<EventBox>>, QueryExecutionFail> {
+        let height = height
+            .try_into()
+            .expect("INTERNAL BUG: Height exceeds usize");
+
+        let (sender, receiver) = oneshot::channel();
+        self.submit(Request::BlockEvents { height, sender })?;
+
+        Ok(receiver
+            .await
+            .expect("INTERNAL BUG: State history thread has stopped"))
+    }
+
+    fn submit(&self, request: Request) -> Result<(), QueryExecutionFail> {
+        self.requests
+            .try_send(request)
+            .map_err(|error| match error {
+                TrySendError::Full(_) => QueryExecutionFail::CapacityLimit,
+                TrySendError::Disconnected(_) => {
+                    panic!("INTERNAL BUG: State history thread has stopped")
+                }
+            })
+    }
+}
+
+impl Replayer {
+    fn run(mut self, requests: mpsc::Receiver<Request>) {
+        // The most recently reconstructed state.
+        // It is advanced in place if a later height is requested.
+        let mut latest: Option<State> = None;
+
+        loop {
+            match requests.recv_timeout(CHECKPOINT_POLL_INTERVAL) {
+                Ok(Request::State { height, f }) => {
+                    let state = self.state_at(height, latest.take());
+
+                    f(&state);
+                    latest = Some(state);
+                }
+                Ok(Request::BlockEvents { height, sender }) => {
+                    if self.kura.get_block_hash(height).is_none() {
+                        let _ = sender.send(None);
+                    } else {
+                        // the events are collected by replaying the block on the state preceding it
+                        let state = self.state_at(height.get() - 1, latest.take());
+                        let mut events = Vec::new();
+                        self.replay(&state, height.get(), |event| events.push(event));
+
+                        let _ = sender.send(Some(events));
+                        latest = Some(state);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            self.checkpoints.write_if_due(&self.state);
+        }
+    }
+
+    /// The state right after the block at `height` was committed, advanced from the `latest` reconstructed state if possible
+    fn state_at(&self, height: usize, latest: Option<State>) -> State {
+        let state = match latest {
+            Some(state) if state.view().height() <= height && self.is_on_chain(&state) => state,
+            _ => self.restore(height),
+        };
+        self.replay(&state, height, |_| {});
+
+        state
+    }
+
+    /// Check that the latest block of `state` wasn't replaced by a soft fork
+    fn is_on_chain(&self, state: &State) -> bool {
+        let state_view = state.view();
+        NonZeroUsize::new(state_view.height()).map_or(true, |height| {
+            self.kura.get_block_hash(height) == state_view.latest_block_hash()
+        })
+    }
+
+    /// Restore the state from the latest checkpoint or snapshot which isn't ahead of `height`, or start from an empty state
+    fn restore(&self, height: usize) -> State {
+        let closest_dir = [&self.checkpoints.store_dir, &self.snapshot_dir]
+            .into_iter()
+            .max_by_key(|store_dir| {
+                read_manifests(store_dir).ok().and_then(|manifests| {
+                    manifests
+                        .into_iter()
+                        .map(|manifest| manifest.height)
+                        .filter(|snapshot_height| *snapshot_height <= height as u64)
+                        .max()
+                })
+            })
+            .expect("there are store directories");
+
+        match try_read_snapshot(
+            closest_dir,
+            &self.kura,
+            || self.query_handle.clone(),
+            BlockCount(height),
+            #[cfg(feature = "telemetry")]
+            StateTelemetry::default(),
+        ) {
+            Ok(state) => state,
+            Err(error) => {
+                debug!(%error, height, "Replaying historical state from genesis");
+                self.empty_state()
+            }
+        }
+    }
+
+    fn empty_state(&self) -> State {
+        let genesis_account =
+            Account::new(self.genesis_account.clone()).build(&self.genesis_account);
+        let genesis_domain =
+            Domain::new(self.genesis_account.domain().clone()).build(&self.genesis_account);
+
+        State::new(
+            World::with([genesis_domain], [genesis_account], []),
+            Arc::clone(&self.kura),
+            self.query_handle.clone(),
+            #[cfg(all(feature = "telemetry", not(test)))]
+            StateTelemetry::default(),
+        )
+    }
+
+    /// Apply blocks from [`Kura`] to `state` until it reaches `height`, passing the emitted events to `on_event`
+    fn replay(&self, state: &State, height: usize, mut on_event: impl FnMut(EventBox)) {
+        let state_height = state.view().height();
+        let mut topology = match state_height {
+            0 => Topology::new(self.trusted_peers.clone()),
+            _ => Topology::new(state.view().commit_topology.clone()),
+        };
+
+        for block_height in state_height + 1..=height {
+            let block = NonZeroUsize::new(block_height)
+                .and_then(|height| self.kura.get_block(height))
+                .expect("INTERNAL BUG: Kura has height at least as large as state height");
+
+            let mut state_block = state.block(block.header());
+            SumeragiHandle::replay_block(
+                &self.chain_id,
+                &self.genesis_account,
+                &block,
+                &mut state_block,
+                &mut on_event,
+                &mut topology,
+            );
+            state_block.commit();
+        }
+    }
+}
+
+impl Checkpoints {
+    /// Write a checkpoint of the latest `state` if [`CHECKPOINT_INTERVAL`] blocks were committed since the previous one
+    fn write_if_due(&mut self, state: &State) {
+        let height = state.view().height();
+        let is_due = match self.latest_height {
+            // a shorter chain replaced the checkpointed one
+            Some(latest) if height < latest => true,
+            Some(latest) => height - latest >= CHECKPOINT_INTERVAL,
+            None => height > 0,
+        };
+        if !is_due {
+            return;
+        }
+
+        match try_write_snapshot(
+            state,
+            &self.store_dir,
+            self.keep_last,
+            &mut self.entry_chunks,
+        ) {
+            Ok(written) => {
+                debug!(
+                    at_height = written.manifest.height,
+                    "Written a checkpoint of the state history"
+                );
+            }
+            Err(error) => {
+                // retried with the next checkpoint
+                warn!(%error, height, "Failed to write a checkpoint of the state history");
+            }
+        }
+        self.latest_height = Some(height);
+    }
+}
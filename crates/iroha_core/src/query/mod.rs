@@ -1,5 +1,6 @@
 //! This module contains [`QueryService`](service::QueryService) and helpers.
 
 pub mod cursor;
+pub mod history;
 pub mod pagination;
 pub mod store;
@@ -1,11 +1,12 @@
 //! This module contains [`LiveQueryStore`] actor.
 
 use std::{
-    num::{NonZeroU64, NonZeroUsize},
+    num::NonZeroU64,
     sync::Arc,
     time::{Duration, Instant},
 };
 
+use arc_swap::ArcSwap;
 use dashmap::{mapref::entry::Entry, DashMap};
 use iroha_config::parameters::actual::LiveQueryStore as Config;
 use iroha_data_model::{
@@ -18,7 +19,7 @@ use iroha_data_model::{
 };
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
 use iroha_logger::{trace, warn};
-use tokio::task::JoinHandle;
+use tokio::{sync::Notify, task::JoinHandle};
 
 use super::cursor::ErasedQueryIterator;
 
@@ -31,12 +32,12 @@ type LiveQuery = ErasedQueryIterator;
 pub struct LiveQueryStore {
     queries: DashMap<QueryId, QueryInfo>,
     queries_per_user: DashMap<AccountId, usize>,
-    // The maximum number of queries in the store
-    capacity: NonZeroUsize,
-    // The maximum number of queries in the store per user
-    capacity_per_user: NonZeroUsize,
-    // Queries older then this time will be automatically removed from the store
-    idle_time: Duration,
+    // Limits of the store, which might be updated at runtime:
+    // the maximum number of queries in the store (overall and per user)
+    // and the time after which idle queries are automatically removed from the store
+    config: ArcSwap<Config>,
+    // Notifies the pruning task that the idle time might have changed
+    config_updated: Notify,
     shutdown_signal: ShutdownSignal,
 }
 
@@ -53,9 +54,8 @@ impl LiveQueryStore {
         Self {
             queries: DashMap::new(),
             queries_per_user: DashMap::new(),
-            idle_time: cfg.idle_time,
-            capacity: cfg.capacity,
-            capacity_per_user: cfg.capacity_per_user,
+            config: ArcSwap::from_pointee(cfg),
+            config_updated: Notify::new(),
             shutdown_signal,
         }
     }
@@ -88,13 +88,14 @@ impl LiveQueryStore {
     }
 
     fn spawn_pruning_task(self: Arc<Self>) -> JoinHandle<()> {
-        let mut idle_interval = tokio::time::interval(self.idle_time);
+        let mut idle_interval = tokio::time::interval(self.config.load().idle_time);
         tokio::task::spawn(async move {
             loop {
                 tokio::select! {
                     _ = idle_interval.tick() => {
+                        let idle_time = self.config.load().idle_time;
                         self.queries.retain(|_, query| {
-                            if query.last_access_time.elapsed() <= self.idle_time {
+                            if query.last_access_time.elapsed() <= idle_time {
                                 true
                             } else {
                                 self.decrease_queries_per_user(query.authority.clone());
@@ -102,6 +103,9 @@ impl LiveQueryStore {
                             }
                         });
                     }
+                    () = self.config_updated.notified() => {
+                        idle_interval = tokio::time::interval(self.config.load().idle_time);
+                    }
                     () = self.shutdown_signal.receive() => {
                         iroha_logger::debug!("LiveQueryStore is being shut down.");
                         break;
@@ -171,17 +175,18 @@ impl LiveQueryStore {
     }
 
     fn check_capacity(&self, authority: &AccountId) -> Result<(), QueryExecutionFail> {
-        if self.queries.len() >= self.capacity.get() {
+        let config = self.config.load();
+        if self.queries.len() >= config.capacity.get() {
             warn!(
-                max_queries = self.capacity,
+                max_queries = config.capacity,
                 "Reached maximum allowed number of queries in LiveQueryStore"
             );
             return Err(QueryExecutionFail::CapacityLimit);
         }
         if let Some(value) = self.queries_per_user.get(authority) {
-            if *value >= self.capacity_per_user.get() {
+            if *value >= config.capacity_per_user.get() {
                 warn!(
-                    max_queries_per_user = self.capacity_per_user,
+                    max_queries_per_user = config.capacity_per_user,
                     %authority,
                     "Account reached maximum allowed number of queries in LiveQueryStore"
                 );
@@ -257,6 +262,14 @@ impl LiveQueryStoreHandle {
         self.store.remove(query_id);
     }
 
+    /// Apply updated limits of the store.
+    ///
+    /// Queries which are already in the store are kept even if they exceed the new limits.
+    pub fn update_config(&self, config: Config) {
+        self.store.config.store(Arc::new(config));
+        self.store.config_updated.notify_one();
+    }
+
     fn construct_query_response(
         batch: QueryOutputBatchBoxTuple,
         remaining_items: u64,
@@ -303,6 +316,7 @@ mod tests {
                 pagination,
                 sorting,
                 fetch_size,
+                at_height: None,
             };
 
             // it's not important which type we use here, just to test the flow
@@ -311,6 +325,8 @@ mod tests {
             let query_output = crate::smartcontracts::query::apply_query_postprocessing(
                 query_output,
                 SelectorTuple::default(),
+                &[],
+                None,
                 &query_params,
             )
             .unwrap();
@@ -1,8 +1,8 @@
 //! Module with queue actor
-use core::time::Duration;
 use std::{num::NonZeroUsize, ops::Deref, sync::Arc};
 
-use crossbeam_queue::ArrayQueue;
+use arc_swap::ArcSwap;
+use crossbeam_queue::SegQueue;
 use dashmap::{mapref::entry::Entry, DashMap};
 use eyre::Result;
 use indexmap::IndexSet;
@@ -34,23 +34,19 @@ impl AcceptedTransaction {
 pub struct Queue {
     events_sender: EventsSender,
     /// The queue for transactions
-    tx_hashes: ArrayQueue<HashOf<SignedTransaction>>,
+    tx_hashes: SegQueue<HashOf<SignedTransaction>>,
     /// [`AcceptedTransaction`]s addressed by `Hash`
     txs: DashMap<HashOf<SignedTransaction>, AcceptedTransaction>,
     /// Amount of transactions per user in the queue
     txs_per_user: DashMap<AccountId, usize>,
-    /// The maximum number of transactions in the queue
-    capacity: NonZeroUsize,
-    /// The maximum number of transactions in the queue per user. Used to apply throttling
-    capacity_per_user: NonZeroUsize,
+    /// Limits of the queue, which might be updated at runtime
+    config: ArcSwap<Config>,
     /// The time source used to check transaction against
     ///
     /// A mock time source is used in tests for determinism
     time_source: TimeSource,
-    /// Length of time after which transactions are dropped.
-    pub tx_time_to_live: Duration,
     /// Queue to gossip transactions
-    tx_gossip: ArrayQueue<HashOf<SignedTransaction>>,
+    tx_gossip: SegQueue<HashOf<SignedTransaction>>,
 }
 
 /// Queue push error
@@ -101,27 +97,25 @@ impl Drop for TransactionGuard {
 
 impl Queue {
     /// Makes queue from configuration
-    pub fn from_config(
-        Config {
-            capacity,
-            capacity_per_user,
-            transaction_time_to_live,
-        }: Config,
-        events_sender: EventsSender,
-    ) -> Self {
+    pub fn from_config(config: Config, events_sender: EventsSender) -> Self {
         Self {
             events_sender,
-            tx_hashes: ArrayQueue::new(capacity.get()),
+            tx_hashes: SegQueue::new(),
             txs: DashMap::new(),
             txs_per_user: DashMap::new(),
-            capacity,
-            capacity_per_user,
+            config: ArcSwap::from_pointee(config),
             time_source: TimeSource::new_system(),
-            tx_time_to_live: transaction_time_to_live,
-            tx_gossip: ArrayQueue::new(capacity.get()),
+            tx_gossip: SegQueue::new(),
         }
     }
 
+    /// Apply updated limits.
+    ///
+    /// Transactions which are already in the queue are kept even if they exceed the new limits.
+    pub fn update_config(&self, config: Config) {
+        self.config.store(Arc::new(config));
+    }
+
     fn is_pending(&self, tx: &AcceptedTransaction, state_view: &StateView) -> bool {
         !self.is_expired(tx) && !tx.is_in_blockchain(state_view)
     }
@@ -130,9 +124,10 @@ impl Queue {
     pub fn is_expired(&self, tx: &AcceptedTransaction) -> bool {
         let tx_creation_time = tx.as_ref().creation_time();
 
+        let queue_time_to_live = self.config.load().transaction_time_to_live;
         let time_limit = tx.as_ref().time_to_live().map_or_else(
-            || self.tx_time_to_live,
-            |tx_time_to_live| core::cmp::min(self.tx_time_to_live, tx_time_to_live),
+            || queue_time_to_live,
+            |tx_time_to_live| core::cmp::min(queue_time_to_live, tx_time_to_live),
         );
 
         let curr_time = self.time_source.get_unix_time();
@@ -205,11 +200,9 @@ impl Queue {
             Entry::Vacant(entry) => entry,
         };
 
-        if txs_len >= self.capacity.get() {
-            warn!(
-                max = self.capacity,
-                "Achieved maximum amount of transactions"
-            );
+        let capacity = self.config.load().capacity;
+        if txs_len >= capacity.get() {
+            warn!(max = capacity, "Achieved maximum amount of transactions");
             return Err(Failure {
                 tx,
                 err: Error::Full,
@@ -222,20 +215,11 @@ impl Queue {
 
         // Insert entry first so that the `tx` popped from `queue` will always have a `(hash, tx)` record in `txs`.
         entry.insert(tx);
-        self.tx_hashes.push(hash).map_err(|err_hash| {
-            warn!("Queue is full");
-            let (_, err_tx) = self
-                .txs
-                .remove(&err_hash)
-                .expect("Inserted just before match");
-            self.decrease_per_user_tx_count(err_tx.as_ref().authority());
-            Failure {
-                tx: err_tx,
-                err: Error::Full,
-            }
-        })?;
-        if let Err(err_hash) = self.tx_gossip.push(hash) {
-            warn!(tx=%err_hash, "Gossiper is lagging behind, not able to queue tx for gossiping");
+        self.tx_hashes.push(hash);
+        if self.tx_gossip.len() < capacity.get() {
+            self.tx_gossip.push(hash);
+        } else {
+            warn!(tx=%hash, "Gossiper is lagging behind, not able to queue tx for gossiping");
         }
         let _ = self.events_sender.send(
             TransactionEvent {
@@ -366,9 +350,10 @@ impl Queue {
             }
             Entry::Occupied(mut occupied) => {
                 let txs = *occupied.get();
-                if txs >= self.capacity_per_user.get() {
+                let capacity_per_user = self.config.load().capacity_per_user;
+                if txs >= capacity_per_user.get() {
                     warn!(
-                        max_txs_per_user = self.capacity_per_user,
+                        max_txs_per_user = capacity_per_user,
                         %account_id,
                         "Account reached maximum allowed number of transactions in the queue per user"
                     );
@@ -419,14 +404,12 @@ pub mod tests {
         pub fn test(cfg: Config, time_source: &TimeSource) -> Self {
             Self {
                 events_sender: tokio::sync::broadcast::Sender::new(1),
-                tx_hashes: ArrayQueue::new(cfg.capacity.get()),
-                tx_gossip: ArrayQueue::new(cfg.capacity.get()),
+                tx_hashes: SegQueue::new(),
+                tx_gossip: SegQueue::new(),
                 txs: DashMap::new(),
                 txs_per_user: DashMap::new(),
-                capacity: cfg.capacity,
-                capacity_per_user: cfg.capacity_per_user,
+                config: ArcSwap::from_pointee(cfg),
                 time_source: time_source.clone(),
-                tx_time_to_live: cfg.transaction_time_to_live,
             }
         }
     }
@@ -524,6 +507,46 @@ pub mod tests {
         ));
     }
 
+    #[test]
+    async fn push_tx_after_capacity_update() {
+        let capacity = nonzero!(10_usize);
+
+        let kura: Arc<Kura> = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = Arc::new(State::new(world_with_test_domains(), kura, query_handle));
+
+        let (time_handle, time_source) = TimeSource::new_mock(Duration::default());
+
+        let config = Config {
+            transaction_time_to_live: Duration::from_secs(100),
+            capacity,
+            ..Config::default()
+        };
+        let queue = Queue::test(config, &time_source);
+
+        for _ in 0..capacity.get() {
+            queue
+                .push(accepted_tx_by_someone(&time_source), state.view())
+                .expect("Failed to push tx into queue");
+            time_handle.advance(Duration::from_millis(10));
+        }
+
+        queue.update_config(Config {
+            capacity: capacity.saturating_add(1),
+            ..config
+        });
+        queue
+            .push(accepted_tx_by_someone(&time_source), state.view())
+            .expect("Capacity was increased");
+        assert!(matches!(
+            queue.push(accepted_tx_by_someone(&time_source), state.view()),
+            Err(Failure {
+                err: Error::Full,
+                ..
+            })
+        ));
+    }
+
     #[test]
     async fn get_available_txs() {
         let max_txs_in_block = nonzero!(2_usize);
@@ -23,13 +23,20 @@ impl Registrable for iroha_data_model::account::NewAccount {
 /// - grant permissions and roles
 /// - Revoke permissions or roles
 pub mod isi {
-    use iroha_data_model::isi::{
-        error::{MintabilityError, RepetitionError},
-        InstructionType,
+    use iroha_data_model::{
+        account::MAX_SIGNATORIES,
+        isi::{
+            error::{MintabilityError, RepetitionError},
+            InstructionType,
+        },
     };
 
     use super::*;
-    use crate::{role::RoleIdWithOwner, state::StateTransaction};
+    use crate::{
+        role::RoleIdWithOwner,
+        smartcontracts::asset::isi::{already_frozen, not_frozen},
+        state::StateTransaction,
+    };
 
     impl Execute for Transfer<Account, AssetDefinitionId, Account> {
         fn execute(
@@ -275,6 +282,248 @@ pub mod isi {
         }
     }
 
+    impl Execute for AddSignatory {
+        #[metrics(+"add_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                account: account_id,
+                signatory,
+            } = self;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if account.has_signatory(&signatory) {
+                return Err(already_a_signatory(&signatory, &account_id));
+            }
+            if account.signatories().len() >= MAX_SIGNATORIES {
+                return Err(Error::InvariantViolation(format!(
+                    "Account can't have more than {MAX_SIGNATORIES} signatories"
+                )));
+            }
+            account.signatories.insert(signatory.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SignatoryAdded(
+                    AccountSignatoryChanged {
+                        account: account_id,
+                        signatory,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RemoveSignatory {
+        #[metrics(+"remove_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                account: account_id,
+                signatory,
+            } = self;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if !account.has_signatory(&signatory) {
+                return Err(not_a_signatory(&signatory, &account_id));
+            }
+            if account.signatories().len() == 1 {
+                return Err(Error::InvariantViolation(
+                    "Can't remove the last signatory of an account".to_owned(),
+                ));
+            }
+            account.signatories.remove(&signatory);
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SignatoryRemoved(
+                    AccountSignatoryChanged {
+                        account: account_id,
+                        signatory,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RotateAccountKey {
+        #[metrics(+"rotate_account_key")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                account: account_id,
+                old_key,
+                new_key,
+            } = self;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if !account.has_signatory(&old_key) {
+                return Err(not_a_signatory(&old_key, &account_id));
+            }
+            if account.has_signatory(&new_key) {
+                return Err(already_a_signatory(&new_key, &account_id));
+            }
+            account.signatories.remove(&old_key);
+            account.signatories.insert(new_key.clone());
+
+            state_transaction.world.emit_events([
+                AccountEvent::SignatoryRemoved(AccountSignatoryChanged {
+                    account: account_id.clone(),
+                    signatory: old_key,
+                }),
+                AccountEvent::SignatoryAdded(AccountSignatoryChanged {
+                    account: account_id,
+                    signatory: new_key,
+                }),
+            ]);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RegisterAlias {
+        #[metrics(+"register_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                account: account_id,
+                alias,
+            } = self;
+
+            if alias.domain != account_id.domain {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias `{alias}` isn't in the domain of `{account_id}`"
+                )));
+            }
+            if let Some(owner) = state_transaction.world.account_aliases.get(&alias) {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias `{alias}` is already taken by `{owner}`"
+                )));
+            }
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if let Some(current) = &account.alias {
+                return Err(Error::InvariantViolation(format!(
+                    "`{account_id}` already has `{current}` alias"
+                )));
+            }
+            account.alias = Some(alias.clone());
+            state_transaction
+                .world
+                .account_aliases
+                .insert(alias.clone(), account_id.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AliasRegistered(AccountAliasChanged {
+                    account: account_id,
+                    alias,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for UnregisterAlias {
+        #[metrics(+"unregister_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let alias = self.alias;
+
+            let Some(account_id) = state_transaction
+                .world
+                .account_aliases
+                .remove(alias.clone())
+            else {
+                return Err(FindError::AccountAlias(alias).into());
+            };
+            state_transaction.world.account_mut(&account_id)?.alias = None;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AliasUnregistered(AccountAliasChanged {
+                    account: account_id,
+                    alias,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Freeze<Account> {
+        #[metrics(+"freeze_account")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.object;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if account.frozen {
+                return Err(already_frozen(&account_id));
+            }
+            account.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::Frozen(account_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<Account> {
+        #[metrics(+"unfreeze_account")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.object;
+
+            let account = state_transaction.world.account_mut(&account_id)?;
+            if !account.frozen {
+                return Err(not_frozen(&account_id));
+            }
+            account.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::Unfrozen(account_id)));
+
+            Ok(())
+        }
+    }
+
+    fn already_a_signatory(signatory: &PublicKey, account_id: &AccountId) -> Error {
+        Error::InvariantViolation(format!(
+            "`{signatory}` is already a signatory of `{account_id}`"
+        ))
+    }
+
+    fn not_a_signatory(signatory: &PublicKey, account_id: &AccountId) -> Error {
+        Error::InvariantViolation(format!(
+            "`{signatory}` is not a signatory of `{account_id}`"
+        ))
+    }
+
     /// Stop minting on the [`AssetDefinition`] globally.
     ///
     /// # Errors
@@ -314,10 +563,14 @@ pub mod query {
         account::Account,
         permission::Permission,
         query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
+        state::{StateEntry, StateProof},
     };
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::{root, StateReadOnly},
+    };
 
     impl ValidQuery for FindRolesByAccountId {
         #[metrics(+"find_roles_by_account_id")]
@@ -394,4 +647,26 @@ pub mod query {
                 .cloned())
         }
     }
+
+    impl ValidSingularQuery for FindAccountByAlias {
+        #[metrics(+"find_account_by_alias")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<AccountId, Error> {
+            state_ro
+                .world()
+                .account_aliases()
+                .get(&self.alias)
+                .cloned()
+                .ok_or_else(|| FindError::AccountAlias(self.alias.clone()).into())
+        }
+    }
+
+    impl ValidSingularQuery for FindAccountStateProof {
+        #[metrics(+"find_account_state_proof")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<StateProof, Error> {
+            let account = state_ro.world().account(&self.id)?.clone();
+
+            root::state_proof(state_ro, StateEntry::Account(account))
+                .ok_or_else(|| FindError::Account(self.id.clone()).into())
+        }
+    }
 }
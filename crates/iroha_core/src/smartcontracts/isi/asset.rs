@@ -24,6 +24,7 @@ impl Registrable for NewAssetDefinition {
             metadata: self.metadata,
             owned_by: authority.clone(),
             total_quantity: Numeric::ZERO,
+            frozen: false,
         }
     }
 }
@@ -50,6 +51,7 @@ pub mod isi {
                 .world
                 .asset_definition(&asset_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_frozen(&asset_id, state_transaction)?;
 
             assert_can_mint(&asset_definition, state_transaction)?;
             let asset = state_transaction
@@ -94,6 +96,8 @@ pub mod isi {
                 .world
                 .asset_definition(&asset_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_frozen(&asset_id, state_transaction)?;
+            assert_not_locked(&asset_id, self.object, state_transaction)?;
 
             let asset = state_transaction
                 .world
@@ -149,6 +153,9 @@ pub mod isi {
                 .world
                 .asset_definition(&source_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_frozen(&source_id, state_transaction)?;
+            assert_not_frozen(&destination_id, state_transaction)?;
+            assert_not_locked(&source_id, self.object, state_transaction)?;
 
             {
                 let asset = state_transaction
@@ -200,6 +207,201 @@ pub mod isi {
         }
     }
 
+    impl Execute for Approve {
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                asset: asset_id,
+                spender,
+                amount,
+            } = self;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition(&asset_id.definition)?;
+            assert_numeric_spec(&amount, &asset_definition)?;
+            let _ = state_transaction.world.account(&asset_id.account)?;
+            let _ = state_transaction.world.account(&spender)?;
+
+            let allowance_id = AssetAllowanceId::new(asset_id, spender);
+            if amount.is_zero() {
+                state_transaction
+                    .world
+                    .asset_allowances
+                    .remove(allowance_id.clone());
+            } else {
+                state_transaction
+                    .world
+                    .asset_allowances
+                    .insert(allowance_id.clone(), amount);
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::AllowanceApproved(AssetAllowance::new(
+                    allowance_id,
+                    amount,
+                ))));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Freeze<AssetDefinition> {
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if asset_definition.frozen {
+                return Err(already_frozen(&asset_definition_id));
+            }
+            asset_definition.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Frozen(asset_definition_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<AssetDefinition> {
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if !asset_definition.frozen {
+                return Err(not_frozen(&asset_definition_id));
+            }
+            asset_definition.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Unfrozen(asset_definition_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Freeze<Asset> {
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            if asset.frozen {
+                return Err(already_frozen(&asset_id));
+            }
+            asset.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Frozen(asset_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<Asset> {
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            if !asset.frozen {
+                return Err(not_frozen(&asset_id));
+            }
+            asset.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Unfrozen(asset_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for TransferFrom {
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self {
+                asset: asset_id,
+                amount,
+                destination,
+            } = self;
+
+            if amount.is_zero() {
+                return Err(Error::InvariantViolation(format!(
+                    "Transfer of zero `{asset_id}` from an allowance"
+                )));
+            }
+
+            let allowance_id = AssetAllowanceId::new(asset_id.clone(), authority.clone());
+            let allowed = state_transaction
+                .world
+                .asset_allowances
+                .get(&allowance_id)
+                .copied()
+                .ok_or_else(|| {
+                    Error::InvariantViolation(format!(
+                        "`{authority}` has no allowance to spend `{asset_id}`"
+                    ))
+                })?;
+            let left = allowed.checked_sub(amount).ok_or_else(|| {
+                Error::InvariantViolation(format!(
+                    "`{authority}` is allowed to spend only `{allowed}` of `{asset_id}`"
+                ))
+            })?;
+            if left.is_zero() {
+                state_transaction
+                    .world
+                    .asset_allowances
+                    .remove(allowance_id.clone());
+            } else {
+                state_transaction
+                    .world
+                    .asset_allowances
+                    .insert(allowance_id.clone(), left);
+            }
+
+            Transfer::asset_numeric(asset_id, amount, destination)
+                .execute(authority, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::AllowanceSpent(AssetAllowance::new(
+                    allowance_id,
+                    left,
+                ))));
+
+            Ok(())
+        }
+    }
+
     /// Assert that asset type is Numeric and that it satisfy asset definition spec
     pub(crate) fn assert_numeric_spec(
         object: &Numeric,
@@ -237,6 +439,70 @@ pub mod isi {
             }
         }
     }
+
+    /// Assert that the asset can be moved, i.e. that neither the asset, nor its account or definition is frozen.
+    fn assert_not_frozen(
+        asset_id: &AssetId,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if state_transaction
+            .world
+            .asset_definition(&asset_id.definition)?
+            .frozen
+        {
+            return Err(frozen(&asset_id.definition));
+        }
+        if state_transaction.world.account(&asset_id.account)?.frozen {
+            return Err(frozen(&asset_id.account));
+        }
+        if state_transaction
+            .world
+            .assets()
+            .get(asset_id)
+            .is_some_and(|asset| asset.frozen)
+        {
+            return Err(frozen(asset_id));
+        }
+        Ok(())
+    }
+
+    /// Assert that `quantity` of the asset isn't locked by open swaps.
+    fn assert_not_locked(
+        asset_id: &AssetId,
+        quantity: Numeric,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let Some(locked) = state_transaction.world.locked_assets.get(asset_id) else {
+            return Ok(());
+        };
+        let value = state_transaction
+            .world
+            .assets
+            .get(asset_id)
+            .map_or(Numeric::ZERO, |asset| asset.value);
+        if value
+            .checked_sub(*locked)
+            .and_then(|unlocked| unlocked.checked_sub(quantity))
+            .is_none()
+        {
+            return Err(Error::InvariantViolation(format!(
+                "`{quantity}` of `{asset_id}` is locked by open swaps"
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn frozen(id: &impl core::fmt::Display) -> Error {
+        Error::InvariantViolation(format!("`{id}` is frozen"))
+    }
+
+    pub(crate) fn already_frozen(id: &impl core::fmt::Display) -> Error {
+        Error::InvariantViolation(format!("`{id}` is already frozen"))
+    }
+
+    pub(crate) fn not_frozen(id: &impl core::fmt::Display) -> Error {
+        Error::InvariantViolation(format!("`{id}` is not frozen"))
+    }
 }
 
 /// Asset-related query implementations.
@@ -244,11 +510,25 @@ pub mod query {
     use eyre::Result;
     use iroha_data_model::{
         asset::{Asset, AssetDefinition},
-        query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
+        query::{
+            dsl::{
+                predicates::NumericPredicateAtom,
+                type_descriptions::{
+                    AssetDefinitionProjection, AssetProjection, NumericProjection,
+                },
+                CompoundPredicate, EvaluatePredicate, HasProjection, PredicateMarker,
+            },
+            error::QueryExecutionFail as Error,
+        },
+        state::{StateEntry, StateProof},
     };
+    use iroha_primitives::numeric::NumericSpec;
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::{root, StateReadOnly},
+    };
 
     impl ValidQuery for FindAssets {
         #[metrics(+"find_assets")]
@@ -257,11 +537,25 @@ pub mod query {
             filter: CompoundPredicate<Asset>,
             state_ro: &impl StateReadOnly,
         ) -> Result<impl Iterator<Item = Asset>, Error> {
-            Ok(state_ro
-                .world()
+            let world = state_ro.world();
+            let assets = world
                 .assets_iter()
-                .filter(move |&asset| filter.applies(asset))
-                .cloned())
+                .filter_map(|asset| {
+                    let spec = || {
+                        world
+                            .asset_definitions()
+                            .get(&asset.id.definition)
+                            .map(|asset_definition| asset_definition.spec)
+                            .ok_or_else(|| FindError::AssetDefinition(asset.id.definition.clone()))
+                            .map_err(Error::from)
+                    };
+                    applies_within_spec(&filter, asset, asset_value_atom, &spec)
+                        .map(|applies| applies.then(|| asset.clone()))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(assets.into_iter())
         }
     }
     impl ValidQuery for FindAssetsDefinitions {
@@ -271,11 +565,112 @@ pub mod query {
             filter: CompoundPredicate<AssetDefinition>,
             state_ro: &impl StateReadOnly,
         ) -> Result<impl Iterator<Item = AssetDefinition>, Error> {
-            Ok(state_ro
+            let asset_definitions = state_ro
                 .world()
                 .asset_definitions_iter()
-                .filter(move |&asset_definition| filter.applies(asset_definition))
-                .cloned())
+                .filter_map(|asset_definition| {
+                    let spec = || Ok(asset_definition.spec);
+                    applies_within_spec(&filter, asset_definition, total_quantity_atom, &spec)
+                        .map(|applies| applies.then(|| asset_definition.clone()))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(asset_definitions.into_iter())
+        }
+    }
+
+    fn asset_value_atom(
+        projection: &AssetProjection<PredicateMarker>,
+    ) -> Option<&NumericPredicateAtom> {
+        match projection {
+            AssetProjection::Value(NumericProjection::Atom(atom)) => Some(atom),
+            _ => None,
+        }
+    }
+
+    fn total_quantity_atom(
+        projection: &AssetDefinitionProjection<PredicateMarker>,
+    ) -> Option<&NumericPredicateAtom> {
+        match projection {
+            AssetDefinitionProjection::TotalQuantity(NumericProjection::Atom(atom)) => Some(atom),
+            _ => None,
+        }
+    }
+
+    /// Evaluate the `filter` like [`CompoundPredicate::applies`], checking that the values compared
+    /// to the quantity of the `item` are representable within the `spec` of its asset definition.
+    ///
+    /// The `spec` is only looked up if the quantity is actually compared.
+    fn applies_within_spec<T>(
+        filter: &CompoundPredicate<T>,
+        item: &T,
+        quantity_atom: fn(&T::Projection) -> Option<&NumericPredicateAtom>,
+        spec: &impl Fn() -> Result<NumericSpec, Error>,
+    ) -> Result<bool, Error>
+    where
+        T: HasProjection<PredicateMarker>,
+        T::Projection: EvaluatePredicate<T>,
+    {
+        let applies = match filter {
+            CompoundPredicate::Atom(projection) => {
+                if let Some(atom) = quantity_atom(projection) {
+                    let spec = spec()?;
+                    atom.check_spec(spec).map_err(|_| {
+                        Error::Conversion(format!(
+                            "Values of `{atom:?}` don't fit the spec `{spec}` of the compared quantity"
+                        ))
+                    })?;
+                }
+                projection.applies(item)
+            }
+            CompoundPredicate::Not(inner) => {
+                !applies_within_spec(inner, item, quantity_atom, spec)?
+            }
+            CompoundPredicate::And(list) => {
+                for inner in list {
+                    if !applies_within_spec(inner, item, quantity_atom, spec)? {
+                        return Ok(false);
+                    }
+                }
+                true
+            }
+            CompoundPredicate::Or(list) => {
+                for inner in list {
+                    if applies_within_spec(inner, item, quantity_atom, spec)? {
+                        return Ok(true);
+                    }
+                }
+                false
+            }
+        };
+
+        Ok(applies)
+    }
+
+    impl ValidQuery for FindAssetAllowances {
+        #[metrics(+"find_asset_allowances")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<AssetAllowance>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = AssetAllowance>, Error> {
+            Ok(state_ro
+                .world()
+                .asset_allowances()
+                .iter()
+                .map(|(id, amount)| AssetAllowance::new(id.clone(), *amount))
+                .filter(move |allowance| filter.applies(allowance)))
+        }
+    }
+
+    impl ValidSingularQuery for FindAssetStateProof {
+        #[metrics(+"find_asset_state_proof")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<StateProof, Error> {
+            let asset = state_ro.world().asset(&self.id)?;
+
+            root::state_proof(state_ro, StateEntry::Asset(asset))
+                .ok_or_else(|| FindError::Asset(self.id.clone()).into())
         }
     }
 }
@@ -111,14 +111,20 @@ pub mod isi {
             for asset_id in remove_assets {
                 state_transaction.world.assets.remove(asset_id);
             }
-
-            if state_transaction
+            state_transaction
                 .world
-                .accounts
-                .remove(account_id.clone())
-                .is_none()
-            {
+                .remove_asset_allowances(|allowance| {
+                    allowance.asset.account == account_id || allowance.spender == account_id
+                });
+            state_transaction.world.cancel_swaps(|swap| {
+                swap.maker == account_id || swap.taker.as_ref() == Some(&account_id)
+            });
+
+            let Some(account) = state_transaction.world.accounts.remove(account_id.clone()) else {
                 return Err(FindError::Account(account_id).into());
+            };
+            if let Some(alias) = account.alias {
+                state_transaction.world.account_aliases.remove(alias);
             }
 
             state_transaction
@@ -202,6 +208,16 @@ pub mod isi {
 
                 events.push(AssetEvent::Deleted(asset_id).into());
             }
+            state_transaction
+                .world
+                .remove_asset_allowances(|allowance| {
+                    allowance.asset.definition == asset_definition_id
+                });
+            state_transaction.world.cancel_swaps(|swap| {
+                swap.items().into_iter().any(|item| {
+                    matches!(item, SwapItem::Asset(asset) if asset.definition == asset_definition_id)
+                })
+            });
 
             if state_transaction
                 .world
@@ -7,6 +7,7 @@ pub mod block;
 pub mod domain;
 pub mod nft;
 pub mod query;
+pub mod swap;
 pub mod triggers;
 pub mod tx;
 pub mod world;
@@ -41,6 +42,7 @@ impl Execute for InstructionBox {
         state_transaction: &mut StateTransaction<'_, '_>,
     ) -> Result<(), Error> {
         iroha_logger::debug!(isi=%self, "Executing");
+        state_transaction.executed_instructions += 1;
 
         match self {
             Self::Register(isi) => isi.execute(authority, state_transaction),
@@ -56,6 +58,19 @@ impl Execute for InstructionBox {
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
+            Self::EmitEvent(isi) => isi.execute(authority, state_transaction),
+            Self::AddSignatory(isi) => isi.execute(authority, state_transaction),
+            Self::RemoveSignatory(isi) => isi.execute(authority, state_transaction),
+            Self::RotateAccountKey(isi) => isi.execute(authority, state_transaction),
+            Self::RegisterAlias(isi) => isi.execute(authority, state_transaction),
+            Self::UnregisterAlias(isi) => isi.execute(authority, state_transaction),
+            Self::Approve(isi) => isi.execute(authority, state_transaction),
+            Self::TransferFrom(isi) => isi.execute(authority, state_transaction),
+            Self::Freeze(isi) => isi.execute(authority, state_transaction),
+            Self::Unfreeze(isi) => isi.execute(authority, state_transaction),
+            Self::CreateSwap(isi) => isi.execute(authority, state_transaction),
+            Self::AcceptSwap(isi) => isi.execute(authority, state_transaction),
+            Self::CancelSwap(isi) => isi.execute(authority, state_transaction),
             Self::Custom(_) => {
                 panic!("Custom instructions should be handled in custom executor");
             }
@@ -207,6 +222,36 @@ impl Execute for RevokeBox {
     }
 }
 
+impl Execute for FreezeBox {
+    #[iroha_logger::log(name = "freeze", skip_all, fields(object))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Account(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for UnfreezeBox {
+    #[iroha_logger::log(name = "unfreeze", skip_all, fields(object))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Account(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
 pub mod prelude {
     //! Re-export important traits and types for glob import `(::*)`
     pub use super::*;
@@ -218,7 +263,8 @@ mod tests {
 
     use iroha_crypto::KeyPair;
     use iroha_test_samples::{
-        gen_account_in, ALICE_ID, SAMPLE_GENESIS_ACCOUNT_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
+        gen_account_in, ALICE_ID, ALICE_KEYPAIR, SAMPLE_GENESIS_ACCOUNT_ID,
+        SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
     };
     use tokio::test;
 
@@ -227,6 +273,7 @@ mod tests {
         block::ValidBlock,
         kura::Kura,
         query::store::LiveQueryStore,
+        smartcontracts::ValidSingularQuery,
         state::{State, World},
         tx::{AcceptTransactionFail, AcceptedTransaction},
     };
@@ -480,4 +527,318 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[test]
+    async fn transaction_signed_by_rotated_out_key_should_be_rejected() -> Result<()> {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let new_key_pair = KeyPair::random();
+        RotateAccountKey::new(
+            account_id.clone(),
+            account_id.signatory().clone(),
+            new_key_pair.public_key().clone(),
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let accept = |key_pair: &KeyPair| {
+            let tx = TransactionBuilder::new(chain_id.clone(), account_id.clone())
+                .with_instructions::<InstructionBox>([])
+                .sign(key_pair.private_key());
+            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits).expect("Valid")
+        };
+        let world = state.world.view();
+        assert!(accept(&new_key_pair).verify_signature(&world).is_ok());
+        assert!(accept(&*ALICE_KEYPAIR).verify_signature(&world).is_err());
+        Ok(())
+    }
+
+    #[test]
+    async fn last_signatory_can_not_be_removed() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let (new_signatory, _) = KeyPair::random().into_parts();
+
+        assert!(matches!(
+            RemoveSignatory::new(account_id.clone(), account_id.signatory().clone())
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        AddSignatory::new(account_id.clone(), new_signatory.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        RemoveSignatory::new(account_id.clone(), account_id.signatory().clone())
+            .execute(&account_id, &mut state_transaction)?;
+
+        let account = state_transaction.world.account(&account_id)?;
+        assert_eq!(account.signatories().collect::<Vec<_>>(), [&new_signatory]);
+        Ok(())
+    }
+
+    #[test]
+    async fn alias_resolves_to_a_single_account() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let alias: AccountAlias = "alice@wonderland".parse()?;
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        RegisterAlias::new(alice_id.clone(), alias.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        assert!(matches!(
+            RegisterAlias::new(bob_id.clone(), alias.clone())
+                .execute(&bob_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert!(matches!(
+            RegisterAlias::new(alice_id.clone(), "rabbit@looking_glass".parse()?)
+                .execute(&alice_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert_eq!(
+            FindAccountByAlias::new(alias.clone()).execute(&state_transaction)?,
+            alice_id
+        );
+
+        UnregisterAlias::new(alias.clone()).execute(&alice_id, &mut state_transaction)?;
+        RegisterAlias::new(bob_id.clone(), alias.clone())
+            .execute(&bob_id, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let view = state.view();
+        assert_eq!(
+            FindAccountByAlias::new(alias.clone()).execute(&view)?,
+            bob_id
+        );
+        assert!(view.world.account(&alice_id)?.alias().is_none());
+        assert_eq!(view.world.account(&bob_id)?.alias(), Some(&alias));
+        Ok(())
+    }
+
+    #[test]
+    async fn transfer_from_spends_allowance() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let rose_id = AssetId::new("rose#wonderland".parse()?, alice_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, rose_id.clone()).execute(&alice_id, &mut state_transaction)?;
+
+        Approve::new(rose_id.clone(), bob_id.clone(), 5_u32)
+            .execute(&alice_id, &mut state_transaction)?;
+        TransferFrom::new(rose_id.clone(), 3_u32, bob_id.clone())
+            .execute(&bob_id, &mut state_transaction)?;
+        assert!(matches!(
+            TransferFrom::new(rose_id.clone(), 3_u32, bob_id.clone())
+                .execute(&bob_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert_eq!(
+            state_transaction
+                .world
+                .asset_allowances()
+                .get(&AssetAllowanceId::new(rose_id.clone(), bob_id.clone())),
+            Some(&Numeric::from(2_u32))
+        );
+
+        // the allowance is revoked by approving zero
+        Approve::new(rose_id.clone(), bob_id.clone(), 0_u32)
+            .execute(&alice_id, &mut state_transaction)?;
+        assert!(TransferFrom::new(rose_id.clone(), 1_u32, bob_id.clone())
+            .execute(&bob_id, &mut state_transaction)
+            .is_err());
+        state_transaction.apply();
+        state_block.commit();
+
+        let view = state.view();
+        assert_eq!(view.world.asset(&rose_id)?.value, Numeric::from(7_u32));
+        assert_eq!(
+            view.world
+                .asset(&AssetId::new("rose#wonderland".parse()?, bob_id))?
+                .value,
+            Numeric::from(3_u32)
+        );
+        assert!(view.world.asset_allowances().iter().next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    async fn frozen_holdings_cannot_move() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let rose_definition_id: AssetDefinitionId = "rose#wonderland".parse()?;
+        let rose_id = AssetId::new(rose_definition_id.clone(), alice_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, rose_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        let transfer = Transfer::asset_numeric(rose_id.clone(), 1_u32, bob_id.clone());
+
+        Freeze::asset(rose_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        assert!(matches!(
+            Freeze::asset(rose_id.clone())
+                .execute(&alice_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert!(transfer
+            .clone()
+            .execute(&alice_id, &mut state_transaction)
+            .is_err());
+        Unfreeze::asset(rose_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        transfer
+            .clone()
+            .execute(&alice_id, &mut state_transaction)?;
+
+        // freezing the receiving account blocks the transfer as well
+        Freeze::account(bob_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        assert!(transfer
+            .clone()
+            .execute(&alice_id, &mut state_transaction)
+            .is_err());
+        Unfreeze::account(bob_id.clone()).execute(&alice_id, &mut state_transaction)?;
+
+        Freeze::asset_definition(rose_definition_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        assert!(Mint::asset_numeric(1_u32, rose_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .is_err());
+        assert!(Burn::asset_numeric(1_u32, rose_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .is_err());
+        Unfreeze::asset_definition(rose_definition_id)
+            .execute(&alice_id, &mut state_transaction)?;
+        transfer.execute(&alice_id, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let view = state.view();
+        assert_eq!(view.world.asset(&rose_id)?.value, Numeric::from(8_u32));
+        assert!(!view.world.asset(&rose_id)?.is_frozen());
+        Ok(())
+    }
+
+    #[test]
+    async fn swap_exchanges_locked_holdings() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let rose_definition_id: AssetDefinitionId = "rose#wonderland".parse()?;
+        let tulip_definition_id: AssetDefinitionId = "tulip#wonderland".parse()?;
+        let alice_rose_id = AssetId::new(rose_definition_id.clone(), alice_id.clone());
+        let bob_tulip_id = AssetId::new(tulip_definition_id.clone(), bob_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::asset_definition(AssetDefinition::numeric(tulip_definition_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, alice_rose_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        Mint::asset_numeric(5_u32, bob_tulip_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        let offered = SwapItem::Asset(SwapAsset::new(rose_definition_id.clone(), 5_u32.into()));
+        let requested = SwapItem::Asset(SwapAsset::new(tulip_definition_id.clone(), 2_u32.into()));
+
+        let swap_id: SwapId = "rose_for_tulip".parse()?;
+        CreateSwap::new(swap_id.clone(), offered.clone(), requested.clone())
+            .with_taker(bob_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        assert!(
+            Transfer::asset_numeric(alice_rose_id.clone(), 6_u32, bob_id.clone())
+                .execute(&alice_id, &mut state_transaction)
+                .is_err(),
+            "offered roses are locked"
+        );
+        assert!(Burn::asset_numeric(6_u32, alice_rose_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .is_err());
+        assert!(
+            AcceptSwap::new(swap_id.clone())
+                .execute(&alice_id, &mut state_transaction)
+                .is_err(),
+            "maker can't accept their own swap"
+        );
+        AcceptSwap::new(swap_id.clone()).execute(&bob_id, &mut state_transaction)?;
+        assert!(AcceptSwap::new(swap_id)
+            .execute(&bob_id, &mut state_transaction)
+            .is_err());
+
+        let cancelled_id: SwapId = "cancelled".parse()?;
+        CreateSwap::new(cancelled_id.clone(), offered, requested)
+            .with_expiry_ms(u64::MAX)
+            .execute(&alice_id, &mut state_transaction)?;
+        CancelSwap::new(cancelled_id).execute(&alice_id, &mut state_transaction)?;
+        Transfer::asset_numeric(alice_rose_id.clone(), 5_u32, bob_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let view = state.view();
+        assert!(view.world.asset(&alice_rose_id).is_err());
+        assert_eq!(
+            view.world
+                .asset(&AssetId::new(rose_definition_id, bob_id))?
+                .value,
+            Numeric::from(10_u32)
+        );
+        assert_eq!(view.world.asset(&bob_tulip_id)?.value, Numeric::from(3_u32));
+        assert_eq!(
+            view.world
+                .asset(&AssetId::new(tulip_definition_id, alice_id))?
+                .value,
+            Numeric::from(2_u32)
+        );
+        assert!(view.world.swaps().iter().next().is_none());
+        assert!(view.world.locked_assets().iter().next().is_none());
+        assert!(view.world.swap_expirations().iter().next().is_none());
+        Ok(())
+    }
 }
@@ -27,6 +27,7 @@ pub mod isi {
     use iroha_telemetry::metrics;
 
     use super::*;
+    use crate::smartcontracts::asset::isi::frozen;
 
     impl Execute for Register<Nft> {
         #[metrics(+"register_nft")]
@@ -69,6 +70,9 @@ pub mod isi {
         ) -> Result<(), Error> {
             let nft_id = self.object;
 
+            if let Some(locked_by) = state_transaction.world.locked_nfts.get(&nft_id) {
+                return Err(locked(&nft_id, locked_by));
+            }
             state_transaction
                 .world
                 .nfts
@@ -78,6 +82,9 @@ pub mod isi {
                 .world
                 .domain(&nft_id.domain)
                 .expect("INTERNAL BUG: Can't find domain of NFT to unregister");
+            state_transaction.world.cancel_swaps(
+                |swap| matches!(&swap.requested, SwapItem::Nft(requested) if *requested == nft_id),
+            );
 
             state_transaction
                 .world
@@ -154,8 +161,15 @@ pub mod isi {
                 destination,
             } = self;
 
-            state_transaction.world.account(&source)?;
-            state_transaction.world.account(&destination)?;
+            if state_transaction.world.account(&source)?.frozen {
+                return Err(frozen(&source));
+            }
+            if state_transaction.world.account(&destination)?.frozen {
+                return Err(frozen(&destination));
+            }
+            if let Some(locked_by) = state_transaction.world.locked_nfts.get(&object) {
+                return Err(locked(&object, locked_by));
+            }
 
             let nft = state_transaction.world.nft_mut(&object)?;
 
@@ -176,6 +190,10 @@ pub mod isi {
             Ok(())
         }
     }
+
+    fn locked(nft_id: &NftId, swap_id: &SwapId) -> Error {
+        Error::InvariantViolation(format!("NFT {nft_id} is locked by swap `{swap_id}`"))
+    }
 }
 
 /// NFT-related query implementations.
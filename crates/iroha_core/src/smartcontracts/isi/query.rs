@@ -1,16 +1,19 @@
 //! Query functionality. The common error type is also defined here,
 //! alongside functions for converting them into HTTP responses.
-use std::cmp::Ordering;
+use std::{cmp::Ordering, num::NonZeroU64};
 
 use eyre::Result;
 use iroha_data_model::{
     prelude::*,
     query::{
-        dsl::{EvaluateSelector, HasProjection, SelectorMarker},
+        dsl::{
+            Aggregate, AggregateFunction, EvaluateSelector, HasProjection, SelectorMarker, SortKey,
+        },
         error::QueryExecutionFail as Error,
-        parameters::QueryParams,
-        CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryRequest,
-        QueryRequestWithAuthority, QueryResponse, SingularQueryBox, SingularQueryOutputBox,
+        parameters::{Pagination, QueryParams, SortOrder},
+        CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryOutputBatchBoxTuple,
+        QueryRequest, QueryRequestWithAuthority, QueryResponse, SingularQueryBox,
+        SingularQueryOutputBox,
     },
 };
 
@@ -53,6 +56,18 @@ impl SortableQueryOutput for Asset {
     }
 }
 
+impl SortableQueryOutput for AssetAllowance {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for Swap {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
 impl SortableQueryOutput for Nft {
     fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
         self.content.get(key).cloned()
@@ -113,24 +128,29 @@ impl SortableQueryOutput for iroha_data_model::block::BlockHeader {
     }
 }
 
-/// Applies sorting and pagination to the query output and wraps it into a type-erasing batching iterator.
+/// Applies sorting and pagination (or aggregation) to the query output and wraps it into a type-erasing batching iterator.
 ///
 /// # Errors
 ///
-/// Returns an error if the fetch size is too big
+/// Returns an error if the fetch size is too big, if one of the sort keys or the aggregate can't be evaluated
+/// or if an aggregated query is sorted or selects fields
 pub fn apply_query_postprocessing<I>(
     iter: I,
     selector: SelectorTuple<I::Item>,
+    sort_by: &[SortKey<I::Item>],
+    aggregate: Option<&Aggregate<I::Item>>,
     &QueryParams {
         pagination,
         ref sorting,
         fetch_size,
+        at_height: _,
     }: &QueryParams,
 ) -> Result<ErasedQueryIterator, Error>
 where
     I: Iterator<Item: SortableQueryOutput + Send + Sync + 'static>,
     I::Item: HasProjection<SelectorMarker, AtomType = ()> + 'static,
-    <I::Item as HasProjection<SelectorMarker>>::Projection: EvaluateSelector<I::Item> + Send + Sync,
+    <I::Item as HasProjection<SelectorMarker>>::Projection:
+        EvaluateSelector<I::Item> + PartialEq + Send + Sync,
     QueryOutputBatchBox: From<Vec<I::Item>>,
 {
     // validate the fetch (aka batch) size
@@ -141,26 +161,40 @@ where
         return Err(Error::FetchSizeTooBig);
     }
 
+    if let Some(aggregate) = aggregate {
+        // groups are always ordered by their keys, sort keys of the items don't apply to them
+        if sorting.sort_by_metadata_key.is_some() || !sort_by.is_empty() {
+            return Err(Error::SortedAggregate);
+        }
+        // the aggregate function selects the aggregated field, a selection of the items would be dropped
+        if selector != SelectorTuple::default() {
+            return Err(Error::SelectedAggregate);
+        }
+        let items: Vec<I::Item> = iter.collect();
+        let batch = aggregate_items(&items, aggregate, pagination)?;
+        return Ok(ErasedQueryIterator::from_batches(split_into_batches(
+            batch, fetch_size,
+        )));
+    }
+
     // sort & paginate, erase the iterator with QueryBatchedErasedIterator
-    let output = if let Some(key) = &sorting.sort_by_metadata_key {
+    let output = if sorting.sort_by_metadata_key.is_some() || !sort_by.is_empty() {
         // if sorting was requested, we need to retrieve all the results first
-        let mut pairs: Vec<(Option<Json>, I::Item)> = iter
-            .map(|value| {
-                let key = value.get_metadata_sorting_key(key);
-                (key, value)
+        let items: Vec<I::Item> = iter.collect();
+        let order = sorted_order(&items, sorting.sort_by_metadata_key.as_ref(), sort_by)?;
+
+        let mut items: Vec<Option<I::Item>> = items.into_iter().map(Some).collect();
+        let sorted = order
+            .into_iter()
+            .map(|idx| {
+                items[idx]
+                    .take()
+                    .expect("each index is visited exactly once")
             })
-            .collect();
-        pairs.sort_by(
-            |(left_key, _), (right_key, _)| match (left_key, right_key) {
-                (Some(l), Some(r)) => l.cmp(r),
-                (Some(_), None) => Ordering::Less,
-                (None, Some(_)) => Ordering::Greater,
-                (None, None) => Ordering::Equal,
-            },
-        );
+            .collect::<Vec<_>>();
 
         ErasedQueryIterator::new(
-            pairs.into_iter().map(|(_, val)| val).paginate(pagination),
+            sorted.into_iter().paginate(pagination),
             selector,
             fetch_size,
         )
@@ -182,6 +216,251 @@ where
     Ok(output)
 }
 
+/// Computes the order of `items` according to the metadata key and the sort keys.
+///
+/// The sort is stable, so the items that compare equal on all keys keep their original order, making the cursors stable.
+fn sorted_order<T>(
+    items: &[T],
+    metadata_key: Option<&Name>,
+    sort_by: &[SortKey<T>],
+) -> Result<Vec<usize>, Error>
+where
+    T: SortableQueryOutput + HasProjection<SelectorMarker, AtomType = ()> + 'static,
+    T::Projection: EvaluateSelector<T>,
+{
+    let metadata_keys = metadata_key.map(|key| {
+        items
+            .iter()
+            .map(|value| value.get_metadata_sorting_key(key))
+            .collect::<Vec<_>>()
+    });
+    // project each sort key into a column of values, so that the projection is evaluated only once per item
+    let columns = sort_by
+        .iter()
+        .map(|key| Ok((key.selector.project_clone(items.iter())?, key.order)))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&left, &right| {
+        let by_metadata = metadata_keys.as_ref().map_or(Ordering::Equal, |keys| {
+            match (&keys[left], &keys[right]) {
+                (Some(l), Some(r)) => l.cmp(r),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        });
+
+        columns
+            .iter()
+            .fold(by_metadata, |ordering, (column, order)| {
+                ordering.then_with(|| {
+                    let ordering = compare_batch_items(column, left, right);
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                })
+            })
+    });
+
+    Ok(order)
+}
+
+/// Aggregates `items`, producing a tuple of group keys (if grouping was requested) and aggregated values.
+///
+/// Groups are ordered by their keys, pagination is applied to the groups.
+fn aggregate_items<T>(
+    items: &[T],
+    aggregate: &Aggregate<T>,
+    pagination: Pagination,
+) -> Result<QueryOutputBatchBoxTuple, Error>
+where
+    T: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+    T::Projection: EvaluateSelector<T>,
+{
+    // count doesn't need any values, so nothing is projected for it
+    let values = match &aggregate.function {
+        AggregateFunction::Count => Vec::new(),
+        AggregateFunction::Sum(selector)
+        | AggregateFunction::Min(selector)
+        | AggregateFunction::Max(selector) => match selector.project_clone(items.iter())? {
+            QueryOutputBatchBox::Numeric(values) => values,
+            _ => {
+                return Err(Error::Conversion(
+                    "Only `Numeric` values can be aggregated".to_owned(),
+                ))
+            }
+        },
+    };
+    let aggregate_group = |group: &[usize]| -> Result<Option<Numeric>, Error> {
+        let mut group_values = group.iter().map(|&idx| values[idx]);
+
+        match aggregate.function {
+            AggregateFunction::Count => Ok(Some(Numeric::from(group.len() as u64))),
+            AggregateFunction::Sum(_) => group_values
+                .try_fold(Numeric::ZERO, Numeric::checked_add)
+                .map(Some)
+                .ok_or(Error::AggregateOverflow),
+            AggregateFunction::Min(_) => Ok(group_values.min()),
+            AggregateFunction::Max(_) => Ok(group_values.max()),
+        }
+    };
+
+    let Some(group_by) = &aggregate.group_by else {
+        let all = (0..items.len()).collect::<Vec<_>>();
+        let aggregated = aggregate_group(&all)?
+            .into_iter()
+            .paginate(pagination)
+            .collect::<Vec<_>>();
+
+        return Ok(QueryOutputBatchBoxTuple::new(vec![aggregated.into()]));
+    };
+
+    let keys = group_by.project_clone(items.iter())?;
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&left, &right| compare_batch_items(&keys, left, right));
+
+    let mut group_keys = Vec::new();
+    let mut aggregated = Vec::new();
+    for group in order
+        .chunk_by(|&left, &right| compare_batch_items(&keys, left, right).is_eq())
+        .paginate(pagination)
+    {
+        let value = aggregate_group(group)?.expect("groups are never empty");
+        group_keys.push(group[0]);
+        aggregated.push(value);
+    }
+
+    Ok(QueryOutputBatchBoxTuple::new(vec![
+        select_batch_items(&keys, &group_keys),
+        aggregated.into(),
+    ]))
+}
+
+/// Splits the already evaluated `batch` into batches of at most `fetch_size` items.
+///
+/// There is always at least one batch, so that the type of the results is known even if there are none.
+fn split_into_batches(
+    batch: QueryOutputBatchBoxTuple,
+    fetch_size: NonZeroU64,
+) -> Vec<QueryOutputBatchBoxTuple> {
+    let fetch_size = fetch_size
+        .get()
+        .try_into()
+        .expect("`u64` should always fit into `usize`");
+    if batch.len() <= fetch_size {
+        return vec![batch];
+    }
+
+    let indices = (0..batch.len()).collect::<Vec<_>>();
+    indices
+        .chunks(fetch_size)
+        .map(|indices| {
+            QueryOutputBatchBoxTuple::new(
+                batch
+                    .iter()
+                    .map(|column| select_batch_items(column, indices))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Compares two items of the same type-erased batch by their indices.
+fn compare_batch_items(batch: &QueryOutputBatchBox, left: usize, right: usize) -> Ordering {
+    fn cmp<T: Ord>(values: &[T], left: usize, right: usize) -> Ordering {
+        values[left].cmp(&values[right])
+    }
+
+    match batch {
+        QueryOutputBatchBox::PublicKey(v) => cmp(v, left, right),
+        QueryOutputBatchBox::String(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Metadata(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Json(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Numeric(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Name(v) => cmp(v, left, right),
+        QueryOutputBatchBox::DomainId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Domain(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AccountId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Account(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AccountAlias(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AssetId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Asset(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AssetAllowanceId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AssetAllowance(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AssetDefinitionId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::AssetDefinition(v) => cmp(v, left, right),
+        QueryOutputBatchBox::NftId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Nft(v) => cmp(v, left, right),
+        QueryOutputBatchBox::SwapId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Swap(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Role(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Parameter(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Permission(v) => cmp(v, left, right),
+        QueryOutputBatchBox::CommittedTransaction(v) => cmp(v, left, right),
+        QueryOutputBatchBox::SignedTransaction(v) => cmp(v, left, right),
+        QueryOutputBatchBox::TransactionHash(v) => cmp(v, left, right),
+        QueryOutputBatchBox::TransactionRejectionReason(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Peer(v) => cmp(v, left, right),
+        QueryOutputBatchBox::RoleId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::TriggerId(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Trigger(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Action(v) => cmp(v, left, right),
+        QueryOutputBatchBox::Block(v) => cmp(v, left, right),
+        QueryOutputBatchBox::BlockHeader(v) => cmp(v, left, right),
+        QueryOutputBatchBox::BlockHeaderHash(v) => cmp(v, left, right),
+        QueryOutputBatchBox::U64(v) => cmp(v, left, right),
+    }
+}
+
+/// Clones the items with the given indices out of a type-erased batch.
+fn select_batch_items(batch: &QueryOutputBatchBox, indices: &[usize]) -> QueryOutputBatchBox {
+    fn select<T: Clone>(values: &[T], indices: &[usize]) -> Vec<T> {
+        indices.iter().map(|&idx| values[idx].clone()).collect()
+    }
+
+    match batch {
+        QueryOutputBatchBox::PublicKey(v) => select(v, indices).into(),
+        QueryOutputBatchBox::String(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Metadata(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Json(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Numeric(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Name(v) => select(v, indices).into(),
+        QueryOutputBatchBox::DomainId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Domain(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AccountId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Account(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AccountAlias(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AssetId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Asset(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AssetAllowanceId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AssetAllowance(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AssetDefinitionId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::AssetDefinition(v) => select(v, indices).into(),
+        QueryOutputBatchBox::NftId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Nft(v) => select(v, indices).into(),
+        QueryOutputBatchBox::SwapId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Swap(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Role(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Parameter(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Permission(v) => select(v, indices).into(),
+        QueryOutputBatchBox::CommittedTransaction(v) => select(v, indices).into(),
+        QueryOutputBatchBox::SignedTransaction(v) => select(v, indices).into(),
+        QueryOutputBatchBox::TransactionHash(v) => select(v, indices).into(),
+        QueryOutputBatchBox::TransactionRejectionReason(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Peer(v) => select(v, indices).into(),
+        QueryOutputBatchBox::RoleId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::TriggerId(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Trigger(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Action(v) => select(v, indices).into(),
+        QueryOutputBatchBox::Block(v) => select(v, indices).into(),
+        QueryOutputBatchBox::BlockHeader(v) => select(v, indices).into(),
+        QueryOutputBatchBox::BlockHeaderHash(v) => select(v, indices).into(),
+        QueryOutputBatchBox::U64(v) => select(v, indices).into(),
+    }
+}
+
 /// Query Request statefully validated on the Iroha node side.
 #[derive(Debug, Clone)]
 pub struct ValidQueryRequest(QueryRequest);
@@ -224,6 +503,15 @@ impl ValidQueryRequest {
         Ok(Self(query))
     }
 
+    /// Height of the block after which the state has to be queried, if requested
+    pub fn at_height(&self) -> Option<NonZeroU64> {
+        match &self.0 {
+            QueryRequest::Singular(singular_query) => singular_query.at_height,
+            QueryRequest::Start(iter_query) => iter_query.params.at_height,
+            QueryRequest::Continue(_) => None,
+        }
+    }
+
     /// Execute a validated query request
     ///
     /// # Errors
@@ -236,15 +524,34 @@ impl ValidQueryRequest {
         state: &impl StateReadOnly,
         authority: &AccountId,
     ) -> Result<QueryResponse, Error> {
+        // the caller is responsible for providing the state at the requested height
+        if let Some(height) = self.at_height() {
+            if height.get() != state.height() as u64 {
+                return Err(Error::HeightUnavailable);
+            }
+        }
+
         match self.0 {
             QueryRequest::Singular(singular_query) => {
-                let output = match singular_query {
+                let output = match singular_query.query {
                     SingularQueryBox::FindExecutorDataModel(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
                     SingularQueryBox::FindParameters(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
+                    SingularQueryBox::FindTransactionProof(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAccountStateProof(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAssetStateProof(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAccountByAlias(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
                 };
 
                 Ok(QueryResponse::Singular(output))
@@ -255,81 +562,127 @@ impl ValidQueryRequest {
                     QueryBox::FindDomains(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindAccounts(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindAssets(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindAssetsDefinitions(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindAssetAllowances(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindNfts(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindSwaps(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindRoles(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindRoleIds(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindPermissionsByAccountId(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindRolesByAccountId(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindAccountsWithAsset(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindPeers(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindActiveTriggerIds(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindTriggers(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindTransactions(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindBlocks(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                     QueryBox::FindBlockHeaders(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
+                        &q.sort_by,
+                        q.aggregate.as_ref(),
                         &iter_query.params,
                     )?,
                 };
@@ -411,7 +764,11 @@ mod tests {
             let peer_id = PeerId::new(peer_public_key);
             let topology = Topology::new(vec![peer_id]);
             let unverified_first_block = BlockBuilder::new(transactions.clone())
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(
+                    0,
+                    state.view().latest_block().as_deref(),
+                    state.view().latest_state_root(),
+                )
                 .sign(&peer_private_key)
                 .unpack(|_| {});
             let mut state_block = state.block(unverified_first_block.header());
@@ -428,7 +785,11 @@ mod tests {
 
             for _ in 1u64..blocks {
                 let unverified_block = BlockBuilder::new(transactions.clone())
-                    .chain(0, state.view().latest_block().as_deref())
+                    .chain(
+                        0,
+                        state.view().latest_block().as_deref(),
+                        state.view().latest_state_root(),
+                    )
                     .sign(&peer_private_key)
                     .unpack(|_| {});
                 let mut state_block = state.block(unverified_block.header());
@@ -449,6 +810,28 @@ mod tests {
         Ok(state)
     }
 
+    #[test]
+    async fn aggregated_query_cannot_select_fields() {
+        let world = world_with_test_domains();
+        let view = world.view();
+        let count = Aggregate::new(AggregateFunction::Count);
+        let postprocess = |selector| {
+            apply_query_postprocessing(
+                view.asset_definitions_iter().cloned(),
+                selector,
+                &[],
+                Some(&count),
+                &QueryParams::default(),
+            )
+        };
+
+        assert!(postprocess(SelectorTuple::default()).is_ok());
+        assert!(matches!(
+            postprocess(SelectorTuple::build(|asset_definition| asset_definition.id)),
+            Err(Error::SelectedAggregate)
+        ));
+    }
+
     #[test]
     async fn find_all_blocks() -> Result<()> {
         let num_blocks = 100;
@@ -563,7 +946,11 @@ mod tests {
         let peer_id = PeerId::new(peer_public_key);
         let topology = Topology::new(vec![peer_id]);
         let unverified_block = BlockBuilder::new(vec![va_tx.clone()])
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(ALICE_KEYPAIR.private_key())
             .unpack(|_| {});
         let mut state_block = state.block(unverified_block.header());
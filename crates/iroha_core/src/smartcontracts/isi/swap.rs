@@ -0,0 +1,259 @@
+//! This module contains [`Swap`] instructions and queries implementations.
+
+use iroha_telemetry::metrics;
+
+use super::prelude::*;
+
+/// ISI module contains all instructions related to swaps:
+/// - create swap, locking the offered holdings
+/// - accept swap, exchanging holdings of both parties
+/// - cancel swap, releasing the offered holdings
+pub mod isi {
+    use iroha_data_model::{
+        isi::error::{MathError, RepetitionError},
+        query::error::FindError,
+    };
+
+    use super::*;
+    use crate::smartcontracts::isi::asset::isi::assert_numeric_spec;
+
+    impl Execute for CreateSwap {
+        #[metrics(+"create_swap")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let CreateSwap {
+                id,
+                offered,
+                requested,
+                taker,
+                expires_at_ms,
+            } = self;
+
+            if state_transaction.world.swaps.get(&id).is_some() {
+                return Err(RepetitionError {
+                    instruction: InstructionType::CreateSwap,
+                    id: IdBox::SwapId(id),
+                }
+                .into());
+            }
+            if let Some(taker) = &taker {
+                state_transaction.world.account(taker)?;
+            }
+
+            let swap = Swap {
+                id: id.clone(),
+                maker: authority.clone(),
+                offered,
+                requested,
+                taker,
+                expires_at_ms,
+            };
+            if swap.is_expired(state_transaction.curr_block.creation_time()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Swap `{id}` is already expired"
+                )));
+            }
+            assert_valid_item(&swap.offered, state_transaction)?;
+            assert_valid_item(&swap.requested, state_transaction)?;
+            lock_offered(&swap, state_transaction)?;
+
+            state_transaction.world.insert_swap(swap.clone());
+            state_transaction
+                .world
+                .emit_events(Some(SwapEvent::Created(swap)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for AcceptSwap {
+        #[metrics(+"accept_swap")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let swap_id = self.id;
+
+            let swap = state_transaction
+                .world
+                .remove_swap(&swap_id)
+                .ok_or_else(|| FindError::Swap(swap_id.clone()))?;
+            if swap.is_expired(state_transaction.curr_block.creation_time()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Swap `{swap_id}` is expired"
+                )));
+            }
+            if swap.maker == *authority {
+                return Err(Error::InvariantViolation(format!(
+                    "Swap `{swap_id}` can't be accepted by its maker"
+                )));
+            }
+            if let Some(taker) = swap.taker.as_ref().filter(|taker| *taker != authority) {
+                return Err(Error::InvariantViolation(format!(
+                    "Swap `{swap_id}` can only be accepted by `{taker}`"
+                )));
+            }
+
+            give(&swap.offered, &swap.maker, authority, state_transaction)?;
+            give(&swap.requested, authority, &swap.maker, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(SwapEvent::Accepted(SwapAccepted {
+                    swap: swap_id,
+                    taker: authority.clone(),
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for CancelSwap {
+        #[metrics(+"cancel_swap")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let swap_id = self.id;
+
+            let swap = state_transaction
+                .world
+                .remove_swap(&swap_id)
+                .ok_or_else(|| FindError::Swap(swap_id.clone()))?;
+
+            state_transaction
+                .world
+                .emit_events(Some(SwapEvent::Cancelled(swap_id)));
+
+            Ok(())
+        }
+    }
+
+    /// Assert that the exchanged holdings exist and that the exchanged quantity is valid
+    fn assert_valid_item(
+        item: &SwapItem,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match item {
+            SwapItem::Asset(asset) => {
+                let asset_definition = state_transaction
+                    .world
+                    .asset_definition(&asset.definition)?;
+                assert_numeric_spec(&asset.quantity, &asset_definition)?;
+                if asset.quantity.is_zero() {
+                    return Err(Error::InvariantViolation(format!(
+                        "Can't swap zero quantity of `{}`",
+                        asset.definition
+                    )));
+                }
+            }
+            SwapItem::Nft(nft_id) => {
+                state_transaction.world.nft(nft_id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lock the holdings offered by the maker, so that they can't be spent until the swap is closed
+    fn lock_offered(
+        swap: &Swap,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match &swap.offered {
+            SwapItem::Asset(offered) => {
+                let asset_id = offered.asset_of(swap.maker.clone());
+                let value = state_transaction
+                    .world
+                    .assets
+                    .get(&asset_id)
+                    .ok_or_else(|| FindError::Asset(asset_id.clone()))?
+                    .value;
+                let locked = state_transaction
+                    .world
+                    .locked_assets
+                    .get(&asset_id)
+                    .copied()
+                    .unwrap_or(Numeric::ZERO)
+                    .checked_add(offered.quantity)
+                    .ok_or(MathError::Overflow)?;
+                if value.checked_sub(locked).is_none() {
+                    return Err(MathError::NotEnoughQuantity.into());
+                }
+
+                state_transaction
+                    .world
+                    .locked_assets
+                    .insert(asset_id, locked);
+            }
+            SwapItem::Nft(nft_id) => {
+                if state_transaction.world.nft(nft_id)?.owned_by != swap.maker {
+                    return Err(Error::InvariantViolation(format!(
+                        "Can't offer NFT {nft_id} since {} doesn't own it",
+                        swap.maker
+                    )));
+                }
+                if let Some(locked_by) = state_transaction.world.locked_nfts.get(nft_id) {
+                    return Err(Error::InvariantViolation(format!(
+                        "NFT {nft_id} is locked by swap `{locked_by}`"
+                    )));
+                }
+
+                state_transaction
+                    .world
+                    .locked_nfts
+                    .insert(nft_id.clone(), swap.id.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfer the exchanged holdings of one party to the other
+    fn give(
+        item: &SwapItem,
+        from: &AccountId,
+        to: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match item {
+            SwapItem::Asset(asset) => {
+                Transfer::asset_numeric(asset.asset_of(from.clone()), asset.quantity, to.clone())
+                    .execute(from, state_transaction)
+            }
+            SwapItem::Nft(nft_id) => Transfer::nft(from.clone(), nft_id.clone(), to.clone())
+                .execute(from, state_transaction),
+        }
+    }
+}
+
+/// Swap-related query implementations.
+pub mod query {
+    use eyre::Result;
+    use iroha_data_model::query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error};
+
+    use super::*;
+    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+
+    impl ValidQuery for FindSwaps {
+        #[metrics(+"find_swaps")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Swap>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Swap>, Error> {
+            Ok(state_ro
+                .world()
+                .swaps()
+                .iter()
+                .map(|(_, swap)| swap)
+                .filter(move |&swap| filter.applies(swap))
+                .cloned())
+        }
+    }
+}
@@ -28,7 +28,7 @@ pub mod isi {
             _authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
-            let new_trigger = self.object;
+            let mut new_trigger = self.object;
 
             if !new_trigger.action.filter.mintable() {
                 match &new_trigger.action.repeats {
@@ -61,16 +61,37 @@ pub mod isi {
                         .map_err(|e: &str| Error::Conversion(e.to_owned()))?,
                 ),
                 EventFilterBox::Time(time_filter) => {
-                    if let ExecutionTime::Schedule(schedule) = time_filter.0 {
-                        let latest_block_time = latest_block_time.unwrap_or_else(|| {
-                            // Genesis block
-                            state_transaction.curr_block.creation_time()
-                        });
-                        if schedule.start() < latest_block_time {
-                            return Err(Error::InvalidParameter(
-                                InvalidParameterError::TimeTriggerInThePast,
-                            ));
+                    let latest_block_time = latest_block_time.unwrap_or_else(|| {
+                        // Genesis block
+                        state_transaction.curr_block.creation_time()
+                    });
+                    let in_the_past = match time_filter.0 {
+                        ExecutionTime::PreCommit => false,
+                        ExecutionTime::Schedule(schedule) => schedule.start() < latest_block_time,
+                        ExecutionTime::Cron(schedule) => {
+                            schedule.end().is_some_and(|end| end <= latest_block_time)
+                                // occurrences before the registration couldn't be counted
+                                || (schedule.max_occurrences.is_some()
+                                    && schedule.start() < latest_block_time)
                         }
+                    };
+                    if in_the_past {
+                        return Err(Error::InvalidParameter(
+                            InvalidParameterError::TimeTriggerInThePast,
+                        ));
+                    }
+                    if let ExecutionTime::Cron(CronSchedule {
+                        max_occurrences: Some(max_occurrences),
+                        ..
+                    }) = time_filter.0
+                    {
+                        // occurrences are counted down by the repeats of the trigger
+                        new_trigger.action.repeats = match new_trigger.action.repeats {
+                            Repeats::Exactly(repeats) => {
+                                Repeats::Exactly(repeats.min(max_occurrences))
+                            }
+                            Repeats::Indefinitely => Repeats::Exactly(max_occurrences),
+                        };
                     }
                     triggers.add_time_trigger(
                         &engine,
@@ -9,7 +9,7 @@
 //! trigger hooks.
 
 use core::cmp::min;
-use std::{fmt, marker::PhantomData, num::NonZeroU64};
+use std::{collections::BTreeSet, fmt, marker::PhantomData, num::NonZeroU64};
 
 use iroha_crypto::HashOf;
 use iroha_data_model::{
@@ -40,7 +40,7 @@ use crate::{
         },
         wasm,
     },
-    state::deserialize::WasmSeed,
+    state::{copy_storage, deserialize::WasmSeed},
 };
 
 /// Error type for [`Set`] operations.
@@ -107,6 +107,8 @@ pub struct SetBlock<'set> {
     /// List of actions that should be triggered by events provided by `handle_*` methods.
     /// Vector is used to save the exact triggers order.
     matched_ids: CellBlock<'set, Vec<(EventBox, TriggerId)>>,
+    /// Ids of the triggers added, modified or removed by the applied transactions
+    written: BTreeSet<TriggerId>,
 }
 
 /// Trigger set for transaction's aggregated changes
@@ -128,6 +130,10 @@ pub struct SetTransaction<'block, 'set> {
     /// List of actions that should be triggered by events provided by `handle_*` methods.
     /// Vector is used to save the exact triggers order.
     matched_ids: CellTransaction<'block, 'set, Vec<(EventBox, TriggerId)>>,
+    /// Ids of the triggers added, modified or removed by the transaction
+    written: BTreeSet<TriggerId>,
+    /// Ids of the triggers written by the block, which the transaction's ones are added to once it's applied
+    block_written: &'block mut BTreeSet<TriggerId>,
 }
 
 /// Consistent point in time view of the [`Set`]
@@ -527,6 +533,7 @@ impl Set {
             ids: self.ids.block(),
             contracts: self.contracts.block(),
             matched_ids: self.matched_ids.block(),
+            written: BTreeSet::new(),
         }
     }
 
@@ -540,6 +547,7 @@ impl Set {
             ids: self.ids.block_and_revert(),
             contracts: self.contracts.block_and_revert(),
             matched_ids: self.matched_ids.block_and_revert(),
+            written: BTreeSet::new(),
         }
     }
 
@@ -557,6 +565,21 @@ impl Set {
     }
 }
 
+impl SetView<'_> {
+    /// Copy the viewed triggers into a new [`Set`], which can be modified independently
+    pub fn to_set(&self) -> Set {
+        Set {
+            data_triggers: copy_storage(&self.data_triggers),
+            pipeline_triggers: copy_storage(&self.pipeline_triggers),
+            time_triggers: copy_storage(&self.time_triggers),
+            by_call_triggers: copy_storage(&self.by_call_triggers),
+            ids: copy_storage(&self.ids),
+            contracts: copy_storage(&self.contracts),
+            matched_ids: Cell::new((*self.matched_ids).clone()),
+        }
+    }
+}
+
 impl<'set> SetBlock<'set> {
     /// Create struct to apply transaction's changes
     pub fn transaction(&mut self) -> SetTransaction<'_, 'set> {
@@ -568,9 +591,16 @@ impl<'set> SetBlock<'set> {
             ids: self.ids.transaction(),
             contracts: self.contracts.transaction(),
             matched_ids: self.matched_ids.transaction(),
+            written: BTreeSet::new(),
+            block_written: &mut self.written,
         }
     }
 
+    /// Ids of the triggers added, modified or removed since the block was created
+    pub fn written(&self) -> &BTreeSet<TriggerId> {
+        &self.written
+    }
+
     /// Commit block's changes
     pub fn commit(self) {
         // NOTE: commit in reverse order
@@ -629,6 +659,8 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         self.time_triggers.apply();
         self.pipeline_triggers.apply();
         self.data_triggers.apply();
+        let mut written = self.written;
+        self.block_written.append(&mut written);
     }
 
     /// Add trigger with [`DataEventFilter`]
@@ -732,6 +764,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         if self.ids.get(&trigger_id).is_some() {
             return Ok(false);
         }
+        self.written.insert(trigger_id.clone());
 
         let loaded_executable = match executable {
             Executable::Wasm(bytes) => {
@@ -781,6 +814,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         F: Fn(&mut dyn LoadedActionTrait) -> R,
     {
         let event_type = self.ids.get(id).copied()?;
+        self.written.insert(id.clone());
 
         let result = match event_type {
             TriggeringEventType::Data => self
@@ -818,6 +852,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         let Some(event_type) = self.ids.remove(id.clone()) else {
             return false;
         };
+        self.written.insert(id.clone());
 
         let removed = match event_type {
             TriggeringEventType::Data => {
@@ -927,12 +962,13 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             by_call_triggers,
             ids,
             contracts,
+            written,
             ..
         } = self;
-        Self::remove_zeros(ids, contracts, data_triggers);
-        Self::remove_zeros(ids, contracts, pipeline_triggers);
-        Self::remove_zeros(ids, contracts, time_triggers);
-        Self::remove_zeros(ids, contracts, by_call_triggers);
+        Self::remove_zeros(ids, contracts, data_triggers, written);
+        Self::remove_zeros(ids, contracts, pipeline_triggers, written);
+        Self::remove_zeros(ids, contracts, time_triggers, written);
+        Self::remove_zeros(ids, contracts, by_call_triggers, written);
     }
 
     /// Remove actions with zero execution count from `triggers`
@@ -940,6 +976,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         ids: &mut StorageTransaction<'block, 'set, TriggerId, TriggeringEventType>,
         contracts: &mut WasmSmartContractMapTransaction<'block, 'set>,
         triggers: &mut StorageTransaction<'block, 'set, TriggerId, LoadedAction<F>>,
+        written: &mut BTreeSet<TriggerId>,
     ) {
         let to_remove: Vec<TriggerId> = triggers
             .iter()
@@ -952,6 +989,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             .collect();
 
         for id in to_remove {
+            written.insert(id.clone());
             ids.remove(id.clone())
                 .and_then(|_| Self::remove_from(contracts, triggers, id).then_some(()))
                 .expect("`Set`'s `ids`, `contracts` and typed trigger collections are inconsistent. This is a bug")
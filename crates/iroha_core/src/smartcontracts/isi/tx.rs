@@ -3,18 +3,22 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use iroha_crypto::HashOf;
+use iroha_crypto::{HashOf, MerkleTree};
 use iroha_data_model::{
     block::{BlockHeader, SignedBlock},
     prelude::*,
-    query::{dsl::CompoundPredicate, error::QueryExecutionFail, CommittedTransaction},
+    query::{
+        dsl::CompoundPredicate,
+        error::{FindError, QueryExecutionFail},
+        CommittedTransaction, TransactionProof,
+    },
     transaction::error::TransactionRejectionReason,
 };
 use iroha_telemetry::metrics;
 use nonzero_ext::nonzero;
 
 use super::*;
-use crate::smartcontracts::ValidQuery;
+use crate::smartcontracts::{ValidQuery, ValidSingularQuery};
 
 /// Iterates transactions of a block in reverse order
 pub(crate) struct BlockTransactionIter(Arc<SignedBlock>, usize);
@@ -80,3 +84,31 @@ impl ValidQuery for FindTransactions {
             .filter(move |tx| filter.applies(tx)))
     }
 }
+
+impl ValidSingularQuery for FindTransactionProof {
+    #[metrics(+"find_transaction_proof")]
+    fn execute(
+        &self,
+        state_ro: &impl StateReadOnly,
+    ) -> Result<TransactionProof, QueryExecutionFail> {
+        let block = state_ro
+            .transaction_height(&self.hash)
+            .and_then(|height| state_ro.kura().get_block(height))
+            .ok_or(FindError::Transaction(self.hash))?;
+        let idx = block
+            .transactions()
+            .position(|tx| tx.hash() == self.hash)
+            .expect("INTERNAL BUG: The transaction is not found in the block it's indexed at");
+        let tree = block
+            .transactions()
+            .map(SignedTransaction::hash)
+            .collect::<MerkleTree<_>>();
+
+        Ok(TransactionProof {
+            block_header: block.header(),
+            proof: tree
+                .proof(idx)
+                .expect("INTERNAL BUG: The transaction is not found"),
+        })
+    }
+}
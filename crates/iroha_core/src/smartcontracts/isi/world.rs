@@ -155,9 +155,35 @@ pub mod isi {
                     state_transaction.world.assets.remove(asset_id);
                 }
 
-                state_transaction.world.accounts.remove(account);
+                if let Some(alias) = state_transaction
+                    .world
+                    .accounts
+                    .remove(account)
+                    .and_then(|account| account.alias)
+                {
+                    state_transaction.world.account_aliases.remove(alias);
+                }
             }
 
+            state_transaction
+                .world
+                .remove_asset_allowances(|allowance| {
+                    allowance.asset.account.domain == domain_id
+                        || allowance.asset.definition.domain == domain_id
+                        || allowance.spender.domain == domain_id
+                });
+            state_transaction.world.cancel_swaps(|swap| {
+                swap.maker.domain == domain_id
+                    || swap
+                        .taker
+                        .as_ref()
+                        .is_some_and(|taker| taker.domain == domain_id)
+                    || swap.items().into_iter().any(|item| match item {
+                        SwapItem::Asset(asset) => asset.definition.domain == domain_id,
+                        SwapItem::Nft(nft_id) => nft_id.domain == domain_id,
+                    })
+            });
+
             let remove_asset_definitions: Vec<AssetDefinitionId> = state_transaction
                 .world
                 .asset_definitions_in_domain_iter(&domain_id)
@@ -453,6 +479,24 @@ pub mod isi {
             Ok(())
         }
     }
+
+    impl Execute for EmitEvent {
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> std::result::Result<(), Error> {
+            let Self { name, payload } = self;
+
+            state_transaction.world.emit_events(Some(CustomEvent {
+                emitter: authority.clone(),
+                name,
+                payload,
+            }));
+
+            Ok(())
+        }
+    }
 }
 /// Query module provides `IrohaQuery` Peer related implementations.
 pub mod query {
@@ -50,10 +50,13 @@ const WASM_MODULE: &str = "iroha";
 mod export {
     pub const EXECUTE_ISI: &str = "execute_instruction";
     pub const EXECUTE_QUERY: &str = "execute_query";
+    pub const EMIT_EVENT: &str = "emit_event";
     pub const SET_DATA_MODEL: &str = "set_data_model";
 
     pub const DBG: &str = "dbg";
     pub const LOG: &str = "log";
+    pub const HASH: &str = "hash";
+    pub const VERIFY_SIGNATURE: &str = "verify_signature";
 }
 
 mod import {
@@ -98,6 +101,28 @@ mod import {
     }
 }
 
+mod fuel {
+    //! Fuel charged by host functions which do heavy work natively and
+    //! thus aren't metered by the runtime
+
+    use super::WasmUsize;
+
+    /// Base cost of a cryptographic host function call
+    const CRYPTO_CALL: u64 = 1_000;
+    /// Cost of processing a single byte of the encoded input
+    const CRYPTO_BYTE: u64 = 2;
+    /// Cost of a signature verification on top of processing the input
+    const SIGNATURE_VERIFICATION: u64 = 100_000;
+
+    pub fn hash(len: WasmUsize) -> u64 {
+        CRYPTO_CALL.saturating_add(CRYPTO_BYTE.saturating_mul(len.into()))
+    }
+
+    pub fn verify_signature(len: WasmUsize) -> u64 {
+        hash(len).saturating_add(SIGNATURE_VERIFICATION)
+    }
+}
+
 pub mod error {
     //! Error types for [`wasm`](super) and their impls
 
@@ -704,6 +729,57 @@ impl<S> Runtime<S> {
     fn dbg(msg: String) {
         eprintln!("{msg}");
     }
+
+    /// Host-defined function which hashes the given bytes with Blake2b.
+    ///
+    /// Like any [`iroha_crypto::Hash`], the result has its least significant bit set to 1,
+    /// so it differs from a raw Blake2b-256 digest but matches hashes computed by Iroha.
+    ///
+    /// # Warning
+    ///
+    /// This function doesn't take ownership of the provided allocation
+    /// but it does transfer ownership of the result to the caller
+    ///
+    /// # Errors
+    ///
+    /// If bytes decoding fails
+    #[allow(clippy::needless_pass_by_value)]
+    #[codec::wrap(state = "S")]
+    fn hash(bytes: Vec<u8>) -> iroha_crypto::Hash {
+        iroha_crypto::Hash::new(bytes)
+    }
+
+    /// Host-defined function which checks that the signature of the given payload
+    /// was made with the given public key of any supported algorithm
+    ///
+    /// # Warning
+    ///
+    /// This function doesn't take ownership of the provided allocation
+    /// but it does transfer ownership of the result to the caller
+    ///
+    /// # Errors
+    ///
+    /// If decoding of the public key, signature or payload fails
+    #[allow(clippy::needless_pass_by_value)]
+    #[codec::wrap(state = "S")]
+    fn verify_signature(
+        (public_key, signature, payload): (PublicKey, iroha_crypto::Signature, Vec<u8>),
+    ) -> bool {
+        signature.verify(&public_key, &payload).is_ok()
+    }
+
+    /// Charge fuel for a host function call.
+    ///
+    /// # Errors
+    ///
+    /// Traps with [`wasmtime::Trap::OutOfFuel`] if there is not enough fuel left
+    fn consume_fuel(caller: &mut Caller<S>, fuel: u64) -> Result<(), WasmtimeError> {
+        let Some(remaining) = caller.get_fuel()?.checked_sub(fuel) else {
+            caller.set_fuel(0)?;
+            return Err(wasmtime::Trap::OutOfFuel.into());
+        };
+        caller.set_fuel(remaining)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -762,6 +838,20 @@ impl<W, S> Runtime<state::CommonState<W, S>> {
     }
 }
 
+impl<S> Runtime<S>
+where
+    Self: import::traits::ExecuteOperations<S>,
+{
+    /// Emit a custom data event on behalf of the authority.
+    ///
+    /// The event is emitted by executing [`EmitEvent`], so it's subject to
+    /// the executor validation and the instruction limits.
+    #[codec::wrap]
+    fn emit_event(instruction: EmitEvent, state: &mut S) -> Result<(), ValidationFail> {
+        Self::__execute_instruction_inner(instruction.into(), state)
+    }
+}
+
 impl<W, S> Runtime<Option<CommonState<W, S>>> {
     #[codec::wrap]
     fn log(
@@ -1019,10 +1109,12 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         let context = Self::get_smart_contract_context(&smart_contract, &mut store);
 
         // NOTE: This function takes ownership of the pointer
-        main_fn
-            .call(&mut store, context)
-            .map_err(ExportFnCallError::from)?;
+        let result = main_fn.call(&mut store, context);
+        let remaining_fuel = store.get_fuel().expect("Fuel consumption is enabled");
         let mut state = store.into_data();
+        state.state.0.consumed_fuel += self.config.fuel.get().saturating_sub(remaining_fuel);
+        result.map_err(ExportFnCallError::from)?;
+
         let executed_queries = state.take_executed_queries();
         forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
@@ -1099,11 +1191,12 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         let context = Self::get_trigger_context(&instance, &mut store);
 
         // NOTE: This function takes ownership of the pointer
-        main_fn
-            .call(&mut store, context)
-            .map_err(ExportFnCallError::from)?;
-
+        let result = main_fn.call(&mut store, context);
+        let remaining_fuel = store.get_fuel().expect("Fuel consumption is enabled");
         let mut state = store.into_data();
+        state.state.0.consumed_fuel += self.config.fuel.get().saturating_sub(remaining_fuel);
+        result.map_err(ExportFnCallError::from)?;
+
         let executed_queries = state.take_executed_queries();
         forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
@@ -1526,6 +1619,26 @@ macro_rules! create_imports {
                     |caller: ::wasmtime::Caller<$ty>, offset, len| Runtime::dbg(caller, offset, len),
                 )
             })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::HASH,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::consume_fuel(&mut caller, fuel::hash(len))?;
+                        Runtime::hash(caller, offset, len)
+                    },
+                )
+            })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::VERIFY_SIGNATURE,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::consume_fuel(&mut caller, fuel::verify_signature(len))?;
+                        Runtime::verify_signature(caller, offset, len)
+                    },
+                )
+            })
             $(.and_then(|l| {
                 l.func_wrap(
                     WASM_MODULE,
@@ -1550,6 +1663,7 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::SmartContract<'wrld, 'block, '
             create_imports!(linker, state::SmartContract<'wrld, 'block, 'state>,
                 export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
                 export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::EMIT_EVENT => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::emit_event(caller, offset, len),
             )?;
             Ok(linker)
         })
@@ -1569,6 +1683,7 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::Trigger<'wrld, 'block, 'state>
             create_imports!(linker, state::Trigger<'wrld, 'block, 'state>,
                 export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
                 export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::EMIT_EVENT => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::emit_event(caller, offset, len),
             )?;
             Ok(linker)
         })
@@ -1803,9 +1918,10 @@ mod tests {
         let kura = Kura::blank_kura_for_testing();
         let query_handle = LiveQueryStore::start_test();
         let state = State::new(world_with_test_account(&authority), kura, query_handle);
-        let query_hex = encode_hex(QueryRequest::Singular(
+        let query_hex = encode_hex(QueryRequest::Singular(SingularQueryWithParams::new(
             SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-        ));
+            None,
+        )));
 
         let wat = format!(
             r#"
@@ -1907,4 +2023,117 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    async fn verify_signature_exported() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let key_pair = KeyPair::random();
+        let signature = iroha_crypto::Signature::new(key_pair.private_key(), b"payload");
+        let verify_wat = |payload: &[u8]| {
+            let args_hex = encode_hex((key_pair.public_key(), &signature, payload));
+            format!(
+                r#"
+                (module
+                    ;; Import host function to execute
+                    (import "iroha" "{verify_fn_name}"
+                        (func $verify_fn (param i32 i32) (result i32)))
+
+                    {memory_and_alloc}
+
+                    ;; Trap unless the signature is valid.
+                    ;; The result is an encoded `bool` prefixed with its length
+                    (func (export "{main_fn_name}") (param i32)
+                        (call $verify_fn (i32.const 0) (i32.const {args_len}))
+                        (i32.load8_u offset=4)
+                        (if (i32.eqz) (then unreachable))))
+                "#,
+                main_fn_name = import::SMART_CONTRACT_MAIN,
+                verify_fn_name = export::VERIFY_SIGNATURE,
+                memory_and_alloc = memory_and_alloc(&args_hex),
+                args_len = args_hex.len() / 3,
+            )
+        };
+
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        RuntimeBuilder::<state::SmartContract>::new()
+            .build()?
+            .execute(
+                &mut state_transaction,
+                authority.clone(),
+                verify_wat(b"payload"),
+            )
+            .expect("Signature should be valid");
+        RuntimeBuilder::<state::SmartContract>::new()
+            .build()?
+            .execute(&mut state_transaction, authority, verify_wat(b"tampered"))
+            .expect_err("Signature should be invalid");
+
+        Ok(())
+    }
+
+    #[test]
+    async fn hash_charges_fuel() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let bytes_hex = encode_hex(vec![0_u8; 100]);
+        let wat = format!(
+            r#"
+            (module
+                ;; Import host function to execute
+                (import "iroha" "{hash_fn_name}"
+                    (func $hash_fn (param i32 i32) (result i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param i32)
+                    (call $hash_fn (i32.const 0) (i32.const {bytes_len}))
+
+                    ;; No use of return values
+                    drop))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            hash_fn_name = export::HASH,
+            memory_and_alloc = memory_and_alloc(&bytes_hex),
+            bytes_len = bytes_hex.len() / 3,
+        );
+
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        RuntimeBuilder::<state::SmartContract>::new()
+            .build()?
+            .execute(&mut state_transaction, authority.clone(), wat.clone())
+            .expect("Hashing should fit into the default fuel limit");
+
+        // enough to run the module itself, but not to hash 100 bytes
+        let config = Config {
+            fuel: nonzero!(1_000_u64),
+            ..Config::default()
+        };
+        let err = RuntimeBuilder::<state::SmartContract>::new()
+            .with_config(config)
+            .build()?
+            .execute(&mut state_transaction, authority, wat)
+            .expect_err("Hashing should run out of fuel");
+        let Error::ExportFnCall(ExportFnCallError::ExecutionLimitsExceeded(err)) = err else {
+            panic!("Unexpected error: {err:?}");
+        };
+        assert_eq!(err.downcast_ref(), Some(&wasmtime::Trap::OutOfFuel));
+
+        Ok(())
+    }
 }
@@ -1,6 +1,15 @@
 //! This module contains [`State`] snapshot actor service.
+//!
+//! Snapshots are incremental: the serialized state is split into content-defined chunks
+//! which are stored under their hashes, so a chunk that didn't change since the previous
+//! snapshot is never written twice. Each snapshot is described by a [`Manifest`] listing
+//! its chunks in order, and only the latest few manifests are kept.
+//!
+//! Entries of the [`World`](crate::state::World) storages are chunked along the leaves of the
+//! [`StateTree`], so that only the chunks with the entries changed since the previous snapshot
+//! are serialized again.
 use std::{
-    io::Read,
+    collections::{BTreeSet, HashMap},
     num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::Arc,
@@ -8,24 +17,132 @@ use std::{
 };
 
 use iroha_config::{parameters::actual::Snapshot as Config, snapshot::Mode};
-use iroha_crypto::HashOf;
-use iroha_data_model::block::BlockHeader;
+use iroha_crypto::{Hash, HashOf};
+use iroha_data_model::{block::BlockHeader, state::StateEntry};
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
 use iroha_logger::prelude::*;
-use serde::{de::DeserializeSeed, Serialize};
+use parity_scale_codec::{Decode, Encode};
+use serde::{de::DeserializeSeed, Deserialize, Serialize, Serializer as _};
+use tokio::sync::watch;
 
 #[cfg(feature = "telemetry")]
 use crate::telemetry::StateTelemetry;
 use crate::{
     kura::{BlockCount, Kura},
     query::store::LiveQueryStoreHandle,
-    state::{deserialize::KuraSeed, State, StateReadOnly},
+    state::{
+        deserialize::KuraSeed,
+        root::{StateTree, StorageEntry},
+        State, StateReadOnly, WorldReadOnly,
+    },
+};
+
+/// Name of the directory with snapshot chunks.
+const CHUNKS_DIR_NAME: &str = "chunks";
+/// Name of the directory with snapshot manifests.
+const MANIFESTS_DIR_NAME: &str = "manifests";
+/// Extension of temporary files which are renamed once fully written.
+const TMP_EXTENSION: &str = "tmp";
+/// Name of the file with the whole state written by the previous versions.
+const LEGACY_SNAPSHOT_FILE_NAME: &str = "snapshot.data";
+
+/// Chunks are never cut shorter than this, except for the last one
+const MIN_CHUNK_LEN: usize = 16 * 1024;
+/// Chunks are always cut at this length
+const MAX_CHUNK_LEN: usize = 256 * 1024;
+/// Chunk boundary is found where the rolling fingerprint has these bits unset,
+/// which makes chunks 64 KiB long on average
+const CHUNK_BOUNDARY_MASK: u64 = (1 << 16) - 1;
+/// Chunk of storage entries ends with an entry whose hash starts with a byte with these bits unset,
+/// which makes chunks 64 entries long on average
+const ENTRY_BOUNDARY_MASK: u8 = (1 << 6) - 1;
+
+/// Pseudo-random values mixed into the rolling fingerprint for every byte
+const GEAR: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut seed = 0_u64;
+    let mut i = 0;
+    while i < table.len() {
+        // splitmix64
+        seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = seed;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        table[i] = value ^ (value >> 31);
+        i += 1;
+    }
+    table
 };
 
-/// Name of the [`State`] snapshot file.
-const SNAPSHOT_FILE_NAME: &str = "snapshot.data";
-/// Name of the temporary [`State`] snapshot file.
-const SNAPSHOT_TMP_FILE_NAME: &str = "snapshot.tmp";
+/// Description of a snapshot stored as a sequence of content-addressed chunks
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Height of the latest block applied to the state
+    pub height: u64,
+    /// Hash of the latest block applied to the state
+    pub latest_block_hash: Option<HashOf<BlockHeader>>,
+    /// Chunks of the serialized state in order
+    pub chunks: Vec<Chunk>,
+}
+
+/// Entry of the [`Manifest`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Serialize, Deserialize)]
+pub struct Chunk {
+    /// Hash of the chunk contents, also used as the chunk file name
+    pub hash: Hash,
+    /// Length of the chunk in bytes
+    pub len: u64,
+}
+
+impl Chunk {
+    /// Describe a chunk with the given `data`
+    pub fn new(data: &[u8]) -> Self {
+        Self {
+            hash: Hash::new(data),
+            len: data.len() as u64,
+        }
+    }
+
+    fn file_name(&self) -> String {
+        self.hash.to_string()
+    }
+}
+
+impl Manifest {
+    /// Total length of the serialized state in bytes
+    pub fn len(&self) -> u64 {
+        self.chunks.iter().map(|chunk| chunk.len).sum()
+    }
+
+    /// Whether the snapshot has no data
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    fn file_name(&self) -> String {
+        format!("{:020}.json", self.height)
+    }
+}
+
+/// Part of the serialized [`State`] which the [`Manifest`] is derived from
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    block_hashes: Vec<HashOf<BlockHeader>>,
+}
+
+impl SnapshotHeader {
+    fn parse(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+
+    fn height(&self) -> u64 {
+        self.block_hashes.len() as u64
+    }
+
+    fn latest_block_hash(&self) -> Option<HashOf<BlockHeader>> {
+        self.block_hashes.last().copied()
+    }
+}
 
 // /// Errors produced by [`SnapshotMaker`] actor.
 // pub type Result<T, E = Error> = core::result::Result<T, E>;
@@ -35,25 +152,47 @@ pub struct SnapshotMaker {
     state: Arc<State>,
     /// Frequency at which snapshot is made
     create_every: Duration,
+    /// Number of the latest snapshots to keep
+    keep_last: NonZeroUsize,
     /// Path to the directory where snapshots are stored
     store_dir: PathBuf,
     /// Hash of the latest block stored in the state
     latest_block_hash: Option<HashOf<BlockHeader>>,
+    /// Chunks of storage entries written by the previous snapshot
+    entry_chunks: EntryChunks,
+}
+
+/// Chunks of [`World`](crate::state::World) storage entries which can be reused by the next snapshot
+#[derive(Debug, Default)]
+pub(crate) struct EntryChunks {
+    /// Lengths of the serialized entries by the hashes of the entries
+    entry_lens: HashMap<HashOf<StateEntry>, usize>,
+    /// Chunks by the hashes of the sequences of entries they consist of
+    chunks: HashMap<Hash, Chunk>,
 }
 
 impl SnapshotMaker {
     /// Start the actor.
-    pub fn start(self, shutdown_signal: ShutdownSignal) -> Child {
+    ///
+    /// Snapshot interval and the number of kept snapshots are updated with the values received from `config_updates`.
+    /// Changes of the mode and the store directory take effect only after a restart.
+    pub fn start(
+        self,
+        config_updates: watch::Receiver<Config>,
+        shutdown_signal: ShutdownSignal,
+    ) -> Child {
         Child::new(
-            tokio::spawn(self.run(shutdown_signal)),
+            tokio::spawn(self.run(config_updates, shutdown_signal)),
             OnShutdown::Wait(Duration::from_secs(2)),
         )
     }
 
-    async fn run(mut self, shutdown_signal: ShutdownSignal) {
-        let mut snapshot_create_every = tokio::time::interval(self.create_every);
-        // Don't try to create snapshot more frequently if previous take longer time
-        snapshot_create_every.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    async fn run(
+        mut self,
+        mut config_updates: watch::Receiver<Config>,
+        shutdown_signal: ShutdownSignal,
+    ) {
+        let mut snapshot_create_every = Self::interval(self.create_every);
 
         loop {
             tokio::select! {
@@ -61,6 +200,14 @@ impl SnapshotMaker {
                     // Offload snapshot creation into blocking thread
                     self.create_snapshot().await;
                 },
+                Ok(()) = config_updates.changed() => {
+                    let config = config_updates.borrow_and_update().clone();
+                    self.keep_last = config.keep_last;
+                    if config.create_every_ms.get() != self.create_every {
+                        self.create_every = config.create_every_ms.get();
+                        snapshot_create_every = Self::interval(self.create_every);
+                    }
+                }
                 () = shutdown_signal.receive() => {
                     info!("Saving latest snapshot and shutting down");
                     self.create_snapshot().await;
@@ -71,28 +218,41 @@ impl SnapshotMaker {
         }
     }
 
+    fn interval(period: Duration) -> tokio::time::Interval {
+        let mut interval = tokio::time::interval(period);
+        // Don't try to create snapshot more frequently if previous take longer time
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        interval
+    }
+
     /// Invoke snapshot creation task
     async fn create_snapshot(&mut self) {
         let store_dir = self.store_dir.clone();
-        let latest_block_hash;
-        let at_height;
-        {
-            let state_view = self.state.view();
-            latest_block_hash = state_view.latest_block_hash();
-            at_height = state_view.height();
-        }
+        let keep_last = self.keep_last;
+        let latest_block_hash = self.state.view().latest_block_hash();
 
         if latest_block_hash != self.latest_block_hash {
             let state = self.state.clone();
-            let handle = tokio::task::spawn_blocking(move || -> Result<(), TryWriteError> {
+            let mut entry_chunks = core::mem::take(&mut self.entry_chunks);
+            let handle = tokio::task::spawn_blocking(move || {
                 // TODO: enhance error by attaching `store_dir` parameter origin
-                try_write_snapshot(&state, store_dir)
+                let written = try_write_snapshot(&state, store_dir, keep_last, &mut entry_chunks);
+                (written, entry_chunks)
             });
 
-            match handle.await {
-                Ok(Ok(())) => {
-                    iroha_logger::info!(at_height, "Successfully created a snapshot of state");
-                    self.latest_block_hash = latest_block_hash;
+            let written = handle.await.map(|(written, entry_chunks)| {
+                self.entry_chunks = entry_chunks;
+                written
+            });
+            match written {
+                Ok(Ok(written)) => {
+                    iroha_logger::info!(
+                        at_height = written.manifest.height,
+                        chunks = written.manifest.chunks.len(),
+                        new_chunks = written.new_chunks,
+                        "Successfully created a snapshot of state"
+                    );
+                    self.latest_block_hash = written.manifest.latest_block_hash;
                 }
                 Ok(Err(error)) => {
                     iroha_logger::error!(%error, "Failed to create a snapshot of state");
@@ -113,8 +273,10 @@ impl SnapshotMaker {
             Some(Self {
                 state,
                 create_every: config.create_every_ms.get(),
+                keep_last: config.keep_last,
                 store_dir: config.store_dir.resolve_relative_path(),
                 latest_block_hash,
+                entry_chunks: EntryChunks::default(),
             })
         } else {
             None
@@ -122,11 +284,12 @@ impl SnapshotMaker {
     }
 }
 
-/// Try to deserialize [`State`] from a snapshot file.
+/// Try to deserialize [`State`] from the latest snapshot which is not ahead of `block_count`.
 ///
 /// # Errors
 /// - IO errors
 /// - Deserialization errors
+/// - Corrupted snapshot chunks
 pub fn try_read_snapshot(
     store_dir: impl AsRef<Path>,
     kura: &Arc<Kura>,
@@ -134,28 +297,38 @@ pub fn try_read_snapshot(
     BlockCount(block_count): BlockCount,
     #[cfg(feature = "telemetry")] telemetry: StateTelemetry,
 ) -> Result<State, TryReadError> {
-    let mut bytes = Vec::new();
-    let path = store_dir.as_ref().join(SNAPSHOT_FILE_NAME);
-    let mut file = match std::fs::OpenOptions::new().read(true).open(&path) {
-        Ok(file) => file,
-        Err(err) => {
-            return if err.kind() == std::io::ErrorKind::NotFound {
-                Err(TryReadError::NotFound)
-            } else {
-                Err(TryReadError::IO(err, path.clone()))
+    let manifests = read_manifests(&store_dir)?;
+    let bytes = if let Some(latest) = manifests.last() {
+        let Some(manifest) = manifests
+            .iter()
+            .rev()
+            .find(|manifest| manifest.height <= block_count as u64)
+        else {
+            return Err(TryReadError::MismatchedHeight {
+                snapshot_height: usize::try_from(latest.height).unwrap_or(usize::MAX),
+                kura_height: block_count,
+            });
+        };
+        read_snapshot_data(&store_dir, manifest)?
+    } else {
+        // Snapshot written by a previous version is read the same way,
+        // and is replaced by the next written snapshot
+        let legacy_path = store_dir.as_ref().join(LEGACY_SNAPSHOT_FILE_NAME);
+        match std::fs::read(&legacy_path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(TryReadError::NotFound)
             }
+            Err(err) => return Err(TryReadError::IO(err, legacy_path)),
         }
     };
-    file.read_to_end(&mut bytes)
-        .map_err(|err| TryReadError::IO(err, path.clone()))?;
-    let mut deserializer = serde_json::Deserializer::from_slice(&bytes);
-    let seed = KuraSeed {
-        kura: Arc::clone(kura),
-        query_handle: live_query_store_lazy(),
+    let state = deserialize_state(
+        &bytes,
+        kura,
+        live_query_store_lazy(),
         #[cfg(feature = "telemetry")]
         telemetry,
-    };
-    let state = seed.deserialize(&mut deserializer)?;
+    )?;
     let state_view = state.view();
     let snapshot_height = state_view.height();
     if snapshot_height > block_count {
@@ -188,30 +361,473 @@ pub fn try_read_snapshot(
     Ok(state)
 }
 
-/// Serialize and write snapshot to file,
-/// overwriting any previously stored data.
+/// Deserialize [`State`] from the data assembled by [`read_snapshot_data`].
+///
+/// # Errors
+/// Deserialization errors
+pub(crate) fn deserialize_state(
+    bytes: &[u8],
+    kura: &Arc<Kura>,
+    query_handle: LiveQueryStoreHandle,
+    #[cfg(feature = "telemetry")] telemetry: StateTelemetry,
+) -> Result<State, TryReadError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let seed = KuraSeed {
+        kura: Arc::clone(kura),
+        query_handle,
+        #[cfg(feature = "telemetry")]
+        telemetry,
+    };
+
+    Ok(seed.deserialize(&mut deserializer)?)
+}
+
+/// Read manifests of all the stored snapshots, ordered by height.
+///
+/// # Errors
+/// - IO errors
+/// - Deserialization errors
+pub fn read_manifests(store_dir: impl AsRef<Path>) -> Result<Vec<Manifest>, TryReadError> {
+    let manifests_dir = store_dir.as_ref().join(MANIFESTS_DIR_NAME);
+    let entries = match std::fs::read_dir(&manifests_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(TryReadError::IO(err, manifests_dir)),
+    };
+
+    let mut manifests = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| TryReadError::IO(err, manifests_dir.clone()))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == TMP_EXTENSION) {
+            continue;
+        }
+        let bytes = std::fs::read(&path).map_err(|err| TryReadError::IO(err, path.clone()))?;
+        manifests.push(serde_json::from_slice::<Manifest>(&bytes)?);
+    }
+    manifests.sort_by_key(|manifest| manifest.height);
+
+    Ok(manifests)
+}
+
+/// Assemble the serialized [`State`] described by `manifest` from its chunks.
+///
+/// # Errors
+/// - IO errors
+/// - Chunks which don't match their hashes
+pub fn read_snapshot_data(
+    store_dir: impl AsRef<Path>,
+    manifest: &Manifest,
+) -> Result<Vec<u8>, TryReadError> {
+    let mut bytes = Vec::with_capacity(usize::try_from(manifest.len()).unwrap_or_default());
+    for chunk in &manifest.chunks {
+        bytes.extend_from_slice(&read_chunk(&store_dir, chunk)?);
+    }
+
+    Ok(bytes)
+}
+
+/// Read a stored `chunk` of a snapshot.
+///
+/// # Errors
+/// - IO errors
+/// - Chunk which doesn't match its hash
+pub fn read_chunk(store_dir: impl AsRef<Path>, chunk: &Chunk) -> Result<Vec<u8>, TryReadError> {
+    let path = store_dir
+        .as_ref()
+        .join(CHUNKS_DIR_NAME)
+        .join(chunk.file_name());
+    let data = std::fs::read(&path).map_err(|err| TryReadError::IO(err, path))?;
+    if Chunk::new(&data) != *chunk {
+        return Err(TryReadError::CorruptedChunk { hash: chunk.hash });
+    }
+
+    Ok(data)
+}
+
+/// Check that the snapshot described by `manifest` is complete and consistent with it.
+///
+/// # Errors
+/// - IO errors
+/// - Chunks which don't match their hashes
+/// - Snapshot data which is not a state or doesn't match the manifest
+pub fn verify_snapshot(
+    store_dir: impl AsRef<Path>,
+    manifest: &Manifest,
+) -> Result<(), TryReadError> {
+    let bytes = read_snapshot_data(store_dir, manifest)?;
+    let header = SnapshotHeader::parse(&bytes)?;
+
+    if header.height() != manifest.height
+        || header.latest_block_hash() != manifest.latest_block_hash
+    {
+        return Err(TryReadError::InconsistentManifest {
+            height: manifest.height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Key of the field whose value follows the serialized `json`
+fn last_key(json: &[u8]) -> &str {
+    let key = json
+        .strip_suffix(b"\":")
+        .expect("INTERNAL BUG: value doesn't follow a key");
+    let start = key
+        .iter()
+        .rposition(|&byte| byte == b'"')
+        .expect("INTERNAL BUG: key isn't quoted");
+    core::str::from_utf8(&key[start + 1..]).expect("INTERNAL BUG: key isn't valid UTF-8")
+}
+
+/// Snapshot which was written by [`try_write_snapshot`]
+pub(crate) struct WrittenSnapshot {
+    pub(crate) manifest: Manifest,
+    /// Number of chunks which weren't stored by previous snapshots
+    new_chunks: usize,
+}
+
+/// Serialize and write snapshot, storing only the chunks which aren't already stored.
+/// Only `keep_last` snapshots are retained afterwards.
+///
+/// Chunks of storage entries are taken from `entry_chunks` if the entries didn't change,
+/// and `entry_chunks` is replaced with the chunks of this snapshot.
+///
+/// # Errors
+/// - IO errors
+/// - Serialization errors
+pub(crate) fn try_write_snapshot(
+    state: &State,
+    store_dir: impl AsRef<Path>,
+    keep_last: NonZeroUsize,
+    entry_chunks: &mut EntryChunks,
+) -> Result<WrittenSnapshot, TryWriteError> {
+    let store_dir = store_dir.as_ref();
+    // can't occur in the serialized state by chance
+    let placeholder = format!("entries-{:032x}", rand::random::<u128>());
+    let (view, skeleton) = state.view_with_skeleton(&placeholder)?;
+    let mut storages: HashMap<_, _> = view
+        .state_tree()
+        .storage_entries(view.latest_block_hash())
+        // the cached tree is being updated by the next block
+        .unwrap_or_else(|| StateTree::new(&view.world).storage_entries())
+        .into_iter()
+        .collect();
+    let previous = core::mem::take(entry_chunks);
+    let mut writer = SnapshotWriter::new(store_dir);
+
+    // Same layout as the serialized `State`, with the storage entries in place of the placeholders
+    let placeholder = format!("\"{placeholder}\"");
+    let mut rest = skeleton.as_slice();
+    while let Some(at) = rest
+        .windows(placeholder.len())
+        .position(|window| window == placeholder.as_bytes())
+    {
+        let (before, after) = rest.split_at(at);
+        let storage = last_key(before);
+        let entries = storages
+            .remove(storage)
+            .unwrap_or_else(|| panic!("INTERNAL BUG: `{storage}` storage isn't in the state tree"));
+        writer.pending.extend_from_slice(before);
+        writer.pending.push(b'[');
+        writer.entries(&view.world, &entries, &previous, entry_chunks)?;
+        writer.pending.push(b']');
+        rest = &after[placeholder.len()..];
+    }
+    assert!(
+        storages.is_empty(),
+        "INTERNAL BUG: storages of the state tree aren't in the serialized state"
+    );
+    writer.pending.extend_from_slice(rest);
+    writer.flush()?;
+
+    let manifest = Manifest {
+        height: view.height() as u64,
+        latest_block_hash: view.latest_block_hash(),
+        chunks: writer.chunks,
+    };
+    write_manifest(store_dir, &manifest)?;
+    prune_snapshots(store_dir, keep_last)?;
+    // superseded by the written snapshot
+    let legacy_path = store_dir.join(LEGACY_SNAPSHOT_FILE_NAME);
+    match std::fs::remove_file(&legacy_path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+            return Err(TryWriteError::IO(err, legacy_path))
+        }
+        _ => {}
+    }
+
+    Ok(WrittenSnapshot {
+        manifest,
+        new_chunks: writer.new_chunks,
+    })
+}
+
+/// Chunks of the snapshot being written by [`try_write_snapshot`]
+struct SnapshotWriter<'dir> {
+    store_dir: &'dir Path,
+    /// Serialized data which isn't split into chunks yet
+    pending: Vec<u8>,
+    chunks: Vec<Chunk>,
+    /// Number of chunks which weren't stored by previous snapshots
+    new_chunks: usize,
+}
+
+impl<'dir> SnapshotWriter<'dir> {
+    fn new(store_dir: &'dir Path) -> Self {
+        Self {
+            store_dir,
+            pending: Vec::new(),
+            chunks: Vec::new(),
+            new_chunks: 0,
+        }
+    }
+
+    /// Append the `entries` of a storage as a sequence, reusing the `previous` chunks of the same entries.
+    /// Chunks of the entries are recorded to `current`.
+    fn entries(
+        &mut self,
+        world: &impl WorldReadOnly,
+        entries: &[StorageEntry],
+        previous: &EntryChunks,
+        current: &mut EntryChunks,
+    ) -> Result<(), TryWriteError> {
+        // entries which weren't in the previous snapshot, serialized to find out their length
+        let mut serialized = HashMap::new();
+        let mut start = 0;
+        let mut len = 0;
+
+        for (idx, entry) in entries.iter().enumerate() {
+            let entry_len = if let Some(len) = previous.entry_lens.get(&entry.hash()) {
+                *len
+            } else {
+                let mut data = Vec::new();
+                entry.serialize(world, &mut serde_json::Serializer::new(&mut data))?;
+                let len = data.len();
+                serialized.insert(entry.hash(), data);
+                len
+            };
+            current.entry_lens.insert(entry.hash(), entry_len);
+            // including the separator
+            len += entry_len + 1;
+
+            let hash = entry.hash();
+            let hash: &[u8; Hash::LENGTH] = hash.as_ref();
+            if hash[0] & ENTRY_BOUNDARY_MASK == 0
+                || len >= MAX_CHUNK_LEN
+                || idx + 1 == entries.len()
+            {
+                self.entry_chunk(
+                    world,
+                    &entries[start..=idx],
+                    start == 0,
+                    &mut serialized,
+                    previous,
+                    current,
+                )?;
+                start = idx + 1;
+                len = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Append a chunk of `entries`, which is either reused from the `previous` snapshot or serialized
+    fn entry_chunk(
+        &mut self,
+        world: &impl WorldReadOnly,
+        entries: &[StorageEntry],
+        is_first: bool,
+        serialized: &mut HashMap<HashOf<StateEntry>, Vec<u8>>,
+        previous: &EntryChunks,
+        current: &mut EntryChunks,
+    ) -> Result<(), TryWriteError> {
+        // chunk of the first entries of a sequence has no leading separator
+        let mut hashes = Vec::with_capacity(1 + entries.len() * Hash::LENGTH);
+        hashes.push(u8::from(is_first));
+        for entry in entries {
+            let hash = entry.hash();
+            let hash: &[u8; Hash::LENGTH] = hash.as_ref();
+            hashes.extend_from_slice(hash);
+        }
+        let id = Hash::new(hashes);
+
+        self.flush()?;
+        let stored = previous.chunks.get(&id).filter(|chunk| {
+            self.store_dir
+                .join(CHUNKS_DIR_NAME)
+                .join(chunk.file_name())
+                .exists()
+        });
+        let chunk = if let Some(chunk) = stored {
+            *chunk
+        } else {
+            let mut data = Vec::new();
+            for (idx, entry) in entries.iter().enumerate() {
+                if idx > 0 || !is_first {
+                    data.push(b',');
+                }
+                match serialized.remove(&entry.hash()) {
+                    Some(entry) => data.extend_from_slice(&entry),
+                    None => entry.serialize(world, &mut serde_json::Serializer::new(&mut data))?,
+                }
+            }
+            let chunk = Chunk::new(&data);
+            if write_chunk(self.store_dir, &chunk, &data)? {
+                self.new_chunks += 1;
+            }
+            chunk
+        };
+        self.chunks.push(chunk);
+        current.chunks.insert(id, chunk);
+
+        Ok(())
+    }
+
+    /// Store the pending data as chunks
+    fn flush(&mut self) -> Result<(), TryWriteError> {
+        let pending = core::mem::take(&mut self.pending);
+        for data in split_into_chunks(&pending) {
+            let chunk = Chunk::new(data);
+            if write_chunk(self.store_dir, &chunk, data)? {
+                self.new_chunks += 1;
+            }
+            self.chunks.push(chunk);
+        }
+
+        Ok(())
+    }
+}
+
+/// Store `data` of the `chunk` unless it is already stored.
+/// Returns whether the chunk was written.
+///
+/// # Errors
+/// IO errors
+pub(crate) fn write_chunk(
+    store_dir: impl AsRef<Path>,
+    chunk: &Chunk,
+    data: &[u8],
+) -> Result<bool, TryWriteError> {
+    let chunks_dir = store_dir.as_ref().join(CHUNKS_DIR_NAME);
+    std::fs::create_dir_all(&chunks_dir)
+        .map_err(|err| TryWriteError::IO(err, chunks_dir.clone()))?;
+
+    let path = chunks_dir.join(chunk.file_name());
+    if path.exists() {
+        return Ok(false);
+    }
+    write_atomically(&path, data)?;
+
+    Ok(true)
+}
+
+/// Store the `manifest`, making the snapshot it describes available once all its chunks are stored.
 ///
 /// # Errors
 /// - IO errors
 /// - Serialization errors
-fn try_write_snapshot(state: &State, store_dir: impl AsRef<Path>) -> Result<(), TryWriteError> {
-    std::fs::create_dir_all(store_dir.as_ref())
-        .map_err(|err| TryWriteError::IO(err, store_dir.as_ref().to_path_buf()))?;
-    let path_to_file = store_dir.as_ref().join(SNAPSHOT_FILE_NAME);
-    let path_to_tmp_file = store_dir.as_ref().join(SNAPSHOT_TMP_FILE_NAME);
-    let file = std::fs::OpenOptions::new()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .open(&path_to_tmp_file)
-        .map_err(|err| TryWriteError::IO(err, path_to_tmp_file.clone()))?;
-    let mut serializer = serde_json::Serializer::new(file);
-    state.serialize(&mut serializer)?;
-    std::fs::rename(path_to_tmp_file, &path_to_file)
-        .map_err(|err| TryWriteError::IO(err, path_to_file.clone()))?;
+pub(crate) fn write_manifest(
+    store_dir: impl AsRef<Path>,
+    manifest: &Manifest,
+) -> Result<(), TryWriteError> {
+    let manifests_dir = store_dir.as_ref().join(MANIFESTS_DIR_NAME);
+    std::fs::create_dir_all(&manifests_dir)
+        .map_err(|err| TryWriteError::IO(err, manifests_dir.clone()))?;
+
+    write_atomically(
+        &manifests_dir.join(manifest.file_name()),
+        &serde_json::to_vec(manifest)?,
+    )
+}
+
+/// Remove all snapshots except for the latest `keep_last` ones and the chunks only they refer to
+fn prune_snapshots(store_dir: &Path, keep_last: NonZeroUsize) -> Result<(), TryWriteError> {
+    let manifests = read_manifests(store_dir)?;
+    let (outdated, retained) = manifests.split_at(manifests.len().saturating_sub(keep_last.get()));
+
+    let manifests_dir = store_dir.join(MANIFESTS_DIR_NAME);
+    for manifest in outdated {
+        let path = manifests_dir.join(manifest.file_name());
+        std::fs::remove_file(&path).map_err(|err| TryWriteError::IO(err, path))?;
+    }
+
+    let referenced = retained
+        .iter()
+        .flat_map(|manifest| &manifest.chunks)
+        .map(Chunk::file_name)
+        .collect::<BTreeSet<_>>();
+    let chunks_dir = store_dir.join(CHUNKS_DIR_NAME);
+    let entries =
+        std::fs::read_dir(&chunks_dir).map_err(|err| TryWriteError::IO(err, chunks_dir.clone()))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|err| TryWriteError::IO(err, chunks_dir.clone()))?
+            .path();
+        // might be written right now, e.g. by state synchronization
+        if path.extension().is_some_and(|ext| ext == TMP_EXTENSION) {
+            continue;
+        }
+        let is_referenced = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| referenced.contains(name));
+        if !is_referenced {
+            std::fs::remove_file(&path).map_err(|err| TryWriteError::IO(err, path))?;
+        }
+    }
+
     Ok(())
 }
 
+/// Write `data` to a temporary file and rename it to `path`,
+/// so that `path` is either absent or fully written
+fn write_atomically(path: &Path, data: &[u8]) -> Result<(), TryWriteError> {
+    let tmp_path = path.with_extension(TMP_EXTENSION);
+    std::fs::write(&tmp_path, data).map_err(|err| TryWriteError::IO(err, tmp_path.clone()))?;
+    std::fs::rename(&tmp_path, path).map_err(|err| TryWriteError::IO(err, path.to_path_buf()))
+}
+
+/// Split `data` into chunks at content-defined boundaries.
+///
+/// Boundaries depend only on the bytes preceding them, so a local change of the data
+/// only changes the chunks around it.
+fn split_into_chunks(mut data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    core::iter::from_fn(move || {
+        if data.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = data.split_at(chunk_len(data));
+        data = rest;
+        Some(chunk)
+    })
+}
+
+/// Length of the first chunk of `data`
+fn chunk_len(data: &[u8]) -> usize {
+    if data.len() <= MIN_CHUNK_LEN {
+        return data.len();
+    }
+
+    let mut fingerprint = 0_u64;
+    for (i, &byte) in data
+        .iter()
+        .enumerate()
+        .take(MAX_CHUNK_LEN)
+        .skip(MIN_CHUNK_LEN)
+    {
+        fingerprint = (fingerprint << 1).wrapping_add(GEAR[usize::from(byte)]);
+        if fingerprint & CHUNK_BOUNDARY_MASK == 0 {
+            return i + 1;
+        }
+    }
+
+    data.len().min(MAX_CHUNK_LEN)
+}
+
 /// Error variants for snapshot reading
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 pub enum TryReadError {
@@ -221,6 +837,16 @@ pub enum TryReadError {
     IO(#[source] std::io::Error, PathBuf),
     /// Error (de)serializing state snapshot
     Serialization(#[from] serde_json::Error),
+    /// Snapshot chunk `{hash}` doesn't match its hash
+    CorruptedChunk {
+        /// Hash of the chunk listed in the manifest
+        hash: Hash,
+    },
+    /// Manifest of the snapshot at height {height} doesn't match the snapshot data
+    InconsistentManifest {
+        /// Height listed in the manifest
+        height: u64,
+    },
     /// Snapshot is in a non-consistent state. Snapshot has greater height (`snapshot_height`) than kura block store (`kura_height`)
     MismatchedHeight {
         /// The amount of block hashes stored by snapshot
@@ -241,28 +867,34 @@ pub enum TryReadError {
 
 /// Error variants for snapshot writing
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
-enum TryWriteError {
+pub enum TryWriteError {
     /// Failed reading/writing {1:?} from disk
     IO(#[source] std::io::Error, PathBuf),
     /// Error (de)serializing World State View snapshot
     Serialization(#[from] serde_json::Error),
+    /// Failed to read previous snapshots
+    Read(#[from] TryReadError),
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::Write};
+    use std::num::NonZeroU64;
 
     use iroha_crypto::KeyPair;
-    use iroha_data_model::peer::PeerId;
+    use iroha_data_model::{peer::PeerId, prelude::*};
+    use iroha_test_samples::gen_account_in;
     use nonzero_ext::nonzero;
     use tempfile::tempdir;
     use tokio::test;
 
     use super::*;
     use crate::{
-        block::ValidBlock, query::store::LiveQueryStore, sumeragi::network_topology::Topology,
+        block::ValidBlock, query::store::LiveQueryStore, smartcontracts::Execute,
+        state::root::state_root, sumeragi::network_topology::Topology,
     };
 
+    const KEEP_LAST: NonZeroUsize = nonzero!(2_usize);
+
     fn state_factory() -> State {
         let kura = Kura::blank_kura_for_testing();
         let query_handle = LiveQueryStore::start_test();
@@ -279,7 +911,13 @@ mod tests {
         let snapshot_store_dir = tmp_root.path().join("path/to/snapshot/dir");
         let state = state_factory();
 
-        try_write_snapshot(&state, &snapshot_store_dir).unwrap();
+        try_write_snapshot(
+            &state,
+            &snapshot_store_dir,
+            KEEP_LAST,
+            &mut EntryChunks::default(),
+        )
+        .unwrap();
 
         assert!(Path::exists(snapshot_store_dir.as_path()))
     }
@@ -290,7 +928,7 @@ mod tests {
         let store_dir = tmp_root.path().join("snapshot");
         let state = state_factory();
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut EntryChunks::default()).unwrap();
         let _wsv = try_read_snapshot(
             &store_dir,
             &Kura::blank_kura_for_testing(),
@@ -325,11 +963,15 @@ mod tests {
     async fn cannot_parse_snapshot_on_read_is_error() {
         let tmp_root = tempdir().unwrap();
         let store_dir = tmp_root.path().join("snapshot");
-        std::fs::create_dir(&store_dir).unwrap();
-        {
-            let mut file = File::create(store_dir.join(SNAPSHOT_FILE_NAME)).unwrap();
-            file.write_all(&[1, 4, 1, 2, 3, 4, 1, 4]).unwrap();
-        }
+        let data = [1, 4, 1, 2, 3, 4, 1, 4];
+        let chunk = Chunk::new(&data);
+        write_chunk(&store_dir, &chunk, &data).unwrap();
+        let manifest = Manifest {
+            height: 0,
+            latest_block_hash: None,
+            chunks: vec![chunk],
+        };
+        write_manifest(&store_dir, &manifest).unwrap();
 
         let Err(error) = try_read_snapshot(
             &store_dir,
@@ -391,7 +1033,7 @@ mod tests {
         }
         kura.store_block(committed_block);
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut EntryChunks::default()).unwrap();
 
         let state = try_read_snapshot(
             &store_dir,
@@ -465,7 +1107,7 @@ mod tests {
             .unwrap();
         kura.store_block(committed_block);
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut EntryChunks::default()).unwrap();
 
         let state = try_read_snapshot(
             &store_dir,
@@ -480,4 +1122,260 @@ mod tests {
         // Invalid block was discarded
         assert_eq!(state.view().height(), 1);
     }
+
+    fn commit_dummy_block(state: &State, kura: &Kura, height: NonZeroU64) {
+        commit_block_with(state, kura, height, []);
+    }
+
+    /// Commit an empty block along with the changes made by `instructions`
+    fn commit_block_with(
+        state: &State,
+        kura: &Kura,
+        height: NonZeroU64,
+        instructions: impl IntoIterator<Item = InstructionBox>,
+    ) {
+        let peer_key_pair = KeyPair::random();
+        let peer_id = PeerId::new(peer_key_pair.public_key().clone());
+        let topology = Topology::new(vec![peer_id]);
+        let committed_block =
+            ValidBlock::new_dummy_and_modify_header(peer_key_pair.private_key(), |header| {
+                header.height = height;
+            })
+            .commit(&topology)
+            .unpack(|_| {})
+            .unwrap();
+
+        let mut state_block = state.block(committed_block.as_ref().header());
+        let authority = gen_account_in("wonderland").0;
+        for instruction in instructions {
+            let mut transaction = state_block.transaction();
+            instruction.execute(&authority, &mut transaction).unwrap();
+            transaction.apply();
+        }
+        let _events =
+            state_block.apply_without_execution(&committed_block, topology.as_ref().to_owned());
+        state_block.commit();
+        kura.store_block(committed_block);
+    }
+
+    #[test]
+    async fn snapshots_share_unchanged_chunks() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_factory();
+
+        commit_dummy_block(&state, &kura, nonzero!(1_u64));
+        let mut entry_chunks = EntryChunks::default();
+        let first = try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut entry_chunks).unwrap();
+        assert_eq!(first.new_chunks, first.manifest.chunks.len());
+
+        let unchanged =
+            try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut entry_chunks).unwrap();
+        assert_eq!(unchanged.new_chunks, 0);
+
+        commit_dummy_block(&state, &kura, nonzero!(2_u64));
+        let second = try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut entry_chunks).unwrap();
+        assert!(second.new_chunks <= second.manifest.chunks.len());
+
+        let manifests = read_manifests(&store_dir).unwrap();
+        assert_eq!(manifests, [first.manifest, second.manifest]);
+        for manifest in &manifests {
+            verify_snapshot(&store_dir, manifest).unwrap();
+        }
+    }
+
+    #[test]
+    async fn snapshot_with_reused_entry_chunks_is_restored() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_factory();
+        let mut entry_chunks = EntryChunks::default();
+
+        commit_dummy_block(&state, &kura, nonzero!(1_u64));
+        try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut entry_chunks).unwrap();
+        assert!(!entry_chunks.chunks.is_empty());
+
+        commit_block_with(
+            &state,
+            &kura,
+            nonzero!(2_u64),
+            [
+                Register::domain(Domain::new("looking_glass".parse().unwrap())).into(),
+                SetKeyValue::domain(
+                    "wonderland".parse().unwrap(),
+                    "key".parse().unwrap(),
+                    "value",
+                )
+                .into(),
+            ],
+        );
+        try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut entry_chunks).unwrap();
+
+        let restored = try_read_snapshot(
+            &store_dir,
+            &kura,
+            LiveQueryStore::start_test,
+            BlockCount(2),
+            #[cfg(feature = "telemetry")]
+            StateTelemetry::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            state_root(&restored.view().world),
+            state_root(&state.view().world)
+        );
+    }
+
+    #[test]
+    async fn temporary_chunks_are_not_pruned() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let state = state_factory();
+        let tmp_chunk = store_dir
+            .join(CHUNKS_DIR_NAME)
+            .join(format!("chunk.{TMP_EXTENSION}"));
+        std::fs::create_dir_all(store_dir.join(CHUNKS_DIR_NAME)).unwrap();
+        std::fs::write(&tmp_chunk, b"being written").unwrap();
+
+        try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut EntryChunks::default()).unwrap();
+
+        assert!(tmp_chunk.exists());
+    }
+
+    #[test]
+    async fn legacy_snapshot_is_migrated() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let state = state_factory();
+
+        // storages were serialized by themselves, and the later ones weren't there at all
+        let mut legacy = serde_json::to_value(&state).unwrap();
+        let world = legacy["world"].as_object_mut().unwrap();
+        world["domains"] = serde_json::to_value(&state.world.domains).unwrap();
+        world["accounts"] = serde_json::to_value(&state.world.accounts).unwrap();
+        world["asset_definitions"] = serde_json::to_value(&state.world.asset_definitions).unwrap();
+        for storage in [
+            "account_aliases",
+            "asset_allowances",
+            "swaps",
+            "locked_assets",
+            "locked_nfts",
+            "swap_expirations",
+        ] {
+            world.remove(storage);
+        }
+        legacy.as_object_mut().unwrap().remove("state_roots");
+        std::fs::create_dir_all(&store_dir).unwrap();
+        let legacy_path = store_dir.join(LEGACY_SNAPSHOT_FILE_NAME);
+        std::fs::write(&legacy_path, serde_json::to_vec(&legacy).unwrap()).unwrap();
+
+        let restored = try_read_snapshot(
+            &store_dir,
+            &Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test,
+            BlockCount(0),
+            #[cfg(feature = "telemetry")]
+            StateTelemetry::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            state_root(&restored.view().world),
+            state_root(&state.view().world)
+        );
+
+        try_write_snapshot(
+            &restored,
+            &store_dir,
+            KEEP_LAST,
+            &mut EntryChunks::default(),
+        )
+        .unwrap();
+        assert!(!legacy_path.exists());
+    }
+
+    #[test]
+    async fn outdated_snapshots_are_pruned() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_factory();
+
+        let mut entry_chunks = EntryChunks::default();
+        for height in 1..=3 {
+            commit_dummy_block(&state, &kura, NonZeroU64::new(height).unwrap());
+            try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut entry_chunks).unwrap();
+        }
+
+        let manifests = read_manifests(&store_dir).unwrap();
+        let heights = manifests.iter().map(|manifest| manifest.height);
+        assert!(heights.eq([2, 3]));
+
+        let referenced = manifests
+            .iter()
+            .flat_map(|manifest| &manifest.chunks)
+            .map(|chunk| chunk.hash)
+            .collect::<BTreeSet<_>>();
+        let stored = std::fs::read_dir(store_dir.join(CHUNKS_DIR_NAME))
+            .unwrap()
+            .count();
+        assert_eq!(stored, referenced.len());
+
+        // The latest snapshot which isn't ahead of kura is read
+        let state = try_read_snapshot(
+            &store_dir,
+            &kura,
+            LiveQueryStore::start_test,
+            BlockCount(2),
+            #[cfg(feature = "telemetry")]
+            StateTelemetry::default(),
+        )
+        .unwrap();
+        assert_eq!(state.view().height(), 2);
+    }
+
+    #[test]
+    async fn corrupted_chunk_is_detected() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let state = state_factory();
+
+        let written =
+            try_write_snapshot(&state, &store_dir, KEEP_LAST, &mut EntryChunks::default()).unwrap();
+        let hash = written.manifest.chunks[0].hash;
+        let path = store_dir
+            .join(CHUNKS_DIR_NAME)
+            .join(written.manifest.chunks[0].file_name());
+        let mut data = std::fs::read(&path).unwrap();
+        data[0] ^= 1;
+        std::fs::write(&path, data).unwrap();
+
+        let Err(error) = verify_snapshot(&store_dir, &written.manifest) else {
+            panic!("should not be ok")
+        };
+        assert!(
+            matches!(error, TryReadError::CorruptedChunk { hash: corrupted } if corrupted == hash)
+        );
+    }
+
+    #[test]
+    async fn local_change_keeps_other_chunks() {
+        let mut data = (0..1_000_000_u32)
+            .flat_map(|i| i.wrapping_mul(2_654_435_761).to_le_bytes())
+            .collect::<Vec<_>>();
+        let chunks = split_into_chunks(&data)
+            .map(Hash::new)
+            .collect::<BTreeSet<_>>();
+
+        let tail = data.split_off(2_000_000);
+        data.extend_from_slice(b"inserted");
+        data.extend(tail);
+        let changed = split_into_chunks(&data)
+            .map(Hash::new)
+            .collect::<BTreeSet<_>>();
+
+        assert!(chunks.len() > 4);
+        assert!(chunks.difference(&changed).count() <= 2);
+    }
 }
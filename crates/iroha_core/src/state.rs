@@ -4,9 +4,10 @@ use std::{
 };
 
 use eyre::Result;
-use iroha_crypto::HashOf;
+use iroha_crypto::{HashOf, SignatureOf};
 use iroha_data_model::{
-    account::AccountId,
+    account::{AccountAlias, AccountId},
+    asset::AssetAllowanceId,
     block::{BlockHeader, SignedBlock},
     events::{
         pipeline::BlockEvent,
@@ -16,20 +17,19 @@ use iroha_data_model::{
     },
     executor::ExecutorDataModel,
     isi::error::{InstructionExecutionError as Error, MathError},
+    nft::NftId,
     parameter::Parameters,
     permission::Permissions,
     prelude::*,
     query::error::{FindError, QueryExecutionFail},
     role::RoleId,
+    state::StateRoot,
 };
 use iroha_logger::prelude::*;
 use iroha_primitives::{must_use::MustUse, numeric::Numeric, small::SmallVec};
 use mv::{
     cell::{Block as CellBlock, Cell, Transaction as CellTransaction, View as CellView},
-    storage::{
-        Block as StorageBlock, RangeIter, Storage, StorageReadOnly,
-        Transaction as StorageTransaction, View as StorageView,
-    },
+    storage::{RangeIter, Storage, StorageReadOnly, View as StorageView},
 };
 use nonzero_ext::nonzero;
 use range_bounds::*;
@@ -57,13 +57,22 @@ use crate::{
         },
         wasm, Execute,
     },
-    state::storage_transactions::{
-        TransactionsBlock, TransactionsReadOnly, TransactionsStorage, TransactionsView,
+    state::{
+        root::{StateTree, StateTreeCache},
+        storage_transactions::{
+            TransactionsBlock, TransactionsReadOnly, TransactionsStorage, TransactionsView,
+        },
+        tracked::{
+            TrackedCellBlock, TrackedCellTransaction, TrackedStorageBlock,
+            TrackedStorageTransaction,
+        },
     },
     Peers,
 };
 
+pub mod root;
 pub(crate) mod storage_transactions;
+pub mod tracked;
 
 /// The global entity consisting of `domains`, `triggers` and etc.
 /// For example registration of domain, will have this as an ISI target.
@@ -74,21 +83,47 @@ pub struct World {
     /// Identifications of discovered peers.
     pub(crate) peers: Cell<Peers>,
     /// Registered domains.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) domains: Storage<DomainId, Domain>,
     /// Registered accounts.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) accounts: Storage<AccountId, Account>,
     /// Registered asset definitions.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) asset_definitions: Storage<AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) assets: Storage<AssetId, Asset>,
     /// Non fungible assets.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) nfts: Storage<NftId, Nft>,
     /// Roles. [`Role`] pairs.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) roles: Storage<RoleId, Role>,
     /// Permission tokens of an account.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) account_permissions: Storage<AccountId, Permissions>,
     /// Roles of an account.
+    #[serde(serialize_with = "serialize_entries")]
     pub(crate) account_roles: Storage<RoleIdWithOwner, ()>,
+    /// Accounts by their aliases.
+    #[serde(serialize_with = "serialize_entries")]
+    pub(crate) account_aliases: Storage<AccountAlias, AccountId>,
+    /// Allowances approved by asset owners to spenders.
+    #[serde(serialize_with = "serialize_entries")]
+    pub(crate) asset_allowances: Storage<AssetAllowanceId, Numeric>,
+    /// Open swaps.
+    #[serde(serialize_with = "serialize_entries")]
+    pub(crate) swaps: Storage<SwapId, Swap>,
+    /// Quantities of assets locked by open swaps.
+    #[serde(serialize_with = "serialize_entries")]
+    pub(crate) locked_assets: Storage<AssetId, Numeric>,
+    /// NFTs locked by open swaps.
+    #[serde(serialize_with = "serialize_entries")]
+    pub(crate) locked_nfts: Storage<NftId, SwapId>,
+    /// Open swaps with an expiry, ordered by their expiration time.
+    #[serde(serialize_with = "serialize_entries")]
+    pub(crate) swap_expirations: Storage<(u64, SwapId), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSet,
     /// Runtime Executor
@@ -97,34 +132,80 @@ pub struct World {
     pub(crate) executor_data_model: Cell<ExecutorDataModel>,
 }
 
+/// Serialize `storage` as a sequence of its entries.
+///
+/// Unlike maps, sequences can be assembled from separately serialized entries,
+/// which lets [snapshots](crate::snapshot) serialize only the entries changed since the previous one.
+fn serialize_entries<K, V, S>(storage: &Storage<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + Serialize + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + Serialize + 'static,
+    S: serde::Serializer,
+{
+    if let Some(placeholder) = ENTRIES_PLACEHOLDER.with_borrow(Clone::clone) {
+        return serializer.serialize_str(&placeholder);
+    }
+    serializer.collect_seq(storage.view().iter())
+}
+
+thread_local! {
+    /// String which [`serialize_entries`] writes instead of the entries while it's set
+    static ENTRIES_PLACEHOLDER: core::cell::RefCell<Option<String>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+/// Copy the entries seen by `storage` into a new [`Storage`]
+pub(crate) fn copy_storage<K, V>(storage: &impl StorageReadOnly<K, V>) -> Storage<K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    storage
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
 /// Struct for block's aggregated changes
 pub struct WorldBlock<'world> {
     /// Iroha on-chain parameters.
-    pub parameters: CellBlock<'world, Parameters>,
+    pub parameters: TrackedCellBlock<'world, Parameters>,
     /// Identifications of discovered peers.
-    pub(crate) peers: CellBlock<'world, Peers>,
+    pub(crate) peers: TrackedCellBlock<'world, Peers>,
     /// Registered domains.
-    pub(crate) domains: StorageBlock<'world, DomainId, Domain>,
+    pub(crate) domains: TrackedStorageBlock<'world, DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: StorageBlock<'world, AccountId, Account>,
+    pub(crate) accounts: TrackedStorageBlock<'world, AccountId, Account>,
     /// Registered asset definitions.
-    pub(crate) asset_definitions: StorageBlock<'world, AssetDefinitionId, AssetDefinition>,
+    pub(crate) asset_definitions: TrackedStorageBlock<'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
-    pub(crate) assets: StorageBlock<'world, AssetId, Asset>,
+    pub(crate) assets: TrackedStorageBlock<'world, AssetId, Asset>,
     /// Registered NFTs.
-    pub(crate) nfts: StorageBlock<'world, NftId, Nft>,
+    pub(crate) nfts: TrackedStorageBlock<'world, NftId, Nft>,
     /// Roles. [`Role`] pairs.
-    pub(crate) roles: StorageBlock<'world, RoleId, Role>,
+    pub(crate) roles: TrackedStorageBlock<'world, RoleId, Role>,
     /// Permission tokens of an account.
-    pub(crate) account_permissions: StorageBlock<'world, AccountId, Permissions>,
+    pub(crate) account_permissions: TrackedStorageBlock<'world, AccountId, Permissions>,
     /// Roles of an account.
-    pub(crate) account_roles: StorageBlock<'world, RoleIdWithOwner, ()>,
+    pub(crate) account_roles: TrackedStorageBlock<'world, RoleIdWithOwner, ()>,
+    /// Accounts by their aliases.
+    pub(crate) account_aliases: TrackedStorageBlock<'world, AccountAlias, AccountId>,
+    /// Allowances approved by asset owners to spenders.
+    pub(crate) asset_allowances: TrackedStorageBlock<'world, AssetAllowanceId, Numeric>,
+    /// Open swaps.
+    pub(crate) swaps: TrackedStorageBlock<'world, SwapId, Swap>,
+    /// Quantities of assets locked by open swaps.
+    pub(crate) locked_assets: TrackedStorageBlock<'world, AssetId, Numeric>,
+    /// NFTs locked by open swaps.
+    pub(crate) locked_nfts: TrackedStorageBlock<'world, NftId, SwapId>,
+    /// Open swaps with an expiry, ordered by their expiration time.
+    pub(crate) swap_expirations: TrackedStorageBlock<'world, (u64, SwapId), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetBlock<'world>,
     /// Runtime Executor
-    pub(crate) executor: CellBlock<'world, Executor>,
+    pub(crate) executor: TrackedCellBlock<'world, Executor>,
     /// Executor-defined data model
-    pub(crate) executor_data_model: CellBlock<'world, ExecutorDataModel>,
+    pub(crate) executor_data_model: TrackedCellBlock<'world, ExecutorDataModel>,
     /// Events produced during execution of block
     events_buffer: Vec<EventBox>,
 }
@@ -132,32 +213,46 @@ pub struct WorldBlock<'world> {
 /// Struct for single transaction's aggregated changes
 pub struct WorldTransaction<'block, 'world> {
     /// Iroha on-chain parameters.
-    pub(crate) parameters: CellTransaction<'block, 'world, Parameters>,
+    pub(crate) parameters: TrackedCellTransaction<'block, 'world, Parameters>,
     /// Identifications of discovered peers.
-    pub(crate) peers: CellTransaction<'block, 'world, Peers>,
+    pub(crate) peers: TrackedCellTransaction<'block, 'world, Peers>,
     /// Registered domains.
-    pub(crate) domains: StorageTransaction<'block, 'world, DomainId, Domain>,
+    pub(crate) domains: TrackedStorageTransaction<'block, 'world, DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: StorageTransaction<'block, 'world, AccountId, Account>,
+    pub(crate) accounts: TrackedStorageTransaction<'block, 'world, AccountId, Account>,
     /// Registered asset definitions.
     pub(crate) asset_definitions:
-        StorageTransaction<'block, 'world, AssetDefinitionId, AssetDefinition>,
+        TrackedStorageTransaction<'block, 'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
-    pub(crate) assets: StorageTransaction<'block, 'world, AssetId, Asset>,
+    pub(crate) assets: TrackedStorageTransaction<'block, 'world, AssetId, Asset>,
     /// Registered NFTs.
-    pub(crate) nfts: StorageTransaction<'block, 'world, NftId, Nft>,
+    pub(crate) nfts: TrackedStorageTransaction<'block, 'world, NftId, Nft>,
     /// Roles. [`Role`] pairs.
-    pub(crate) roles: StorageTransaction<'block, 'world, RoleId, Role>,
+    pub(crate) roles: TrackedStorageTransaction<'block, 'world, RoleId, Role>,
     /// Permission tokens of an account.
-    pub(crate) account_permissions: StorageTransaction<'block, 'world, AccountId, Permissions>,
+    pub(crate) account_permissions:
+        TrackedStorageTransaction<'block, 'world, AccountId, Permissions>,
     /// Roles of an account.
-    pub(crate) account_roles: StorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    pub(crate) account_roles: TrackedStorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    /// Accounts by their aliases.
+    pub(crate) account_aliases: TrackedStorageTransaction<'block, 'world, AccountAlias, AccountId>,
+    /// Allowances approved by asset owners to spenders.
+    pub(crate) asset_allowances:
+        TrackedStorageTransaction<'block, 'world, AssetAllowanceId, Numeric>,
+    /// Open swaps.
+    pub(crate) swaps: TrackedStorageTransaction<'block, 'world, SwapId, Swap>,
+    /// Quantities of assets locked by open swaps.
+    pub(crate) locked_assets: TrackedStorageTransaction<'block, 'world, AssetId, Numeric>,
+    /// NFTs locked by open swaps.
+    pub(crate) locked_nfts: TrackedStorageTransaction<'block, 'world, NftId, SwapId>,
+    /// Open swaps with an expiry, ordered by their expiration time.
+    pub(crate) swap_expirations: TrackedStorageTransaction<'block, 'world, (u64, SwapId), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetTransaction<'block, 'world>,
     /// Runtime Executor
-    pub(crate) executor: CellTransaction<'block, 'world, Executor>,
+    pub(crate) executor: TrackedCellTransaction<'block, 'world, Executor>,
     /// Executor-defined data model
-    pub(crate) executor_data_model: CellTransaction<'block, 'world, ExecutorDataModel>,
+    pub(crate) executor_data_model: TrackedCellTransaction<'block, 'world, ExecutorDataModel>,
     /// Events produced during execution of a transaction
     events_buffer: TransactionEventBuffer<'block>,
 }
@@ -192,6 +287,18 @@ pub struct WorldView<'world> {
     pub(crate) account_permissions: StorageView<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageView<'world, RoleIdWithOwner, ()>,
+    /// Accounts by their aliases.
+    pub(crate) account_aliases: StorageView<'world, AccountAlias, AccountId>,
+    /// Allowances approved by asset owners to spenders.
+    pub(crate) asset_allowances: StorageView<'world, AssetAllowanceId, Numeric>,
+    /// Open swaps.
+    pub(crate) swaps: StorageView<'world, SwapId, Swap>,
+    /// Quantities of assets locked by open swaps.
+    pub(crate) locked_assets: StorageView<'world, AssetId, Numeric>,
+    /// NFTs locked by open swaps.
+    pub(crate) locked_nfts: StorageView<'world, NftId, SwapId>,
+    /// Open swaps with an expiry, ordered by their expiration time.
+    pub(crate) swap_expirations: StorageView<'world, (u64, SwapId), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetView<'world>,
     /// Runtime Executor
@@ -208,6 +315,8 @@ pub struct State {
     /// Blockchain.
     // TODO: Cell is redundant here since block_hashes is very easy to rollback by just popping the last element
     pub block_hashes: Cell<Vec<HashOf<BlockHeader>>>,
+    /// Roots of the world state resulting from every block.
+    pub state_roots: Cell<Vec<StateRoot>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: TransactionsStorage,
     /// Topology used to commit latest block
@@ -229,6 +338,9 @@ pub struct State {
     #[cfg(feature = "telemetry")]
     #[serde(skip)]
     pub telemetry: StateTelemetry,
+    /// Merkle tree of the latest world state
+    #[serde(skip)]
+    state_tree: StateTreeCache,
     /// Lock to prevent getting inconsistent view of the state
     #[serde(skip)]
     view_lock: parking_lot::RwLock<()>,
@@ -240,6 +352,8 @@ pub struct StateBlock<'state> {
     pub world: WorldBlock<'state>,
     /// Blockchain.
     pub block_hashes: CellBlock<'state, Vec<HashOf<BlockHeader>>>,
+    /// Roots of the world state resulting from every block.
+    pub state_roots: CellBlock<'state, Vec<StateRoot>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: TransactionsBlock<'state>,
     /// Topology used to commit latest block
@@ -256,6 +370,10 @@ pub struct StateBlock<'state> {
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
+    /// Merkle tree of the latest world state
+    state_tree: &'state StateTreeCache,
+    /// Merkle tree of the world state resulting from the applied block, cached on commit
+    updated_state_tree: Option<(HashOf<BlockHeader>, StateTree)>,
     /// Lock to prevent getting inconsistent view of the state
     view_lock: &'state parking_lot::RwLock<()>,
 
@@ -268,10 +386,14 @@ pub struct StateTransaction<'block, 'state> {
     pub world: WorldTransaction<'block, 'state>,
     /// Blockchain.
     pub block_hashes: CellTransaction<'block, 'state, Vec<HashOf<BlockHeader>>>,
+    /// Roots of the world state resulting from every block.
+    pub state_roots: CellTransaction<'block, 'state, Vec<StateRoot>>,
     /// Topology used to commit latest block
     pub commit_topology: CellTransaction<'block, 'state, Vec<PeerId>>,
     /// Topology used to commit previous block
     pub prev_commit_topology: CellTransaction<'block, 'state, Vec<PeerId>>,
+    /// Hashes of transactions mapped onto block height where they stored
+    transactions: &'block TransactionsBlock<'state>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -282,8 +404,14 @@ pub struct StateTransaction<'block, 'state> {
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
+    /// Merkle tree of the latest world state
+    state_tree: &'state StateTreeCache,
 
     pub(crate) curr_block: BlockHeader,
+    /// Fuel consumed by WASM smart contracts and triggers during the transaction
+    pub(crate) consumed_fuel: u64,
+    /// Number of instructions executed during the transaction
+    pub(crate) executed_instructions: u64,
 }
 
 /// Consistent point in time view of the [`State`]
@@ -292,6 +420,8 @@ pub struct StateView<'state> {
     pub world: WorldView<'state>,
     /// Blockchain.
     pub block_hashes: CellView<'state, Vec<HashOf<BlockHeader>>>,
+    /// Roots of the world state resulting from every block.
+    pub state_roots: CellView<'state, Vec<StateRoot>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: TransactionsView<'state>,
     /// Topology used to commit latest block
@@ -308,6 +438,8 @@ pub struct StateView<'state> {
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
+    /// Merkle tree of the latest world state
+    state_tree: &'state StateTreeCache,
 }
 
 impl World {
@@ -364,19 +496,25 @@ impl World {
     /// Create struct to apply block's changes
     pub fn block(&self) -> WorldBlock {
         WorldBlock {
-            parameters: self.parameters.block(),
-            peers: self.peers.block(),
-            domains: self.domains.block(),
-            accounts: self.accounts.block(),
-            asset_definitions: self.asset_definitions.block(),
-            assets: self.assets.block(),
-            nfts: self.nfts.block(),
-            roles: self.roles.block(),
-            account_permissions: self.account_permissions.block(),
-            account_roles: self.account_roles.block(),
+            parameters: self.parameters.block().into(),
+            peers: self.peers.block().into(),
+            domains: self.domains.block().into(),
+            accounts: self.accounts.block().into(),
+            asset_definitions: self.asset_definitions.block().into(),
+            assets: self.assets.block().into(),
+            nfts: self.nfts.block().into(),
+            roles: self.roles.block().into(),
+            account_permissions: self.account_permissions.block().into(),
+            account_roles: self.account_roles.block().into(),
+            account_aliases: self.account_aliases.block().into(),
+            asset_allowances: self.asset_allowances.block().into(),
+            swaps: self.swaps.block().into(),
+            locked_assets: self.locked_assets.block().into(),
+            locked_nfts: self.locked_nfts.block().into(),
+            swap_expirations: self.swap_expirations.block().into(),
             triggers: self.triggers.block(),
-            executor: self.executor.block(),
-            executor_data_model: self.executor_data_model.block(),
+            executor: self.executor.block().into(),
+            executor_data_model: self.executor_data_model.block().into(),
             events_buffer: Vec::new(),
         }
     }
@@ -384,19 +522,25 @@ impl World {
     /// Create struct to apply block's changes while reverting changes made in the latest block
     pub fn block_and_revert(&self) -> WorldBlock {
         WorldBlock {
-            parameters: self.parameters.block_and_revert(),
-            peers: self.peers.block_and_revert(),
-            domains: self.domains.block_and_revert(),
-            accounts: self.accounts.block_and_revert(),
-            asset_definitions: self.asset_definitions.block_and_revert(),
-            assets: self.assets.block_and_revert(),
-            nfts: self.nfts.block_and_revert(),
-            roles: self.roles.block_and_revert(),
-            account_permissions: self.account_permissions.block_and_revert(),
-            account_roles: self.account_roles.block_and_revert(),
+            parameters: self.parameters.block_and_revert().into(),
+            peers: self.peers.block_and_revert().into(),
+            domains: self.domains.block_and_revert().into(),
+            accounts: self.accounts.block_and_revert().into(),
+            asset_definitions: self.asset_definitions.block_and_revert().into(),
+            assets: self.assets.block_and_revert().into(),
+            nfts: self.nfts.block_and_revert().into(),
+            roles: self.roles.block_and_revert().into(),
+            account_permissions: self.account_permissions.block_and_revert().into(),
+            account_roles: self.account_roles.block_and_revert().into(),
+            account_aliases: self.account_aliases.block_and_revert().into(),
+            asset_allowances: self.asset_allowances.block_and_revert().into(),
+            swaps: self.swaps.block_and_revert().into(),
+            locked_assets: self.locked_assets.block_and_revert().into(),
+            locked_nfts: self.locked_nfts.block_and_revert().into(),
+            swap_expirations: self.swap_expirations.block_and_revert().into(),
             triggers: self.triggers.block_and_revert(),
-            executor: self.executor.block_and_revert(),
-            executor_data_model: self.executor_data_model.block_and_revert(),
+            executor: self.executor.block_and_revert().into(),
+            executor_data_model: self.executor_data_model.block_and_revert().into(),
             events_buffer: Vec::new(),
         }
     }
@@ -414,6 +558,12 @@ impl World {
             roles: self.roles.view(),
             account_permissions: self.account_permissions.view(),
             account_roles: self.account_roles.view(),
+            account_aliases: self.account_aliases.view(),
+            asset_allowances: self.asset_allowances.view(),
+            swaps: self.swaps.view(),
+            locked_assets: self.locked_assets.view(),
+            locked_nfts: self.locked_nfts.view(),
+            swap_expirations: self.swap_expirations.view(),
             triggers: self.triggers.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
@@ -421,6 +571,33 @@ impl World {
     }
 }
 
+impl WorldView<'_> {
+    /// Copy the viewed world into a new [`World`], which can be modified independently
+    pub fn to_world(&self) -> World {
+        World {
+            parameters: Cell::new((*self.parameters).clone()),
+            peers: Cell::new((*self.peers).clone()),
+            domains: copy_storage(&self.domains),
+            accounts: copy_storage(&self.accounts),
+            asset_definitions: copy_storage(&self.asset_definitions),
+            assets: copy_storage(&self.assets),
+            nfts: copy_storage(&self.nfts),
+            roles: copy_storage(&self.roles),
+            account_permissions: copy_storage(&self.account_permissions),
+            account_roles: copy_storage(&self.account_roles),
+            account_aliases: copy_storage(&self.account_aliases),
+            asset_allowances: copy_storage(&self.asset_allowances),
+            swaps: copy_storage(&self.swaps),
+            locked_assets: copy_storage(&self.locked_assets),
+            locked_nfts: copy_storage(&self.locked_nfts),
+            swap_expirations: copy_storage(&self.swap_expirations),
+            triggers: self.triggers.to_set(),
+            executor: Cell::new((*self.executor).clone()),
+            executor_data_model: Cell::new((*self.executor_data_model).clone()),
+        }
+    }
+}
+
 /// Trait to perform read-only operations on [`WorldBlock`], [`WorldTransaction`] and [`WorldView`]
 #[allow(missing_docs)]
 pub trait WorldReadOnly {
@@ -434,6 +611,12 @@ pub trait WorldReadOnly {
     fn roles(&self) -> &impl StorageReadOnly<RoleId, Role>;
     fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions>;
     fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()>;
+    fn account_aliases(&self) -> &impl StorageReadOnly<AccountAlias, AccountId>;
+    fn asset_allowances(&self) -> &impl StorageReadOnly<AssetAllowanceId, Numeric>;
+    fn swaps(&self) -> &impl StorageReadOnly<SwapId, Swap>;
+    fn locked_assets(&self) -> &impl StorageReadOnly<AssetId, Numeric>;
+    fn locked_nfts(&self) -> &impl StorageReadOnly<NftId, SwapId>;
+    fn swap_expirations(&self) -> &impl StorageReadOnly<(u64, SwapId), ()>;
     fn triggers(&self) -> &impl TriggerSetReadOnly;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
@@ -560,6 +743,25 @@ pub trait WorldReadOnly {
         Ok(f(account))
     }
 
+    /// Check if `signature` of `payload` was made by one of the signatories of the account.
+    ///
+    /// An account which isn't registered is only controlled by the signatory from its id.
+    fn verify_account_signature<T: parity_scale_codec::Encode>(
+        &self,
+        id: &AccountId,
+        signature: &SignatureOf<T>,
+        payload: &T,
+    ) -> bool {
+        let hash = HashOf::new(payload);
+
+        match self.accounts().get(id) {
+            Some(account) => account
+                .signatories()
+                .any(|signatory| signature.verify_hash(signatory, hash).is_ok()),
+            None => signature.verify_hash(id.signatory(), hash).is_ok(),
+        }
+    }
+
     /// Get [`Account`]'s [`RoleId`]s
     // NOTE: have to use concreate type because don't want to capture lifetme of `id`
     #[allow(clippy::type_complexity)]
@@ -698,7 +900,7 @@ pub trait WorldReadOnly {
 }
 
 macro_rules! impl_world_ro {
-    ($($ident:ty),*) => {$(
+    ($($ident:ty $(: $deref:tt)?),*) => {$(
         impl WorldReadOnly for $ident {
             fn parameters(&self) -> &Parameters {
                 &self.parameters
@@ -707,28 +909,46 @@ macro_rules! impl_world_ro {
                 &self.peers
             }
             fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain> {
-                &self.domains
+                &$($deref)? self.domains
             }
             fn accounts(&self) -> &impl StorageReadOnly<AccountId, Account> {
-                &self.accounts
+                &$($deref)? self.accounts
             }
             fn asset_definitions(&self) -> &impl StorageReadOnly<AssetDefinitionId, AssetDefinition> {
-                &self.asset_definitions
+                &$($deref)? self.asset_definitions
             }
             fn assets(&self) -> &impl StorageReadOnly<AssetId, Asset> {
-                &self.assets
+                &$($deref)? self.assets
             }
             fn nfts(&self) -> &impl StorageReadOnly<NftId, Nft> {
-                &self.nfts
+                &$($deref)? self.nfts
             }
             fn roles(&self) -> &impl StorageReadOnly<RoleId, Role> {
-                &self.roles
+                &$($deref)? self.roles
             }
             fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions> {
-                &self.account_permissions
+                &$($deref)? self.account_permissions
             }
             fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()> {
-                &self.account_roles
+                &$($deref)? self.account_roles
+            }
+            fn account_aliases(&self) -> &impl StorageReadOnly<AccountAlias, AccountId> {
+                &$($deref)? self.account_aliases
+            }
+            fn asset_allowances(&self) -> &impl StorageReadOnly<AssetAllowanceId, Numeric> {
+                &$($deref)? self.asset_allowances
+            }
+            fn swaps(&self) -> &impl StorageReadOnly<SwapId, Swap> {
+                &$($deref)? self.swaps
+            }
+            fn locked_assets(&self) -> &impl StorageReadOnly<AssetId, Numeric> {
+                &$($deref)? self.locked_assets
+            }
+            fn locked_nfts(&self) -> &impl StorageReadOnly<NftId, SwapId> {
+                &$($deref)? self.locked_nfts
+            }
+            fn swap_expirations(&self) -> &impl StorageReadOnly<(u64, SwapId), ()> {
+                &$($deref)? self.swap_expirations
             }
             fn triggers(&self) -> &impl TriggerSetReadOnly {
                 &self.triggers
@@ -743,8 +963,9 @@ macro_rules! impl_world_ro {
     )*};
 }
 
+// storages of blocks and transactions are read through their trackers
 impl_world_ro! {
-    WorldBlock<'_>, WorldTransaction<'_, '_>, WorldView<'_>
+    WorldBlock<'_>: *, WorldTransaction<'_, '_>: *, WorldView<'_>
 }
 
 impl<'world> WorldBlock<'world> {
@@ -761,6 +982,12 @@ impl<'world> WorldBlock<'world> {
             roles: self.roles.transaction(),
             account_permissions: self.account_permissions.transaction(),
             account_roles: self.account_roles.transaction(),
+            account_aliases: self.account_aliases.transaction(),
+            asset_allowances: self.asset_allowances.transaction(),
+            swaps: self.swaps.transaction(),
+            locked_assets: self.locked_assets.transaction(),
+            locked_nfts: self.locked_nfts.transaction(),
+            swap_expirations: self.swap_expirations.transaction(),
             triggers: self.triggers.transaction(),
             executor: self.executor.transaction(),
             executor_data_model: self.executor_data_model.transaction(),
@@ -785,6 +1012,12 @@ impl<'world> WorldBlock<'world> {
             roles,
             account_permissions,
             account_roles,
+            account_aliases,
+            asset_allowances,
+            swaps,
+            locked_assets,
+            locked_nfts,
+            swap_expirations,
             triggers,
             executor,
             executor_data_model,
@@ -794,6 +1027,12 @@ impl<'world> WorldBlock<'world> {
         executor_data_model.commit();
         executor.commit();
         triggers.commit();
+        swap_expirations.commit();
+        locked_nfts.commit();
+        locked_assets.commit();
+        swaps.commit();
+        asset_allowances.commit();
+        account_aliases.commit();
         account_roles.commit();
         account_permissions.commit();
         roles.commit();
@@ -822,6 +1061,12 @@ impl WorldTransaction<'_, '_> {
             roles,
             account_permissions,
             account_roles,
+            account_aliases,
+            asset_allowances,
+            swaps,
+            locked_assets,
+            locked_nfts,
+            swap_expirations,
             triggers,
             executor,
             executor_data_model,
@@ -830,6 +1075,12 @@ impl WorldTransaction<'_, '_> {
         executor_data_model.apply();
         executor.apply();
         triggers.apply();
+        swap_expirations.apply();
+        locked_nfts.apply();
+        locked_assets.apply();
+        swaps.apply();
+        asset_allowances.apply();
+        account_aliases.apply();
         account_roles.apply();
         account_permissions.apply();
         roles.apply();
@@ -907,6 +1158,88 @@ impl WorldTransaction<'_, '_> {
         }
     }
 
+    /// Remove all allowances with ids matching the `predicate`
+    pub fn remove_asset_allowances(&mut self, predicate: impl Fn(&AssetAllowanceId) -> bool) {
+        let allowances_to_remove = self
+            .asset_allowances
+            .iter()
+            .map(|(id, _)| id)
+            .filter(|id| predicate(id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for allowance in allowances_to_remove {
+            self.asset_allowances.remove(allowance);
+        }
+    }
+
+    /// Open the `swap`, indexing it by its expiration time if it has one
+    pub fn insert_swap(&mut self, swap: Swap) {
+        if let Some(expires_at_ms) = swap.expires_at_ms {
+            self.swap_expirations
+                .insert((expires_at_ms, swap.id.clone()), ());
+        }
+        self.swaps.insert(swap.id.clone(), swap);
+    }
+
+    /// Remove the swap with the given `id`, releasing the holdings locked by it
+    pub fn remove_swap(&mut self, id: &SwapId) -> Option<Swap> {
+        let swap = self.swaps.remove(id.clone())?;
+        if let Some(expires_at_ms) = swap.expires_at_ms {
+            self.swap_expirations.remove((expires_at_ms, id.clone()));
+        }
+        self.unlock_swap(&swap);
+        Some(swap)
+    }
+
+    /// Remove all swaps matching the `predicate`, releasing the holdings locked by them.
+    /// Returns ids of the removed swaps.
+    pub fn remove_swaps(&mut self, predicate: impl Fn(&Swap) -> bool) -> Vec<SwapId> {
+        let swaps_to_remove = self
+            .swaps
+            .iter()
+            .map(|(_, swap)| swap)
+            .filter(|swap| predicate(swap))
+            .map(|swap| swap.id.clone())
+            .collect::<Vec<_>>();
+
+        for id in &swaps_to_remove {
+            self.remove_swap(id);
+        }
+        swaps_to_remove
+    }
+
+    /// Cancel all swaps matching the `predicate`, e.g. when a party or holdings involved in them are unregistered
+    pub fn cancel_swaps(&mut self, predicate: impl Fn(&Swap) -> bool) {
+        let cancelled = self.remove_swaps(predicate);
+        self.emit_events(cancelled.into_iter().map(SwapEvent::Cancelled));
+    }
+
+    /// Release the holdings of the maker locked by the `swap`
+    pub fn unlock_swap(&mut self, swap: &Swap) {
+        match &swap.offered {
+            SwapItem::Asset(offered) => {
+                let asset_id = offered.asset_of(swap.maker.clone());
+                let left = self
+                    .locked_assets
+                    .get(&asset_id)
+                    .and_then(|locked| locked.checked_sub(offered.quantity))
+                    .filter(|left| !left.is_zero());
+                match left {
+                    Some(left) => {
+                        self.locked_assets.insert(asset_id, left);
+                    }
+                    None => {
+                        self.locked_assets.remove(asset_id);
+                    }
+                }
+            }
+            SwapItem::Nft(nft_id) => {
+                self.locked_nfts.remove(nft_id.clone());
+            }
+        }
+    }
+
     /// Get mutable reference to [`Asset`]
     ///
     /// # Errors
@@ -1077,6 +1410,12 @@ impl WorldTransaction<'_, '_> {
         self.events_buffer.push(event.into());
     }
 
+    /// Events produced by the current transaction so far
+    fn transaction_events(&self) -> &[EventBox] {
+        let buffer = &self.events_buffer;
+        &buffer.events_buffer[buffer.events_buffer.len() - buffer.events_created_in_transaction..]
+    }
+
     /// The function puts events produced by iterator into `events_buffer`.
     /// Events should be produced in the order of expanding scope: from specific to general.
     /// Example: account events before domain events.
@@ -1144,11 +1483,13 @@ impl State {
             commit_topology: Cell::new(Vec::new()),
             prev_commit_topology: Cell::new(Vec::new()),
             block_hashes: Cell::new(Vec::new()),
+            state_roots: Cell::new(Vec::new()),
             engine: wasm::create_engine(),
             kura,
             query_handle,
             #[cfg(feature = "telemetry")]
             telemetry,
+            state_tree: StateTreeCache::default(),
             view_lock: parking_lot::RwLock::new(()),
         }
     }
@@ -1204,6 +1545,7 @@ impl State {
         StateBlock {
             world: self.world.block(),
             block_hashes: self.block_hashes.block(),
+            state_roots: self.state_roots.block(),
             transactions: self.transactions.block(),
             commit_topology: self.commit_topology.block(),
             prev_commit_topology: self.prev_commit_topology.block(),
@@ -1212,6 +1554,8 @@ impl State {
             query_handle: &self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
+            state_tree: &self.state_tree,
+            updated_state_tree: None,
             view_lock: &self.view_lock,
             curr_block,
         }
@@ -1222,6 +1566,7 @@ impl State {
         StateBlock {
             world: self.world.block_and_revert(),
             block_hashes: self.block_hashes.block_and_revert(),
+            state_roots: self.state_roots.block_and_revert(),
             transactions: self.transactions.block_and_revert(),
             commit_topology: self.commit_topology.block_and_revert(),
             prev_commit_topology: self.prev_commit_topology.block_and_revert(),
@@ -1230,6 +1575,8 @@ impl State {
             query_handle: &self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
+            state_tree: &self.state_tree,
+            updated_state_tree: None,
             view_lock: &self.view_lock,
             curr_block,
         }
@@ -1238,9 +1585,15 @@ impl State {
     /// Create point in time view of [`WorldState`]
     pub fn view(&self) -> StateView<'_> {
         let _view_lock = self.view_lock.read();
+        self.view_unlocked()
+    }
+
+    /// Create point in time view of the [`State`] without taking the view lock
+    fn view_unlocked(&self) -> StateView<'_> {
         StateView {
             world: self.world.view(),
             block_hashes: self.block_hashes.view(),
+            state_roots: self.state_roots.view(),
             transactions: self.transactions.view(),
             commit_topology: self.commit_topology.view(),
             prev_commit_topology: self.prev_commit_topology.view(),
@@ -1249,6 +1602,60 @@ impl State {
             query_handle: &self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
+            state_tree: &self.state_tree,
+        }
+    }
+
+    /// Get a view of the state along with the state serialized as JSON at the same point in time,
+    /// where the entries of every [`World`] storage are replaced with the `placeholder` string.
+    ///
+    /// [Snapshots](crate::snapshot) follow the layout of the serialized state this way,
+    /// while the storage entries are serialized separately.
+    ///
+    /// # Errors
+    /// Serialization errors
+    pub(crate) fn view_with_skeleton(
+        &self,
+        placeholder: &str,
+    ) -> serde_json::Result<(StateView<'_>, Vec<u8>)> {
+        struct ResetPlaceholder;
+
+        impl Drop for ResetPlaceholder {
+            fn drop(&mut self) {
+                ENTRIES_PLACEHOLDER.set(None);
+            }
+        }
+
+        // blocks aren't committed until the serialization is finished
+        let _view_lock = self.view_lock.read();
+        let view = self.view_unlocked();
+        ENTRIES_PLACEHOLDER.set(Some(placeholder.to_owned()));
+        let _reset = ResetPlaceholder;
+        let skeleton = serde_json::to_vec(self)?;
+
+        Ok((view, skeleton))
+    }
+
+    /// Copy the latest state into a new [`State`], which can be modified without affecting `self`.
+    ///
+    /// The copy doesn't report telemetry.
+    pub fn fork(&self) -> Self {
+        let view = self.view();
+        Self {
+            world: view.world.to_world(),
+            block_hashes: Cell::new(view.block_hashes.to_vec()),
+            state_roots: Cell::new(view.state_roots.to_vec()),
+            transactions: view.transactions.to_storage(),
+            commit_topology: Cell::new(view.commit_topology.to_vec()),
+            prev_commit_topology: Cell::new(view.prev_commit_topology.to_vec()),
+            // the executor and triggers are compiled for this engine
+            engine: self.engine.clone(),
+            kura: Arc::clone(&self.kura),
+            query_handle: self.query_handle.clone(),
+            #[cfg(feature = "telemetry")]
+            telemetry: StateTelemetry::default(),
+            state_tree: StateTreeCache::default(),
+            view_lock: parking_lot::RwLock::new(()),
         }
     }
 }
@@ -1258,6 +1665,7 @@ impl State {
 pub trait StateReadOnly {
     fn world(&self) -> &impl WorldReadOnly;
     fn block_hashes(&self) -> &[HashOf<BlockHeader>];
+    fn state_roots(&self) -> &[StateRoot];
     fn commit_topology(&self) -> &[PeerId];
     fn prev_commit_topology(&self) -> &[PeerId];
     fn engine(&self) -> &wasmtime::Engine;
@@ -1265,6 +1673,9 @@ pub trait StateReadOnly {
     fn query_handle(&self) -> &LiveQueryStoreHandle;
     #[cfg(feature = "telemetry")]
     fn metrics(&self) -> &StateTelemetry;
+    fn state_tree(&self) -> &StateTreeCache;
+    /// Height of the block the transaction is committed in
+    fn transaction_height(&self, hash: &HashOf<SignedTransaction>) -> Option<NonZeroUsize>;
 
     /// Get a reference to the block one before the latest block.
     /// Returns None if at least 2 blocks are not committed.
@@ -1297,6 +1708,17 @@ pub trait StateReadOnly {
         self.block_hashes().iter().nth_back(1).copied()
     }
 
+    /// Return the root of the world state resulting from the latest block
+    fn latest_state_root(&self) -> Option<StateRoot> {
+        self.state_roots().iter().nth_back(0).copied()
+    }
+
+    /// Return the root of the world state resulting from the block one before the latest block.
+    /// Returns None if at least 2 blocks are not committed.
+    fn prev_state_root(&self) -> Option<StateRoot> {
+        self.state_roots().iter().nth_back(1).copied()
+    }
+
     /// Load all blocks in the block chain from disc
     fn all_blocks(
         &self,
@@ -1345,6 +1767,9 @@ macro_rules! impl_state_ro {
             fn block_hashes(&self) -> &[HashOf<BlockHeader>] {
                 &self.block_hashes
             }
+            fn state_roots(&self) -> &[StateRoot] {
+                &self.state_roots
+            }
             fn commit_topology(&self) -> &[PeerId] {
                 &self.commit_topology
             }
@@ -1364,6 +1789,12 @@ macro_rules! impl_state_ro {
             fn metrics(&self) -> &StateTelemetry {
                 &self.telemetry
             }
+            fn state_tree(&self) -> &StateTreeCache {
+                &self.state_tree
+            }
+            fn transaction_height(&self, hash: &HashOf<SignedTransaction>) -> Option<NonZeroUsize> {
+                self.transactions.get(hash)
+            }
         }
     )*};
 }
@@ -1406,14 +1837,19 @@ impl<'state> StateBlock<'state> {
         StateTransaction {
             world: self.world.trasaction(),
             block_hashes: self.block_hashes.transaction(),
+            state_roots: self.state_roots.transaction(),
             commit_topology: self.commit_topology.transaction(),
             prev_commit_topology: self.prev_commit_topology.transaction(),
+            transactions: &self.transactions,
             engine: self.engine,
             kura: self.kura,
             query_handle: self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: self.telemetry,
+            state_tree: self.state_tree,
             curr_block: self.curr_block,
+            consumed_fuel: 0,
+            executed_instructions: 0,
         }
     }
 
@@ -1423,9 +1859,12 @@ impl<'state> StateBlock<'state> {
         let Self {
             world,
             block_hashes,
+            state_roots,
             transactions,
             commit_topology: committed_topology,
             prev_commit_topology: prev_committed_topology,
+            state_tree,
+            updated_state_tree,
             view_lock,
             ..
         } = self;
@@ -1433,8 +1872,12 @@ impl<'state> StateBlock<'state> {
         prev_committed_topology.commit();
         committed_topology.commit();
         transactions.commit();
+        state_roots.commit();
         block_hashes.commit();
         world.commit();
+        if let Some((block_hash, tree)) = updated_state_tree {
+            state_tree.put(block_hash, tree);
+        }
     }
 
     /// Commit `CommittedBlock` with changes in form of **Iroha Special
@@ -1514,6 +1957,7 @@ impl<'state> StateBlock<'state> {
         self.transactions.insert_block(transactions, block_height);
 
         self.world.triggers.handle_time_event(time_event);
+        self.expire_swaps(block.as_ref().header().creation_time());
 
         let res = self.process_triggers();
 
@@ -1524,7 +1968,16 @@ impl<'state> StateBlock<'state> {
             );
         }
 
+        let state_tree = match self.state_tree.take(self.latest_block_hash()) {
+            Some(mut tree) => {
+                tree.update(&self.world);
+                tree
+            }
+            None => StateTree::new(&self.world),
+        };
         self.block_hashes.push(block_hash);
+        self.state_roots.push(state_tree.root());
+        self.updated_state_tree = Some((block_hash, state_tree));
 
         *self.prev_commit_topology = core::mem::take(&mut self.commit_topology);
         *self.commit_topology = topology;
@@ -1539,6 +1992,28 @@ impl<'state> StateBlock<'state> {
         core::mem::take(&mut self.world.events_buffer)
     }
 
+    /// Close swaps which can no longer be accepted, releasing the holdings locked by them
+    ///
+    /// Only the expired swaps are visited, as they come first in the index of expiration times.
+    fn expire_swaps(&mut self, block_time: Duration) {
+        let mut transaction = self.transaction();
+        let expired = transaction
+            .world
+            .swap_expirations
+            .iter()
+            .map(|((expires_at_ms, id), _)| (*expires_at_ms, id))
+            .take_while(|(expires_at_ms, _)| Duration::from_millis(*expires_at_ms) < block_time)
+            .map(|(_, id)| id.clone())
+            .collect::<Vec<_>>();
+        for id in &expired {
+            transaction.world.remove_swap(id);
+        }
+        transaction
+            .world
+            .emit_events(expired.into_iter().map(SwapEvent::Expired));
+        transaction.apply();
+    }
+
     /// Create time event using previous and current blocks
     fn create_time_event(&self, block: &CommittedBlock) -> TimeEvent {
         let to = block.as_ref().header().creation_time();
@@ -1605,18 +2080,42 @@ impl<'state> StateBlock<'state> {
 }
 
 impl StateTransaction<'_, '_> {
+    /// Fuel consumed by WASM smart contracts and triggers during the transaction so far
+    pub fn consumed_fuel(&self) -> u64 {
+        self.consumed_fuel
+    }
+
+    /// Number of instructions executed during the transaction so far
+    pub fn executed_instructions(&self) -> u64 {
+        self.executed_instructions
+    }
+
+    /// Data events emitted during the transaction so far
+    pub fn data_events(&self) -> Vec<DataEvent> {
+        self.world
+            .transaction_events()
+            .iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => Some(event.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Apply transaction making it's changes visible
     pub fn apply(self) {
         // NOTE: intentionally destruct self not to forget apply some fields
         let Self {
             world,
             block_hashes,
+            state_roots,
             commit_topology: committed_topology,
             prev_commit_topology: prev_committed_topology,
             ..
         } = self;
         prev_committed_topology.apply();
         committed_topology.apply();
+        state_roots.apply();
         block_hashes.apply();
         world.apply();
     }
@@ -1965,6 +2464,10 @@ mod range_bounds {
 
 pub(crate) mod deserialize {
     use mv::serde::CellSeeded;
+    use serde::{
+        de::{value::MapAccessDeserializer, SeqAccess},
+        Deserialize,
+    };
 
     use super::*;
 
@@ -2032,6 +2535,61 @@ pub(crate) mod deserialize {
         }
     }
 
+    /// Entries of a [`World`] storage, serialized either as a sequence by [`serialize_entries`]
+    /// or by the storage itself, as in the snapshots taken before
+    struct Entries<K, V>(Storage<K, V>)
+    where
+        K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+        V: Clone + core::fmt::Debug + Send + Sync + 'static;
+
+    impl<'de, K, V> Deserialize<'de> for Entries<K, V>
+    where
+        K: Ord + Clone + core::fmt::Debug + Send + Sync + Deserialize<'de> + 'static,
+        V: Clone + core::fmt::Debug + Send + Sync + Deserialize<'de> + 'static,
+        Storage<K, V>: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct EntriesVisitor<K, V>(PhantomData<(K, V)>);
+
+            impl<'de, K, V> Visitor<'de> for EntriesVisitor<K, V>
+            where
+                K: Ord + Clone + core::fmt::Debug + Send + Sync + Deserialize<'de> + 'static,
+                V: Clone + core::fmt::Debug + Send + Sync + Deserialize<'de> + 'static,
+                Storage<K, V>: Deserialize<'de>,
+            {
+                type Value = Entries<K, V>;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("storage entries")
+                }
+
+                fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+                where
+                    S: SeqAccess<'de>,
+                {
+                    let mut entries = Vec::new();
+                    while let Some(entry) = seq.next_element::<(K, V)>()? {
+                        entries.push(entry);
+                    }
+
+                    Ok(Entries(entries.into_iter().collect()))
+                }
+
+                fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+                where
+                    M: MapAccess<'de>,
+                {
+                    Storage::deserialize(MapAccessDeserializer::new(map)).map(Entries)
+                }
+            }
+
+            deserializer.deserialize_any(EntriesVisitor(PhantomData))
+        }
+    }
+
     impl<'de> DeserializeSeed<'de> for WasmSeed<'_, World> {
         type Value = World;
 
@@ -2065,6 +2623,12 @@ pub(crate) mod deserialize {
                     let mut roles = None;
                     let mut account_permissions = None;
                     let mut account_roles = None;
+                    let mut account_aliases = None;
+                    let mut asset_allowances = None;
+                    let mut swaps = None;
+                    let mut locked_assets = None;
+                    let mut locked_nfts = None;
+                    let mut swap_expirations = None;
                     let mut triggers = None;
                     let mut executor = None;
                     let mut executor_data_model = None;
@@ -2078,28 +2642,46 @@ pub(crate) mod deserialize {
                                 peers = Some(map.next_value()?);
                             }
                             "domains" => {
-                                domains = Some(map.next_value()?);
+                                domains = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "accounts" => {
-                                accounts = Some(map.next_value()?);
+                                accounts = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "asset_definitions" => {
-                                asset_definitions = Some(map.next_value()?);
+                                asset_definitions = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "assets" => {
-                                assets = Some(map.next_value()?);
+                                assets = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "nfts" => {
-                                nfts = Some(map.next_value()?);
+                                nfts = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "roles" => {
-                                roles = Some(map.next_value()?);
+                                roles = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "account_permissions" => {
-                                account_permissions = Some(map.next_value()?);
+                                account_permissions = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "account_roles" => {
-                                account_roles = Some(map.next_value()?);
+                                account_roles = Some(map.next_value::<Entries<_, _>>()?.0);
+                            }
+                            "account_aliases" => {
+                                account_aliases = Some(map.next_value::<Entries<_, _>>()?.0);
+                            }
+                            "asset_allowances" => {
+                                asset_allowances = Some(map.next_value::<Entries<_, _>>()?.0);
+                            }
+                            "swaps" => {
+                                swaps = Some(map.next_value::<Entries<_, _>>()?.0);
+                            }
+                            "locked_assets" => {
+                                locked_assets = Some(map.next_value::<Entries<_, _>>()?.0);
+                            }
+                            "locked_nfts" => {
+                                locked_nfts = Some(map.next_value::<Entries<_, _>>()?.0);
+                            }
+                            "swap_expirations" => {
+                                swap_expirations = Some(map.next_value::<Entries<_, _>>()?.0);
                             }
                             "triggers" => {
                                 triggers =
@@ -2136,6 +2718,14 @@ pub(crate) mod deserialize {
                         })?,
                         account_roles: account_roles
                             .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?,
+                        // NOTE: snapshots taken before these storages were introduced don't have them,
+                        // while nothing could have been stored in them
+                        account_aliases: account_aliases.unwrap_or_default(),
+                        asset_allowances: asset_allowances.unwrap_or_default(),
+                        swaps: swaps.unwrap_or_default(),
+                        locked_assets: locked_assets.unwrap_or_default(),
+                        locked_nfts: locked_nfts.unwrap_or_default(),
+                        swap_expirations: swap_expirations.unwrap_or_default(),
                         triggers: triggers
                             .ok_or_else(|| serde::de::Error::missing_field("triggers"))?,
                         executor: executor
@@ -2156,6 +2746,12 @@ pub(crate) mod deserialize {
                     "roles",
                     "account_permissions",
                     "account_roles",
+                    "account_aliases",
+                    "asset_allowances",
+                    "swaps",
+                    "locked_assets",
+                    "locked_nfts",
+                    "swap_expirations",
                     "triggers",
                     "executor",
                     "executor_data_model",
@@ -2200,6 +2796,7 @@ pub(crate) mod deserialize {
                 {
                     let mut world = None;
                     let mut block_hashes = None;
+                    let mut state_roots = None;
                     let mut transactions = None;
                     let mut commit_topology = None;
                     let mut prev_commit_topology = None;
@@ -2219,6 +2816,9 @@ pub(crate) mod deserialize {
                             "block_hashes" => {
                                 block_hashes = Some(map.next_value()?);
                             }
+                            "state_roots" => {
+                                state_roots = Some(map.next_value()?);
+                            }
                             "transactions" => {
                                 transactions = Some(map.next_value()?);
                             }
@@ -2232,10 +2832,18 @@ pub(crate) mod deserialize {
                         }
                     }
 
+                    let world: World =
+                        world.ok_or_else(|| serde::de::Error::missing_field("world"))?;
+                    // NOTE: snapshots taken before state roots were introduced don't have them,
+                    // only the root of the latest block can be recovered from the world state
+                    let state_roots =
+                        state_roots.unwrap_or_else(|| Cell::new(vec![root::state_root(&world)]));
+
                     Ok(State {
-                        world: world.ok_or_else(|| serde::de::Error::missing_field("world"))?,
+                        world,
                         block_hashes: block_hashes
                             .ok_or_else(|| serde::de::Error::missing_field("block_hashes"))?,
+                        state_roots,
                         transactions: transactions
                             .ok_or_else(|| serde::de::Error::missing_field("transactions"))?,
                         commit_topology: commit_topology
@@ -2248,6 +2856,7 @@ pub(crate) mod deserialize {
                         #[cfg(feature = "telemetry")]
                         telemetry: self.loader.telemetry,
                         engine,
+                        state_tree: StateTreeCache::default(),
                         view_lock: parking_lot::RwLock::new(()),
                     })
                 }
@@ -2258,6 +2867,7 @@ pub(crate) mod deserialize {
                 &[
                     "world",
                     "block_hashes",
+                    "state_roots",
                     "transactions",
                     "commit_topology",
                     "prev_commit_topology",
@@ -2272,12 +2882,13 @@ pub(crate) mod deserialize {
 mod tests {
     use core::num::NonZeroU64;
 
+    use iroha_crypto::KeyPair;
     use iroha_test_samples::gen_account_in;
 
     use super::*;
     use crate::{
         block::ValidBlock, query::store::LiveQueryStore, role::RoleIdWithOwner,
-        sumeragi::network_topology::Topology,
+        smartcontracts::isi::Registrable as _, sumeragi::network_topology::Topology,
     };
 
     /// Used to inject faulty payload for testing
@@ -2441,4 +3052,83 @@ mod tests {
         let range = view.range(AssetByAccountBounds::new(&account_id));
         assert_eq!(range.count(), 2);
     }
+
+    #[tokio::test]
+    async fn only_expired_swaps_are_removed() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(World::default(), kura, query_handle);
+        let block = new_dummy_block_with_payload(|_| {});
+        let mut state_block = state.block(block.as_ref().header());
+
+        let (maker, _) = gen_account_in("wonderland");
+        let swap = |id: &str, expires_at_ms: Option<u64>| Swap {
+            id: id.parse().unwrap(),
+            maker: maker.clone(),
+            offered: SwapItem::Nft(format!("{id}$wonderland").parse().unwrap()),
+            requested: SwapItem::Nft("requested$wonderland".parse().unwrap()),
+            taker: None,
+            expires_at_ms,
+        };
+        let mut transaction = state_block.transaction();
+        transaction.world.insert_swap(swap("early", Some(1_000)));
+        transaction.world.insert_swap(swap("late", Some(5_000)));
+        transaction.world.insert_swap(swap("open", None));
+        transaction.apply();
+
+        state_block.expire_swaps(Duration::from_millis(2_000));
+        state_block.commit();
+
+        let view = state.view();
+        let swaps = view
+            .world
+            .swaps()
+            .iter()
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(swaps, ["late", "open"]);
+        let expirations = view
+            .world
+            .swap_expirations()
+            .iter()
+            .map(|((expires_at_ms, id), _)| (*expires_at_ms, id.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(expirations, [(5_000, "late".to_owned())]);
+    }
+
+    #[test]
+    fn account_signature_is_checked_against_signatories() {
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let new_keypair = KeyPair::random();
+        let mut account = Account::new(alice_id.clone()).build(&alice_id);
+        account.signatories = [new_keypair.public_key().clone()].into();
+        let domain = Domain::new(alice_id.domain.clone()).build(&alice_id);
+        let world = World::with([domain], [account], []);
+        let world = world.view();
+
+        let payload = "payload".to_owned();
+        let sign = |keypair: &KeyPair| SignatureOf::new(keypair.private_key(), &payload);
+
+        assert!(world.verify_account_signature(&alice_id, &sign(&new_keypair), &payload));
+        // the key from the id was rotated out
+        assert!(!world.verify_account_signature(&alice_id, &sign(&alice_keypair), &payload));
+        assert!(!world.verify_account_signature(
+            &alice_id,
+            &sign(&new_keypair),
+            &"tampered".to_owned()
+        ));
+    }
+
+    #[test]
+    fn unregistered_account_is_controlled_by_signatory_from_id() {
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+        let world = World::default();
+        let world = world.view();
+
+        let payload = "payload".to_owned();
+        let sign = |keypair: &KeyPair| SignatureOf::new(keypair.private_key(), &payload);
+
+        assert!(world.verify_account_signature(&bob_id, &sign(&bob_keypair), &payload));
+        assert!(!world.verify_account_signature(&bob_id, &sign(&KeyPair::random()), &payload));
+    }
 }
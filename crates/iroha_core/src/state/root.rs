@@ -0,0 +1,1030 @@
+//! Authenticated digest of the [`World`](super::World) state.
+//!
+//! Leaves of the state tree are hashes of [`StateEntry`]s in their canonical order.
+
+use core::num::NonZeroU64;
+use std::collections::BTreeSet;
+
+use iroha_crypto::{Hash, HashOf, MerkleTree};
+use iroha_data_model::{
+    account::{Account, AccountAlias, AccountId},
+    asset::{Asset, AssetAllowanceId, AssetDefinition, AssetDefinitionId, AssetId},
+    block::BlockHeader,
+    domain::{Domain, DomainId},
+    executor::{Executor, ExecutorDataModel},
+    nft::{Nft, NftId},
+    parameter::Parameters,
+    peer::PeerId,
+    permission::Permissions,
+    role::{Role, RoleId},
+    state::{StateEntry, StateProof, StateRoot},
+    swap::{Swap, SwapId},
+    trigger::{Trigger, TriggerId},
+    Identifiable,
+};
+use iroha_primitives::numeric::Numeric;
+use mv::storage::StorageReadOnly;
+use parity_scale_codec::Encode;
+use parking_lot::Mutex;
+use serde::{ser::Error as _, Serialize, Serializer};
+
+use super::{StateReadOnly, WorldBlock, WorldReadOnly};
+use crate::{role::RoleIdWithOwner, smartcontracts::triggers::set::SetReadOnly};
+
+/// Borrowed [`StateEntry`] which has exactly the same encoding
+#[derive(Encode)]
+enum StateEntryRef<'world> {
+    Parameters(&'world Parameters),
+    Peer(&'world PeerId),
+    Domain(&'world Domain),
+    Account(&'world Account),
+    AssetDefinition(&'world AssetDefinition),
+    Asset(&'world Asset),
+    AssetAllowance(&'world AssetAllowanceId, &'world Numeric),
+    Nft(&'world Nft),
+    Swap(&'world Swap),
+    Role(&'world Role),
+    AccountPermissions(&'world AccountId, &'world Permissions),
+    AccountRole(&'world AccountId, &'world RoleId),
+    Trigger(Box<Trigger>),
+    ExecutorDataModel(&'world ExecutorDataModel),
+    AliasedAccount(&'world AccountAlias, &'world AccountId),
+    LockedAsset(&'world AssetId, &'world Numeric),
+    LockedNft(&'world NftId, &'world SwapId),
+    SwapExpiration(&'world u64, &'world SwapId),
+    Executor(Option<&'world Executor>),
+}
+
+impl StateEntryRef<'_> {
+    fn hash(&self) -> HashOf<StateEntry> {
+        HashOf::from_untyped_unchecked(Hash::new(self.encode()))
+    }
+}
+
+/// Kind of a [`StateEntry`], in the canonical order of the leaves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EntryKind {
+    Parameters,
+    Peer,
+    Domain,
+    Account,
+    AssetDefinition,
+    Asset,
+    AssetAllowance,
+    Nft,
+    Swap,
+    Role,
+    AccountPermissions,
+    AccountRole,
+    Trigger,
+    ExecutorDataModel,
+    AliasedAccount,
+    LockedAsset,
+    LockedNft,
+    SwapExpiration,
+    Executor,
+}
+
+impl EntryKind {
+    const ALL: [Self; 19] = [
+        Self::Parameters,
+        Self::Peer,
+        Self::Domain,
+        Self::Account,
+        Self::AssetDefinition,
+        Self::Asset,
+        Self::AssetAllowance,
+        Self::Nft,
+        Self::Swap,
+        Self::Role,
+        Self::AccountPermissions,
+        Self::AccountRole,
+        Self::Trigger,
+        Self::ExecutorDataModel,
+        Self::AliasedAccount,
+        Self::LockedAsset,
+        Self::LockedNft,
+        Self::SwapExpiration,
+        Self::Executor,
+    ];
+
+    /// Name of the [`World`](super::World) storage holding the entries of this kind, if there is one
+    fn storage(self) -> Option<&'static str> {
+        match self {
+            Self::Domain => Some("domains"),
+            Self::Account => Some("accounts"),
+            Self::AssetDefinition => Some("asset_definitions"),
+            Self::Asset => Some("assets"),
+            Self::AssetAllowance => Some("asset_allowances"),
+            Self::Nft => Some("nfts"),
+            Self::Swap => Some("swaps"),
+            Self::Role => Some("roles"),
+            Self::AccountPermissions => Some("account_permissions"),
+            Self::AccountRole => Some("account_roles"),
+            Self::AliasedAccount => Some("account_aliases"),
+            Self::LockedAsset => Some("locked_assets"),
+            Self::LockedNft => Some("locked_nfts"),
+            Self::SwapExpiration => Some("swap_expirations"),
+            Self::Parameters
+            | Self::Peer
+            | Self::Trigger
+            | Self::ExecutorDataModel
+            | Self::Executor => None,
+        }
+    }
+}
+
+/// Identification of a [`StateEntry`]. Leaves are ordered by the keys of their entries.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum EntryKey {
+    Parameters,
+    Peer(PeerId),
+    Domain(DomainId),
+    Account(AccountId),
+    AssetDefinition(AssetDefinitionId),
+    Asset(AssetId),
+    AssetAllowance(AssetAllowanceId),
+    Nft(NftId),
+    Swap(SwapId),
+    Role(RoleId),
+    AccountPermissions(AccountId),
+    AccountRole(AccountId, RoleId),
+    Trigger(TriggerId),
+    ExecutorDataModel,
+    AliasedAccount(AccountAlias),
+    LockedAsset(AssetId),
+    LockedNft(NftId),
+    SwapExpiration(u64, SwapId),
+    Executor,
+}
+
+impl EntryKey {
+    fn kind(&self) -> EntryKind {
+        match self {
+            Self::Parameters => EntryKind::Parameters,
+            Self::Peer(_) => EntryKind::Peer,
+            Self::Domain(_) => EntryKind::Domain,
+            Self::Account(_) => EntryKind::Account,
+            Self::AssetDefinition(_) => EntryKind::AssetDefinition,
+            Self::Asset(_) => EntryKind::Asset,
+            Self::AssetAllowance(_) => EntryKind::AssetAllowance,
+            Self::Nft(_) => EntryKind::Nft,
+            Self::Swap(_) => EntryKind::Swap,
+            Self::Role(_) => EntryKind::Role,
+            Self::AccountPermissions(_) => EntryKind::AccountPermissions,
+            Self::AccountRole(..) => EntryKind::AccountRole,
+            Self::Trigger(_) => EntryKind::Trigger,
+            Self::ExecutorDataModel => EntryKind::ExecutorDataModel,
+            Self::AliasedAccount(_) => EntryKind::AliasedAccount,
+            Self::LockedAsset(_) => EntryKind::LockedAsset,
+            Self::LockedNft(_) => EntryKind::LockedNft,
+            Self::SwapExpiration(..) => EntryKind::SwapExpiration,
+            Self::Executor => EntryKind::Executor,
+        }
+    }
+
+    fn of(entry: &StateEntry) -> Self {
+        match entry {
+            StateEntry::Parameters(_) => Self::Parameters,
+            StateEntry::Peer(peer) => Self::Peer(peer.clone()),
+            StateEntry::Domain(domain) => Self::Domain(domain.id().clone()),
+            StateEntry::Account(account) => Self::Account(account.id().clone()),
+            StateEntry::AssetDefinition(definition) => {
+                Self::AssetDefinition(definition.id().clone())
+            }
+            StateEntry::Asset(asset) => Self::Asset(asset.id().clone()),
+            StateEntry::AssetAllowance(allowance) => Self::AssetAllowance(allowance.id.clone()),
+            StateEntry::Nft(nft) => Self::Nft(nft.id().clone()),
+            StateEntry::Swap(swap) => Self::Swap(swap.id.clone()),
+            StateEntry::Role(role) => Self::Role(role.id().clone()),
+            StateEntry::AccountPermissions(permissions) => {
+                Self::AccountPermissions(permissions.account.clone())
+            }
+            StateEntry::AccountRole(role) => {
+                Self::AccountRole(role.account.clone(), role.role.clone())
+            }
+            StateEntry::Trigger(trigger) => Self::Trigger(trigger.id().clone()),
+            StateEntry::ExecutorDataModel(_) => Self::ExecutorDataModel,
+            StateEntry::AliasedAccount(aliased) => Self::AliasedAccount(aliased.alias.clone()),
+            StateEntry::LockedAsset(locked) => Self::LockedAsset(locked.asset.clone()),
+            StateEntry::LockedNft(locked) => Self::LockedNft(locked.nft.clone()),
+            StateEntry::SwapExpiration(expiration) => {
+                Self::SwapExpiration(expiration.expires_at_ms, expiration.swap.clone())
+            }
+            StateEntry::Executor(_) => Self::Executor,
+        }
+    }
+}
+
+/// Hash of the trigger entry with the given `id`, if it is registered
+fn trigger_hash(triggers: &impl SetReadOnly, id: &TriggerId) -> Option<HashOf<StateEntry>> {
+    let action = triggers.inspect_by_id(id, |action| action.clone_and_box())?;
+    let action = triggers.get_original_action(action).into();
+
+    Some(StateEntryRef::Trigger(Box::new(Trigger::new(id.clone(), action))).hash())
+}
+
+/// Hash of the entry with the given `key` in the `world` state, if it is there
+fn leaf_hash(world: &impl WorldReadOnly, key: &EntryKey) -> Option<HashOf<StateEntry>> {
+    let entry = match key {
+        EntryKey::Parameters => StateEntryRef::Parameters(world.parameters()),
+        EntryKey::Peer(id) => StateEntryRef::Peer(world.peers().iter().find(|peer| *peer == id)?),
+        EntryKey::Domain(id) => StateEntryRef::Domain(world.domains().get(id)?),
+        EntryKey::Account(id) => StateEntryRef::Account(world.accounts().get(id)?),
+        EntryKey::AssetDefinition(id) => {
+            StateEntryRef::AssetDefinition(world.asset_definitions().get(id)?)
+        }
+        EntryKey::Asset(id) => StateEntryRef::Asset(world.assets().get(id)?),
+        EntryKey::AssetAllowance(id) => {
+            StateEntryRef::AssetAllowance(id, world.asset_allowances().get(id)?)
+        }
+        EntryKey::Nft(id) => StateEntryRef::Nft(world.nfts().get(id)?),
+        EntryKey::Swap(id) => StateEntryRef::Swap(world.swaps().get(id)?),
+        EntryKey::Role(id) => StateEntryRef::Role(world.roles().get(id)?),
+        EntryKey::AccountPermissions(id) => {
+            StateEntryRef::AccountPermissions(id, world.account_permissions().get(id)?)
+        }
+        EntryKey::AccountRole(account, role) => {
+            world
+                .account_roles()
+                .get(&RoleIdWithOwner::new(account.clone(), role.clone()))?;
+            StateEntryRef::AccountRole(account, role)
+        }
+        EntryKey::Trigger(id) => return trigger_hash(world.triggers(), id),
+        EntryKey::ExecutorDataModel => {
+            StateEntryRef::ExecutorDataModel(world.executor_data_model())
+        }
+        EntryKey::AliasedAccount(alias) => {
+            StateEntryRef::AliasedAccount(alias, world.account_aliases().get(alias)?)
+        }
+        EntryKey::LockedAsset(id) => StateEntryRef::LockedAsset(id, world.locked_assets().get(id)?),
+        EntryKey::LockedNft(id) => StateEntryRef::LockedNft(id, world.locked_nfts().get(id)?),
+        EntryKey::SwapExpiration(expires_at_ms, id) => {
+            world
+                .swap_expirations()
+                .get(&(*expires_at_ms, id.clone()))?;
+            StateEntryRef::SwapExpiration(expires_at_ms, id)
+        }
+        EntryKey::Executor => StateEntryRef::Executor(world.executor().raw()),
+    };
+
+    Some(entry.hash())
+}
+
+/// Leaves of all the entries of the given `kind` in the `world` state, ordered by their keys
+fn kind_leaves(world: &impl WorldReadOnly, kind: EntryKind) -> Vec<(EntryKey, HashOf<StateEntry>)> {
+    let mut leaves: Vec<_> = match kind {
+        EntryKind::Parameters => vec![(
+            EntryKey::Parameters,
+            StateEntryRef::Parameters(world.parameters()).hash(),
+        )],
+        EntryKind::Peer => world
+            .peers()
+            .iter()
+            .map(|id| (EntryKey::Peer(id.clone()), StateEntryRef::Peer(id).hash()))
+            .collect(),
+        EntryKind::Domain => world
+            .domains()
+            .iter()
+            .map(|(id, v)| {
+                (
+                    EntryKey::Domain(id.clone()),
+                    StateEntryRef::Domain(v).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::Account => world
+            .accounts()
+            .iter()
+            .map(|(id, v)| {
+                (
+                    EntryKey::Account(id.clone()),
+                    StateEntryRef::Account(v).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::AssetDefinition => world
+            .asset_definitions()
+            .iter()
+            .map(|(id, v)| {
+                (
+                    EntryKey::AssetDefinition(id.clone()),
+                    StateEntryRef::AssetDefinition(v).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::Asset => world
+            .assets()
+            .iter()
+            .map(|(id, v)| (EntryKey::Asset(id.clone()), StateEntryRef::Asset(v).hash()))
+            .collect(),
+        EntryKind::AssetAllowance => world
+            .asset_allowances()
+            .iter()
+            .map(|(id, v)| {
+                (
+                    EntryKey::AssetAllowance(id.clone()),
+                    StateEntryRef::AssetAllowance(id, v).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::Nft => world
+            .nfts()
+            .iter()
+            .map(|(id, v)| (EntryKey::Nft(id.clone()), StateEntryRef::Nft(v).hash()))
+            .collect(),
+        EntryKind::Swap => world
+            .swaps()
+            .iter()
+            .map(|(id, v)| (EntryKey::Swap(id.clone()), StateEntryRef::Swap(v).hash()))
+            .collect(),
+        EntryKind::Role => world
+            .roles()
+            .iter()
+            .map(|(id, v)| (EntryKey::Role(id.clone()), StateEntryRef::Role(v).hash()))
+            .collect(),
+        EntryKind::AccountPermissions => world
+            .account_permissions()
+            .iter()
+            .map(|(id, v)| {
+                (
+                    EntryKey::AccountPermissions(id.clone()),
+                    StateEntryRef::AccountPermissions(id, v).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::AccountRole => world
+            .account_roles()
+            .iter()
+            .map(|(k, ())| {
+                (
+                    EntryKey::AccountRole(k.account.clone(), k.id.clone()),
+                    StateEntryRef::AccountRole(&k.account, &k.id).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::Trigger => {
+            let triggers = world.triggers();
+            triggers
+                .ids_iter()
+                .map(|id| {
+                    let hash = trigger_hash(triggers, id).expect(
+                        "INTERNAL BUG: Trigger Id is in the list of ids but not in the triggers map",
+                    );
+                    (EntryKey::Trigger(id.clone()), hash)
+                })
+                .collect()
+        }
+        EntryKind::ExecutorDataModel => vec![(
+            EntryKey::ExecutorDataModel,
+            StateEntryRef::ExecutorDataModel(world.executor_data_model()).hash(),
+        )],
+        EntryKind::AliasedAccount => world
+            .account_aliases()
+            .iter()
+            .map(|(alias, id)| {
+                (
+                    EntryKey::AliasedAccount(alias.clone()),
+                    StateEntryRef::AliasedAccount(alias, id).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::LockedAsset => world
+            .locked_assets()
+            .iter()
+            .map(|(id, quantity)| {
+                (
+                    EntryKey::LockedAsset(id.clone()),
+                    StateEntryRef::LockedAsset(id, quantity).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::LockedNft => world
+            .locked_nfts()
+            .iter()
+            .map(|(id, swap)| {
+                (
+                    EntryKey::LockedNft(id.clone()),
+                    StateEntryRef::LockedNft(id, swap).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::SwapExpiration => world
+            .swap_expirations()
+            .iter()
+            .map(|((expires_at_ms, id), ())| {
+                (
+                    EntryKey::SwapExpiration(*expires_at_ms, id.clone()),
+                    StateEntryRef::SwapExpiration(expires_at_ms, id).hash(),
+                )
+            })
+            .collect(),
+        EntryKind::Executor => vec![(
+            EntryKey::Executor,
+            StateEntryRef::Executor(world.executor().raw()).hash(),
+        )],
+    };
+    leaves.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    leaves
+}
+
+/// Entry of a [`World`](super::World) storage along with the hash of its leaf in the [`StateTree`]
+#[derive(Debug, Clone)]
+pub struct StorageEntry {
+    key: EntryKey,
+    hash: HashOf<StateEntry>,
+}
+
+impl StorageEntry {
+    /// Hash of the entry, which changes whenever the entry does
+    pub fn hash(&self) -> HashOf<StateEntry> {
+        self.hash
+    }
+
+    /// Serialize the entry as a `(key, value)` pair of its storage in the `world` state
+    ///
+    /// # Errors
+    ///
+    /// Fails if the entry is not in the `world` state or can't be serialized
+    pub fn serialize<S: Serializer>(
+        &self,
+        world: &impl WorldReadOnly,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        fn pair<K: Serialize, V: Serialize, S: Serializer>(
+            key: &K,
+            value: Option<&V>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let value = value.ok_or_else(|| S::Error::custom("entry is missing from the state"))?;
+            (key, value).serialize(serializer)
+        }
+
+        match &self.key {
+            EntryKey::Domain(id) => pair(id, world.domains().get(id), serializer),
+            EntryKey::Account(id) => pair(id, world.accounts().get(id), serializer),
+            EntryKey::AssetDefinition(id) => {
+                pair(id, world.asset_definitions().get(id), serializer)
+            }
+            EntryKey::Asset(id) => pair(id, world.assets().get(id), serializer),
+            EntryKey::AssetAllowance(id) => pair(id, world.asset_allowances().get(id), serializer),
+            EntryKey::Nft(id) => pair(id, world.nfts().get(id), serializer),
+            EntryKey::Swap(id) => pair(id, world.swaps().get(id), serializer),
+            EntryKey::Role(id) => pair(id, world.roles().get(id), serializer),
+            EntryKey::AccountPermissions(id) => {
+                pair(id, world.account_permissions().get(id), serializer)
+            }
+            EntryKey::AccountRole(account, role) => {
+                let id = RoleIdWithOwner::new(account.clone(), role.clone());
+                pair(&id, world.account_roles().get(&id), serializer)
+            }
+            EntryKey::AliasedAccount(alias) => {
+                pair(alias, world.account_aliases().get(alias), serializer)
+            }
+            EntryKey::LockedAsset(id) => pair(id, world.locked_assets().get(id), serializer),
+            EntryKey::LockedNft(id) => pair(id, world.locked_nfts().get(id), serializer),
+            EntryKey::SwapExpiration(expires_at_ms, id) => {
+                let key = (*expires_at_ms, id.clone());
+                pair(&key, world.swap_expirations().get(&key), serializer)
+            }
+            EntryKey::Parameters
+            | EntryKey::Peer(_)
+            | EntryKey::Trigger(_)
+            | EntryKey::ExecutorDataModel
+            | EntryKey::Executor => Err(S::Error::custom("entry is not in a storage")),
+        }
+    }
+}
+
+/// Entries changed by a block, collected from the keys written to the [`World`](super::World) storages
+#[derive(Default)]
+struct Changes {
+    /// Entries that were created, updated or removed
+    keys: BTreeSet<EntryKey>,
+    /// Kinds of entries that could have changed in bulk
+    kinds: BTreeSet<EntryKind>,
+}
+
+impl Changes {
+    fn new(world: &WorldBlock) -> Self {
+        fn written<K: Clone>(keys: &BTreeSet<K>, key: fn(K) -> EntryKey) -> Vec<EntryKey> {
+            keys.iter().cloned().map(key).collect()
+        }
+
+        let mut changes = Self::default();
+        if world.parameters.written() {
+            changes.keys.insert(EntryKey::Parameters);
+        }
+        // peers are kept as a whole, so the removed ones aren't known
+        if world.peers.written() {
+            changes.kinds.insert(EntryKind::Peer);
+        }
+        if world.executor_data_model.written() {
+            changes.keys.insert(EntryKey::ExecutorDataModel);
+        }
+        if world.executor.written() {
+            changes.keys.insert(EntryKey::Executor);
+        }
+        changes.keys.extend(
+            [
+                written(world.domains.written(), EntryKey::Domain),
+                written(world.accounts.written(), EntryKey::Account),
+                written(world.asset_definitions.written(), EntryKey::AssetDefinition),
+                written(world.assets.written(), EntryKey::Asset),
+                written(world.asset_allowances.written(), EntryKey::AssetAllowance),
+                written(world.nfts.written(), EntryKey::Nft),
+                written(world.swaps.written(), EntryKey::Swap),
+                written(world.roles.written(), EntryKey::Role),
+                written(
+                    world.account_permissions.written(),
+                    EntryKey::AccountPermissions,
+                ),
+                written(world.account_aliases.written(), EntryKey::AliasedAccount),
+                written(world.locked_assets.written(), EntryKey::LockedAsset),
+                written(world.locked_nfts.written(), EntryKey::LockedNft),
+                written(world.triggers.written(), EntryKey::Trigger),
+            ]
+            .into_iter()
+            .flatten(),
+        );
+        changes.keys.extend(
+            world
+                .account_roles
+                .written()
+                .iter()
+                .map(|role| EntryKey::AccountRole(role.account.clone(), role.id.clone())),
+        );
+        changes.keys.extend(
+            world
+                .swap_expirations
+                .written()
+                .iter()
+                .map(|(expires_at_ms, id)| EntryKey::SwapExpiration(*expires_at_ms, id.clone())),
+        );
+        changes
+    }
+}
+
+/// Merkle tree of the world state along with its leaves in their canonical order.
+///
+/// The tree is kept in sync with the world state using the keys written to its storages by the applied blocks,
+/// so that only the changed entries have to be rehashed.
+#[derive(Debug)]
+pub struct StateTree {
+    leaves: Vec<(EntryKey, HashOf<StateEntry>)>,
+    tree: MerkleTree<StateEntry>,
+}
+
+impl StateTree {
+    /// Build the tree of all the entries of the `world` state
+    pub fn new(world: &impl WorldReadOnly) -> Self {
+        let leaves = EntryKind::ALL
+            .into_iter()
+            .flat_map(|kind| kind_leaves(world, kind))
+            .collect::<Vec<_>>();
+        let tree = leaves.iter().map(|(_, hash)| *hash).collect();
+
+        Self { leaves, tree }
+    }
+
+    /// Root hash of the tree
+    pub fn root(&self) -> StateRoot {
+        self.tree
+            .hash()
+            .expect("INTERNAL BUG: World state always contains parameters")
+    }
+
+    /// Bring the tree in sync with the state of the `world` block by rehashing the entries written by it
+    pub fn update(&mut self, world: &WorldBlock) {
+        let Changes { keys, kinds } = Changes::new(world);
+        let mut updated = Vec::new();
+        let mut restructured = false;
+
+        for kind in kinds.iter().copied() {
+            let start = self.leaves.partition_point(|(key, _)| key.kind() < kind);
+            let end = self.leaves.partition_point(|(key, _)| key.kind() <= kind);
+            let leaves = kind_leaves(world, kind);
+
+            if leaves.len() == end - start
+                && leaves
+                    .iter()
+                    .zip(&self.leaves[start..end])
+                    .all(|((key, _), (old_key, _))| key == old_key)
+            {
+                for (idx, (_, hash)) in (start..).zip(leaves) {
+                    if self.leaves[idx].1 != hash {
+                        self.leaves[idx].1 = hash;
+                        updated.push(idx);
+                    }
+                }
+            } else {
+                self.leaves.splice(start..end, leaves);
+                restructured = true;
+            }
+        }
+
+        for key in keys.into_iter().filter(|key| !kinds.contains(&key.kind())) {
+            let hash = leaf_hash(world, &key);
+            match (self.leaves.binary_search_by(|(k, _)| k.cmp(&key)), hash) {
+                (Ok(idx), Some(hash)) => {
+                    if self.leaves[idx].1 != hash {
+                        self.leaves[idx].1 = hash;
+                        updated.push(idx);
+                    }
+                }
+                (Ok(idx), None) => {
+                    self.leaves.remove(idx);
+                    restructured = true;
+                }
+                (Err(idx), Some(hash)) => {
+                    self.leaves.insert(idx, (key, hash));
+                    restructured = true;
+                }
+                (Err(_), None) => {}
+            }
+        }
+
+        if restructured {
+            // positions of the leaves have shifted
+            self.tree = self.leaves.iter().map(|(_, hash)| *hash).collect();
+        } else {
+            for idx in updated {
+                self.tree
+                    .update_leaf(idx, self.leaves[idx].1)
+                    .expect("INTERNAL BUG: Every leaf is in the tree");
+            }
+        }
+    }
+
+    /// Entries of every [`World`](super::World) storage by the storage names, ordered by their keys
+    pub fn storage_entries(&self) -> Vec<(&'static str, Vec<StorageEntry>)> {
+        EntryKind::ALL
+            .into_iter()
+            .filter_map(|kind| {
+                let storage = kind.storage()?;
+                let start = self.leaves.partition_point(|(key, _)| key.kind() < kind);
+                let end = self.leaves.partition_point(|(key, _)| key.kind() <= kind);
+                let entries = self.leaves[start..end]
+                    .iter()
+                    .map(|(key, hash)| StorageEntry {
+                        key: key.clone(),
+                        hash: *hash,
+                    })
+                    .collect();
+
+                Some((storage, entries))
+            })
+            .collect()
+    }
+
+    /// Prove that `entry` is a part of the world state the tree was built for. Returns `None` if it is not.
+    pub fn proof(&self, height: NonZeroU64, entry: StateEntry) -> Option<StateProof> {
+        let idx = self
+            .leaves
+            .binary_search_by(|(key, _)| key.cmp(&EntryKey::of(&entry)))
+            .ok()?;
+        if self.leaves[idx].1 != HashOf::new(&entry) {
+            return None;
+        }
+        let proof = self.tree.proof(idx)?;
+
+        Some(StateProof {
+            height,
+            entry,
+            proof,
+        })
+    }
+}
+
+/// [`StateTree`] of the latest committed block, shared by all the versions of the [`State`](super::State)
+#[derive(Debug, Default)]
+pub struct StateTreeCache(Mutex<Option<(HashOf<BlockHeader>, StateTree)>>);
+
+impl StateTreeCache {
+    /// Take the cached tree if it was built for the world state resulting from the block `block_hash`
+    pub fn take(&self, block_hash: Option<HashOf<BlockHeader>>) -> Option<StateTree> {
+        let mut cached = self.0.lock();
+        if cached.as_ref().map(|(hash, _)| *hash) != block_hash {
+            return None;
+        }
+        cached.take().map(|(_, tree)| tree)
+    }
+
+    /// Entries of the [`World`](super::World) storages of the cached tree, see [`StateTree::storage_entries`],
+    /// if it was built for the world state resulting from the block `block_hash`
+    pub fn storage_entries(
+        &self,
+        block_hash: Option<HashOf<BlockHeader>>,
+    ) -> Option<Vec<(&'static str, Vec<StorageEntry>)>> {
+        let cached = self.0.lock();
+        cached
+            .as_ref()
+            .filter(|(hash, _)| Some(*hash) == block_hash)
+            .map(|(_, tree)| tree.storage_entries())
+    }
+
+    /// Cache the `tree` of the world state resulting from the block `block_hash`
+    pub fn put(&self, block_hash: HashOf<BlockHeader>, tree: StateTree) {
+        *self.0.lock() = Some((block_hash, tree));
+    }
+}
+
+/// Compute the root hash of the `world` state
+pub fn state_root(world: &impl WorldReadOnly) -> StateRoot {
+    StateTree::new(world).root()
+}
+
+/// Prove that `entry` is a part of the latest world state. Returns `None` if it is not.
+pub fn state_proof(state: &impl StateReadOnly, entry: StateEntry) -> Option<StateProof> {
+    let height = NonZeroU64::new(state.height() as u64)?;
+
+    {
+        let cached = state.state_tree().0.lock();
+        if let Some((_, tree)) = cached
+            .as_ref()
+            .filter(|(hash, _)| Some(*hash) == state.latest_block_hash())
+        {
+            return tree.proof(height, entry);
+        }
+    }
+
+    // the cached tree is being updated by the next block or was built for another version of the state
+    StateTree::new(state.world()).proof(height, entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::{
+        asset::AssetAllowance, parameter::BlockParameter, prelude::*, state::AccountRole,
+    };
+    use iroha_test_samples::gen_account_in;
+
+    use super::*;
+    use crate::{
+        block::ValidBlock,
+        kura::Kura,
+        query::store::LiveQueryStore,
+        smartcontracts::Execute,
+        state::{State, World},
+    };
+
+    #[test]
+    fn borrowed_entry_is_encoded_as_owned() {
+        let (account_id, _) = gen_account_in("wonderland");
+        let account = Account::new(account_id.clone()).into_account();
+        let role_id: RoleId = "role".parse().unwrap();
+        let allowance = AssetAllowance::new(
+            AssetAllowanceId::new(
+                format!("rose##{account_id}").parse().unwrap(),
+                account_id.clone(),
+            ),
+            Numeric::new(13, 1),
+        );
+
+        assert_eq!(
+            StateEntryRef::Account(&account).hash(),
+            HashOf::new(&StateEntry::Account(account.clone()))
+        );
+        assert_eq!(
+            StateEntryRef::AssetAllowance(&allowance.id, &allowance.amount).hash(),
+            HashOf::new(&StateEntry::AssetAllowance(allowance.clone()))
+        );
+        assert_eq!(
+            StateEntryRef::AccountRole(&account_id, &role_id).hash(),
+            HashOf::new(&StateEntry::AccountRole(AccountRole {
+                account: account_id,
+                role: role_id,
+            }))
+        );
+    }
+
+    #[tokio::test]
+    #[allow(clippy::too_many_lines)]
+    async fn updated_tree_matches_rebuilt_one() {
+        let (alice_id, _) = gen_account_in("wonderland");
+        let (bob_id, _) = gen_account_in("wonderland");
+        let rose: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let tulip: AssetDefinitionId = "tulip#wonderland".parse().unwrap();
+        let alice_rose = AssetId::new(rose.clone(), alice_id.clone());
+        let bob_tulip = AssetId::new(tulip.clone(), bob_id.clone());
+        let rabbit: NftId = "rabbit$wonderland".parse().unwrap();
+        let alias: AccountAlias = "alice@wonderland".parse().unwrap();
+        let role: RoleId = "gardener".parse().unwrap();
+        let permission = Permission::new("CanWaterFlowers".into(), Json::from(true));
+        let trigger: TriggerId = "watering".parse().unwrap();
+        let key: Name = "key".parse().unwrap();
+        let executor = {
+            let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../../defaults/executor.wasm");
+            Executor::new(WasmSmartContract::from_compiled(
+                std::fs::read(path).unwrap(),
+            ))
+        };
+        let state = State::new(
+            World::default(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        let header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(header);
+        let mut tree = StateTree::new(&state_block.world);
+
+        let alice = &alice_id;
+        let bob = &bob_id;
+        let instructions: Vec<(&AccountId, InstructionBox)> = vec![
+            (
+                alice,
+                Register::domain(Domain::new("wonderland".parse().unwrap())).into(),
+            ),
+            (
+                alice,
+                Register::account(Account::new(alice_id.clone())).into(),
+            ),
+            (
+                alice,
+                Register::account(Account::new(bob_id.clone())).into(),
+            ),
+            (alice, Upgrade::new(executor).into()),
+            (
+                alice,
+                Register::peer(PeerId::new(KeyPair::random().into_parts().0)).into(),
+            ),
+            (
+                alice,
+                SetParameter::new(Parameter::Block(BlockParameter::MaxTransactions(
+                    NonZeroU64::new(7).unwrap(),
+                )))
+                .into(),
+            ),
+            (
+                alice,
+                Register::asset_definition(AssetDefinition::numeric(rose.clone())).into(),
+            ),
+            (
+                alice,
+                Register::asset_definition(AssetDefinition::numeric(tulip.clone())).into(),
+            ),
+            (
+                alice,
+                Mint::asset_numeric(10_u32, alice_rose.clone()).into(),
+            ),
+            (alice, Mint::asset_numeric(5_u32, bob_tulip.clone()).into()),
+            (alice, Burn::asset_numeric(1_u32, alice_rose.clone()).into()),
+            (
+                alice,
+                Transfer::asset_numeric(alice_rose.clone(), 2_u32, bob_id.clone()).into(),
+            ),
+            (
+                alice,
+                Register::nft(Nft::new(rabbit.clone(), Metadata::default())).into(),
+            ),
+            (
+                alice,
+                Transfer::nft(alice_id.clone(), rabbit.clone(), bob_id.clone()).into(),
+            ),
+            (
+                alice,
+                SetKeyValue::account(bob_id.clone(), key.clone(), "value").into(),
+            ),
+            (alice, RemoveKeyValue::account(bob_id.clone(), key).into()),
+            (
+                alice,
+                AddSignatory::new(bob_id.clone(), KeyPair::random().into_parts().0).into(),
+            ),
+            (
+                alice,
+                RegisterAlias::new(alice_id.clone(), alias.clone()).into(),
+            ),
+            (
+                alice,
+                Approve::new(alice_rose.clone(), bob_id.clone(), 3_u32).into(),
+            ),
+            (
+                bob,
+                TransferFrom::new(alice_rose.clone(), 1_u32, bob_id.clone()).into(),
+            ),
+            (alice, Freeze::asset(alice_rose.clone()).into()),
+            (alice, Unfreeze::asset(alice_rose.clone()).into()),
+            (
+                alice,
+                CreateSwap::new(
+                    "rose_for_tulip".parse().unwrap(),
+                    SwapItem::Asset(SwapAsset::new(rose.clone(), 2_u32.into())),
+                    SwapItem::Asset(SwapAsset::new(tulip.clone(), 1_u32.into())),
+                )
+                .with_expiry_ms(u64::MAX)
+                .into(),
+            ),
+            (
+                bob,
+                AcceptSwap::new("rose_for_tulip".parse().unwrap()).into(),
+            ),
+            (
+                bob,
+                CreateSwap::new(
+                    "rabbit_for_rose".parse().unwrap(),
+                    SwapItem::Nft(rabbit),
+                    SwapItem::Asset(SwapAsset::new(rose, 1_u32.into())),
+                )
+                .into(),
+            ),
+            (
+                bob,
+                CancelSwap::new("rabbit_for_rose".parse().unwrap()).into(),
+            ),
+            (
+                alice,
+                Register::role(
+                    Role::new(role.clone(), alice_id.clone()).add_permission(permission.clone()),
+                )
+                .into(),
+            ),
+            (
+                alice,
+                Grant::account_role(role.clone(), bob_id.clone()).into(),
+            ),
+            (
+                alice,
+                Revoke::account_role(role.clone(), bob_id.clone()).into(),
+            ),
+            (
+                alice,
+                Grant::account_permission(permission.clone(), bob_id.clone()).into(),
+            ),
+            (
+                alice,
+                Revoke::account_permission(permission, bob_id.clone()).into(),
+            ),
+            (
+                alice,
+                Register::trigger(Trigger::new(
+                    trigger.clone(),
+                    Action::new(
+                        Vec::<InstructionBox>::new(),
+                        Repeats::Exactly(3),
+                        alice_id.clone(),
+                        ExecuteTriggerEventFilter::new()
+                            .for_trigger(trigger.clone())
+                            .under_authority(alice_id.clone()),
+                    ),
+                ))
+                .into(),
+            ),
+            (alice, Mint::trigger_repetitions(2, trigger.clone()).into()),
+            (alice, Burn::trigger_repetitions(1, trigger.clone()).into()),
+            (alice, Unregister::trigger(trigger).into()),
+            (alice, UnregisterAlias::new(alias).into()),
+            (alice, Unregister::role(role).into()),
+            (alice, Unregister::asset_definition(tulip).into()),
+            (alice, Unregister::account(bob_id.clone()).into()),
+            (
+                alice,
+                Unregister::domain("wonderland".parse().unwrap()).into(),
+            ),
+        ];
+        for (authority, instruction) in instructions {
+            let description = format!("{instruction:?}");
+            let mut transaction = state_block.transaction();
+            instruction
+                .execute(authority, &mut transaction)
+                .unwrap_or_else(|err| panic!("{description} failed: {err}"));
+            transaction.apply();
+
+            tree.update(&state_block.world);
+            assert_eq!(
+                tree.root(),
+                StateTree::new(&state_block.world).root(),
+                "{description}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_transaction_leaves_tree_intact() {
+        let (alice_id, _) = gen_account_in("wonderland");
+        let state = State::new(
+            World::default(),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        let header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(header);
+        let mut tree = StateTree::new(&state_block.world);
+        let root = tree.root();
+
+        let mut transaction = state_block.transaction();
+        Register::domain(Domain::new("wonderland".parse().unwrap()))
+            .execute(&alice_id, &mut transaction)
+            .unwrap();
+        drop(transaction);
+
+        assert!(state_block.world.domains.written().is_empty());
+        tree.update(&state_block.world);
+        assert_eq!(tree.root(), root);
+    }
+}
@@ -104,6 +104,48 @@ mod view {
         pub(super) blocks: &'storage DashMap<Key, Value>,
     }
 
+    impl TransactionsView<'_> {
+        /// Number of transactions in the storage
+        pub fn len(&self) -> usize {
+            self.latest_block.as_ref().map_or(0, |block| {
+                let block_height = block.height;
+                block.transactions.len()
+                    + self
+                        .blocks
+                        .iter()
+                        .filter(|entry| {
+                            *entry.value() < block_height
+                                && !block.transactions.contains(entry.key())
+                        })
+                        .count()
+            })
+        }
+
+        /// Whether there are no transactions in the storage
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Copy the viewed transactions into a new [`TransactionsStorage`]
+        pub fn to_storage(&self) -> TransactionsStorage {
+            let Some(block) = &self.latest_block else {
+                return TransactionsStorage::new();
+            };
+            let blocks = self
+                .blocks
+                .iter()
+                .filter(|entry| *entry.value() < block.height)
+                .map(|entry| (*entry.key(), *entry.value()))
+                .collect();
+
+            TransactionsStorage {
+                latest_block: ArcSwapOption::new(Some(Arc::clone(block))),
+                blocks,
+                write_lock: Mutex::new(()),
+            }
+        }
+    }
+
     impl TransactionsReadOnly for TransactionsView<'_> {
         fn get<Q>(&self, key: &Q) -> Option<Value>
         where
@@ -399,6 +441,39 @@ mod tests {
         assert_eq!(view2.get(&k0), Some(v1));
     }
 
+    #[test]
+    fn len() {
+        let [k0, k1, k2] = get_keys();
+        let [v1, v2] = get_values();
+
+        let storage = TransactionsStorage::new();
+        assert!(storage.view().is_empty());
+
+        {
+            let mut block = storage.block();
+            insert_keys(&mut block, &[k0, k1], v1);
+            block.commit()
+        }
+        let view1 = storage.view();
+
+        {
+            let mut block = storage.block();
+            insert_keys(&mut block, &[k2], v2);
+            block.commit()
+        }
+        let view2 = storage.view();
+
+        {
+            let block = storage.block_and_revert();
+            block.commit();
+        }
+        let view3 = storage.view();
+
+        assert_eq!(view1.len(), 2);
+        assert_eq!(view2.len(), 3);
+        assert_eq!(view3.len(), 2);
+    }
+
     #[test]
     fn serialization() {
         fn assert_views_equal(view1: &TransactionsView, view2: &TransactionsView, keys: &[Key]) {
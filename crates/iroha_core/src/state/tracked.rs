@@ -0,0 +1,226 @@
+//! Blocks and transactions of the [`World`](super::World) storages which keep track of the entries written to them,
+//! so that the [`StateTree`](super::root::StateTree) rehashes only the entries changed by a block.
+//!
+//! Entries are read through [`Deref`], while every write goes through the methods recording its key.
+
+use core::ops::{Deref, DerefMut};
+use std::collections::BTreeSet;
+
+use mv::{
+    cell::{Block as CellBlock, Transaction as CellTransaction},
+    storage::{Block as StorageBlock, Transaction as StorageTransaction},
+};
+
+/// [`StorageBlock`] along with the keys written to it by the applied transactions and the block itself
+pub struct TrackedStorageBlock<'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    storage: StorageBlock<'world, K, V>,
+    written: BTreeSet<K>,
+}
+
+/// [`StorageTransaction`] along with the keys written to it, which are passed to the block once it's applied
+pub struct TrackedStorageTransaction<'block, 'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    storage: StorageTransaction<'block, 'world, K, V>,
+    written: BTreeSet<K>,
+    block_written: &'block mut BTreeSet<K>,
+}
+
+impl<'world, K, V> TrackedStorageBlock<'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    /// Keys written since the block was created, including the removed ones
+    pub fn written(&self) -> &BTreeSet<K> {
+        &self.written
+    }
+
+    /// Create transaction to apply changes to the block
+    pub fn transaction(&mut self) -> TrackedStorageTransaction<'_, 'world, K, V> {
+        TrackedStorageTransaction {
+            storage: self.storage.transaction(),
+            written: BTreeSet::new(),
+            block_written: &mut self.written,
+        }
+    }
+
+    /// Commit block's changes
+    pub fn commit(self) {
+        self.storage.commit();
+    }
+
+    /// Insert `value` under `key`, returning the previous value
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.written.insert(key.clone());
+        self.storage.insert(key, value)
+    }
+
+    /// Remove the value under `key`, returning it
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        self.written.insert(key.clone());
+        self.storage.remove(key)
+    }
+
+    /// Get mutable access to the value under `key`
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.written.insert(key.clone());
+        self.storage.get_mut(key)
+    }
+}
+
+impl<'world, K, V> From<StorageBlock<'world, K, V>> for TrackedStorageBlock<'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    fn from(storage: StorageBlock<'world, K, V>) -> Self {
+        Self {
+            storage,
+            written: BTreeSet::new(),
+        }
+    }
+}
+
+impl<'world, K, V> Deref for TrackedStorageBlock<'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    type Target = StorageBlock<'world, K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.storage
+    }
+}
+
+impl<'block, 'world, K, V> TrackedStorageTransaction<'block, 'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    /// Apply transaction's changes to the block. Writes of a dropped transaction are discarded.
+    pub fn apply(self) {
+        self.storage.apply();
+        let mut written = self.written;
+        self.block_written.append(&mut written);
+    }
+
+    /// Insert `value` under `key`, returning the previous value
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.written.insert(key.clone());
+        self.storage.insert(key, value)
+    }
+
+    /// Remove the value under `key`, returning it
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        self.written.insert(key.clone());
+        self.storage.remove(key)
+    }
+
+    /// Get mutable access to the value under `key`
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.written.insert(key.clone());
+        self.storage.get_mut(key)
+    }
+}
+
+impl<'block, 'world, K, V> Deref for TrackedStorageTransaction<'block, 'world, K, V>
+where
+    K: Ord + Clone + core::fmt::Debug + Send + Sync + 'static,
+    V: Clone + core::fmt::Debug + Send + Sync + 'static,
+{
+    type Target = StorageTransaction<'block, 'world, K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.storage
+    }
+}
+
+/// [`CellBlock`] which remembers whether its value was accessed mutably
+pub struct TrackedCellBlock<'world, T: Clone> {
+    cell: CellBlock<'world, T>,
+    written: bool,
+}
+
+/// [`CellTransaction`] which remembers whether its value was accessed mutably,
+/// passing it to the block once it's applied
+pub struct TrackedCellTransaction<'block, 'world, T: Clone> {
+    cell: CellTransaction<'block, 'world, T>,
+    written: bool,
+    block_written: &'block mut bool,
+}
+
+impl<'world, T: Clone> TrackedCellBlock<'world, T> {
+    /// Whether the value could have changed since the block was created
+    pub fn written(&self) -> bool {
+        self.written
+    }
+
+    /// Create transaction to apply changes to the block
+    pub fn transaction(&mut self) -> TrackedCellTransaction<'_, 'world, T> {
+        TrackedCellTransaction {
+            cell: self.cell.transaction(),
+            written: false,
+            block_written: &mut self.written,
+        }
+    }
+
+    /// Commit block's changes
+    pub fn commit(self) {
+        self.cell.commit();
+    }
+}
+
+impl<'world, T: Clone> From<CellBlock<'world, T>> for TrackedCellBlock<'world, T> {
+    fn from(cell: CellBlock<'world, T>) -> Self {
+        Self {
+            cell,
+            written: false,
+        }
+    }
+}
+
+impl<'world, T: Clone> Deref for TrackedCellBlock<'world, T> {
+    type Target = CellBlock<'world, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cell
+    }
+}
+
+impl<T: Clone> DerefMut for TrackedCellBlock<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.written = true;
+        &mut self.cell
+    }
+}
+
+impl<T: Clone> TrackedCellTransaction<'_, '_, T> {
+    /// Apply transaction's changes to the block. Writes of a dropped transaction are discarded.
+    pub fn apply(self) {
+        self.cell.apply();
+        *self.block_written |= self.written;
+    }
+}
+
+impl<'block, 'world, T: Clone> Deref for TrackedCellTransaction<'block, 'world, T> {
+    type Target = CellTransaction<'block, 'world, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cell
+    }
+}
+
+impl<T: Clone> DerefMut for TrackedCellTransaction<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.written = true;
+        &mut self.cell
+    }
+}
@@ -0,0 +1,732 @@
+//! This module contains structures and messages for synchronization of the state between peers.
+//!
+//! A peer which joins the network with an empty block store can bootstrap from a recent
+//! [snapshot](crate::snapshot) of a trusted peer instead of replaying the whole chain.
+//! The snapshot is downloaded chunk by chunk and is only installed if it matches the state root
+//! committed to by the following block, signed by a quorum of peers.
+//! Blocks up to the snapshot are downloaded without being executed, and the parts of the snapshot
+//! outside of the world state are checked against them before the snapshot is installed.
+//! The remaining blocks are received through regular [block sync](crate::block_sync).
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use iroha_config::parameters::actual::{StateSync as Config, TrustedPeers};
+use iroha_crypto::{Hash, HashOf};
+use iroha_data_model::{
+    block::{BlockHeader, SignedBlock},
+    prelude::*,
+};
+use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
+use iroha_logger::prelude::*;
+use iroha_macro::*;
+use iroha_p2p::{peer::message::PeerMessage, Post, UpdatePeers, UpdateTopology};
+use parity_scale_codec::{Decode, Encode};
+use tokio::sync::mpsc;
+
+#[cfg(feature = "telemetry")]
+use crate::telemetry::StateTelemetry;
+use crate::{
+    block::{BlockValidationError, CommittedBlock, ValidBlock},
+    kura::Kura,
+    query::store::LiveQueryStoreHandle,
+    snapshot::{self, Chunk, Manifest},
+    state::{root::state_root, State, StateReadOnly},
+    sumeragi::network_topology::Topology,
+    IrohaNetwork, NetworkMessage,
+};
+
+/// How many chunks are requested from a peer at once
+const CHUNKS_IN_FLIGHT: usize = 16;
+
+/// [`StateSynchronizer`] actor handle.
+#[derive(Clone)]
+pub struct StateSynchronizerHandle {
+    message_sender: mpsc::Sender<message::Message>,
+}
+
+impl StateSynchronizerHandle {
+    /// Send [`message::Message`] to [`StateSynchronizer`] actor.
+    pub async fn message(&self, message: message::Message) {
+        self.message_sender.send(message).await.expect(
+            "StateSynchronizer must handle messages until there is at least one handle to it",
+        )
+    }
+}
+
+/// Structure responsible for sharing snapshots and historical blocks with bootstrapping peers.
+pub struct StateSynchronizer {
+    kura: Arc<Kura>,
+    peer: Peer,
+    network: IrohaNetwork,
+    store_dir: PathBuf,
+    gossip_size: NonZeroU32,
+}
+
+impl StateSynchronizer {
+    /// Construct [`Self`].
+    ///
+    /// Snapshots are shared from `store_dir`, and at most `gossip_size` blocks are shared at once.
+    pub fn new(
+        kura: Arc<Kura>,
+        peer: Peer,
+        network: IrohaNetwork,
+        store_dir: PathBuf,
+        gossip_size: NonZeroU32,
+    ) -> Self {
+        Self {
+            kura,
+            peer,
+            network,
+            store_dir,
+            gossip_size,
+        }
+    }
+
+    /// Start [`Self`] actor.
+    pub fn start(self, shutdown_signal: ShutdownSignal) -> (StateSynchronizerHandle, Child) {
+        let (message_sender, message_receiver) = mpsc::channel(1);
+        (
+            StateSynchronizerHandle { message_sender },
+            Child::new(
+                tokio::spawn(self.run(message_receiver, shutdown_signal)),
+                OnShutdown::Abort,
+            ),
+        )
+    }
+
+    /// [`Self`] task.
+    async fn run(
+        self,
+        mut message_receiver: mpsc::Receiver<message::Message>,
+        shutdown_signal: ShutdownSignal,
+    ) {
+        loop {
+            tokio::select! {
+                Some(msg) = message_receiver.recv() => {
+                    msg.handle_message(&self);
+                }
+                () = shutdown_signal.receive() => {
+                    debug!("Shutting down state sync");
+                    break;
+                },
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// The latest snapshot which is consistent with the local block store,
+    /// together with the block following it
+    fn latest_snapshot(&self) -> Option<(Manifest, Arc<SignedBlock>)> {
+        let manifests = snapshot::read_manifests(&self.store_dir)
+            .map_err(|error| warn!(%error, "Failed to read snapshot manifests"))
+            .ok()?;
+
+        manifests.into_iter().rev().find_map(|manifest| {
+            let height = usize::try_from(manifest.height)
+                .ok()
+                .and_then(NonZeroUsize::new)?;
+            if self.kura.get_block_hash(height) != manifest.latest_block_hash {
+                return None;
+            }
+            let commit_block = self.kura.get_block(height.checked_add(1)?)?;
+            Some((manifest, commit_block))
+        })
+    }
+}
+
+pub mod message {
+    //! Module containing messages for [`StateSynchronizer`](super::StateSynchronizer).
+
+    use super::*;
+
+    /// Request for the latest snapshot of a peer
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct GetSnapshot {
+        /// Peer id
+        pub peer_id: PeerId,
+    }
+
+    /// Description of the latest snapshot of a peer
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct ShareSnapshot {
+        /// Manifest of the snapshot
+        pub manifest: Manifest,
+        /// Block following the snapshot. It commits to the state root of the snapshot
+        pub commit_block: SignedBlock,
+    }
+
+    /// Request for a snapshot chunk
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct GetChunk {
+        /// Peer id
+        pub peer_id: PeerId,
+        /// Requested chunk
+        pub chunk: Chunk,
+    }
+
+    /// Content of a snapshot chunk
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct ShareChunk {
+        /// Chunk data
+        pub data: Vec<u8>,
+    }
+
+    /// Request for blocks starting at some height
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct GetBlocks {
+        /// Peer id
+        pub peer_id: PeerId,
+        /// Height of the first requested block
+        pub from: NonZeroU64,
+        /// Maximal number of requested blocks
+        pub count: NonZeroU32,
+    }
+
+    /// Consecutive blocks starting at the requested height
+    #[derive(Debug, Clone, Decode, Encode)]
+    pub struct ShareBlocks {
+        /// Blocks
+        pub blocks: Vec<SignedBlock>,
+    }
+
+    /// Message's variants that are used by peers to synchronize state.
+    #[derive(Debug, Clone, Decode, Encode, FromVariant)]
+    pub enum Message {
+        /// Request for the latest snapshot for the peer with `PeerId`.
+        GetSnapshot(GetSnapshot),
+        /// The response to `GetSnapshot`. Not sent if the peer has no snapshots.
+        ShareSnapshot(Box<ShareSnapshot>),
+        /// Request for a snapshot chunk for the peer with `PeerId`.
+        GetChunk(GetChunk),
+        /// The response to `GetChunk`. Not sent if the peer doesn't have the chunk.
+        ShareChunk(ShareChunk),
+        /// Request for historical blocks for the peer with `PeerId`.
+        GetBlocks(GetBlocks),
+        /// The response to `GetBlocks`.
+        ShareBlocks(ShareBlocks),
+    }
+
+    impl Message {
+        /// Handles the incoming message.
+        pub(super) fn handle_message(&self, state_sync: &StateSynchronizer) {
+            match self {
+                Message::GetSnapshot(GetSnapshot { peer_id }) => {
+                    let Some((manifest, commit_block)) = state_sync.latest_snapshot() else {
+                        return;
+                    };
+                    trace!(height = manifest.height, peer=%peer_id, "Sharing snapshot");
+
+                    Message::ShareSnapshot(Box::new(ShareSnapshot {
+                        manifest,
+                        commit_block: (*commit_block).clone(),
+                    }))
+                    .send_to(&state_sync.network, peer_id.clone());
+                }
+                Message::GetChunk(GetChunk { peer_id, chunk }) => {
+                    match snapshot::read_chunk(&state_sync.store_dir, chunk) {
+                        Ok(data) => Message::ShareChunk(ShareChunk { data })
+                            .send_to(&state_sync.network, peer_id.clone()),
+                        Err(error) => {
+                            debug!(%error, peer=%peer_id, "Requested snapshot chunk is unavailable");
+                        }
+                    }
+                }
+                Message::GetBlocks(GetBlocks {
+                    peer_id,
+                    from,
+                    count,
+                }) => {
+                    let count = count.min(&state_sync.gossip_size).get();
+                    let blocks = (from.get()..from.get().saturating_add(count.into()))
+                        .map_while(|height| {
+                            usize::try_from(height)
+                                .ok()
+                                .and_then(NonZeroUsize::new)
+                                .and_then(|height| state_sync.kura.get_block(height))
+                        })
+                        .map(|block| (*block).clone())
+                        .collect::<Vec<_>>();
+
+                    if !blocks.is_empty() {
+                        trace!(%from, peer=%peer_id, "Sharing historical blocks");
+
+                        Message::ShareBlocks(ShareBlocks { blocks })
+                            .send_to(&state_sync.network, peer_id.clone());
+                    }
+                }
+                Message::ShareSnapshot(_) | Message::ShareChunk(_) | Message::ShareBlocks(_) => {
+                    trace!("Ignoring state sync response outside of bootstrap");
+                }
+            }
+        }
+
+        /// Send this message over the network to the specified `peer`.
+        pub(super) fn send_to(self, network: &IrohaNetwork, peer: PeerId) {
+            let data = NetworkMessage::StateSync(Box::new(self));
+            network.post(Post {
+                data,
+                peer_id: peer,
+            });
+        }
+    }
+}
+
+type MessageReceiver = mpsc::Receiver<PeerMessage<NetworkMessage>>;
+
+/// Discard the messages which are already received, so that stale responses don't hold up the next requests
+fn drain(receiver: &mut MessageReceiver) {
+    while receiver.try_recv().is_ok() {}
+}
+
+/// Bootstrap of a peer with an empty block store from a snapshot of a trusted peer.
+pub struct Bootstrap {
+    /// State sync configuration
+    pub config: Config,
+    /// This peer
+    pub peer: Peer,
+    /// Peers the snapshot is requested from
+    pub trusted_peers: TrustedPeers,
+    /// Network handle
+    pub network: IrohaNetwork,
+    /// Block store which receives blocks up to the snapshot
+    pub kura: Arc<Kura>,
+    /// Query handle of the restored state
+    pub query_handle: LiveQueryStoreHandle,
+    /// Directory where the downloaded snapshot is stored
+    pub store_dir: PathBuf,
+    /// Telemetry of the restored state
+    #[cfg(feature = "telemetry")]
+    pub telemetry: StateTelemetry,
+}
+
+impl Bootstrap {
+    /// Download and verify the latest snapshot shared by trusted peers,
+    /// then fill [`Kura`] with the blocks preceding it.
+    ///
+    /// Returns [`None`] if no valid snapshot could be downloaded.
+    /// In that case the block store may still contain some of the downloaded blocks,
+    /// which are valid and have to be replayed.
+    pub async fn run(self) -> Option<State> {
+        let (message_sender, mut message_receiver) = mpsc::channel(CHUNKS_IN_FLIGHT);
+        self.network.subscribe_to_peers_messages(message_sender);
+
+        let peers = self.connect_trusted_peers().await;
+        if peers.is_empty() {
+            warn!("No trusted peers are online, skipping state sync");
+            return None;
+        }
+
+        for peer_id in &peers {
+            message::Message::from(message::GetSnapshot {
+                peer_id: self.peer.id.clone(),
+            })
+            .send_to(&self.network, peer_id.clone());
+        }
+        let mut offers = Vec::new();
+        while offers.len() < peers.len() {
+            let Some(offer) = self
+                .receive(&mut message_receiver, |peer_id, message| match message {
+                    message::Message::ShareSnapshot(offer) => Some((peer_id, offer)),
+                    _ => None,
+                })
+                .await
+            else {
+                break;
+            };
+            offers.push(offer);
+        }
+        offers.sort_by_key(|(_, offer)| Reverse(offer.manifest.height));
+
+        for (peer_id, offer) in offers {
+            // responses to the previous peer might still be queued
+            drain(&mut message_receiver);
+            let state = match self
+                .fetch_snapshot(&mut message_receiver, &peer_id, &offer)
+                .await
+            {
+                Ok(state) => state,
+                Err(error) => {
+                    warn!(%error, peer=%peer_id, height=offer.manifest.height, "Failed to sync state from snapshot");
+                    continue;
+                }
+            };
+            info!(peer=%peer_id, height=offer.manifest.height, "Downloaded state snapshot");
+
+            let block_hashes = state.view().block_hashes().to_vec();
+            drain(&mut message_receiver);
+            if let Err(error) = self
+                .fetch_history(&mut message_receiver, &peers, &block_hashes)
+                .await
+            {
+                error!(%error, "Failed to download blocks preceding the snapshot");
+                return None;
+            }
+            if let Err(error) = self.verify_history(&state, &offer.commit_block) {
+                error!(%error, peer=%peer_id, "Snapshot doesn't match the blocks preceding it");
+                return None;
+            }
+            if let Err(error) = snapshot::write_manifest(&self.store_dir, &offer.manifest) {
+                warn!(%error, "Failed to store the manifest of the downloaded snapshot");
+            }
+            return Some(state);
+        }
+
+        info!("Trusted peers didn't share any valid snapshot, skipping state sync");
+        None
+    }
+
+    /// Connect to trusted peers and wait until a quorum of them is online
+    async fn connect_trusted_peers(&self) -> Vec<PeerId> {
+        let trusted = self
+            .trusted_peers
+            .others
+            .iter()
+            .map(|peer| peer.id.clone())
+            .collect::<BTreeSet<_>>();
+        self.network.update_peers_addresses(UpdatePeers(
+            self.trusted_peers
+                .others
+                .iter()
+                .map(|peer| (peer.id.clone(), peer.address.clone()))
+                .collect(),
+        ));
+        self.network.update_topology(UpdateTopology(
+            trusted
+                .iter()
+                .chain(core::iter::once(&self.peer.id))
+                .cloned()
+                .collect(),
+        ));
+
+        let online_trusted = |online: &iroha_p2p::OnlinePeers| {
+            online
+                .iter()
+                .map(|peer| peer.id.clone())
+                .filter(|peer_id| trusted.contains(peer_id))
+                .collect::<Vec<_>>()
+        };
+        let quorum = Topology::new(trusted.iter().cloned()).min_votes_for_commit();
+        let mut network = self.network.clone();
+        let _ = tokio::time::timeout(self.config.timeout, async {
+            while network.online_peers(online_trusted).len() < quorum {
+                network.wait_online_peers_update(|_| ()).await;
+            }
+        })
+        .await;
+
+        network.online_peers(online_trusted)
+    }
+
+    /// Wait for a state sync message for which `f` returns [`Some`]
+    async fn receive<T: Send>(
+        &self,
+        receiver: &mut MessageReceiver,
+        mut f: impl FnMut(PeerId, message::Message) -> Option<T> + Send,
+    ) -> Option<T> {
+        tokio::time::timeout(self.config.timeout, async {
+            while let Some(PeerMessage(peer, message)) = receiver.recv().await {
+                if let NetworkMessage::StateSync(message) = message {
+                    if let Some(output) = f(peer.id, *message) {
+                        return Some(output);
+                    }
+                }
+            }
+            None
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Download the snapshot from `peer_id` and restore the state from it
+    /// if its world state is committed to by a block signed by a quorum of trusted peers.
+    ///
+    /// The rest of the snapshot is only trusted after [`Self::verify_history`].
+    async fn fetch_snapshot(
+        &self,
+        receiver: &mut MessageReceiver,
+        peer_id: &PeerId,
+        offer: &message::ShareSnapshot,
+    ) -> Result<State, Error> {
+        let message::ShareSnapshot {
+            manifest,
+            commit_block,
+        } = offer;
+        let header = commit_block.header();
+        if manifest.height == 0
+            || header.height.get() != manifest.height + 1
+            || header.prev_block_hash != manifest.latest_block_hash
+        {
+            return Err(Error::MismatchedBlock);
+        }
+
+        let mut missing = manifest
+            .chunks
+            .iter()
+            .filter(|chunk| snapshot::read_chunk(&self.store_dir, chunk).is_err())
+            .map(|chunk| (chunk.hash, *chunk))
+            .collect::<BTreeMap<Hash, Chunk>>();
+        debug!(
+            peer=%peer_id,
+            height=manifest.height,
+            chunks=manifest.chunks.len(),
+            missing=missing.len(),
+            "Downloading state snapshot"
+        );
+
+        while !missing.is_empty() {
+            let mut pending = missing
+                .values()
+                .take(CHUNKS_IN_FLIGHT)
+                .copied()
+                .collect::<Vec<_>>();
+            for chunk in &pending {
+                message::Message::from(message::GetChunk {
+                    peer_id: self.peer.id.clone(),
+                    chunk: *chunk,
+                })
+                .send_to(&self.network, peer_id.clone());
+            }
+
+            while !pending.is_empty() {
+                let data = self
+                    .receive(receiver, |sender, message| match message {
+                        message::Message::ShareChunk(message::ShareChunk { data })
+                            if sender == *peer_id =>
+                        {
+                            Some(data)
+                        }
+                        _ => None,
+                    })
+                    .await
+                    .ok_or(Error::Timeout)?;
+
+                // Chunks which weren't requested or don't match their hash are ignored
+                let chunk = Chunk::new(&data);
+                if missing.get(&chunk.hash) == Some(&chunk) {
+                    snapshot::write_chunk(&self.store_dir, &chunk, &data)?;
+                    missing.remove(&chunk.hash);
+                    pending.retain(|requested| *requested != chunk);
+                }
+            }
+        }
+
+        let (state, root) = {
+            let store_dir = self.store_dir.clone();
+            let manifest = manifest.clone();
+            let kura = Arc::clone(&self.kura);
+            let query_handle = self.query_handle.clone();
+            #[cfg(feature = "telemetry")]
+            let telemetry = self.telemetry.clone();
+
+            // the whole state is processed, which would block the runtime
+            tokio::task::spawn_blocking(move || {
+                let data = snapshot::read_snapshot_data(&store_dir, &manifest)?;
+                let state = snapshot::deserialize_state(
+                    &data,
+                    &kura,
+                    query_handle,
+                    #[cfg(feature = "telemetry")]
+                    telemetry,
+                )?;
+                let root = state_root(state.view().world());
+                Ok::<_, Error>((state, root))
+            })
+            .await
+            .expect("INTERNAL BUG: Failed to join snapshot deserialization task")?
+        };
+
+        {
+            let state_view = state.view();
+            if state_view.height() as u64 != manifest.height
+                || state_view.latest_block_hash() != manifest.latest_block_hash
+            {
+                return Err(Error::MismatchedBlock);
+            }
+            if header.prev_state_root != Some(root) {
+                return Err(Error::MismatchedStateRoot);
+            }
+
+            let mut topology = Topology::new(state_view.commit_topology().to_vec());
+            topology.nth_rotation(header.view_change_index as usize);
+            ValidBlock::verify_commit_signatures(commit_block, &topology)?;
+            // the topology comes from the snapshot itself, so the signatories have to be trusted independently
+            if !self.is_signed_by_trusted_quorum(commit_block, &topology) {
+                return Err(Error::UntrustedSignatories);
+            }
+        }
+
+        Ok(state)
+    }
+
+    /// Check the parts of the restored `state` which aren't covered by the state root
+    /// against the blocks preceding the snapshot, which are already stored in [`Kura`],
+    /// and `commit_block` following it.
+    ///
+    /// Block hashes are checked by [`Self::fetch_history`] as the blocks are downloaded.
+    fn verify_history(&self, state: &State, commit_block: &SignedBlock) -> Result<(), Error> {
+        let view = state.view();
+        // each state root is committed to by the following block
+        let mut state_roots = view.state_roots().iter().copied();
+        let mut transactions = 0;
+        let mut latest_block = None;
+        for height in 1..=view.height() {
+            let block = NonZeroUsize::new(height)
+                .and_then(|height| self.kura.get_block(height))
+                .ok_or(Error::MismatchedHistory)?;
+            if height > 1 && block.header().prev_state_root != state_roots.next() {
+                return Err(Error::MismatchedStateRoot);
+            }
+            for tx in block.transactions() {
+                if view.transaction_height(&tx.hash()) != NonZeroUsize::new(height) {
+                    return Err(Error::MismatchedTransactions);
+                }
+                transactions += 1;
+            }
+            latest_block = Some(block);
+        }
+        if commit_block.header().prev_state_root != state_roots.next()
+            || state_roots.next().is_some()
+        {
+            return Err(Error::MismatchedStateRoot);
+        }
+        if view.transactions.len() != transactions {
+            return Err(Error::MismatchedTransactions);
+        }
+
+        // the topology is updated with the peers of the world state after each block
+        let commit_topology = view.commit_topology().iter().collect::<BTreeSet<_>>();
+        if commit_topology.len() != view.commit_topology().len()
+            || commit_topology != view.world().peers().iter().collect::<BTreeSet<_>>()
+        {
+            return Err(Error::MismatchedTopology);
+        }
+        match latest_block {
+            // genesis isn't signed by the topology
+            Some(latest_block) if view.height() > 1 => {
+                let mut topology = Topology::new(view.prev_commit_topology().to_vec());
+                topology.nth_rotation(latest_block.header().view_change_index as usize);
+                ValidBlock::verify_commit_signatures(&latest_block, &topology)
+                    .map_err(|_| Error::MismatchedTopology)?;
+            }
+            _ => {
+                if !view.prev_commit_topology().is_empty() {
+                    return Err(Error::MismatchedTopology);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `block` has valid signatures of a quorum of the trusted peers,
+    /// given `topology` which the signatures refer to
+    fn is_signed_by_trusted_quorum(&self, block: &SignedBlock, topology: &Topology) -> bool {
+        let trusted = Topology::new(self.trusted_peers.others.iter().map(|peer| peer.id.clone()));
+        let header = block.header();
+        let signatories = block
+            .signatures()
+            .filter_map(|signature| {
+                let signatory = topology.as_ref().get(usize::try_from(signature.0).ok()?)?;
+                let is_valid = trusted.position(signatory.public_key()).is_some()
+                    && signature.1.verify(signatory.public_key(), &header).is_ok();
+                is_valid.then_some(signatory)
+            })
+            .collect::<BTreeSet<_>>();
+
+        !trusted.as_ref().is_empty() && signatories.len() >= trusted.min_votes_for_commit()
+    }
+
+    /// Fill [`Kura`] with blocks matching `block_hashes`, retrying with other peers on failure
+    async fn fetch_history(
+        &self,
+        receiver: &mut MessageReceiver,
+        peers: &[PeerId],
+        block_hashes: &[HashOf<BlockHeader>],
+    ) -> Result<(), Error> {
+        let mut height = 1;
+        let mut failed_peers = 0;
+
+        for peer_id in peers.iter().cycle() {
+            if height > block_hashes.len() {
+                break;
+            }
+            if failed_peers == peers.len() {
+                return Err(Error::MismatchedHistory);
+            }
+
+            message::Message::from(message::GetBlocks {
+                peer_id: self.peer.id.clone(),
+                from: NonZeroU64::new(height as u64).expect("Starts from 1"),
+                count: NonZeroU32::MAX,
+            })
+            .send_to(&self.network, peer_id.clone());
+            let blocks = self
+                .receive(receiver, |sender, message| match message {
+                    message::Message::ShareBlocks(message::ShareBlocks { blocks })
+                        if sender == *peer_id =>
+                    {
+                        Some(blocks)
+                    }
+                    _ => None,
+                })
+                .await
+                .unwrap_or_default();
+
+            let stored_before = height;
+            for block in blocks {
+                let Some(expected_hash) = block_hashes.get(height - 1) else {
+                    break;
+                };
+                let expected_prev_hash = height.checked_sub(2).map(|idx| block_hashes[idx]);
+                if block.hash() != *expected_hash
+                    || block.header().prev_block_hash != expected_prev_hash
+                {
+                    warn!(peer=%peer_id, height, "Peer shared a block which doesn't match the snapshot");
+                    break;
+                }
+
+                self.kura.store_block(CommittedBlock::new_unchecked(block));
+                height += 1;
+            }
+
+            if height == stored_before {
+                failed_peers += 1;
+            } else {
+                failed_peers = 0;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error variants for state sync
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+enum Error {
+    /// Peer didn't respond in time
+    Timeout,
+    /// Failed to read the downloaded snapshot
+    Read(#[from] snapshot::TryReadError),
+    /// Failed to store the downloaded snapshot
+    Write(#[from] snapshot::TryWriteError),
+    /// Snapshot doesn't match the block following it
+    MismatchedBlock,
+    /// Snapshot state root doesn't match the one committed to by the following block
+    MismatchedStateRoot,
+    /// Block following the snapshot isn't committed
+    NotCommitted(#[from] BlockValidationError),
+    /// Block following the snapshot isn't signed by a quorum of trusted peers
+    UntrustedSignatories,
+    /// None of the peers shared blocks matching the snapshot
+    MismatchedHistory,
+    /// Snapshot transactions don't match the blocks preceding it
+    MismatchedTransactions,
+    /// Snapshot topology doesn't match its peers or the signatures of the latest block
+    MismatchedTopology,
+}
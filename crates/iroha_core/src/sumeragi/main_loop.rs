@@ -896,6 +896,18 @@ impl Sumeragi {
         let block_expected = tx_cache_non_empty || !prev_block_is_empty;
 
         if tx_cache_full || block_expected && (view_change_in_progress || deadline_reached) {
+            // Signatories might have changed since the transactions were queued,
+            // and a single invalid signature makes the whole block invalid
+            let world = state.world.view();
+            self.transaction_cache.retain(|tx| {
+                let valid = tx.verify_signature(&world).is_ok();
+                if !valid {
+                    debug!(tx=%tx.as_ref().hash(), "Transaction signatory was removed");
+                }
+                valid
+            });
+            drop(world);
+
             let transactions = self
                 .transaction_cache
                 .iter()
@@ -906,6 +918,7 @@ impl Sumeragi {
                 .chain(
                     self.topology.view_change_index(),
                     state.view().latest_block().as_deref(),
+                    state.view().latest_state_root(),
                 )
                 .sign(self.key_pair.private_key())
                 .unpack(|e| self.send_event(e));
@@ -1521,7 +1534,11 @@ mod tests {
 
         // Creating a block of two identical transactions and validating it
         let unverified_genesis = BlockBuilder::new(vec![peers, tx.clone(), tx])
-            .chain(0, state.view().latest_block().as_deref())
+            .chain(
+                0,
+                state.view().latest_block().as_deref(),
+                state.view().latest_state_root(),
+            )
             .sign(leader_private_key)
             .unpack(|_| {});
 
@@ -1561,7 +1578,11 @@ mod tests {
 
             // Creating a block of two identical transactions and validating it
             BlockBuilder::new(vec![tx1, tx2])
-                .chain(0, state.view().latest_block().as_deref())
+                .chain(
+                    0,
+                    state.view().latest_block().as_deref(),
+                    state.view().latest_state_root(),
+                )
                 .sign(leader_private_key)
                 .unpack(|_| {})
         };
@@ -89,12 +89,12 @@ impl SumeragiHandle {
         }
     }
 
-    fn replay_block(
+    pub(crate) fn replay_block(
         chain_id: &ChainId,
         genesis_account: &AccountId,
         block: &SignedBlock,
         state_block: &mut StateBlock<'_>,
-        events_sender: &EventsSender,
+        mut send_event: impl FnMut(EventBox),
         topology: &mut Topology,
     ) {
         // NOTE: topology need to be updated up to block's view_change_index
@@ -107,14 +107,10 @@ impl SumeragiHandle {
             genesis_account,
             state_block,
         )
-        .unpack(|e| {
-            let _ = events_sender.send(e.into());
-        })
+        .unpack(|e| send_event(e.into()))
         .expect("INTERNAL BUG: Invalid block stored in Kura")
         .commit(topology)
-        .unpack(|e| {
-            let _ = events_sender.send(e.into());
-        })
+        .unpack(|e| send_event(e.into()))
         .expect("INTERNAL BUG: Invalid block stored in Kura");
 
         if block.as_ref().header().is_genesis() {
@@ -126,9 +122,7 @@ impl SumeragiHandle {
         state_block
             .apply_without_execution(&block, topology.as_ref().to_owned())
             .into_iter()
-            .for_each(|e| {
-                let _ = events_sender.send(e);
-            });
+            .for_each(send_event);
     }
 }
 
@@ -196,7 +190,9 @@ impl SumeragiStartArgs {
                 &genesis_account,
                 &block,
                 &mut state_block,
-                &events_sender,
+                |event| {
+                    let _ = events_sender.send(event);
+                },
                 &mut topology,
             );
 
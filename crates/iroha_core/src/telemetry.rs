@@ -64,6 +64,14 @@ impl Telemetry {
         self.metrics.view_changes.set(value);
     }
 
+    /// Increase the number of requests to `endpoint` rejected due to the rate limit of `key` kind
+    pub fn inc_rate_limited_requests(&self, endpoint: &str, key: &str) {
+        self.metrics
+            .rate_limited_requests
+            .with_label_values(&[endpoint, key])
+            .inc();
+    }
+
     /// Report the event of block commit, measuring the block time.
     pub fn report_block_commit_blocking(&self, block_header: BlockHeader) {
         let report = BlockCommitReport::new(block_header, &self.time_source);
@@ -427,7 +435,11 @@ mod tests {
             let tx =
                 AcceptedTransaction::accept(tx, &chain_id(), max_clock_drift, tx_limits).unwrap();
             let block = BlockBuilder::new_with_time_source(vec![tx], self.time_source.clone())
-                .chain(0, self.state.view().latest_block().as_deref())
+                .chain(
+                    0,
+                    self.state.view().latest_block().as_deref(),
+                    self.state.view().latest_state_root(),
+                )
                 .sign(&self.leader_private_key)
                 .unpack(|_| {});
 
@@ -555,7 +567,7 @@ mod tests {
     fn genesis_commit_time_is_zero() {
         let (time_handle, time_source) = TimeSource::new_mock(Duration::from_millis(1500));
         let header = BlockBuilder::new_with_time_source(vec![], time_source.clone())
-            .chain(1, None)
+            .chain(1, None, None)
             .sign(KeyPair::random().private_key())
             .unpack(|_| {})
             .header();
@@ -8,15 +8,19 @@
 //! This is also where the actual execution of instructions, as well
 //! as various forms of validation are performed.
 
-use std::time::{Duration, SystemTime};
+use std::{
+    num::NonZeroU64,
+    time::{Duration, SystemTime},
+};
 
 use eyre::Result;
 use iroha_crypto::SignatureOf;
 pub use iroha_data_model::prelude::*;
 use iroha_data_model::{
+    block::BlockHeader,
     isi::error::Mismatch,
     query::error::FindError,
-    transaction::{error::TransactionLimitError, TransactionPayload},
+    transaction::{error::TransactionLimitError, TransactionPayload, TransactionSignature},
 };
 use iroha_logger::{debug, error};
 use iroha_macro::FromVariant;
@@ -24,7 +28,7 @@ use mv::storage::StorageReadOnly;
 
 use crate::{
     smartcontracts::{wasm, wasm::cache::WasmCache},
-    state::{StateBlock, StateTransaction},
+    state::{State, StateBlock, StateReadOnly, StateTransaction, WorldReadOnly},
 };
 
 /// `AcceptedTransaction` — a transaction accepted by Iroha peer.
@@ -173,6 +177,30 @@ impl AcceptedTransaction {
 
         Ok(Self(tx))
     }
+
+    /// Check that the transaction is signed by one of the signatories of its authority.
+    ///
+    /// Signatories can change from block to block, so this is checked against the given `world`
+    /// and not when the transaction is decoded.
+    ///
+    /// # Errors
+    ///
+    /// - if the signature doesn't match any of the signatories
+    pub fn verify_signature(
+        &self,
+        world: &impl WorldReadOnly,
+    ) -> Result<(), SignatureVerificationFail> {
+        let TransactionSignature(signature) = self.0.signature();
+
+        if world.verify_account_signature(self.0.authority(), signature, self.0.payload()) {
+            return Ok(());
+        }
+
+        Err(SignatureVerificationFail {
+            signature: signature.clone(),
+            reason: "Signature doesn't match any of the authority's signatories".to_owned(),
+        })
+    }
 }
 
 impl From<AcceptedTransaction> for SignedTransaction {
@@ -193,6 +221,39 @@ impl AsRef<SignedTransaction> for AcceptedTransaction {
     }
 }
 
+impl State {
+    /// Execute `tx` on top of the latest state as if it were the only transaction of the next block.
+    ///
+    /// The transaction is executed on a [fork](Self::fork) of the state, so the state is left intact
+    /// and block processing isn't held up. Triggers activated by the transaction are not executed,
+    /// since they only run once the block is committed.
+    pub fn simulate_transaction(&self, tx: AcceptedTransaction) -> TransactionSimulation {
+        let state = self.fork();
+        let header = {
+            let state_view = state.view();
+            let creation_time_ms = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("INTERNAL BUG: Failed to get the current system time")
+                .as_millis()
+                .try_into()
+                .expect("INTERNAL BUG: Unix time exceeds u64::MAX");
+
+            BlockHeader {
+                height: NonZeroU64::new(state_view.height() as u64 + 1)
+                    .expect("INTERNAL BUG: Blockchain height overflow"),
+                prev_block_hash: state_view.latest_block_hash(),
+                transactions_hash: None,
+                prev_state_root: state_view.state_roots().last().copied(),
+                creation_time_ms,
+                view_change_index: 0,
+            }
+        };
+
+        let mut state_block = state.block(header);
+        state_block.simulate(tx, &mut WasmCache::new())
+    }
+}
+
 impl StateBlock<'_> {
     /// Move transaction lifecycle forward by checking if the
     /// instructions can be applied to the [`StateBlock`].
@@ -217,6 +278,28 @@ impl StateBlock<'_> {
         Ok(tx.0)
     }
 
+    /// Execute `tx` like [`Self::validate`] does, but discard all of its changes.
+    pub fn simulate(
+        &mut self,
+        tx: AcceptedTransaction,
+        wasm_cache: &mut WasmCache<'_, '_, '_>,
+    ) -> TransactionSimulation {
+        let mut state_transaction = self.transaction();
+        let result = Self::validate_internal(tx, &mut state_transaction, wasm_cache);
+        let events = if result.is_ok() {
+            state_transaction.data_events()
+        } else {
+            Vec::new()
+        };
+
+        TransactionSimulation {
+            rejection_reason: result.err(),
+            events,
+            fuel: state_transaction.consumed_fuel,
+            instructions: state_transaction.executed_instructions,
+        }
+    }
+
     fn validate_internal(
         tx: AcceptedTransaction,
         state_transaction: &mut StateTransaction<'_, '_>,
@@ -230,6 +313,10 @@ impl StateBlock<'_> {
             ));
         }
 
+        if tx.verify_signature(&state_transaction.world).is_err() {
+            return Err(TransactionRejectionReason::InvalidSignature);
+        }
+
         debug!(tx=%tx.as_ref().hash(), "Validating transaction");
         Self::validate_with_runtime_executor(tx.clone(), state_transaction, wasm_cache)?;
 
@@ -296,3 +383,57 @@ impl StateBlock<'_> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::gen_account_in;
+
+    use super::*;
+    use crate::{
+        kura::Kura,
+        query::store::LiveQueryStore,
+        smartcontracts::isi::Registrable as _,
+        state::{root::state_root, World},
+    };
+
+    #[tokio::test]
+    async fn simulation_leaves_state_intact() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain = Domain::new("wonderland".parse().expect("Valid")).build(&alice_id);
+        let world = World::with([domain], [account], []);
+        let state = State::new(
+            world,
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+        let root = state_root(state.view().world());
+
+        let asset_definition_id: AssetDefinitionId = "rose#wonderland".parse().expect("Valid");
+        let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
+            .with_instructions([Register::asset_definition(AssetDefinition::numeric(
+                asset_definition_id.clone(),
+            ))])
+            .sign(alice_keypair.private_key());
+        let tx =
+            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits).expect("Valid");
+        let simulation = state.simulate_transaction(tx);
+
+        assert!(simulation.rejection_reason.is_none());
+        assert!(!simulation.events.is_empty());
+        let state_view = state.view();
+        assert!(state_view
+            .world()
+            .asset_definitions()
+            .get(&asset_definition_id)
+            .is_none());
+        assert_eq!(state_view.height(), 0);
+        assert_eq!(state_root(state_view.world()), root);
+    }
+}
@@ -40,7 +40,7 @@ use iroha_primitives::const_vec::ConstVec;
 use iroha_schema::{Declaration, IntoSchema, MetaMap, Metadata, NamedFieldsMeta, TypeId};
 #[cfg(target_family = "wasm")]
 use lazy::PublicKeyLazy;
-pub use merkle::MerkleTree;
+pub use merkle::{MerkleProof, MerkleTree};
 #[cfg(not(feature = "ffi_import"))]
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize, Serializer};
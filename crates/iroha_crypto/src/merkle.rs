@@ -15,6 +15,16 @@ use crate::{Hash, HashOf};
 #[repr(transparent)]
 pub struct MerkleTree<T>(Vec<Option<HashOf<T>>>);
 
+/// Proof of inclusion of a leaf into a [`MerkleTree`]
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct MerkleProof<T> {
+    /// Index of the leaf in the order of insertion
+    leaf_index: u32,
+    /// Hashes of the siblings of the nodes on the path from the leaf to the root, starting from the leaf.
+    /// `None` stands for a missing right sibling.
+    audit_path: Vec<Option<HashOf<T>>>,
+}
+
 /// Iterator over leaves of [`MerkleTree`]
 pub struct LeafHashIterator<T> {
     tree: MerkleTree<T>,
@@ -159,6 +169,29 @@ impl<T> MerkleTree<T> {
         None
     }
 
+    /// Get the proof of inclusion of the `idx`-th leaf.
+    pub fn proof(&self, idx: usize) -> Option<MerkleProof<T>> {
+        let leaf_index = u32::try_from(idx).ok()?;
+        self.get_leaf_hash(idx)?;
+
+        let mut node_idx = 2_usize.pow(self.height()) - 1 + idx;
+        let mut audit_path = Vec::with_capacity(self.height() as usize);
+        while let Some(parent_idx) = self.parent(node_idx) {
+            let sibling_idx = match node_idx % 2 {
+                0 => node_idx - 1,
+                1 => node_idx + 1,
+                _ => unreachable!(),
+            };
+            audit_path.push(self.get(sibling_idx).copied().flatten());
+            node_idx = parent_idx;
+        }
+
+        Some(MerkleProof {
+            leaf_index,
+            audit_path,
+        })
+    }
+
     /// Add `hash` to the tail of the tree.
     pub fn add(&mut self, hash: HashOf<T>) {
         // If the tree is perfect, increment its height to double the leaf capacity.
@@ -180,6 +213,17 @@ impl<T> MerkleTree<T> {
         self.update(self.len().saturating_sub(1));
     }
 
+    /// Replace the `idx`-th leaf hash with `hash`.
+    /// Returns `None` if the tree has no such leaf.
+    pub fn update_leaf(&mut self, idx: usize, hash: HashOf<T>) -> Option<()> {
+        let node_idx = (2_usize.pow(self.height()) - 1).checked_add(idx)?;
+        let node = self.0.get_mut(node_idx)?;
+        node.as_ref()?;
+        *node = Some(hash);
+        self.update(node_idx);
+        Some(())
+    }
+
     fn update(&mut self, idx: usize) {
         let mut node = match self.get(idx) {
             Some(node) => *node,
@@ -225,6 +269,39 @@ impl<T> MerkleTree<T> {
     }
 }
 
+impl<T> MerkleProof<T> {
+    /// Index of the proven leaf in the order of insertion.
+    pub fn leaf_index(&self) -> u32 {
+        self.leaf_index
+    }
+
+    /// Check that `leaf` is the leaf at [`Self::leaf_index`] of the tree with the given `root` hash.
+    pub fn verify(&self, root: &HashOf<MerkleTree<T>>, leaf: HashOf<T>) -> bool {
+        let height = u32::try_from(self.audit_path.len()).unwrap_or(u32::MAX);
+        if self
+            .leaf_index
+            .checked_shr(height)
+            .is_some_and(|overflow| overflow != 0)
+        {
+            return false;
+        }
+
+        let mut idx = self.leaf_index;
+        let mut node = Some(leaf);
+        for sibling in &self.audit_path {
+            node = match (idx % 2, sibling) {
+                (0, _) => MerkleTree::nodes_pair_hash(node.as_ref(), sibling.as_ref()),
+                (_, Some(_)) => MerkleTree::nodes_pair_hash(sibling.as_ref(), node.as_ref()),
+                // a right node always has its left sibling
+                (_, None) => return false,
+            };
+            idx /= 2;
+        }
+
+        node.map(HashOf::transmute).as_ref() == Some(root)
+    }
+}
+
 impl<T> Iterator for LeafHashIterator<T> {
     type Item = HashOf<T>;
 
@@ -312,4 +389,49 @@ mod tests {
             assert_eq!(testee_leaf, tester_leaf);
         }
     }
+
+    #[test]
+    fn leaf_update() {
+        const N_LEAVES: u8 = 5;
+
+        let mut hashes = test_hashes(N_LEAVES);
+        let mut tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+
+        let updated = HashOf::from_untyped_unchecked(Hash::prehashed([0xff; Hash::LENGTH]));
+        for idx in [0, 3, 4] {
+            tree.update_leaf(idx, updated).unwrap();
+            hashes[idx] = updated;
+            let rebuilt = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+            assert_eq!(tree.hash(), rebuilt.hash());
+        }
+        assert!(tree.update_leaf(N_LEAVES as usize, updated).is_none());
+    }
+
+    #[test]
+    fn proof() {
+        const N_LEAVES: u8 = 5;
+
+        let hashes = test_hashes(N_LEAVES);
+        let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+        let root = tree.hash().unwrap();
+
+        for (idx, leaf) in hashes.iter().enumerate() {
+            let proof = tree.proof(idx).unwrap();
+            assert_eq!(proof.leaf_index() as usize, idx);
+            assert!(proof.verify(&root, *leaf));
+            for other in hashes.iter().filter(|other| *other != leaf) {
+                assert!(!proof.verify(&root, *other));
+            }
+        }
+        assert!(tree.proof(N_LEAVES as usize).is_none());
+    }
+
+    #[test]
+    fn proof_of_single_leaf() {
+        let hashes = test_hashes(1);
+        let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+
+        let proof = tree.proof(0).unwrap();
+        assert!(proof.verify(&tree.hash().unwrap(), hashes[0]));
+    }
 }
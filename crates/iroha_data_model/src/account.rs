@@ -1,7 +1,9 @@
 //! Structures, traits and impls related to `Account`s.
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
 use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, IdEqOrdHash};
@@ -12,8 +14,8 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 
 pub use self::model::*;
 use crate::{
-    domain::prelude::*, metadata::Metadata, HasMetadata, Identifiable, ParseError, PublicKey,
-    Registered,
+    domain::prelude::*, metadata::Metadata, name::Name, HasMetadata, Identifiable, ParseError,
+    PublicKey, Registered,
 };
 
 #[model]
@@ -22,8 +24,10 @@ mod model {
 
     use super::*;
 
-    /// Identification of [`Account`] by the combination of the [`PublicKey`] as its sole signatory and the [`Domain`](crate::domain::Domain) it belongs to.
-    /// TODO #4373 include multi-signatory use.
+    /// Identification of [`Account`] by the combination of the [`PublicKey`] it was created with and the [`Domain`](crate::domain::Domain) it belongs to.
+    ///
+    /// The public key stays a stable identifier of the account even after it's rotated out of the
+    /// [`Account::signatories`] that are allowed to sign on behalf of the account.
     ///
     /// # Examples
     ///
@@ -59,10 +63,51 @@ mod model {
     pub struct AccountId {
         /// [`Domain`](crate::domain::Domain) that the [`Account`] belongs to.
         pub domain: DomainId,
-        /// Sole signatory of the [`Account`].
+        /// Signatory the [`Account`] was created with.
         pub signatory: PublicKey,
     }
 
+    /// Human-readable alias of an [`Account`] in the [`Domain`](crate::domain::Domain) the account belongs to.
+    ///
+    /// Aliases are unique, so an alias resolves to at most one [`AccountId`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iroha_data_model::account::AccountAlias;
+    ///
+    /// let alias: AccountAlias = "alice@wonderland"
+    ///     .parse()
+    ///     .expect("alias@domain should be valid format");
+    /// ```
+    #[derive(
+        DebugCustom,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        DeserializeFromStr,
+        SerializeDisplay,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}@{domain}")]
+    #[debug(fmt = "{name}@{domain}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AccountAlias {
+        /// Name of the alias, unique within the domain.
+        pub name: Name,
+        /// [`Domain`](crate::domain::Domain) of the aliased [`Account`].
+        pub domain: DomainId,
+    }
+
     /// Account entity is an authority which is used to execute `Iroha Special Instructions`.
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -73,6 +118,12 @@ mod model {
     pub struct Account {
         /// Identification of the [`Account`].
         pub id: AccountId,
+        /// Public keys allowed to sign transactions and queries on behalf of the account.
+        pub signatories: BTreeSet<PublicKey>,
+        /// Human-readable alias of the [`Account`], if one is registered.
+        pub alias: Option<AccountAlias>,
+        /// Whether the holdings of the [`Account`] are frozen, so that none of its assets or NFTs can be moved.
+        pub frozen: bool,
         /// Metadata of this account as a key-value store.
         pub metadata: Metadata,
     }
@@ -93,6 +144,11 @@ mod model {
     }
 }
 
+/// Maximum number of [`Account::signatories`] an account can have.
+///
+/// Every signatory has to be tried when verifying a signature of the account.
+pub const MAX_SIGNATORIES: usize = 16;
+
 impl AccountId {
     /// Return `true` if the account signatory matches the given `public_key`.
     #[inline]
@@ -110,11 +166,37 @@ impl Account {
         <Self as Registered>::With::new(id)
     }
 
-    /// Return a reference to the `Account` signatory.
+    /// Return a reference to the signatory the `Account` was created with.
+    ///
+    /// It might not be one of the [`Self::signatories`] anymore if it was rotated out.
     #[inline]
     pub fn signatory(&self) -> &PublicKey {
         &self.id.signatory
     }
+
+    /// Get an iterator over public keys allowed to sign on behalf of the `Account`
+    #[inline]
+    pub fn signatories(&self) -> impl ExactSizeIterator<Item = &PublicKey> {
+        self.signatories.iter()
+    }
+
+    /// Return `true` if `public_key` is allowed to sign on behalf of the `Account`
+    #[inline]
+    pub fn has_signatory(&self, public_key: &PublicKey) -> bool {
+        self.signatories.contains(public_key)
+    }
+
+    /// Return a reference to the alias of the `Account`, if it has one
+    #[inline]
+    pub fn alias(&self) -> Option<&AccountAlias> {
+        self.alias.as_ref()
+    }
+
+    /// Return `true` if the holdings of the `Account` are frozen
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
 }
 
 impl NewAccount {
@@ -136,10 +218,13 @@ impl NewAccount {
 
 #[cfg(feature = "transparent_api")]
 impl NewAccount {
-    /// Convert into [`Account`].
+    /// Convert into [`Account`] controlled by the signatory from its id.
     pub fn into_account(self) -> Account {
         Account {
+            signatories: BTreeSet::from([self.id.signatory.clone()]),
             id: self.id,
+            alias: None,
+            frozen: false,
             metadata: self.metadata,
         }
     }
@@ -188,9 +273,41 @@ impl FromStr for AccountId {
     }
 }
 
+impl FromStr for AccountAlias {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplit_once('@') {
+            None => Err(ParseError {
+                reason: "Account alias should have format `alias@domain`",
+            }),
+            Some(("", _)) => Err(ParseError {
+                reason: "Empty `alias` part in `alias@domain`",
+            }),
+            Some((_, "")) => Err(ParseError {
+                reason: "Empty `domain` part in `alias@domain`",
+            }),
+            Some((name_candidate, domain_id_candidate)) => {
+                if name_candidate.parse::<PublicKey>().is_ok() {
+                    return Err(ParseError {
+                        reason: "`alias` part in `alias@domain` can't be a public key, otherwise it's indistinguishable from an account ID",
+                    });
+                }
+                let name = name_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `alias` part in `alias@domain`",
+                })?;
+                let domain_id = domain_id_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `domain` part in `alias@domain`",
+                })?;
+                Ok(Self::new(name, domain_id))
+            }
+        }
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Account, AccountId};
+    pub use super::{Account, AccountAlias, AccountId};
 }
 
 #[cfg(test)]
@@ -215,4 +332,20 @@ mod tests {
             .parse::<AccountId>()
             .expect_err("signatory#domain should not be valid");
     }
+
+    #[test]
+    fn parse_account_alias() {
+        const SIGNATORY: &str =
+            "ed0120EDF6D7B52C7032D03AEC696F2068BD53101528F3C7B6081BFF05A1662D7FC245";
+        let alias = "alice@wonderland"
+            .parse::<AccountAlias>()
+            .expect("should be valid");
+        assert_eq!(alias.to_string(), "alice@wonderland");
+        let _err_empty_alias = "@wonderland"
+            .parse::<AccountAlias>()
+            .expect_err("@domain should not be valid");
+        let _err_signatory = format!("{SIGNATORY}@wonderland")
+            .parse::<AccountAlias>()
+            .expect_err("signatory@domain should not be valid alias");
+    }
 }
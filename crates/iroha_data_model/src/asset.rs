@@ -128,6 +128,8 @@ mod model {
         /// The total amount of this asset in existence (sum of all asset values).
         #[getset(get_copy = "pub")]
         pub total_quantity: Numeric,
+        /// Whether the [`AssetDefinition`] is frozen, so that none of its assets can be moved.
+        pub frozen: bool,
     }
 
     /// Asset represents some sort of commodity or value.
@@ -152,6 +154,63 @@ mod model {
         /// Asset's Quantity.
         #[getset(get = "pub")]
         pub value: Numeric,
+        /// Whether the [`Asset`] is frozen, so that it can't be moved.
+        pub frozen: bool,
+    }
+
+    /// Identification of an [`AssetAllowance`]: the [`Asset`] to spend and the account allowed to spend it.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{asset} by {spender}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AssetAllowanceId {
+        /// Asset that can be spent.
+        pub asset: AssetId,
+        /// Account allowed to spend the asset on behalf of its owner.
+        pub spender: AccountId,
+    }
+
+    /// Quantity of an [`Asset`] that its owner allows another account to spend on their behalf.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}: {amount}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AssetAllowance {
+        /// Identification of the allowance.
+        pub id: AssetAllowanceId,
+        /// Quantity left to spend.
+        pub amount: Numeric,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`AssetDefinition`]
@@ -222,6 +281,12 @@ impl AssetDefinition {
     pub fn numeric(id: AssetDefinitionId) -> <Self as Registered>::With {
         <Self as Registered>::With::new(id, NumericSpec::default())
     }
+
+    /// Return `true` if the [`AssetDefinition`] is frozen
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
 }
 
 impl AssetId {
@@ -240,8 +305,15 @@ impl Asset {
         Self {
             id,
             value: value.into(),
+            frozen: false,
         }
     }
+
+    /// Return `true` if the [`Asset`] is frozen
+    #[inline]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
 }
 
 impl NewAssetDefinition {
@@ -370,7 +442,8 @@ impl Registered for AssetDefinition {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Asset, AssetDefinition, AssetDefinitionId, AssetId, Mintable, NewAssetDefinition,
+        Asset, AssetAllowance, AssetAllowanceId, AssetDefinition, AssetDefinitionId, AssetId,
+        Mintable, NewAssetDefinition,
     };
 }
 
@@ -18,7 +18,10 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::transaction::{error::TransactionRejectionReason, prelude::*};
+use crate::{
+    state::StateRoot,
+    transaction::{error::TransactionRejectionReason, prelude::*},
+};
 
 #[model]
 mod model {
@@ -59,6 +62,10 @@ mod model {
         /// None if no transactions (empty block).
         #[getset(get_copy = "pub")]
         pub transactions_hash: Option<HashOf<MerkleTree<SignedTransaction>>>,
+        /// Root hash of the world state resulting from the previous block.
+        /// None for the genesis block.
+        #[getset(get_copy = "pub")]
+        pub prev_state_root: Option<StateRoot>,
         /// Creation timestamp (unix time in milliseconds).
         #[getset(skip)]
         pub creation_time_ms: u64,
@@ -339,6 +346,7 @@ impl SignedBlock {
             height: nonzero!(1_u64),
             prev_block_hash: None,
             transactions_hash: Some(transactions_hash),
+            prev_state_root: None,
             creation_time_ms,
             view_change_index: 0,
         };
@@ -85,12 +85,16 @@ mod model {
         Domain(domain::DomainEvent),
         /// Trigger event
         Trigger(trigger::TriggerEvent),
+        /// Swap event
+        Swap(swap::SwapEvent),
         /// Role event
         Role(role::RoleEvent),
         /// Configuration event
         Configuration(config::ConfigurationEvent),
         /// Executor event
         Executor(executor::ExecutorEvent),
+        /// Custom event emitted by a smart contract or a trigger
+        Custom(custom::CustomEvent),
     }
 }
 
@@ -114,6 +118,12 @@ mod asset {
             Added(AssetChanged),
             #[has_origin(asset_changed => &asset_changed.asset)]
             Removed(AssetChanged),
+            #[has_origin(allowance => &allowance.id.asset)]
+            AllowanceApproved(AssetAllowance),
+            #[has_origin(allowance => &allowance.id.asset)]
+            AllowanceSpent(AssetAllowance),
+            Frozen(AssetId),
+            Unfrozen(AssetId),
         }
     }
 
@@ -132,6 +142,8 @@ mod asset {
             TotalQuantityChanged(AssetDefinitionTotalQuantityChanged),
             #[has_origin(ownership_changed => &ownership_changed.asset_definition)]
             OwnerChanged(AssetDefinitionOwnerChanged),
+            Frozen(AssetDefinitionId),
+            Unfrozen(AssetDefinitionId),
         }
     }
 
@@ -361,6 +373,16 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target)]
             MetadataRemoved(AccountMetadataChanged),
+            #[has_origin(signatory_changed => &signatory_changed.account)]
+            SignatoryAdded(AccountSignatoryChanged),
+            #[has_origin(signatory_changed => &signatory_changed.account)]
+            SignatoryRemoved(AccountSignatoryChanged),
+            #[has_origin(alias_changed => &alias_changed.account)]
+            AliasRegistered(AccountAliasChanged),
+            #[has_origin(alias_changed => &alias_changed.account)]
+            AliasUnregistered(AccountAliasChanged),
+            Frozen(AccountId),
+            Unfrozen(AccountId),
         }
     }
 
@@ -413,6 +435,50 @@ mod account {
             pub account: AccountId,
             pub role: RoleId,
         }
+
+        /// Depending on the wrapping event, [`AccountSignatoryChanged`] represents the added or removed account signatory
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountSignatoryChanged {
+            pub account: AccountId,
+            pub signatory: PublicKey,
+        }
+
+        /// Depending on the wrapping event, [`AccountAliasChanged`] represents the registered or unregistered account alias
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountAliasChanged {
+            pub account: AccountId,
+            pub alias: AccountAlias,
+        }
     }
 
     impl AccountPermissionChanged {
@@ -534,6 +600,54 @@ mod trigger {
     }
 }
 
+mod swap {
+    //! This module contains `SwapEvent` and its impls
+
+    use iroha_data_model_derive::model;
+
+    pub use self::model::*;
+    use super::*;
+
+    data_event! {
+        #[has_origin(origin = Swap)]
+        pub enum SwapEvent {
+            #[has_origin(swap => swap.id())]
+            Created(Swap),
+            #[has_origin(swap_accepted => &swap_accepted.swap)]
+            Accepted(SwapAccepted),
+            Cancelled(SwapId),
+            Expired(SwapId),
+        }
+    }
+
+    #[model]
+    mod model {
+        use super::*;
+
+        /// Event indicates that the [`Swap`] was accepted and the holdings of both parties were exchanged
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct SwapAccepted {
+            pub swap: SwapId,
+            pub taker: AccountId,
+        }
+    }
+}
+
 mod config {
     pub use self::model::*;
     use super::*;
@@ -651,6 +765,52 @@ mod executor {
     }
 }
 
+mod custom {
+    use iroha_data_model_derive::model;
+
+    pub use self::model::*;
+    use super::*;
+
+    #[model]
+    mod model {
+        use super::*;
+
+        /// Application-defined event emitted with the `EmitEvent` instruction.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct CustomEvent {
+            /// Account on behalf of which the event was emitted
+            pub emitter: AccountId,
+            /// Name of the event, e.g. `order_filled`
+            pub name: Name,
+            /// Application-specific payload
+            #[getset(skip)]
+            pub payload: Json,
+        }
+    }
+
+    impl CustomEvent {
+        /// Application-specific payload
+        pub fn payload(&self) -> &Json {
+            &self.payload
+        }
+    }
+}
+
 /// Trait for events originating from [`HasOrigin::Origin`].
 pub trait HasOrigin {
     /// Type of the origin.
@@ -692,7 +852,9 @@ impl DataEvent {
             | Self::Executor(_)
             | Self::Peer(_)
             | Self::Role(_)
-            | Self::Trigger(_) => None,
+            | Self::Trigger(_)
+            | Self::Swap(_)
+            | Self::Custom(_) => None,
         }
     }
 }
@@ -716,18 +878,23 @@ impl<Id> MetadataChanged<Id> {
 
 pub mod prelude {
     pub use super::{
-        account::{AccountEvent, AccountEventSet, AccountPermissionChanged, AccountRoleChanged},
+        account::{
+            AccountAliasChanged, AccountEvent, AccountEventSet, AccountPermissionChanged,
+            AccountRoleChanged, AccountSignatoryChanged,
+        },
         asset::{
             AssetChanged, AssetDefinitionEvent, AssetDefinitionEventSet,
             AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
             AssetEventSet,
         },
         config::{ConfigurationEvent, ConfigurationEventSet, ParameterChanged},
+        custom::CustomEvent,
         domain::{DomainEvent, DomainEventSet, DomainOwnerChanged},
         executor::{ExecutorEvent, ExecutorEventSet, ExecutorUpgrade},
         nft::{NftEvent, NftEventSet, NftOwnerChanged},
         peer::{PeerEvent, PeerEventSet},
         role::{RoleEvent, RoleEventSet, RolePermissionChanged},
+        swap::{SwapAccepted, SwapEvent, SwapEventSet},
         trigger::{TriggerEvent, TriggerEventSet, TriggerNumberOfExecutionsChanged},
         DataEvent, HasOrigin, MetadataChanged,
     };
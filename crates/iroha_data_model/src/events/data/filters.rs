@@ -47,12 +47,16 @@ mod model {
         Nft(NftEventFilter),
         /// Matches [`TriggerEvent`]s
         Trigger(TriggerEventFilter),
+        /// Matches [`SwapEvent`]s
+        Swap(SwapEventFilter),
         /// Matches [`RoleEvent`]s
         Role(RoleEventFilter),
         /// Matches [`ConfigurationEvent`]s
         Configuration(ConfigurationEventFilter),
         /// Matches [`ExecutorEvent`]s
         Executor(ExecutorEventFilter),
+        /// Matches [`CustomEvent`]s
+        Custom(CustomEventFilter),
     }
 
     /// An event filter for [`PeerEvent`]s
@@ -209,6 +213,28 @@ mod model {
         pub(super) event_set: TriggerEventSet,
     }
 
+    /// An event filter for [`SwapEvent`]s
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct SwapEventFilter {
+        /// If specified matches only events originating from this swap
+        pub(super) id_matcher: Option<super::SwapId>,
+        /// Matches only event from this set
+        pub(super) event_set: SwapEventSet,
+    }
+
     /// An event filter for [`RoleEvent`]s
     #[derive(
         Debug,
@@ -271,6 +297,28 @@ mod model {
         /// Matches only event from this set
         pub(super) event_set: ExecutorEventSet,
     }
+
+    /// An event filter for [`CustomEvent`]s
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct CustomEventFilter {
+        /// If specified matches only events emitted on behalf of this account
+        pub(super) emitter_matcher: Option<AccountId>,
+        /// If specified matches only events with this name
+        pub(super) name_matcher: Option<Name>,
+    }
 }
 
 impl PeerEventFilter {
@@ -616,6 +664,55 @@ impl super::EventFilter for TriggerEventFilter {
     }
 }
 
+impl SwapEventFilter {
+    /// Creates a new [`SwapEventFilter`] accepting all [`SwapEvent`]s.
+    pub const fn new() -> Self {
+        Self {
+            id_matcher: None,
+            event_set: SwapEventSet::all(),
+        }
+    }
+
+    /// Modifies a [`SwapEventFilter`] to accept only [`SwapEvent`]s originating from ids matching `id_matcher`.
+    #[must_use]
+    pub fn for_swap(mut self, id_matcher: SwapId) -> Self {
+        self.id_matcher = Some(id_matcher);
+        self
+    }
+
+    /// Modifies a [`SwapEventFilter`] to accept only [`SwapEvent`]s of types matching `event_set`.
+    #[must_use]
+    pub const fn for_events(mut self, event_set: SwapEventSet) -> Self {
+        self.event_set = event_set;
+        self
+    }
+}
+
+impl Default for SwapEventFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl super::EventFilter for SwapEventFilter {
+    type Event = super::SwapEvent;
+
+    fn matches(&self, event: &Self::Event) -> bool {
+        if let Some(id_matcher) = &self.id_matcher {
+            if id_matcher != event.origin() {
+                return false;
+            }
+        }
+
+        if !self.event_set.matches(event) {
+            return false;
+        }
+
+        true
+    }
+}
+
 impl RoleEventFilter {
     /// Creates a new [`RoleEventFilter`] accepting all [`RoleEvent`]s.
     pub const fn new() -> Self {
@@ -735,6 +832,57 @@ impl super::EventFilter for ExecutorEventFilter {
     }
 }
 
+impl CustomEventFilter {
+    /// Creates a new [`CustomEventFilter`] accepting all [`CustomEvent`]s.
+    pub const fn new() -> Self {
+        Self {
+            emitter_matcher: None,
+            name_matcher: None,
+        }
+    }
+
+    /// Modifies a [`CustomEventFilter`] to accept only [`CustomEvent`]s emitted on behalf of `emitter`.
+    #[must_use]
+    pub fn for_emitter(mut self, emitter: AccountId) -> Self {
+        self.emitter_matcher = Some(emitter);
+        self
+    }
+
+    /// Modifies a [`CustomEventFilter`] to accept only [`CustomEvent`]s named `name`.
+    #[must_use]
+    pub fn for_name(mut self, name: Name) -> Self {
+        self.name_matcher = Some(name);
+        self
+    }
+}
+
+impl Default for CustomEventFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl super::EventFilter for CustomEventFilter {
+    type Event = super::CustomEvent;
+
+    fn matches(&self, event: &Self::Event) -> bool {
+        if let Some(emitter) = &self.emitter_matcher {
+            if emitter != event.emitter() {
+                return false;
+            }
+        }
+
+        if let Some(name) = &self.name_matcher {
+            if name != event.name() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
 #[cfg(feature = "transparent_api")]
 impl EventFilter for DataEventFilter {
     type Event = DataEvent;
@@ -758,26 +906,32 @@ impl EventFilter for DataEventFilter {
 
             (DataEvent::Peer(event), Peer(filter)) => filter.matches(event),
             (DataEvent::Trigger(event), Trigger(filter)) => filter.matches(event),
+            (DataEvent::Swap(event), Swap(filter)) => filter.matches(event),
             (DataEvent::Role(event), Role(filter)) => filter.matches(event),
             (DataEvent::Configuration(event), Configuration(filter)) => filter.matches(event),
             (DataEvent::Executor(event), Executor(filter)) => filter.matches(event),
+            (DataEvent::Custom(event), Custom(filter)) => filter.matches(event),
 
             (
                 DataEvent::Peer(_)
                 | DataEvent::Domain(_)
                 | DataEvent::Trigger(_)
+                | DataEvent::Swap(_)
                 | DataEvent::Role(_)
                 | DataEvent::Configuration(_)
-                | DataEvent::Executor(_),
+                | DataEvent::Executor(_)
+                | DataEvent::Custom(_),
                 Any,
             ) => true,
             (
                 DataEvent::Peer(_)
                 | DataEvent::Domain(_)
                 | DataEvent::Trigger(_)
+                | DataEvent::Swap(_)
                 | DataEvent::Role(_)
                 | DataEvent::Configuration(_)
-                | DataEvent::Executor(_),
+                | DataEvent::Executor(_)
+                | DataEvent::Custom(_),
                 _,
             ) => false,
         }
@@ -787,8 +941,8 @@ impl EventFilter for DataEventFilter {
 pub mod prelude {
     pub use super::{
         AccountEventFilter, AssetDefinitionEventFilter, AssetEventFilter, ConfigurationEventFilter,
-        DataEventFilter, DomainEventFilter, ExecutorEventFilter, NftEventFilter, PeerEventFilter,
-        RoleEventFilter, TriggerEventFilter,
+        CustomEventFilter, DataEventFilter, DomainEventFilter, ExecutorEventFilter, NftEventFilter,
+        PeerEventFilter, RoleEventFilter, SwapEventFilter, TriggerEventFilter,
     };
 }
 #[cfg(test)]
@@ -245,6 +245,7 @@ mod conversions {
         RoleEventFilter             => DataEventFilter => EventFilterBox,
         ConfigurationEventFilter    => DataEventFilter => EventFilterBox,
         ExecutorEventFilter         => DataEventFilter => EventFilterBox,
+        CustomEventFilter           => DataEventFilter => EventFilterBox,
 
         TransactionEventFilter => PipelineEventFilterBox => EventFilterBox,
         BlockEventFilter       => PipelineEventFilterBox => EventFilterBox,
@@ -255,7 +256,8 @@ mod conversions {
 pub mod stream {
     //! Structures related to event streaming over HTTP
 
-    use derive_more::Constructor;
+    use core::num::NonZeroU64;
+
     use iroha_data_model_derive::model;
     use iroha_version::prelude::*;
 
@@ -269,19 +271,49 @@ pub mod stream {
         /// Message sent by the stream producer.
         /// Event sent by the peer.
         #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventMessage(pub EventBox);
+        pub struct EventMessage {
+            /// The event itself
+            pub event: EventBox,
+            /// Height of the block the event belongs to.
+            ///
+            /// Only set for subscriptions replaying events from a block height, where it can be used to deduplicate events after reconnecting.
+            pub block_height: Option<NonZeroU64>,
+        }
 
         /// Message sent by the stream consumer.
         /// Request sent by the client to subscribe to events.
-        #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventSubscriptionRequest(pub Vec<EventFilterBox>);
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct EventSubscriptionRequest {
+            /// Only events matching any of the filters are sent
+            pub filters: Vec<EventFilterBox>,
+            /// If set, events of the already committed blocks are replayed starting from this height before switching to live events
+            #[serde(default)]
+            pub from_height: Option<NonZeroU64>,
+        }
+    }
+
+    impl EventSubscriptionRequest {
+        /// Subscribe to the live events matching any of the `filters`.
+        pub fn new(filters: Vec<EventFilterBox>) -> Self {
+            Self {
+                filters,
+                from_height: None,
+            }
+        }
+
+        /// Replay events of the blocks starting from `height` first.
+        #[must_use]
+        pub fn with_from_height(self, height: NonZeroU64) -> Self {
+            Self {
+                from_height: Some(height),
+                ..self
+            }
+        }
     }
 
     impl From<EventMessage> for EventBox {
         fn from(source: EventMessage) -> Self {
-            source.0
+            source.event
         }
     }
 }
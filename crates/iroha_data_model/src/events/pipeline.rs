@@ -357,6 +357,7 @@ mod tests {
                 height,
                 prev_block_hash: None,
                 transactions_hash: Some(transactions_hash),
+                prev_state_root: None,
                 creation_time_ms: 0,
                 view_change_index: 0,
             }
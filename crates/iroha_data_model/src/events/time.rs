@@ -1,5 +1,5 @@
 //! Time event and filter
-use core::{ops::Range, time::Duration};
+use core::{fmt, ops::Range, str::FromStr, time::Duration};
 
 use derive_more::Constructor;
 use getset::Getters;
@@ -7,6 +7,7 @@ use iroha_data_model_derive::model;
 
 pub use self::model::*;
 use super::*;
+use crate::ParseError;
 
 #[model]
 mod model {
@@ -77,6 +78,8 @@ mod model {
         PreCommit,
         /// Execute with some schedule
         Schedule(Schedule),
+        /// Execute on every occurrence of a cron expression
+        Cron(CronSchedule),
     }
 
     /// Schedule of the trigger
@@ -101,6 +104,59 @@ mod model {
         pub period_ms: Option<u64>,
     }
 
+    /// Cron-like schedule of the trigger.
+    ///
+    /// Occurrences are evaluated in UTC against the creation time of blocks,
+    /// so the trigger is executed once per every occurrence between two blocks.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct CronSchedule {
+        /// Times of the executions
+        pub expression: CronExpression,
+        /// Occurrences before this time are skipped
+        pub start_ms: u64,
+        /// If some, occurrences at or after this time are skipped
+        pub end_ms: Option<u64>,
+        /// If some, the maximum number of occurrences since `start_ms`.
+        ///
+        /// Such a schedule can't start before its trigger is registered,
+        /// and the repeats of the trigger are limited to this number
+        pub max_occurrences: Option<u32>,
+    }
+
+    /// Cron expression with minute, hour, day of month, month and day of week fields,
+    /// e.g. `0 0 * * 1-5` for every weekday at midnight.
+    ///
+    /// Each field is either `*`, a value, a range `a-b` or a comma-separated list of them.
+    /// Any of them can be followed by a step, e.g. `*/15` or `5/15` for every 15th value starting at 5.
+    /// Both 0 and 7 stand for Sunday. If both the day of month and the day of week are restricted,
+    /// a day matching either of them is an occurrence, as in cron. Macros like `@daily` are supported too.
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, IntoSchema)]
+    pub struct CronExpression {
+        /// Bit `i` is set if minute `i` matches
+        pub(super) minutes: u64,
+        /// Bit `i` is set if hour `i` matches
+        pub(super) hours: u32,
+        /// Bit `i` is set if day `i` of the month matches
+        pub(super) days_of_month: u32,
+        /// Bit `i` is set if month `i` matches
+        pub(super) months: u16,
+        /// Bit `i` is set if day `i` of the week matches, 0 being Sunday
+        pub(super) days_of_week: u8,
+    }
+
     /// Time interval in which `TimeAction` should appear
     #[derive(
         Debug,
@@ -141,6 +197,9 @@ impl EventFilter for TimeEventFilter {
             ExecutionTime::Schedule(schedule) => {
                 count_matches_in_interval(schedule, &event.interval)
             }
+            ExecutionTime::Cron(schedule) => {
+                count_cron_matches_in_interval(schedule, &event.interval)
+            }
         }
     }
 
@@ -180,6 +239,23 @@ fn count_matches_in_interval(schedule: &Schedule, interval: &TimeInterval) -> u3
     )
 }
 
+/// Count occurrences of the cron `schedule` within the `interval`
+#[cfg(feature = "transparent_api")]
+fn count_cron_matches_in_interval(schedule: &CronSchedule, interval: &TimeInterval) -> u32 {
+    let since_ms = interval.since_ms.max(schedule.start_ms);
+    let to_ms = interval
+        .since_ms
+        .saturating_add(interval.length_ms)
+        .min(schedule.end_ms.unwrap_or(u64::MAX));
+
+    // Past occurrences are counted down by the repeats of the trigger
+    let limit = schedule.max_occurrences.unwrap_or(u32::MAX);
+
+    schedule
+        .expression
+        .count_occurrences(since_ms, to_ms, limit)
+}
+
 /// Multiply `duration` by `n`
 ///
 /// Usage of this function allows to operate with much longer time *intervals*
@@ -242,6 +318,399 @@ impl Schedule {
     }
 }
 
+impl CronSchedule {
+    /// Create new [`CronSchedule`] starting at the Unix epoch, without end and occurrence limit
+    #[must_use]
+    #[inline]
+    pub fn new(expression: CronExpression) -> Self {
+        Self {
+            expression,
+            start_ms: 0,
+            end_ms: None,
+            max_occurrences: None,
+        }
+    }
+
+    /// Skip occurrences before `start`
+    #[must_use]
+    #[inline]
+    pub fn starting_at(mut self, start: Duration) -> Self {
+        self.start_ms = start
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX");
+        self
+    }
+
+    /// Skip occurrences at or after `end`
+    #[must_use]
+    #[inline]
+    pub fn ending_at(mut self, end: Duration) -> Self {
+        self.end_ms = Some(
+            end.as_millis()
+                .try_into()
+                .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX"),
+        );
+        self
+    }
+
+    /// Limit the number of occurrences since the start
+    #[must_use]
+    #[inline]
+    pub fn with_max_occurrences(mut self, max_occurrences: u32) -> Self {
+        self.max_occurrences = Some(max_occurrences);
+        self
+    }
+
+    /// Instant before which occurrences are skipped
+    pub fn start(&self) -> Duration {
+        Duration::from_millis(self.start_ms)
+    }
+
+    /// Instant since which occurrences are skipped
+    pub fn end(&self) -> Option<Duration> {
+        self.end_ms.map(Duration::from_millis)
+    }
+}
+
+const CRON_MINUTES: (u32, u32) = (0, 59);
+const CRON_HOURS: (u32, u32) = (0, 23);
+const CRON_DAYS_OF_MONTH: (u32, u32) = (1, 31);
+const CRON_MONTHS: (u32, u32) = (1, 12);
+const CRON_DAYS_OF_WEEK: (u32, u32) = (0, 6);
+
+impl CronExpression {
+    /// Check that every field has at least one value and no values out of its bounds
+    fn is_valid(&self) -> bool {
+        [
+            (self.minutes, CRON_MINUTES),
+            (self.hours.into(), CRON_HOURS),
+            (self.days_of_month.into(), CRON_DAYS_OF_MONTH),
+            (self.months.into(), CRON_MONTHS),
+            (self.days_of_week.into(), CRON_DAYS_OF_WEEK),
+        ]
+        .into_iter()
+        .all(|(mask, bounds)| mask != 0 && mask & !cron_mask(bounds) == 0)
+    }
+
+    /// Parse a comma-separated list of values, ranges and steps into a bit mask
+    fn parse_field(field: &str, (min, max): (u32, u32)) -> Result<u64, ParseError> {
+        const INVALID_VALUE: ParseError = ParseError {
+            reason: "Cron expression field contains an invalid value",
+        };
+        const OUT_OF_BOUNDS: ParseError = ParseError {
+            reason: "Cron expression field contains a value out of bounds",
+        };
+
+        let parse_value = |value: &str| value.parse::<u32>().map_err(|_| INVALID_VALUE);
+
+        field.split(',').try_fold(0, |mask, part| {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (
+                    range,
+                    Some(parse_value(step)?)
+                        .filter(|step| *step > 0)
+                        .ok_or(INVALID_VALUE)?,
+                ),
+                None => (part, 1),
+            };
+            let (first, last) = match range.split_once('-') {
+                _ if range == "*" => (min, max),
+                Some((first, last)) => (parse_value(first)?, parse_value(last)?),
+                // `a/n` stands for `a-max/n`
+                None if part.contains('/') => (parse_value(range)?, max),
+                None => {
+                    let value = parse_value(range)?;
+                    (value, value)
+                }
+            };
+            if first < min || last > max || first > last {
+                return Err(OUT_OF_BOUNDS);
+            }
+
+            Ok((first..=last)
+                .step_by(step.try_into().expect("`u32` should always fit in `usize`"))
+                .fold(mask, |mask, value| mask | 1 << value))
+        })
+    }
+
+    /// Write a bit mask as a comma-separated list of values and ranges
+    fn fmt_field(f: &mut fmt::Formatter<'_>, mask: u64, bounds: (u32, u32)) -> fmt::Result {
+        if mask == cron_mask(bounds) {
+            return f.write_str("*");
+        }
+
+        let mut separator = "";
+        let mut value = bounds.0;
+        while value <= bounds.1 {
+            if mask & 1 << value == 0 {
+                value += 1;
+                continue;
+            }
+
+            let first = value;
+            while value < bounds.1 && mask & 1 << (value + 1) != 0 {
+                value += 1;
+            }
+            if first == value {
+                write!(f, "{separator}{first}")?;
+            } else {
+                write!(f, "{separator}{first}-{value}")?;
+            }
+            separator = ",";
+            value += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Bit mask with all values within `bounds` set
+fn cron_mask((min, max): (u32, u32)) -> u64 {
+    (u64::MAX >> (63 - max)) & (u64::MAX << min)
+}
+
+impl FromStr for CronExpression {
+    type Err = ParseError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        const FIELD_COUNT: ParseError = ParseError {
+            reason: "Cron expression must consist of 5 fields: \
+                    minute, hour, day of month, month and day of week",
+        };
+
+        let expression = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expression => expression,
+        };
+
+        let mut fields = expression.split_whitespace();
+        let mut next_field = |bounds| {
+            fields
+                .next()
+                .ok_or(FIELD_COUNT)
+                .and_then(|field| Self::parse_field(field, bounds))
+        };
+        let minutes = next_field(CRON_MINUTES)?;
+        let hours = next_field(CRON_HOURS)?;
+        let days_of_month = next_field(CRON_DAYS_OF_MONTH)?;
+        let months = next_field(CRON_MONTHS)?;
+        // 7 is an alias of Sunday
+        let days_of_week = next_field((CRON_DAYS_OF_WEEK.0, CRON_DAYS_OF_WEEK.1 + 1))?;
+        if fields.next().is_some() {
+            return Err(FIELD_COUNT);
+        }
+
+        Ok(Self {
+            minutes,
+            hours: hours.try_into().expect("Checked by the field bounds"),
+            days_of_month: days_of_month
+                .try_into()
+                .expect("Checked by the field bounds"),
+            months: months.try_into().expect("Checked by the field bounds"),
+            days_of_week: ((days_of_week | days_of_week >> 7) & cron_mask(CRON_DAYS_OF_WEEK))
+                .try_into()
+                .expect("Checked by the field bounds"),
+        })
+    }
+}
+
+impl fmt::Display for CronExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Self::fmt_field(f, self.minutes, CRON_MINUTES)?;
+        f.write_str(" ")?;
+        Self::fmt_field(f, self.hours.into(), CRON_HOURS)?;
+        f.write_str(" ")?;
+        Self::fmt_field(f, self.days_of_month.into(), CRON_DAYS_OF_MONTH)?;
+        f.write_str(" ")?;
+        Self::fmt_field(f, self.months.into(), CRON_MONTHS)?;
+        f.write_str(" ")?;
+        Self::fmt_field(f, self.days_of_week.into(), CRON_DAYS_OF_WEEK)
+    }
+}
+
+impl fmt::Debug for CronExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CronExpression")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl Serialize for CronExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CronExpression {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Decode for CronExpression {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let expression = Self {
+            minutes: Decode::decode(input)?,
+            hours: Decode::decode(input)?,
+            days_of_month: Decode::decode(input)?,
+            months: Decode::decode(input)?,
+            days_of_week: Decode::decode(input)?,
+        };
+        if !expression.is_valid() {
+            return Err("Cron expression field contains a value out of bounds".into());
+        }
+
+        Ok(expression)
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl CronExpression {
+    const MINUTES_PER_DAY: u64 = 24 * 60;
+    const MS_PER_MINUTE: u64 = 60 * 1000;
+
+    /// Count occurrences within `from_ms..to_ms`, but no more than `limit`
+    fn count_occurrences(&self, from_ms: u64, to_ms: u64, limit: u32) -> u32 {
+        let to = to_ms.div_ceil(Self::MS_PER_MINUTE);
+        let mut minute = from_ms.div_ceil(Self::MS_PER_MINUTE);
+
+        let mut count = 0;
+        while count < limit {
+            let Some(occurrence) = self.next_occurrence(minute, to) else {
+                break;
+            };
+            count += 1;
+            minute = occurrence + 1;
+        }
+
+        count
+    }
+
+    /// The first occurrence within `from..to`, in minutes since the Unix epoch
+    fn next_occurrence(&self, from: u64, to: u64) -> Option<u64> {
+        let mut minute = from;
+        while minute < to {
+            let day = minute / Self::MINUTES_PER_DAY;
+            let (year, month, day_of_month) = civil_from_days(day);
+
+            if self.months & 1 << month == 0 {
+                let (year, month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                minute = days_from_civil(year, month) * Self::MINUTES_PER_DAY;
+                continue;
+            }
+
+            // The Unix epoch is Thursday
+            let day_of_week = ((day + 4) % 7)
+                .try_into()
+                .expect("Day of week should always fit in `u32`");
+            if !self.matches_day(day_of_month, day_of_week) {
+                minute = (day + 1) * Self::MINUTES_PER_DAY;
+                continue;
+            }
+
+            let hour = minute % Self::MINUTES_PER_DAY / 60;
+            let Some(next_hour) = next_set_bit(self.hours.into(), hour) else {
+                minute = (day + 1) * Self::MINUTES_PER_DAY;
+                continue;
+            };
+            if next_hour != hour {
+                minute = day * Self::MINUTES_PER_DAY + next_hour * 60;
+                continue;
+            }
+
+            let Some(next_minute) = next_set_bit(self.minutes, minute % 60) else {
+                minute = day * Self::MINUTES_PER_DAY + (hour + 1) * 60;
+                continue;
+            };
+            let occurrence = day * Self::MINUTES_PER_DAY + hour * 60 + next_minute;
+            return (occurrence < to).then_some(occurrence);
+        }
+
+        None
+    }
+
+    fn matches_day(&self, day_of_month: u32, day_of_week: u32) -> bool {
+        let day_of_month_matches = self.days_of_month & 1 << day_of_month != 0;
+        let day_of_week_matches = self.days_of_week & 1 << day_of_week != 0;
+
+        if u64::from(self.days_of_week) == cron_mask(CRON_DAYS_OF_WEEK) {
+            day_of_month_matches
+        } else if u64::from(self.days_of_month) == cron_mask(CRON_DAYS_OF_MONTH) {
+            day_of_week_matches
+        } else {
+            day_of_month_matches || day_of_week_matches
+        }
+    }
+}
+
+/// The lowest set bit of `mask` starting from bit `from`
+#[cfg(feature = "transparent_api")]
+fn next_set_bit(mask: u64, from: u64) -> Option<u64> {
+    let mask = mask.checked_shr(from.try_into().ok()?)?;
+    (mask != 0).then(|| from + u64::from(mask.trailing_zeros()))
+}
+
+/// Year, month and day of the month of the date `days` after the Unix epoch
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+#[cfg(feature = "transparent_api")]
+fn civil_from_days(days: u64) -> (u64, u32, u32) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months are counted from March here
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (
+        year,
+        month.try_into().expect("Month should always fit in `u32`"),
+        day.try_into().expect("Day should always fit in `u32`"),
+    )
+}
+
+/// Days from the Unix epoch to the first day of `month` of `year`
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+#[cfg(feature = "transparent_api")]
+fn days_from_civil(year: u64, month: u32) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    // Months are counted from March here
+    let day_of_year = (153 * u64::from((month + 9) % 12) + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
 impl TimeInterval {
     /// Create new [`Self`]
     pub fn new(since: Duration, length: Duration) -> Self {
@@ -284,7 +753,8 @@ impl From<TimeInterval> for Range<Duration> {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     pub use super::{
-        ExecutionTime, Schedule as TimeSchedule, TimeEvent, TimeEventFilter, TimeInterval,
+        CronExpression, CronSchedule, ExecutionTime, Schedule as TimeSchedule, TimeEvent,
+        TimeEventFilter, TimeInterval,
     };
 }
 
@@ -533,4 +1003,119 @@ mod tests {
             assert_eq!(filter.count_matches(&event), 0);
         }
     }
+
+    /// Tests for [`CronSchedule`]
+    mod cron_schedule {
+        use super::*;
+
+        /// 2024-01-01 00:00:00 UTC, Monday
+        const NEW_YEAR: u64 = 1_704_067_200;
+        const DAY: u64 = 24 * 60 * 60;
+
+        fn count(schedule: CronSchedule, since: u64, length: u64) -> u32 {
+            let filter = TimeEventFilter(ExecutionTime::Cron(schedule));
+            let interval =
+                TimeInterval::new(Duration::from_secs(since), Duration::from_secs(length));
+
+            filter.count_matches(&TimeEvent { interval })
+        }
+
+        fn cron(expression: &str) -> CronSchedule {
+            CronSchedule::new(expression.parse().unwrap())
+        }
+
+        #[test]
+        fn test_parse_and_display() {
+            let expression: CronExpression = "*/15 0-6/2 1,15 * 7".parse().unwrap();
+            assert_eq!(expression.to_string(), "0,15,30,45 0,2,4,6 1,15 * 0");
+            assert_eq!(
+                expression.to_string().parse::<CronExpression>().unwrap(),
+                expression
+            );
+
+            let expression: CronExpression = "@daily".parse().unwrap();
+            assert_eq!(expression.to_string(), "0 0 * * *");
+            let expression: CronExpression = "30 9 * 1-3,12 1-5".parse().unwrap();
+            assert_eq!(expression.to_string(), "30 9 * 1-3,12 1-5");
+        }
+
+        #[test]
+        fn test_parse_invalid() {
+            for expression in [
+                "",
+                "* * * *",
+                "* * * * * *",
+                "60 * * * *",
+                "* 24 * * *",
+                "* * 0 * *",
+                "* * * 13 *",
+                "* * * * 8",
+                "5-1 * * * *",
+                "*/0 * * * *",
+                "a * * * *",
+            ] {
+                assert!(
+                    expression.parse::<CronExpression>().is_err(),
+                    "`{expression}` should be invalid"
+                );
+            }
+        }
+
+        #[test]
+        fn test_every_weekday() {
+            // Monday to Friday, the next Monday is outside of the interval
+            assert_eq!(count(cron("0 0 * * 1-5"), NEW_YEAR, 7 * DAY), 5);
+            // Saturday and Sunday
+            assert_eq!(count(cron("0 0 * * 0,6"), NEW_YEAR, 7 * DAY), 2);
+        }
+
+        #[test]
+        fn test_first_day_of_month() {
+            // 2024 is a leap year
+            assert_eq!(count(cron("@monthly"), NEW_YEAR, 366 * DAY), 12);
+            assert_eq!(count(cron("0 12 29 2 *"), NEW_YEAR, 366 * DAY), 1);
+            assert_eq!(
+                count(cron("0 12 29 2 *"), NEW_YEAR + 366 * DAY, 365 * DAY),
+                0
+            );
+        }
+
+        #[test]
+        fn test_day_of_month_or_day_of_week() {
+            // The 1st, 8th, 15th, 22nd and 29th of January are Mondays,
+            // so only the 13th is added by the day of month
+            assert_eq!(count(cron("0 0 13 * 1"), NEW_YEAR, 31 * DAY), 6);
+        }
+
+        #[test]
+        fn test_interval_borders() {
+            // The left border is included and the right one is not
+            assert_eq!(count(cron("0 * * * *"), NEW_YEAR, 60 * 60), 1);
+            assert_eq!(count(cron("0 * * * *"), NEW_YEAR + 1, 60 * 60), 1);
+            assert_eq!(count(cron("0 * * * *"), NEW_YEAR + 1, 60 * 60 - 1), 0);
+        }
+
+        #[test]
+        fn test_start_and_end() {
+            let schedule = cron("*/15 * * * *")
+                .starting_at(Duration::from_secs(NEW_YEAR + 60 * 60))
+                .ending_at(Duration::from_secs(NEW_YEAR + 90 * 60));
+
+            assert_eq!(count(schedule, NEW_YEAR, 60 * 60), 0);
+            // 01:00 and 01:15
+            assert_eq!(count(schedule, NEW_YEAR, DAY), 2);
+        }
+
+        #[test]
+        fn test_max_occurrences() {
+            let schedule = cron("*/15 * * * *")
+                .starting_at(Duration::from_secs(NEW_YEAR))
+                .with_max_occurrences(6);
+
+            assert_eq!(count(schedule, NEW_YEAR, 60 * 60), 4);
+            // Occurrences in the previous intervals are counted by the trigger repeats
+            assert_eq!(count(schedule, NEW_YEAR + 60 * 60, 60 * 60), 4);
+            assert_eq!(count(schedule, NEW_YEAR, 2 * 60 * 60), 6);
+        }
+    }
 }
@@ -117,6 +117,34 @@ mod model {
         Upgrade(Upgrade),
         #[debug(fmt = "{_0:?}")]
         Log(Log),
+        #[debug(fmt = "{_0:?}")]
+        EmitEvent(EmitEvent),
+        #[debug(fmt = "{_0:?}")]
+        AddSignatory(AddSignatory),
+        #[debug(fmt = "{_0:?}")]
+        RemoveSignatory(RemoveSignatory),
+        #[debug(fmt = "{_0:?}")]
+        RotateAccountKey(RotateAccountKey),
+        #[debug(fmt = "{_0:?}")]
+        RegisterAlias(RegisterAlias),
+        #[debug(fmt = "{_0:?}")]
+        UnregisterAlias(UnregisterAlias),
+        #[debug(fmt = "{_0:?}")]
+        Approve(Approve),
+        #[debug(fmt = "{_0:?}")]
+        TransferFrom(TransferFrom),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Freeze(FreezeBox),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Unfreeze(UnfreezeBox),
+        #[debug(fmt = "{_0:?}")]
+        CreateSwap(CreateSwap),
+        #[debug(fmt = "{_0:?}")]
+        AcceptSwap(AcceptSwap),
+        #[debug(fmt = "{_0:?}")]
+        CancelSwap(CancelSwap),
 
         #[debug(fmt = "{_0:?}")]
         Custom(CustomInstruction),
@@ -178,6 +206,23 @@ impl_instruction! {
     Upgrade,
     ExecuteTrigger,
     Log,
+    EmitEvent,
+    AddSignatory,
+    RemoveSignatory,
+    RotateAccountKey,
+    RegisterAlias,
+    UnregisterAlias,
+    Approve,
+    TransferFrom,
+    Freeze<Account>,
+    Freeze<AssetDefinition>,
+    Freeze<Asset>,
+    Unfreeze<Account>,
+    Unfreeze<AssetDefinition>,
+    Unfreeze<Asset>,
+    CreateSwap,
+    AcceptSwap,
+    CancelSwap,
 }
 
 impl Instruction for InstructionBox {}
@@ -959,6 +1004,315 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction to emit a [`CustomEvent`](crate::events::data::prelude::CustomEvent)
+        /// on behalf of the authority.
+        #[derive(Display)]
+        #[display(fmt = "EMIT_EVENT({name}): {payload}")]
+        pub struct EmitEvent {
+            /// Name of the event
+            pub name: Name,
+            /// Application-specific payload
+            pub payload: Json,
+        }
+    }
+
+    impl EmitEvent {
+        /// Constructor
+        pub fn new(name: Name, payload: impl Into<Json>) -> Self {
+            Self {
+                name,
+                payload: payload.into(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to allow one more public key to sign on behalf of an account.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ADD_SIGNATORY `{signatory}` TO `{account}`")]
+        pub struct AddSignatory {
+            /// Account to add the signatory to
+            pub account: AccountId,
+            /// Public key to be added
+            pub signatory: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to stop a public key from signing on behalf of an account.
+        ///
+        /// The last signatory of an account can't be removed.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REMOVE_SIGNATORY `{signatory}` FROM `{account}`")]
+        pub struct RemoveSignatory {
+            /// Account to remove the signatory from
+            pub account: AccountId,
+            /// Public key to be removed
+            pub signatory: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to atomically replace one signatory of an account with another.
+        ///
+        /// The [`AccountId`] doesn't change, so the account keeps its assets, NFTs, roles and permissions.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ROTATE_ACCOUNT_KEY `{old_key}` TO `{new_key}` OF `{account}`")]
+        pub struct RotateAccountKey {
+            /// Account whose key is rotated
+            pub account: AccountId,
+            /// Signatory to be removed
+            pub old_key: PublicKey,
+            /// Signatory to be added
+            pub new_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to give an account a human-readable alias in its domain.
+        ///
+        /// An account has at most one alias and an alias can't be taken by another account.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REGISTER_ALIAS `{alias}` FOR `{account}`")]
+        pub struct RegisterAlias {
+            /// Account to be aliased
+            pub account: AccountId,
+            /// Alias in the domain of the account
+            pub alias: AccountAlias,
+        }
+    }
+
+    isi! {
+        /// Instruction to free the alias of an account.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UNREGISTER_ALIAS `{alias}`")]
+        pub struct UnregisterAlias {
+            /// Alias to be unregistered
+            pub alias: AccountAlias,
+        }
+    }
+
+    isi! {
+        /// Instruction to allow an account to spend up to `amount` of an asset on behalf of its owner.
+        ///
+        /// Replaces the previous allowance of the spender, so a zero `amount` revokes it.
+        #[derive(Display)]
+        #[display(fmt = "APPROVE `{spender}` TO SPEND `{amount}` OF `{asset}`")]
+        pub struct Approve {
+            /// Asset that can be spent
+            pub asset: AssetId,
+            /// Account allowed to spend the asset
+            pub spender: AccountId,
+            /// Maximum quantity to be spent
+            pub amount: Numeric,
+        }
+    }
+
+    impl Approve {
+        /// Constructor
+        pub fn new(asset: AssetId, spender: AccountId, amount: impl Into<Numeric>) -> Self {
+            Self {
+                asset,
+                spender,
+                amount: amount.into(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to transfer an asset of another account within the allowance approved for the authority.
+        ///
+        /// The transferred quantity is debited from the allowance. Transfers of zero and transfers
+        /// without an allowance are rejected.
+        #[derive(Display)]
+        #[display(fmt = "TRANSFER_FROM `{asset}` `{amount}` TO `{destination}`")]
+        pub struct TransferFrom {
+            /// Asset to be spent
+            pub asset: AssetId,
+            /// Quantity to be transferred
+            pub amount: Numeric,
+            /// Account to receive the asset
+            pub destination: AccountId,
+        }
+    }
+
+    impl TransferFrom {
+        /// Constructor
+        pub fn new(asset: AssetId, amount: impl Into<Numeric>, destination: AccountId) -> Self {
+            Self {
+                asset,
+                amount: amount.into(),
+                destination,
+            }
+        }
+    }
+
+    isi! {
+        /// Generic instruction to freeze an object, so that the assets it covers can't be minted, burned or transferred.
+        pub struct Freeze<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be frozen.
+            pub object: O::Id,
+        }
+    }
+
+    impl_display! {
+        Freeze<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "FREEZE `{}`",
+        object,
+    }
+
+    impl_into_box! {
+        Freeze<Account> |
+        Freeze<AssetDefinition> |
+        Freeze<Asset>
+    => FreezeBox => InstructionBox[Freeze],
+    => FreezeBoxRef<'a> => InstructionBoxRef<'a>[Freeze]
+    }
+
+    impl Freeze<Account> {
+        /// Constructs a new [`Freeze`] for all assets and NFTs of an [`Account`].
+        pub fn account(account_id: AccountId) -> Self {
+            Self { object: account_id }
+        }
+    }
+
+    impl Freeze<AssetDefinition> {
+        /// Constructs a new [`Freeze`] for all assets of an [`AssetDefinition`].
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId) -> Self {
+            Self {
+                object: asset_definition_id,
+            }
+        }
+    }
+
+    impl Freeze<Asset> {
+        /// Constructs a new [`Freeze`] for an [`Asset`].
+        pub fn asset(asset_id: AssetId) -> Self {
+            Self { object: asset_id }
+        }
+    }
+
+    isi! {
+        /// Generic instruction to unfreeze an object frozen by [`Freeze`].
+        pub struct Unfreeze<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be unfrozen.
+            pub object: O::Id,
+        }
+    }
+
+    impl_display! {
+        Unfreeze<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "UNFREEZE `{}`",
+        object,
+    }
+
+    impl_into_box! {
+        Unfreeze<Account> |
+        Unfreeze<AssetDefinition> |
+        Unfreeze<Asset>
+    => UnfreezeBox => InstructionBox[Unfreeze],
+    => UnfreezeBoxRef<'a> => InstructionBoxRef<'a>[Unfreeze]
+    }
+
+    impl Unfreeze<Account> {
+        /// Constructs a new [`Unfreeze`] for all assets and NFTs of an [`Account`].
+        pub fn account(account_id: AccountId) -> Self {
+            Self { object: account_id }
+        }
+    }
+
+    impl Unfreeze<AssetDefinition> {
+        /// Constructs a new [`Unfreeze`] for all assets of an [`AssetDefinition`].
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId) -> Self {
+            Self {
+                object: asset_definition_id,
+            }
+        }
+    }
+
+    impl Unfreeze<Asset> {
+        /// Constructs a new [`Unfreeze`] for an [`Asset`].
+        pub fn asset(asset_id: AssetId) -> Self {
+            Self { object: asset_id }
+        }
+    }
+
+    isi! {
+        /// Instruction to offer holdings of the authority in exchange for holdings of another account.
+        ///
+        /// The offered holdings are locked until the swap is accepted, cancelled or expired.
+        #[derive(Display)]
+        #[display(fmt = "CREATE_SWAP `{id}`: `{offered}` FOR `{requested}`")]
+        pub struct CreateSwap {
+            /// Identification of the swap
+            pub id: SwapId,
+            /// Holdings of the authority to be given away
+            pub offered: SwapItem,
+            /// Holdings to be received in exchange
+            pub requested: SwapItem,
+            /// If specified, only this account can accept the swap
+            pub taker: Option<AccountId>,
+            /// Block creation time (in milliseconds since unix epoch) after which the swap can't be accepted
+            pub expires_at_ms: Option<u64>,
+        }
+    }
+
+    impl CreateSwap {
+        /// Constructor of a swap which can be accepted by any account until cancelled.
+        pub fn new(id: SwapId, offered: SwapItem, requested: SwapItem) -> Self {
+            Self {
+                id,
+                offered,
+                requested,
+                taker: None,
+                expires_at_ms: None,
+            }
+        }
+
+        /// Only allow `taker` to accept the swap.
+        #[must_use]
+        pub fn with_taker(mut self, taker: AccountId) -> Self {
+            self.taker = Some(taker);
+            self
+        }
+
+        /// Expire the swap after block creation time `expires_at_ms`.
+        #[must_use]
+        pub fn with_expiry_ms(mut self, expires_at_ms: u64) -> Self {
+            self.expires_at_ms = Some(expires_at_ms);
+            self
+        }
+    }
+
+    isi! {
+        /// Instruction to accept a swap on behalf of the authority, atomically exchanging the holdings of both parties.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ACCEPT_SWAP `{id}`")]
+        pub struct AcceptSwap {
+            /// Identification of the swap
+            pub id: SwapId,
+        }
+    }
+
+    isi! {
+        /// Instruction to cancel a swap, releasing the holdings locked by it.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "CANCEL_SWAP `{id}`")]
+        pub struct CancelSwap {
+            /// Identification of the swap
+            pub id: SwapId,
+        }
+    }
+
     isi! {
         /// Blockchain specific instruction (defined in the executor).
         /// Can be used to extend instruction set or add expression system.
@@ -1188,6 +1542,40 @@ isi_box! {
     }
 }
 
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(FreezeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Freeze`] instructions.
+    pub enum FreezeBox {
+        /// Freeze [`Account`].
+        Account(Freeze<Account>),
+        /// Freeze [`AssetDefinition`].
+        AssetDefinition(Freeze<AssetDefinition>),
+        /// Freeze [`Asset`].
+        Asset(Freeze<Asset>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(UnfreezeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Unfreeze`] instructions.
+    pub enum UnfreezeBox {
+        /// Unfreeze [`Account`].
+        Account(Unfreeze<Account>),
+        /// Unfreeze [`AssetDefinition`].
+        AssetDefinition(Unfreeze<AssetDefinition>),
+        /// Unfreeze [`Asset`].
+        Asset(Unfreeze<Asset>),
+    }
+}
+
 pub mod error {
     //! Module containing errors that can occur during instruction evaluation
 
@@ -1430,7 +1818,8 @@ pub mod error {
         pub enum InvalidParameterError {
             /// Invalid WASM binary: {0}
             Wasm(String),
-            /// Attempt to register a time-trigger with `start` point in the past
+            /// Attempt to register a time-trigger whose `start` or `end` point is in the past.
+            /// Cron schedules may start in the past only if their occurrences aren't limited
             TimeTriggerInThePast,
         }
 
@@ -1485,9 +1874,11 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Burn, BurnBox, CustomInstruction, ExecuteTrigger, Grant, GrantBox, InstructionBox, Log,
-        Mint, MintBox, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox,
-        SetKeyValue, SetKeyValueBox, SetParameter, Transfer, TransferBox, Unregister,
-        UnregisterBox, Upgrade,
+        AcceptSwap, AddSignatory, Approve, Burn, BurnBox, CancelSwap, CreateSwap,
+        CustomInstruction, EmitEvent, ExecuteTrigger, Freeze, FreezeBox, Grant, GrantBox,
+        InstructionBox, Log, Mint, MintBox, Register, RegisterAlias, RegisterBox, RemoveKeyValue,
+        RemoveKeyValueBox, RemoveSignatory, Revoke, RevokeBox, RotateAccountKey, SetKeyValue,
+        SetKeyValueBox, SetParameter, Transfer, TransferBox, TransferFrom, Unfreeze, UnfreezeBox,
+        Unregister, UnregisterAlias, UnregisterBox, Upgrade,
     };
 }
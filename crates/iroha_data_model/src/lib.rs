@@ -42,12 +42,16 @@ pub mod isi;
 pub mod metadata;
 pub mod name;
 pub mod nft;
+#[cfg(feature = "http")]
+pub mod operator;
 pub mod parameter;
 pub mod peer;
 pub mod permission;
 pub mod query;
 pub mod role;
 pub mod smart_contract;
+pub mod state;
+pub mod swap;
 pub mod transaction;
 pub mod trigger;
 pub mod visit;
@@ -116,10 +120,29 @@ mod seal {
         Revoke<RoleId, Account>,
         Revoke<Permission, Role>,
 
+        Freeze<Account>,
+        Freeze<AssetDefinition>,
+        Freeze<Asset>,
+
+        Unfreeze<Account>,
+        Unfreeze<AssetDefinition>,
+        Unfreeze<Asset>,
+
         SetParameter,
         Upgrade,
         ExecuteTrigger,
         Log,
+        EmitEvent,
+        AddSignatory,
+        RemoveSignatory,
+        RotateAccountKey,
+        RegisterAlias,
+        UnregisterAlias,
+        Approve,
+        TransferFrom,
+        CreateSwap,
+        AcceptSwap,
+        CancelSwap,
 
         // Boxed queries
         SingularQueryBox,
@@ -127,6 +150,8 @@ mod seal {
         FindAccountsWithAsset,
         FindAssets,
         FindAssetsDefinitions,
+        FindAssetAllowances,
+        FindSwaps,
         FindNfts,
         FindDomains,
         FindPeers,
@@ -141,6 +166,10 @@ mod seal {
         FindRoleIds,
         FindRolesByAccountId,
         FindParameters,
+        FindTransactionProof,
+        FindAccountStateProof,
+        FindAssetStateProof,
+        FindAccountByAlias,
     }
 }
 
@@ -263,6 +292,8 @@ mod model {
         PeerId(peer::PeerId),
         /// [`TriggerId`](trigger::TriggerId) variant.
         TriggerId(trigger::TriggerId),
+        /// [`SwapId`](swap::SwapId) variant.
+        SwapId(swap::SwapId),
         /// [`RoleId`](`role::RoleId`) variant.
         RoleId(role::RoleId),
         /// [`Permission`](`permission::Permission`) variant.
@@ -388,6 +419,7 @@ impl_encode_as_id_box! {
     asset::AssetDefinitionId,
     asset::AssetId,
     trigger::TriggerId,
+    swap::SwapId,
     permission::Permission,
     role::RoleId,
 }
@@ -490,7 +522,8 @@ pub mod prelude {
         account::prelude::*, asset::prelude::*, domain::prelude::*, events::prelude::*,
         executor::prelude::*, isi::prelude::*, metadata::prelude::*, name::prelude::*,
         nft::prelude::*, parameter::prelude::*, peer::prelude::*, permission::prelude::*,
-        query::prelude::*, role::prelude::*, transaction::prelude::*, trigger::prelude::*, ChainId,
-        EnumTryAsError, HasMetadata, IdBox, Identifiable, ValidationFail,
+        query::prelude::*, role::prelude::*, state::prelude::*, swap::prelude::*,
+        transaction::prelude::*, trigger::prelude::*, ChainId, EnumTryAsError, HasMetadata, IdBox,
+        Identifiable, ValidationFail,
     };
 }
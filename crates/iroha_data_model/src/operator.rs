@@ -0,0 +1,105 @@
+//! Structures related to authentication of requests to operator endpoints of Torii,
+//! such as configuration updates, status and metrics.
+//!
+//! An operator request carries a signature of [`OperatorRequestPayload`] made with one of the admin keys
+//! configured on the peer. The signature is transferred in HTTP headers along with the public key
+//! and the timestamp.
+
+use core::time::Duration;
+
+use iroha_crypto::{Hash, KeyPair, SignatureOf};
+use iroha_data_model_derive::model;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+
+pub use self::model::*;
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Description of a request to an operator endpoint which is signed by an admin key.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, IntoSchema)]
+    pub struct OperatorRequestPayload {
+        /// HTTP method of the request, e.g. `POST`
+        pub method: String,
+        /// Path of the request URI relative to the Torii root, e.g. `/configuration`
+        pub path: String,
+        /// Hash of the request body
+        pub body_hash: Hash,
+        /// Time the request was created at, as milliseconds since the Unix epoch
+        pub timestamp_ms: u64,
+    }
+}
+
+impl OperatorRequestPayload {
+    /// Construct the payload of a request with the given `body`, created at `timestamp`.
+    pub fn new(
+        method: impl Into<String>,
+        path: impl Into<String>,
+        body: &[u8],
+        timestamp: Duration,
+    ) -> Self {
+        Self {
+            method: method.into(),
+            path: path.into(),
+            body_hash: Hash::new(body),
+            timestamp_ms: timestamp
+                .as_millis()
+                .try_into()
+                .expect("INTERNAL BUG: Unix time in milliseconds exceeds u64"),
+        }
+    }
+
+    /// Time the request was created at, as [`Duration`] since the Unix epoch.
+    pub fn timestamp(&self) -> Duration {
+        Duration::from_millis(self.timestamp_ms)
+    }
+
+    /// Sign the payload with the private key of `key_pair`.
+    pub fn sign(&self, key_pair: &KeyPair) -> SignatureOf<Self> {
+        SignatureOf::new(key_pair.private_key(), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_covers_every_field() {
+        let key_pair = KeyPair::random();
+        let payload = OperatorRequestPayload::new(
+            "POST",
+            "/configuration",
+            b"{}",
+            Duration::from_secs(1_700_000_000),
+        );
+        let signature = payload.sign(&key_pair);
+        signature
+            .verify(key_pair.public_key(), &payload)
+            .expect("payload is signed");
+
+        let tampered = [
+            OperatorRequestPayload {
+                method: "GET".into(),
+                ..payload.clone()
+            },
+            OperatorRequestPayload {
+                path: "/metrics".into(),
+                ..payload.clone()
+            },
+            OperatorRequestPayload {
+                body_hash: Hash::new(b"{\"logger\":null}"),
+                ..payload.clone()
+            },
+            OperatorRequestPayload {
+                timestamp_ms: payload.timestamp_ms + 1,
+                ..payload.clone()
+            },
+        ];
+        for payload in tampered {
+            assert!(signature.verify(key_pair.public_key(), &payload).is_err());
+        }
+    }
+}
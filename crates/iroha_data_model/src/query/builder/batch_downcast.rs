@@ -5,6 +5,7 @@ use std::vec;
 
 use crate::query::{QueryOutputBatchBox, QueryOutputBatchBoxTuple};
 
+/// Iterator over a batch of results of a query which selects a single field
 #[derive(Debug)]
 pub struct TypedBatchIterUntupled<T> {
     t: vec::IntoIter<T>,
@@ -24,6 +25,7 @@ impl<T> ExactSizeIterator for TypedBatchIterUntupled<T> {
     }
 }
 
+/// Error of converting a type-erased batch of query results into the expected type
 #[derive(Debug, Copy, Clone, displaydoc::Display)]
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
 pub enum TypedBatchDowncastError {
@@ -35,8 +37,16 @@ pub enum TypedBatchDowncastError {
     WrongType(usize),
 }
 
+/// Type of query results which can be extracted from a type-erased batch
 pub trait HasTypedBatchIter {
+    /// Iterator over the results in a batch
     type TypedBatchIter: Iterator<Item = Self> + ExactSizeIterator;
+
+    /// Convert a type-erased batch into an iterator over the results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch holds results of a different type.
     fn downcast(
         erased_batch: QueryOutputBatchBoxTuple,
     ) -> Result<Self::TypedBatchIter, TypedBatchDowncastError>;
@@ -74,6 +84,7 @@ macro_rules! typed_batch_tuple {
         )*
     ) => {
         $(
+            /// Iterator over a batch of results of a query which selects multiple fields
             #[derive(Debug)]
             pub struct $name<$($ty),+> {
                 $($ty_name: vec::IntoIter<$ty>),+
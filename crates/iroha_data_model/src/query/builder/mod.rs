@@ -5,22 +5,23 @@ mod iter;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
-use core::marker::PhantomData;
+use core::{marker::PhantomData, num::NonZeroU64};
 
+pub use batch_downcast::{HasTypedBatchIter, TypedBatchDowncastError};
 use derive_where::derive_where;
+use iroha_primitives::numeric::Numeric;
 pub use iter::QueryIterator;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::query::{
-    builder::batch_downcast::HasTypedBatchIter,
     dsl::{
-        BaseProjector, CompoundPredicate, HasPrototype, IntoSelectorTuple, PredicateMarker,
-        SelectorMarker, SelectorTuple,
+        Aggregate, AggregateFunction, BaseProjector, CompoundPredicate, HasPrototype, IntoSelector,
+        IntoSelectorTuple, PredicateMarker, SelectorMarker, SelectorTuple, SortKey,
     },
-    parameters::{FetchSize, Pagination, QueryParams, Sorting},
-    Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithFilter, QueryWithParams, SingularQueryBox,
-    SingularQueryOutputBox,
+    parameters::{FetchSize, Pagination, QueryParams, SortOrder, Sorting},
+    Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithFilter, QueryWithParams,
+    SingularQueryOutputBox, SingularQueryWithParams,
 };
 
 /// A trait abstracting away concrete backend for executing queries against iroha.
@@ -39,7 +40,7 @@ pub trait QueryExecutor {
     /// Returns an error if the query execution fails.
     fn execute_singular_query(
         &self,
-        query: SingularQueryBox,
+        query: SingularQueryWithParams,
     ) -> Result<SingularQueryOutputBox, Self::Error>;
 
     /// Starts an iterable query and returns the first batch of results, the remaining number of results and a cursor to continue the query.
@@ -97,9 +98,17 @@ impl<E> From<E> for SingleQueryError<E> {
     }
 }
 
+/// Type state of a [`QueryBuilder`] returning the matching results themselves.
+#[derive(Debug, Clone, Copy)]
+pub enum NotAggregated {}
+
+/// Type state of a [`QueryBuilder`] returning aggregated values (e.g. after [`QueryBuilder::count`]).
+#[derive(Debug, Clone, Copy)]
+pub enum Aggregated {}
+
 /// Struct that simplifies construction of an iterable query.
-#[derive_where(Clone; Q, CompoundPredicate<Q::Item>, SelectorTuple<Q::Item>)]
-pub struct QueryBuilder<'e, E, Q, T>
+#[derive_where(Clone; Q, CompoundPredicate<Q::Item>, SelectorTuple<Q::Item>, SortKey<Q::Item>, Aggregate<Q::Item>)]
+pub struct QueryBuilder<'e, E, Q, T, A = NotAggregated>
 where
     Q: Query,
 {
@@ -107,11 +116,15 @@ where
     query: Q,
     filter: CompoundPredicate<Q::Item>,
     selector: SelectorTuple<Q::Item>,
+    sort_by: Vec<SortKey<Q::Item>>,
+    aggregate: Option<Aggregate<Q::Item>>,
     pagination: Pagination,
     sorting: Sorting,
     fetch_size: FetchSize,
-    // NOTE: T is a phantom type used to denote the selected tuple in `selector`
-    phantom: PhantomData<T>,
+    at_height: Option<NonZeroU64>,
+    // NOTE: T is a phantom type used to denote the selected tuple in `selector`,
+    // A denotes whether the results are aggregated
+    phantom: PhantomData<(T, A)>,
 }
 
 impl<'a, E, Q> QueryBuilder<'a, E, Q, Q::Item>
@@ -125,15 +138,18 @@ where
             query,
             filter: CompoundPredicate::PASS,
             selector: SelectorTuple::default(),
+            sort_by: Vec::new(),
+            aggregate: None,
             pagination: Pagination::default(),
             sorting: Sorting::default(),
             fetch_size: FetchSize::default(),
+            at_height: None,
             phantom: PhantomData,
         }
     }
 }
 
-impl<'a, E, Q, T> QueryBuilder<'a, E, Q, T>
+impl<'a, E, Q, T, A> QueryBuilder<'a, E, Q, T, A>
 where
     Q: Query,
 {
@@ -169,6 +185,36 @@ where
         self.filter(predicate_builder(Default::default()))
     }
 
+    /// Only return part of the results specified by the pagination.
+    #[must_use]
+    pub fn with_pagination(self, pagination: Pagination) -> Self {
+        Self { pagination, ..self }
+    }
+
+    /// Change the batch size of the iterable query.
+    ///
+    /// Larger batch sizes reduce the number of round-trips to iroha peer, but require more memory.
+    #[must_use]
+    pub fn with_fetch_size(self, fetch_size: FetchSize) -> Self {
+        Self { fetch_size, ..self }
+    }
+
+    /// Query the state as it was right after the block at the given height was committed.
+    ///
+    /// How far back the state can be queried is limited by the peer configuration.
+    #[must_use]
+    pub fn at_height(self, height: NonZeroU64) -> Self {
+        Self {
+            at_height: Some(height),
+            ..self
+        }
+    }
+}
+
+impl<'a, E, Q, T> QueryBuilder<'a, E, Q, T>
+where
+    Q: Query,
+{
     /// Return only the fields of the results specified by the given closure.
     ///
     /// You can select multiple fields by returning a tuple from the closure.
@@ -195,9 +241,12 @@ where
             query: self.query,
             filter: self.filter,
             selector: new_selector,
+            sort_by: self.sort_by,
+            aggregate: self.aggregate,
             pagination: self.pagination,
             sorting: self.sorting,
             fetch_size: self.fetch_size,
+            at_height: self.at_height,
             phantom: PhantomData,
         }
     }
@@ -208,35 +257,202 @@ where
         Self { sorting, ..self }
     }
 
-    /// Only return part of the results specified by the pagination.
+    /// Sort the results by the given key.
+    ///
+    /// If multiple keys are added, the subsequent ones are used to break ties of the previous ones.
     #[must_use]
-    pub fn with_pagination(self, pagination: Pagination) -> Self {
-        Self { pagination, ..self }
+    pub fn sort_by(mut self, key: SortKey<Q::Item>) -> Self {
+        self.sort_by.push(key);
+        self
     }
 
-    /// Change the batch size of the iterable query.
+    /// Sort the results by the field specified by the given closure.
     ///
-    /// Larger batch sizes reduce the number of round-trips to iroha peer, but require more memory.
+    /// If multiple keys are added, the subsequent ones are used to break ties of the previous ones.
     #[must_use]
-    pub fn with_fetch_size(self, fetch_size: FetchSize) -> Self {
-        Self { fetch_size, ..self }
+    pub fn sort_by_with<B, O>(self, f: B, order: SortOrder) -> Self
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelector<SelectingType = Q::Item>,
+    {
+        self.sort_by(SortKey::build(f, order))
     }
 }
 
-impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+// aggregates select the aggregated field themselves, so they are only available before `select_with`
+impl<'a, E, Q> QueryBuilder<'a, E, Q, Q::Item>
 where
     Q: Query,
-    E: QueryExecutor,
-    QueryBox: From<QueryWithFilter<Q>>,
-    T: HasTypedBatchIter,
 {
-    /// Execute the query, returning an iterator over its results.
+    /// Return the number of matching results instead of the results themselves.
     ///
-    /// # Errors
+    /// Use [`QueryBuilder::group_by_with`] to count the results in groups.
+    #[must_use]
+    pub fn count(self) -> QueryBuilder<'a, E, Q, Numeric, Aggregated> {
+        self.aggregate(AggregateFunction::Count)
+    }
+
+    /// Return the sum of the field specified by the given closure instead of the results themselves.
+    #[must_use]
+    pub fn sum_with<B, O>(self, f: B) -> QueryBuilder<'a, E, Q, Numeric, Aggregated>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelector<SelectingType = Q::Item, SelectedType = Numeric>,
+    {
+        self.aggregate(AggregateFunction::Sum(
+            f(Default::default()).into_selector(),
+        ))
+    }
+
+    /// Return the smallest value of the field specified by the given closure instead of the results themselves.
     ///
-    /// Returns an error if the query execution fails.
-    pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
-        let with_filter = QueryWithFilter::new(self.query, self.filter, self.selector);
+    /// Returns no results if nothing matches the query.
+    #[must_use]
+    pub fn min_with<B, O>(self, f: B) -> QueryBuilder<'a, E, Q, Numeric, Aggregated>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelector<SelectingType = Q::Item, SelectedType = Numeric>,
+    {
+        self.aggregate(AggregateFunction::Min(
+            f(Default::default()).into_selector(),
+        ))
+    }
+
+    /// Return the largest value of the field specified by the given closure instead of the results themselves.
+    ///
+    /// Returns no results if nothing matches the query.
+    #[must_use]
+    pub fn max_with<B, O>(self, f: B) -> QueryBuilder<'a, E, Q, Numeric, Aggregated>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelector<SelectingType = Q::Item, SelectedType = Numeric>,
+    {
+        self.aggregate(AggregateFunction::Max(
+            f(Default::default()).into_selector(),
+        ))
+    }
+
+    fn aggregate(
+        self,
+        function: AggregateFunction<Q::Item>,
+    ) -> QueryBuilder<'a, E, Q, Numeric, Aggregated> {
+        QueryBuilder {
+            query_executor: self.query_executor,
+            query: self.query,
+            filter: self.filter,
+            selector: self.selector,
+            sort_by: self.sort_by,
+            aggregate: Some(Aggregate::new(function)),
+            pagination: self.pagination,
+            sorting: self.sorting,
+            fetch_size: self.fetch_size,
+            at_height: self.at_height,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, E, Q> QueryBuilder<'a, E, Q, Numeric, Aggregated>
+where
+    Q: Query,
+{
+    /// Group the results by the field specified by the given closure before aggregating them.
+    ///
+    /// The query returns the aggregated value for each group along with the group key, ordered by the key.
+    #[must_use]
+    pub fn group_by_with<B, O>(
+        self,
+        f: B,
+    ) -> QueryBuilder<'a, E, Q, (O::SelectedType, Numeric), Aggregated>
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelector<SelectingType = Q::Item>,
+    {
+        let aggregate = self
+            .aggregate
+            .expect("aggregated query builders always have an aggregate")
+            .with_group_by(f(Default::default()).into_selector());
+
+        QueryBuilder {
+            query_executor: self.query_executor,
+            query: self.query,
+            filter: self.filter,
+            selector: self.selector,
+            sort_by: self.sort_by,
+            aggregate: Some(aggregate),
+            pagination: self.pagination,
+            sorting: self.sorting,
+            fetch_size: self.fetch_size,
+            at_height: self.at_height,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'e, E, Q, T, A> QueryBuilder<'e, E, Q, T, A>
+where
+    Q: Query,
+    QueryBox: From<QueryWithFilter<Q>>,
+{
+    /// Build the query without executing it, returning it along with the query backend.
+    ///
+    /// Useful for backends which can't implement [`QueryExecutor`], e.g. asynchronous ones.
+    pub fn into_parts(self) -> (&'e E, QueryWithParams) {
+        let mut with_filter =
+            QueryWithFilter::new(self.query, self.filter, self.selector).with_sort_by(self.sort_by);
+        if let Some(aggregate) = self.aggregate {
+            with_filter = with_filter.with_aggregate(aggregate);
+        }
         let boxed: QueryBox = with_filter.into();
 
         let query = QueryWithParams {
@@ -245,11 +461,30 @@ where
                 pagination: self.pagination,
                 sorting: self.sorting,
                 fetch_size: self.fetch_size,
+                at_height: self.at_height,
             },
         };
 
-        let (first_batch, remaining_items, continue_cursor) =
-            self.query_executor.start_query(query)?;
+        (self.query_executor, query)
+    }
+}
+
+impl<E, Q, T, A> QueryBuilder<'_, E, Q, T, A>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+    T: HasTypedBatchIter,
+{
+    /// Execute the query, returning an iterator over its results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
+        let (query_executor, query) = self.into_parts();
+
+        let (first_batch, remaining_items, continue_cursor) = query_executor.start_query(query)?;
 
         let iterator = QueryIterator::<E, T>::new(first_batch, remaining_items, continue_cursor)
             .expect(
@@ -289,7 +524,7 @@ where
     fn execute_single(self) -> Result<T, SingleQueryError<E::Error>>;
 }
 
-impl<E, Q, T> QueryBuilderExt<E, Q, T> for QueryBuilder<'_, E, Q, T>
+impl<E, Q, T, A> QueryBuilderExt<E, Q, T> for QueryBuilder<'_, E, Q, T, A>
 where
     E: QueryExecutor,
     Q: Query,
@@ -0,0 +1,61 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use derive_where::derive_where;
+use iroha_macro::serde_where;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::query::dsl::{HasProjection, SelectorMarker};
+
+/// An aggregation of the results of an iterable query.
+///
+/// Instead of returning the matching items, the query returns a single aggregated [`Numeric`](iroha_primitives::numeric::Numeric) value.
+/// If `group_by` is specified, the items are grouped by the selected field and the query returns a `(key, value)` pair for each group, ordered by the key.
+#[derive_where(Debug, Eq, PartialEq, Clone; T::Projection)]
+#[serde_where(T::Projection)]
+#[derive(Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct Aggregate<T: HasProjection<SelectorMarker, AtomType = ()>> {
+    /// Function used to aggregate the items (of each group).
+    pub function: AggregateFunction<T>,
+    /// Selector of the field to group the items by.
+    #[serde(default)]
+    pub group_by: Option<T::Projection>,
+}
+
+/// A function aggregating a collection of items into a single [`Numeric`](iroha_primitives::numeric::Numeric) value.
+///
+/// The selectors must select a `Numeric` field of `T`, otherwise the query fails.
+#[derive_where(Debug, Eq, PartialEq, Clone; T::Projection)]
+#[serde_where(T::Projection)]
+#[derive(Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub enum AggregateFunction<T: HasProjection<SelectorMarker, AtomType = ()>> {
+    /// Number of items.
+    Count,
+    /// Sum of the selected values. The sum of no values is zero.
+    Sum(T::Projection),
+    /// Smallest of the selected values. Not defined for no values.
+    Min(T::Projection),
+    /// Largest of the selected values. Not defined for no values.
+    Max(T::Projection),
+}
+
+impl<T: HasProjection<SelectorMarker, AtomType = ()>> Aggregate<T> {
+    /// Create a new aggregation of all the items.
+    pub fn new(function: AggregateFunction<T>) -> Self {
+        Self {
+            function,
+            group_by: None,
+        }
+    }
+
+    /// Group the items by the selected field before aggregating them.
+    #[must_use]
+    pub fn with_group_by(self, group_by: T::Projection) -> Self {
+        Self {
+            group_by: Some(group_by),
+            ..self
+        }
+    }
+}
@@ -69,23 +69,37 @@
 //! This is the type that is actually sent when a query is requested.
 //!
 //! A selector also selects just a single field. To allow selecting multiple fields, [`SelectorTuple`] is used in queries.
+//!
+//! # Sort keys
+//!
+//! Selectors are also used to specify the fields to sort the query results by.
+//! A [`SortKey`] pairs a selector with a sorting direction, multiple keys can be combined to break ties.
+//!
+//! # Aggregates
+//!
+//! Instead of returning the items, a query can aggregate them into a single value with an [`Aggregate`].
+//! Selectors specify the numeric field to aggregate and, optionally, the field to group the items by.
 
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String, vec::Vec};
 use core::marker::PhantomData;
 
+mod aggregate;
 mod compound_predicate;
 pub mod predicates;
 mod selector_traits;
 mod selector_tuple;
+mod sort_key;
 pub mod type_descriptions;
 
 use iroha_schema::IntoSchema;
 
 pub use self::{
+    aggregate::{Aggregate, AggregateFunction},
     compound_predicate::CompoundPredicate,
     selector_traits::{IntoSelector, IntoSelectorTuple},
     selector_tuple::SelectorTuple,
+    sort_key::SortKey,
 };
 use crate::query::{error::QueryExecutionFail, QueryOutputBatchBox};
 
@@ -206,6 +220,7 @@ where
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        predicates::prelude::*, type_descriptions::prelude::*, CompoundPredicate, SelectorTuple,
+        predicates::prelude::*, type_descriptions::prelude::*, Aggregate, AggregateFunction,
+        CompoundPredicate, SelectorTuple, SortKey,
     };
 }
@@ -4,11 +4,14 @@
 use alloc::{format, string::String, vec::Vec};
 
 use iroha_crypto::{HashOf, PublicKey};
-use iroha_primitives::{json::Json, numeric::Numeric};
+use iroha_primitives::{
+    json::Json,
+    numeric::{Numeric, NumericSpec, NumericSpecError},
+};
 
 use crate::{
-    account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
+    account::{Account, AccountAlias, AccountId},
+    asset::{Asset, AssetAllowance, AssetAllowanceId, AssetDefinition, AssetDefinitionId, AssetId},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
@@ -20,21 +23,23 @@ use crate::{
     query::{
         dsl::{
             type_descriptions::{
-                AccountIdPrototype, AccountPrototype, ActionPrototype, AssetDefinitionIdPrototype,
+                AccountAliasPrototype, AccountIdPrototype, AccountPrototype, ActionPrototype,
+                AssetAllowanceIdPrototype, AssetAllowancePrototype, AssetDefinitionIdPrototype,
                 AssetDefinitionPrototype, AssetIdPrototype, AssetPrototype,
                 BlockHeaderHashPrototype, BlockHeaderPrototype, CommittedTransactionPrototype,
                 DomainIdPrototype, DomainPrototype, JsonPrototype, MetadataPrototype,
                 NamePrototype, NftIdPrototype, NftPrototype, NumericPrototype, ParameterPrototype,
                 PeerIdPrototype, PermissionPrototype, PublicKeyPrototype, RoleIdPrototype,
                 RolePrototype, SignedBlockPrototype, SignedTransactionPrototype, StringPrototype,
-                TransactionErrorPrototype, TransactionHashPrototype, TriggerIdPrototype,
-                TriggerPrototype,
+                SwapIdPrototype, SwapPrototype, TransactionErrorPrototype,
+                TransactionHashPrototype, TriggerIdPrototype, TriggerPrototype, U64Prototype,
             },
             CompoundPredicate, ObjectProjector, PredicateMarker,
         },
         CommittedTransaction,
     },
     role::{Role, RoleId},
+    swap::{Swap, SwapId},
     transaction::{error::TransactionRejectionReason, SignedTransaction},
     trigger::{action, Trigger, TriggerId},
 };
@@ -254,6 +259,168 @@ where
     }
 }
 
+/// A range of [`Numeric`] values, including `start` and excluding `end`, like [`core::ops::Range`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    serde::Deserialize,
+    serde::Serialize,
+    iroha_schema::IntoSchema,
+)]
+pub struct NumericRange {
+    /// The lower bound of the range (inclusive).
+    pub start: Numeric,
+    /// The upper bound of the range (exclusive).
+    pub end: Numeric,
+}
+
+impl NumericRange {
+    /// Construct a new range from `start` (inclusive) to `end` (exclusive).
+    pub fn new(start: impl Into<Numeric>, end: impl Into<Numeric>) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+
+    /// Checks if the value is contained in the range. An empty range (`start >= end`) contains nothing.
+    pub fn contains(&self, value: &Numeric) -> bool {
+        self.start <= *value && *value < self.end
+    }
+}
+
+impl From<core::ops::Range<Numeric>> for NumericRange {
+    fn from(range: core::ops::Range<Numeric>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+/// An atomic predicate on [`Numeric`]
+///
+/// Comparisons are done by value, so numbers with different scales compare equal when they represent the same quantity (e.g. `1.50 == 1.5`).
+/// When comparing quantities of an asset definition, the expected values must be representable within its [`NumericSpec`],
+/// otherwise the query is rejected (see [`NumericPredicateAtom::check_spec`]).
+// Defined separately to allow the constructors accept anything convertible to [`Numeric`]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    serde::Deserialize,
+    serde::Serialize,
+    iroha_schema::IntoSchema,
+)]
+// it's better for future compatibility, since adding branches can make the atom non-`Copy`
+#[allow(missing_copy_implementations)]
+pub enum NumericPredicateAtom {
+    /// Checks if the input is equal to the expected value.
+    Equals(Numeric),
+    /// Checks if the input is strictly less than the expected value.
+    LessThan(Numeric),
+    /// Checks if the input is strictly greater than the expected value.
+    GreaterThan(Numeric),
+    /// Checks if the input is contained in the expected range.
+    InRange(NumericRange),
+    /// Checks if the input is zero.
+    IsZero,
+}
+
+impl NumericPredicateAtom {
+    /// Check that the expected values can be represented within `spec`, i.e. that they are not more precise than the compared quantities.
+    ///
+    /// Trailing zeros don't count, so `1.50` fits a spec with the scale of `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericSpecError::ScaleTooHigh`] if any of the expected values doesn't fit the `spec`.
+    pub fn check_spec(&self, spec: NumericSpec) -> Result<(), NumericSpecError> {
+        let fits = |value: &Numeric| value.round(spec) == *value;
+        let fit = match self {
+            NumericPredicateAtom::Equals(expected)
+            | NumericPredicateAtom::LessThan(expected)
+            | NumericPredicateAtom::GreaterThan(expected) => fits(expected),
+            NumericPredicateAtom::InRange(range) => fits(&range.start) && fits(&range.end),
+            NumericPredicateAtom::IsZero => true,
+        };
+        if fit {
+            Ok(())
+        } else {
+            Err(NumericSpecError::ScaleTooHigh)
+        }
+    }
+}
+
+impl super::HasPredicateAtom for Numeric {
+    type Predicate = NumericPredicateAtom;
+}
+
+impl super::EvaluatePredicate<Numeric> for NumericPredicateAtom {
+    fn applies(&self, input: &Numeric) -> bool {
+        match self {
+            NumericPredicateAtom::Equals(expected) => input == expected,
+            NumericPredicateAtom::LessThan(expected) => input < expected,
+            NumericPredicateAtom::GreaterThan(expected) => input > expected,
+            NumericPredicateAtom::InRange(range) => range.contains(input),
+            NumericPredicateAtom::IsZero => input.is_zero(),
+        }
+    }
+}
+
+impl<Projector> NumericPrototype<PredicateMarker, Projector>
+where
+    Projector: ObjectProjector<PredicateMarker, InputType = Numeric>,
+{
+    /// Checks if the input is equal to the expected value.
+    pub fn eq(self, expected: impl Into<Numeric>) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(NumericPredicateAtom::Equals(expected.into())),
+        )
+    }
+
+    /// Checks if the input is strictly less than the expected value.
+    pub fn lt(self, expected: impl Into<Numeric>) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(NumericPredicateAtom::LessThan(expected.into())),
+        )
+    }
+
+    /// Checks if the input is strictly greater than the expected value.
+    pub fn gt(self, expected: impl Into<Numeric>) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(NumericPredicateAtom::GreaterThan(expected.into())),
+        )
+    }
+
+    /// Checks if the input is contained in the expected range (`start` inclusive, `end` exclusive).
+    pub fn in_range(
+        self,
+        range: impl Into<NumericRange>,
+    ) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(NumericPredicateAtom::InRange(range.into())),
+        )
+    }
+
+    /// Checks if the input is zero.
+    pub fn is_zero(self) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(self.projector.wrap_atom(NumericPredicateAtom::IsZero))
+    }
+}
+
 impl_predicate_atom! {
     MetadataPredicateAtom(_input: Metadata) [MetadataPrototype] {
         // TODO: populate
@@ -266,8 +433,13 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: Json) [eq] => input == expected,
     }
-    NumericPredicateAtom(_input: Numeric) [NumericPrototype] {
-        // TODO: populate
+    U64PredicateAtom(input: u64) [U64Prototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: u64) [eq] => input == expected,
+        /// Checks if the input is strictly less than the expected value.
+        LessThan(expected: u64) [lt] => input < expected,
+        /// Checks if the input is strictly greater than the expected value.
+        GreaterThan(expected: u64) [gt] => input > expected,
     }
 
     // account
@@ -276,6 +448,12 @@ impl_predicate_atom! {
         Equals(expected: AccountId) [eq] => input == expected,
     }
     AccountPredicateAtom(_input: Account) [AccountPrototype] {}
+    AccountAliasPredicateAtom(input: Option<AccountAlias>) [AccountAliasPrototype] {
+        /// Checks if the account has the expected alias.
+        Equals(expected: AccountAlias) [eq] => input.as_ref() == Some(expected),
+        /// Checks if the account has an alias.
+        IsSome [is_some] => input.is_some(),
+    }
 
     // asset
     AssetDefinitionPredicateAtom(_input: AssetDefinition) [AssetDefinitionPrototype] {}
@@ -288,6 +466,11 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: AssetDefinitionId) [eq] => input == expected,
     }
+    AssetAllowancePredicateAtom(_input: AssetAllowance) [AssetAllowancePrototype] {}
+    AssetAllowanceIdPredicateAtom(input: AssetAllowanceId) [AssetAllowanceIdPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: AssetAllowanceId) [eq] => input == expected,
+    }
 
     // NFT
     NftPredicateAtom(_input: Nft) [NftPrototype] {}
@@ -296,6 +479,13 @@ impl_predicate_atom! {
         Equals(expected: NftId) [eq] => input == expected,
     }
 
+    // swap
+    SwapIdPredicateAtom(input: SwapId) [SwapIdPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: SwapId) [eq] => input == expected,
+    }
+    SwapPredicateAtom(_input: Swap) [SwapPrototype] {}
+
     // block
     BlockHeaderHashPredicateAtom(input: HashOf<BlockHeader>) [BlockHeaderHashPrototype] {
         /// Checks if the input is equal to the expected value.
@@ -352,14 +542,51 @@ impl_predicate_atom! {
 pub mod prelude {
     //! Re-export all predicate boxes for a glob import `(::*)`
     pub use super::{
-        AccountIdPredicateAtom, AccountPredicateAtom, ActionPredicateAtom,
+        AccountAliasPredicateAtom, AccountIdPredicateAtom, AccountPredicateAtom,
+        ActionPredicateAtom, AssetAllowanceIdPredicateAtom, AssetAllowancePredicateAtom,
         AssetDefinitionIdPredicateAtom, AssetDefinitionPredicateAtom, AssetIdPredicateAtom,
         AssetPredicateAtom, BlockHeaderHashPredicateAtom, BlockHeaderPredicateAtom,
         CommittedTransactionPredicateAtom, DomainIdPredicateAtom, DomainPredicateAtom,
         JsonPredicateAtom, MetadataPredicateAtom, NftIdPredicateAtom, NftPredicateAtom,
-        NumericPredicateAtom, ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
-        PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom, SignedBlockPredicateAtom,
-        SignedTransactionPredicateAtom, StringPredicateAtom, TransactionErrorPredicateAtom,
+        NumericPredicateAtom, NumericRange, ParameterPredicateAtom, PeerIdPredicateAtom,
+        PermissionPredicateAtom, PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom,
+        SignedBlockPredicateAtom, SignedTransactionPredicateAtom, StringPredicateAtom,
+        SwapIdPredicateAtom, SwapPredicateAtom, TransactionErrorPredicateAtom,
         TransactionHashPredicateAtom, TriggerIdPredicateAtom, TriggerPredicateAtom,
+        U64PredicateAtom,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use iroha_primitives::numeric::{numeric, Numeric, NumericSpec};
+
+    use super::{NumericPredicateAtom, NumericRange};
+
+    #[test]
+    fn expected_values_are_checked_against_spec() {
+        let spec = NumericSpec::fractional(1);
+
+        assert!(NumericPredicateAtom::Equals(numeric!(1.5))
+            .check_spec(spec)
+            .is_ok());
+        // trailing zeros don't make the value more precise
+        assert!(NumericPredicateAtom::LessThan(numeric!(1.50))
+            .check_spec(spec)
+            .is_ok());
+        assert!(NumericPredicateAtom::GreaterThan(numeric!(1.05))
+            .check_spec(spec)
+            .is_err());
+        assert!(
+            NumericPredicateAtom::InRange(NumericRange::new(numeric!(1), numeric!(2.25)))
+                .check_spec(spec)
+                .is_err()
+        );
+        assert!(NumericPredicateAtom::Equals(numeric!(1.5))
+            .check_spec(NumericSpec::integer())
+            .is_err());
+        assert!(NumericPredicateAtom::IsZero
+            .check_spec(NumericSpec::integer())
+            .is_ok());
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use derive_where::derive_where;
+use iroha_macro::serde_where;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::query::{
+    dsl::{BaseProjector, HasProjection, HasPrototype, IntoSelector, SelectorMarker},
+    parameters::SortOrder,
+};
+
+/// A key to sort the results of an iterable query by.
+///
+/// The key is a field of `T` selected with a selector projection, the same way fields are selected in a [`SelectorTuple`](super::SelectorTuple).
+/// When multiple keys are specified, the subsequent keys are used to break the ties of the previous ones.
+#[derive_where(Debug, Eq, PartialEq, Clone; T::Projection)]
+#[serde_where(T::Projection)]
+#[derive(Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct SortKey<T: HasProjection<SelectorMarker, AtomType = ()>> {
+    /// Selector of the field to sort by.
+    pub selector: T::Projection,
+    /// Direction of sorting.
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+impl<T: HasProjection<SelectorMarker, AtomType = ()>> SortKey<T> {
+    /// Create a new sort key from a selector projection and the sorting direction.
+    pub fn new(selector: T::Projection, order: SortOrder) -> Self {
+        Self { selector, order }
+    }
+
+    /// Build a sort key using a prototype.
+    pub fn build<F, O>(f: F, order: SortOrder) -> Self
+    where
+        T: HasPrototype,
+        F: FnOnce(
+            <T as HasPrototype>::Prototype<SelectorMarker, BaseProjector<SelectorMarker, T>>,
+        ) -> O,
+        <T as HasPrototype>::Prototype<SelectorMarker, BaseProjector<SelectorMarker, T>>: Default,
+        O: IntoSelector<SelectingType = T>,
+    {
+        Self::new(f(Default::default()).into_selector(), order)
+    }
+}
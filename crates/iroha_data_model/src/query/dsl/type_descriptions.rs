@@ -13,8 +13,8 @@ use crate::query::dsl::{
     ObjectProjector, PredicateMarker, Projectable, SelectorMarker,
 };
 use crate::{
-    account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
+    account::{Account, AccountAlias, AccountId},
+    asset::{Asset, AssetAllowance, AssetAllowanceId, AssetDefinition, AssetDefinitionId, AssetId},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
@@ -28,6 +28,7 @@ use crate::{
         CommittedTransaction, QueryOutputBatchBox,
     },
     role::{Role, RoleId},
+    swap::{Swap, SwapId},
     transaction::{error::TransactionRejectionReason, SignedTransaction},
     trigger::{action, Trigger, TriggerId},
 };
@@ -243,20 +244,23 @@ macro_rules! type_descriptions {
 
 type_descriptions! {
     // Type[ProjectionName, PrototypeName]: Dependency1, Dependency2, ...
-    Account[AccountProjection, AccountPrototype]: AccountId, DomainId, Name, PublicKey, Metadata, Json {
+    Account[AccountProjection, AccountPrototype]: AccountId, DomainId, Name, PublicKey, Option<AccountAlias>, Metadata, Json {
         // field_name(ProjectionVariant, ProjectorName): FieldType
         id(Id, AccountIdProjector): AccountId,
+        alias(Alias, AccountAliasProjector): Option<AccountAlias>,
         metadata(Metadata, AccountMetadataProjector): Metadata,
     }
     AccountId[AccountIdProjection, AccountIdPrototype]: DomainId, Name, PublicKey {
         domain(Domain, AccountIdDomainProjector): DomainId,
         signatory(Signatory, AccountIdSignatoryProjector): PublicKey,
     }
+    Option<AccountAlias>[AccountAliasProjection, AccountAliasPrototype] {}
 
     // asset
-    AssetDefinition[AssetDefinitionProjection, AssetDefinitionPrototype]: AssetDefinitionId, DomainId, Name, Metadata, Json {
+    AssetDefinition[AssetDefinitionProjection, AssetDefinitionPrototype]: AssetDefinitionId, DomainId, Name, Metadata, Json, Numeric {
         id(Id, AssetDefinitionIdProjector): AssetDefinitionId,
         metadata(Metadata, AssetDefinitionMetadataProjector): Metadata,
+        total_quantity(TotalQuantity, AssetDefinitionTotalQuantityProjector): Numeric,
     }
     AssetDefinitionId[AssetDefinitionIdProjection, AssetDefinitionIdPrototype]: DomainId, Name {
         domain(Domain, AssetDefinitionIdDomainProjector): DomainId,
@@ -270,6 +274,14 @@ type_descriptions! {
         account(Account, AssetIdAccountProjector): AccountId,
         definition(Definition, AssetIdDefinitionProjector): AssetDefinitionId,
     }
+    AssetAllowance[AssetAllowanceProjection, AssetAllowancePrototype]: AssetAllowanceId, AssetId, AccountId, DomainId, Name, PublicKey, AssetDefinitionId, Numeric {
+        id(Id, AssetAllowanceIdProjector): AssetAllowanceId,
+        amount(Amount, AssetAllowanceAmountProjector): Numeric,
+    }
+    AssetAllowanceId[AssetAllowanceIdProjection, AssetAllowanceIdPrototype]: AssetId, AccountId, DomainId, Name, PublicKey, AssetDefinitionId {
+        asset(Asset, AssetAllowanceIdAssetProjector): AssetId,
+        spender(Spender, AssetAllowanceIdSpenderProjector): AccountId,
+    }
 
     // NFT
     Nft[NftProjection, NftPrototype]: NftId, DomainId, AccountId, PublicKey, Name, Metadata, Json {
@@ -282,11 +294,21 @@ type_descriptions! {
         name(Name, NftIdNameProjector): Name,
     }
 
+    // swap
+    SwapId[SwapIdProjection, SwapIdPrototype]: Name {
+        name(Name, SwapIdNameProjector): Name,
+    }
+    Swap[SwapProjection, SwapPrototype]: SwapId, Name, AccountId, DomainId, PublicKey {
+        id(Id, SwapIdProjector): SwapId,
+        maker(Maker, SwapMakerProjector): AccountId,
+    }
+
     // block
     HashOf<BlockHeader>[BlockHeaderHashProjection, BlockHeaderHashPrototype] {}
     #[custom_evaluate] // hash needs to be computed on-the-fly
-    BlockHeader[BlockHeaderProjection, BlockHeaderPrototype]: HashOf<BlockHeader> {
+    BlockHeader[BlockHeaderProjection, BlockHeaderPrototype]: HashOf<BlockHeader>, u64 {
         hash(Hash, BlockHeaderHashProjector): HashOf<BlockHeader>,
+        creation_time_ms(CreationTimeMs, BlockHeaderCreationTimeMsProjector): u64,
     }
     #[custom_evaluate] // SignedBlock is opaque, so `header` is a method
     SignedBlock[SignedBlockProjection, SignedBlockPrototype]: BlockHeader, HashOf<BlockHeader> {
@@ -355,6 +377,7 @@ type_descriptions! {
     PublicKey[PublicKeyProjection, PublicKeyPrototype] {}
     Json[JsonProjection, JsonPrototype] {}
     Numeric[NumericProjection, NumericPrototype] {}
+    u64[U64Projection, U64Prototype] {}
 }
 
 /// A set of helpers for [`EvaluateSelector`] implementations that are fallible
@@ -474,6 +497,9 @@ impl EvaluatePredicate<BlockHeader> for BlockHeaderProjection<PredicateMarker> {
         match self {
             BlockHeaderProjection::Atom(atom) => atom.applies(input),
             BlockHeaderProjection::Hash(hash) => hash.applies(&input.hash()),
+            BlockHeaderProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.applies(&input.creation_time_ms)
+            }
         }
     }
 }
@@ -487,6 +513,9 @@ impl EvaluateSelector<BlockHeader> for BlockHeaderProjection<SelectorMarker> {
         match self {
             BlockHeaderProjection::Atom(()) => Ok(batch.copied().collect::<Vec<_>>().into()),
             BlockHeaderProjection::Hash(hash) => hash.project(batch.map(BlockHeader::hash)),
+            BlockHeaderProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.project(batch.map(|item| item.creation_time_ms))
+            }
         }
     }
 
@@ -497,6 +526,9 @@ impl EvaluateSelector<BlockHeader> for BlockHeaderProjection<SelectorMarker> {
         match self {
             BlockHeaderProjection::Atom(()) => Ok(batch.collect::<Vec<_>>().into()),
             BlockHeaderProjection::Hash(hash) => hash.project(batch.map(|item| item.hash())),
+            BlockHeaderProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.project(batch.map(|item| item.creation_time_ms))
+            }
         }
     }
 }
@@ -9,11 +9,12 @@ use alloc::{
     string::String,
     vec::{self, Vec},
 };
+use core::num::NonZeroU64;
 #[cfg(feature = "std")]
 use std::vec;
 
 use derive_more::Constructor;
-use iroha_crypto::{PublicKey, SignatureOf};
+use iroha_crypto::{HashOf, PublicKey, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_primitives::{json::Json, numeric::Numeric};
@@ -26,11 +27,11 @@ use serde::{Deserialize, Serialize};
 pub use self::model::*;
 use self::{
     account::*, asset::*, block::*, domain::*, dsl::*, executor::*, nft::*, peer::*, permission::*,
-    role::*, transaction::*, trigger::*,
+    role::*, swap::*, transaction::*, trigger::*,
 };
 use crate::{
-    account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
+    account::{Account, AccountAlias, AccountId},
+    asset::{Asset, AssetAllowance, AssetAllowanceId, AssetDefinition, AssetDefinitionId, AssetId},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
@@ -41,6 +42,7 @@ use crate::{
     permission::Permission,
     role::{Role, RoleId},
     seal::Sealed,
+    swap::{Swap, SwapId},
     transaction::{error::TransactionRejectionReason, SignedTransaction},
     trigger::{Trigger, TriggerId},
 };
@@ -70,18 +72,18 @@ pub trait Query: Sealed {
 mod model {
     use derive_where::derive_where;
     use getset::Getters;
-    use iroha_crypto::HashOf;
+    use iroha_crypto::{HashOf, MerkleProof};
     use iroha_macro::serde_where;
 
     use super::*;
     use crate::trigger::action;
 
     /// An iterable query bundled with a filter
-    #[serde_where(Q, CompoundPredicate<Q::Item>, SelectorTuple<Q::Item>)]
+    #[serde_where(Q, CompoundPredicate<Q::Item>, SelectorTuple<Q::Item>, SortKey<Q::Item>, Aggregate<Q::Item>)]
     #[derive_where(
-        Debug, Clone, PartialEq, Eq; Q, CompoundPredicate<Q::Item>, SelectorTuple<Q::Item>
+        Debug, Clone, PartialEq, Eq; Q, CompoundPredicate<Q::Item>, SelectorTuple<Q::Item>, SortKey<Q::Item>, Aggregate<Q::Item>
     )]
-    #[derive(Decode, Encode, Constructor, IntoSchema, Deserialize, Serialize)]
+    #[derive(Decode, Encode, IntoSchema, Deserialize, Serialize)]
     pub struct QueryWithFilter<Q>
     where
         Q: Query,
@@ -91,6 +93,12 @@ mod model {
         pub predicate: CompoundPredicate<Q::Item>,
         #[serde(default)]
         pub selector: SelectorTuple<Q::Item>,
+        /// Keys to sort the results by, applied before pagination. Subsequent keys break ties of the previous ones.
+        #[serde(default)]
+        pub sort_by: Vec<SortKey<Q::Item>>,
+        /// If set, the matching items are aggregated instead of being returned. The `selector` is ignored in this case.
+        #[serde(default)]
+        pub aggregate: Option<Aggregate<Q::Item>>,
     }
 
     fn predicate_default<T>() -> CompoundPredicate<T>
@@ -109,7 +117,9 @@ mod model {
         FindAccounts(QueryWithFilter<FindAccounts>),
         FindAssets(QueryWithFilter<FindAssets>),
         FindAssetsDefinitions(QueryWithFilter<FindAssetsDefinitions>),
+        FindAssetAllowances(QueryWithFilter<FindAssetAllowances>),
         FindNfts(QueryWithFilter<FindNfts>),
+        FindSwaps(QueryWithFilter<FindSwaps>),
         FindRoles(QueryWithFilter<FindRoles>),
 
         FindRoleIds(QueryWithFilter<FindRoleIds>),
@@ -142,12 +152,17 @@ mod model {
         Domain(Vec<Domain>),
         AccountId(Vec<AccountId>),
         Account(Vec<Account>),
+        AccountAlias(Vec<Option<AccountAlias>>),
         AssetId(Vec<AssetId>),
         Asset(Vec<Asset>),
+        AssetAllowanceId(Vec<AssetAllowanceId>),
+        AssetAllowance(Vec<AssetAllowance>),
         AssetDefinitionId(Vec<AssetDefinitionId>),
         AssetDefinition(Vec<AssetDefinition>),
         NftId(Vec<NftId>),
         Nft(Vec<Nft>),
+        SwapId(Vec<SwapId>),
+        Swap(Vec<Swap>),
         Role(Vec<Role>),
         Parameter(Vec<Parameter>),
         Permission(Vec<Permission>),
@@ -163,6 +178,7 @@ mod model {
         Block(Vec<SignedBlock>),
         BlockHeader(Vec<BlockHeader>),
         BlockHeaderHash(Vec<HashOf<BlockHeader>>),
+        U64(Vec<u64>),
     }
 
     #[derive(
@@ -179,6 +195,10 @@ mod model {
     pub enum SingularQueryBox {
         FindExecutorDataModel(FindExecutorDataModel),
         FindParameters(FindParameters),
+        FindTransactionProof(FindTransactionProof),
+        FindAccountStateProof(FindAccountStateProof),
+        FindAssetStateProof(FindAssetStateProof),
+        FindAccountByAlias(FindAccountByAlias),
     }
 
     /// An enum of all possible singular query outputs
@@ -188,6 +208,9 @@ mod model {
     pub enum SingularQueryOutputBox {
         ExecutorDataModel(crate::executor::ExecutorDataModel),
         Parameters(Parameters),
+        TransactionProof(TransactionProof),
+        StateProof(crate::state::StateProof),
+        AccountId(AccountId),
     }
 
     /// The results of a single iterable query request.
@@ -211,12 +234,25 @@ mod model {
         pub params: QueryParams,
     }
 
+    /// A type-erased singular query, along with all the parameters needed to execute it
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub struct SingularQueryWithParams {
+        pub query: SingularQueryBox,
+        /// Height of the block after which the state is queried.
+        ///
+        /// If not specified then the latest state is queried.
+        #[serde(default)]
+        pub at_height: Option<NonZeroU64>,
+    }
+
     /// A query request that can be sent to an Iroha peer.
     ///
     /// In case of HTTP API, the query request must also be signed (see [`QueryRequestWithAuthority`] and [`SignedQuery`]).
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     pub enum QueryRequest {
-        Singular(SingularQueryBox),
+        Singular(SingularQueryWithParams),
         Start(QueryWithParams),
         Continue(ForwardCursor),
     }
@@ -249,7 +285,9 @@ mod model {
     declare_versioned!(SignedQuery 1..2, Debug, Clone, FromVariant, IntoSchema);
 
     /// A signed and authorized query request
-    #[derive(Debug, Clone, Encode, Serialize, IntoSchema)]
+    ///
+    /// The signature is verified by the peer against the signatories of the authority.
+    #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     #[version_with_scale(version = 1, versioned_alias = "SignedQuery")]
     pub struct SignedQueryV1 {
         pub signature: QuerySignature,
@@ -282,6 +320,50 @@ mod model {
         /// Reason of rejection, if any
         pub error: Option<TransactionRejectionReason>,
     }
+
+    /// Output of [`FindTransactionProof`] query
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    pub struct TransactionProof {
+        /// Header of the block to which the transaction belongs to
+        pub block_header: BlockHeader,
+        /// Proof of inclusion of the transaction hash into [`BlockHeader::transactions_hash`]
+        pub proof: MerkleProof<SignedTransaction>,
+    }
+}
+
+impl<Q: Query> QueryWithFilter<Q> {
+    /// Create a new [`QueryWithFilter`] with results left in their natural order.
+    pub fn new(
+        query: Q,
+        predicate: CompoundPredicate<Q::Item>,
+        selector: SelectorTuple<Q::Item>,
+    ) -> Self {
+        Self {
+            query,
+            predicate,
+            selector,
+            sort_by: Vec::new(),
+            aggregate: None,
+        }
+    }
+
+    /// Sort the results by the given keys.
+    #[must_use]
+    pub fn with_sort_by(self, sort_by: Vec<SortKey<Q::Item>>) -> Self {
+        Self { sort_by, ..self }
+    }
+
+    /// Aggregate the matching items instead of returning them.
+    #[must_use]
+    pub fn with_aggregate(self, aggregate: Aggregate<Q::Item>) -> Self {
+        Self {
+            aggregate: Some(aggregate),
+            ..self
+        }
+    }
 }
 
 impl QueryOutputBatchBox {
@@ -302,12 +384,17 @@ impl QueryOutputBatchBox {
             (Self::Domain(v1), Self::Domain(v2)) => v1.extend(v2),
             (Self::AccountId(v1), Self::AccountId(v2)) => v1.extend(v2),
             (Self::Account(v1), Self::Account(v2)) => v1.extend(v2),
+            (Self::AccountAlias(v1), Self::AccountAlias(v2)) => v1.extend(v2),
             (Self::AssetId(v1), Self::AssetId(v2)) => v1.extend(v2),
             (Self::Asset(v1), Self::Asset(v2)) => v1.extend(v2),
+            (Self::AssetAllowanceId(v1), Self::AssetAllowanceId(v2)) => v1.extend(v2),
+            (Self::AssetAllowance(v1), Self::AssetAllowance(v2)) => v1.extend(v2),
             (Self::AssetDefinitionId(v1), Self::AssetDefinitionId(v2)) => v1.extend(v2),
             (Self::AssetDefinition(v1), Self::AssetDefinition(v2)) => v1.extend(v2),
             (Self::NftId(v1), Self::NftId(v2)) => v1.extend(v2),
             (Self::Nft(v1), Self::Nft(v2)) => v1.extend(v2),
+            (Self::SwapId(v1), Self::SwapId(v2)) => v1.extend(v2),
+            (Self::Swap(v1), Self::Swap(v2)) => v1.extend(v2),
             (Self::Role(v1), Self::Role(v2)) => v1.extend(v2),
             (Self::Parameter(v1), Self::Parameter(v2)) => v1.extend(v2),
             (Self::Permission(v1), Self::Permission(v2)) => v1.extend(v2),
@@ -325,6 +412,7 @@ impl QueryOutputBatchBox {
             (Self::Block(v1), Self::Block(v2)) => v1.extend(v2),
             (Self::BlockHeader(v1), Self::BlockHeader(v2)) => v1.extend(v2),
             (Self::BlockHeaderHash(v1), Self::BlockHeaderHash(v2)) => v1.extend(v2),
+            (Self::U64(v1), Self::U64(v2)) => v1.extend(v2),
             _ => panic!("Cannot extend different types of IterableQueryOutputBatchBox"),
         }
     }
@@ -343,12 +431,17 @@ impl QueryOutputBatchBox {
             Self::Domain(v) => v.len(),
             Self::AccountId(v) => v.len(),
             Self::Account(v) => v.len(),
+            Self::AccountAlias(v) => v.len(),
             Self::AssetId(v) => v.len(),
             Self::Asset(v) => v.len(),
+            Self::AssetAllowanceId(v) => v.len(),
+            Self::AssetAllowance(v) => v.len(),
             Self::AssetDefinitionId(v) => v.len(),
             Self::AssetDefinition(v) => v.len(),
             Self::NftId(v) => v.len(),
             Self::Nft(v) => v.len(),
+            Self::SwapId(v) => v.len(),
+            Self::Swap(v) => v.len(),
             Self::Role(v) => v.len(),
             Self::Parameter(v) => v.len(),
             Self::Permission(v) => v.len(),
@@ -364,6 +457,7 @@ impl QueryOutputBatchBox {
             Self::Block(v) => v.len(),
             Self::BlockHeader(v) => v.len(),
             Self::BlockHeaderHash(v) => v.len(),
+            Self::U64(v) => v.len(),
         }
     }
 }
@@ -487,162 +581,6 @@ impl SignedQuery {
     }
 }
 
-mod candidate {
-    use parity_scale_codec::Input;
-
-    use super::*;
-
-    #[derive(Decode, Deserialize)]
-    struct SignedQueryCandidate {
-        signature: QuerySignature,
-        payload: QueryRequestWithAuthority,
-    }
-
-    impl SignedQueryCandidate {
-        fn validate(self) -> Result<SignedQueryV1, &'static str> {
-            #[cfg(not(target_family = "wasm"))]
-            {
-                let QuerySignature(signature) = &self.signature;
-                signature
-                    .verify(&self.payload.authority.signatory, &self.payload)
-                    .map_err(|_| "Query request signature is not valid")?;
-            }
-
-            Ok(SignedQueryV1 {
-                payload: self.payload,
-                signature: self.signature,
-            })
-        }
-    }
-
-    impl Decode for SignedQueryV1 {
-        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
-            SignedQueryCandidate::decode(input)?
-                .validate()
-                .map_err(Into::into)
-        }
-    }
-
-    impl<'de> Deserialize<'de> for SignedQueryV1 {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: serde::Deserializer<'de>,
-        {
-            use serde::de::Error as _;
-
-            SignedQueryCandidate::deserialize(deserializer)?
-                .validate()
-                .map_err(D::Error::custom)
-        }
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use std::sync::LazyLock;
-
-        use iroha_crypto::KeyPair;
-        use parity_scale_codec::{DecodeAll, Encode};
-
-        use crate::{
-            account::AccountId,
-            query::{
-                candidate::SignedQueryCandidate, FindExecutorDataModel, QueryRequest,
-                QuerySignature, SignedQuery, SingularQueryBox,
-            },
-        };
-
-        static ALICE_ID: LazyLock<AccountId> = LazyLock::new(|| {
-            format!("{}@{}", ALICE_KEYPAIR.public_key(), "wonderland")
-                .parse()
-                .unwrap()
-        });
-        static ALICE_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
-            KeyPair::new(
-                "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03"
-                    .parse()
-                    .unwrap(),
-                "802620CCF31D85E3B32A4BEA59987CE0C78E3B8E2DB93881468AB2435FE45D5C9DCD53"
-                    .parse()
-                    .unwrap(),
-            )
-            .unwrap()
-        });
-
-        static BOB_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
-            KeyPair::new(
-                "ed012004FF5B81046DDCCF19E2E451C45DFB6F53759D4EB30FA2EFA807284D1CC33016"
-                    .parse()
-                    .unwrap(),
-                "802620AF3F96DEEF44348FEB516C057558972CEC4C75C4DB9C5B3AAC843668854BF828"
-                    .parse()
-                    .unwrap(),
-            )
-            .unwrap()
-        });
-
-        #[test]
-        fn valid() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
-                SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-            )
-            .with_authority(ALICE_ID.clone())
-            .sign(&ALICE_KEYPAIR);
-
-            let candidate = SignedQueryCandidate {
-                signature: signed_query.signature,
-                payload: signed_query.payload,
-            };
-
-            candidate.validate().unwrap();
-        }
-
-        #[test]
-        fn invalid_signature() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
-                SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-            )
-            .with_authority(ALICE_ID.clone())
-            .sign(&ALICE_KEYPAIR);
-
-            let mut candidate = SignedQueryCandidate {
-                signature: signed_query.signature,
-                payload: signed_query.payload,
-            };
-
-            // corrupt the signature by changing a single byte in an encoded signature
-            let mut signature_bytes = candidate.signature.encode();
-            let idx = signature_bytes.len() - 1;
-            signature_bytes[idx] = signature_bytes[idx].wrapping_add(1);
-            candidate.signature = QuerySignature::decode_all(&mut &signature_bytes[..]).unwrap();
-
-            assert_eq!(
-                candidate.validate().unwrap_err(),
-                "Query request signature is not valid"
-            );
-        }
-
-        #[test]
-        fn mismatching_authority() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
-                SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-            )
-            // signing with a wrong key here
-            .with_authority(ALICE_ID.clone())
-            .sign(&BOB_KEYPAIR);
-
-            let candidate = SignedQueryCandidate {
-                signature: signed_query.signature,
-                payload: signed_query.payload,
-            };
-
-            assert_eq!(
-                candidate.validate().unwrap_err(),
-                "Query request signature is not valid"
-            );
-        }
-    }
-}
-
 /// Use a custom syntax to implement [`Query`] for applicable types
 macro_rules! impl_iter_queries {
     ($ty:ty => $item:ty $(, $($rest:tt)*)?) => {
@@ -681,7 +619,9 @@ impl_iter_queries! {
     FindAccounts => crate::account::Account,
     FindAssets => crate::asset::Asset,
     FindAssetsDefinitions => crate::asset::AssetDefinition,
+    FindAssetAllowances => crate::asset::AssetAllowance,
     FindNfts => crate::nft::Nft,
+    FindSwaps => crate::swap::Swap,
     FindDomains => crate::domain::Domain,
     FindPeers => crate::peer::PeerId,
     FindActiveTriggerIds => crate::trigger::TriggerId,
@@ -695,6 +635,10 @@ impl_iter_queries! {
 impl_singular_queries! {
     FindParameters => crate::parameter::Parameters,
     FindExecutorDataModel => crate::executor::ExecutorDataModel,
+    FindTransactionProof => TransactionProof,
+    FindAccountStateProof => crate::state::StateProof,
+    FindAssetStateProof => crate::state::StateProof,
+    FindAccountByAlias => crate::account::AccountId,
 }
 
 impl AsRef<SignedTransaction> for CommittedTransaction {
@@ -703,6 +647,17 @@ impl AsRef<SignedTransaction> for CommittedTransaction {
     }
 }
 
+impl TransactionProof {
+    /// Check that the transaction with the given `hash` is included into the block with [`Self::block_header`].
+    ///
+    /// The block header itself has to be trusted, e.g. by checking that it is signed by a quorum of peers.
+    pub fn verify(&self, hash: HashOf<SignedTransaction>) -> bool {
+        self.block_header
+            .transactions_hash
+            .is_some_and(|root| self.proof.verify(&root, hash))
+    }
+}
+
 /// A macro reducing boilerplate when defining query types.
 macro_rules! queries {
     ($($($meta:meta)* $item:item)+) => {
@@ -824,11 +779,35 @@ pub mod account {
             /// `Id` of the definition of the asset which should be stored in founded accounts.
             pub asset_definition: AssetDefinitionId,
         }
+
+        /// [`FindAccountStateProof`] Iroha Query finds the proof of inclusion of the [`Account`] into the world state
+        #[derive(Display)]
+        #[display(fmt = "Find state proof of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountStateProof` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountStateProof {
+            /// `Id` of the account to find the proof for.
+            pub id: AccountId,
+        }
+
+        /// [`FindAccountByAlias`] Iroha Query finds the [`AccountId`] registered under the given [`AccountAlias`]
+        #[derive(Display)]
+        #[display(fmt = "Find account by `{alias}` alias")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountByAlias` has no trap representation in `AccountAlias`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountByAlias {
+            /// Alias of the account to find.
+            pub alias: AccountAlias,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAccounts, FindAccountsWithAsset};
+        pub use super::{
+            FindAccountByAlias, FindAccountStateProof, FindAccounts, FindAccountsWithAsset,
+        };
     }
 }
 
@@ -842,6 +821,8 @@ pub mod asset {
 
     use derive_more::Display;
 
+    use crate::prelude::*;
+
     queries! {
         /// [`FindAssets`] Iroha Query finds all [`Asset`]s presented in Iroha Peer.
         #[derive(Copy, Display)]
@@ -855,10 +836,30 @@ pub mod asset {
         #[display(fmt = "Find all asset definitions")]
         #[ffi_type]
         pub struct FindAssetsDefinitions;
+
+        /// [`FindAssetAllowances`] Iroha Query finds all [`AssetAllowance`]s
+        /// approved by asset owners to other accounts.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all asset allowances")]
+        #[ffi_type]
+        pub struct FindAssetAllowances;
+
+        /// [`FindAssetStateProof`] Iroha Query finds the proof of inclusion of the [`Asset`] into the world state
+        #[derive(Display)]
+        #[display(fmt = "Find state proof of `{id}` asset")]
+        #[repr(transparent)]
+        // SAFETY: `FindAssetStateProof` has no trap representation in `AssetId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAssetStateProof {
+            /// `Id` of the asset to find the proof for.
+            pub id: AssetId,
+        }
     }
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAssets, FindAssetsDefinitions};
+        pub use super::{
+            FindAssetAllowances, FindAssetStateProof, FindAssets, FindAssetsDefinitions,
+        };
     }
 }
 
@@ -885,6 +886,28 @@ pub mod nft {
     }
 }
 
+pub mod swap {
+    //! Queries related to [`Swap`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    queries! {
+        /// [`FindSwaps`] Iroha Query finds all open [`Swap`]s.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all swaps")]
+        #[ffi_type]
+        pub struct FindSwaps;
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindSwaps;
+    }
+}
+
 pub mod domain {
     //! Queries related to [`Domain`].
 
@@ -996,6 +1019,9 @@ pub mod transaction {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use iroha_crypto::HashOf;
+
+    use crate::transaction::SignedTransaction;
 
     queries! {
         /// [`FindTransactions`] Iroha Query lists all transactions included in a blockchain
@@ -1003,11 +1029,22 @@ pub mod transaction {
         #[display(fmt = "Find all transactions")]
         #[ffi_type]
         pub struct FindTransactions;
+
+        /// [`FindTransactionProof`] Iroha Query finds the proof of inclusion of the transaction with the given hash into a committed block
+        #[derive(Display)]
+        #[display(fmt = "Find inclusion proof of `{hash}` transaction")]
+        #[repr(transparent)]
+        // SAFETY: `FindTransactionProof` has no trap representation in `HashOf<SignedTransaction>`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindTransactionProof {
+            /// Hash of the transaction to find the proof for.
+            pub hash: HashOf<SignedTransaction>,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::FindTransactions;
+        pub use super::{FindTransactionProof, FindTransactions};
     }
 }
 
@@ -1100,6 +1137,14 @@ pub mod error {
             InvalidSingularParameters,
             /// Reached the limit of parallel queries. Either wait for previous queries to complete, or increase the limit in the config.
             CapacityLimit,
+            /// Aggregated value overflowed
+            AggregateOverflow,
+            /// Aggregated queries can't be sorted, groups are always ordered by their keys
+            SortedAggregate,
+            /// State at the requested height is not committed yet or no longer retained
+            HeightUnavailable,
+            /// Aggregated queries can't select fields, the aggregate function selects the aggregated field itself
+            SelectedAggregate,
         }
 
         /// Type assertion error
@@ -1129,6 +1174,8 @@ pub mod error {
             Nft(NftId),
             /// Failed to find account: `{0}`
             Account(AccountId),
+            /// Failed to find account by alias: `{0}`
+            AccountAlias(AccountAlias),
             /// Failed to find domain: `{0}`
             Domain(DomainId),
             /// Failed to find metadata key: `{0}`
@@ -1141,6 +1188,8 @@ pub mod error {
             Peer(PeerId),
             /// Trigger with id `{0}` not found
             Trigger(TriggerId),
+            /// Swap with id `{0}` not found
+            Swap(SwapId),
             /// Role with id `{0}` not found
             Role(RoleId),
             /// Failed to find [`Permission`] by id.
@@ -1158,7 +1207,7 @@ pub mod prelude {
         account::prelude::*, asset::prelude::*, block::prelude::*, builder::prelude::*,
         domain::prelude::*, dsl::prelude::*, executor::prelude::*, nft::prelude::*,
         parameters::prelude::*, peer::prelude::*, permission::prelude::*, role::prelude::*,
-        transaction::prelude::*, trigger::prelude::*, CommittedTransaction, QueryBox, QueryRequest,
-        SingularQueryBox,
+        swap::prelude::*, transaction::prelude::*, trigger::prelude::*, CommittedTransaction,
+        QueryBox, QueryRequest, SingularQueryBox, SingularQueryWithParams,
     };
 }
@@ -69,7 +69,32 @@ mod model {
         pub offset: u64,
     }
 
+    /// Direction in which query results are sorted
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Default,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum SortOrder {
+        /// Smallest values come first
+        #[default]
+        Ascending,
+        /// Largest values come first
+        Descending,
+    }
+
     /// Struct for sorting requests
+    ///
+    /// Sorting by arbitrary fields of the query output is specified with [`SortKey`](crate::query::dsl::SortKey)s in [`QueryWithFilter`](crate::query::QueryWithFilter).
+    /// If both are present, the metadata key is used as the primary key and the sort keys break the ties.
     #[derive(
         Debug,
         Clone,
@@ -128,6 +153,10 @@ mod model {
         pub pagination: Pagination,
         pub sorting: Sorting,
         pub fetch_size: FetchSize,
+        /// Height of the block after which the state is queried.
+        ///
+        /// If not specified then the latest state is queried.
+        pub at_height: Option<NonZeroU64>,
     }
 }
 
@@ -142,5 +171,5 @@ impl Sorting {
 
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::{FetchSize, Pagination, Sorting};
+    pub use super::{FetchSize, Pagination, SortOrder, Sorting};
 }
@@ -0,0 +1,185 @@
+//! This module contains [`StateEntry`] and related implementations.
+//!
+//! Every block header commits to the world state resulting from the previous block
+//! with the root of a [`MerkleTree`] over the hashes of all the state entries.
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::num::NonZeroU64;
+
+use iroha_crypto::{HashOf, MerkleProof, MerkleTree};
+use iroha_data_model_derive::model;
+use iroha_macro::FromVariant;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::{
+    account::{Account, AccountAlias, AccountId},
+    asset::{Asset, AssetAllowance, AssetDefinition, AssetId},
+    domain::Domain,
+    executor::{Executor, ExecutorDataModel},
+    nft::{Nft, NftId},
+    parameter::Parameters,
+    peer::PeerId,
+    permission::Permissions,
+    role::{Role, RoleId},
+    swap::{Swap, SwapId},
+    trigger::Trigger,
+};
+
+/// Root hash of the world state
+pub type StateRoot = HashOf<MerkleTree<StateEntry>>;
+
+#[model]
+mod model {
+    use getset::{CopyGetters, Getters};
+
+    use super::*;
+
+    /// An entry of the world state.
+    ///
+    /// Entries are ordered by their kind (in the order of variants) and then by their ids.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, FromVariant, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum StateEntry {
+        /// On-chain parameters
+        Parameters(Parameters),
+        /// Registered peer
+        Peer(PeerId),
+        /// Registered domain
+        Domain(Domain),
+        /// Registered account
+        Account(Account),
+        /// Registered asset definition
+        AssetDefinition(AssetDefinition),
+        /// Registered asset
+        Asset(Asset),
+        /// Allowance to spend an asset on behalf of its owner
+        AssetAllowance(AssetAllowance),
+        /// Registered NFT
+        Nft(Nft),
+        /// Open swap
+        Swap(Swap),
+        /// Registered role
+        Role(Role),
+        /// Permissions granted to an account
+        AccountPermissions(AccountPermissions),
+        /// Role granted to an account
+        AccountRole(AccountRole),
+        /// Registered trigger
+        Trigger(Box<Trigger>),
+        /// Data model of the current executor
+        ExecutorDataModel(ExecutorDataModel),
+        /// Account registered under an alias
+        AliasedAccount(AliasedAccount),
+        /// Quantity of an asset locked by open swaps
+        LockedAsset(LockedAsset),
+        /// NFT locked by an open swap
+        LockedNft(LockedNft),
+        /// Expiration time of an open swap
+        SwapExpiration(SwapExpiration),
+        /// WASM blob of the current executor, `None` while the initial executor is in use
+        Executor(Option<Executor>),
+    }
+
+    /// Permissions granted directly to an account
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AccountPermissions {
+        /// Account the permissions are granted to
+        pub account: AccountId,
+        /// Granted permissions
+        pub permissions: Permissions,
+    }
+
+    /// Role granted to an account
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AccountRole {
+        /// Account the role is granted to
+        pub account: AccountId,
+        /// Granted role
+        pub role: RoleId,
+    }
+
+    /// Account registered under an alias
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AliasedAccount {
+        /// Alias of the account
+        pub alias: AccountAlias,
+        /// Aliased account
+        pub account: AccountId,
+    }
+
+    /// Quantity of an asset locked by open swaps
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct LockedAsset {
+        /// Locked asset
+        pub asset: AssetId,
+        /// Locked quantity
+        pub quantity: Numeric,
+    }
+
+    /// NFT locked by an open swap
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct LockedNft {
+        /// Locked NFT
+        pub nft: NftId,
+        /// Swap the NFT is locked by
+        pub swap: SwapId,
+    }
+
+    /// Expiration time of an open swap
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct SwapExpiration {
+        /// Time when the swap expires, in milliseconds since the Unix epoch
+        pub expires_at_ms: u64,
+        /// Expiring swap
+        pub swap: SwapId,
+    }
+
+    /// Proof that a [`StateEntry`] is a part of the world state
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        Getters,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct StateProof {
+        /// Height of the block after which the world state contains the entry.
+        ///
+        /// The state root is committed to by the header of the block at the next height.
+        #[getset(get_copy = "pub")]
+        pub height: NonZeroU64,
+        /// Proven entry
+        #[getset(get = "pub")]
+        pub entry: StateEntry,
+        /// Proof of inclusion of the entry into the world state
+        #[getset(get = "pub")]
+        pub proof: MerkleProof<StateEntry>,
+    }
+}
+
+impl StateProof {
+    /// Check that [`Self::entry`] is a part of the world state with the given `root`.
+    ///
+    /// The root has to be trusted, e.g. taken from [`BlockHeader::prev_state_root`](crate::block::BlockHeader::prev_state_root)
+    /// of a block signed by a quorum of peers.
+    pub fn verify(&self, root: &StateRoot) -> bool {
+        self.proof.verify(root, HashOf::new(&self.entry))
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{StateEntry, StateProof, StateRoot};
+}
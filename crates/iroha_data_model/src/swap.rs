@@ -0,0 +1,171 @@
+//! This module contains [`Swap`] structure and its implementation
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use crate::{
+    account::AccountId,
+    asset::{AssetDefinitionId, AssetId},
+    nft::NftId,
+};
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display, FromStr};
+    use getset::Getters;
+    use iroha_data_model_derive::IdEqOrdHash;
+    use iroha_primitives::numeric::Numeric;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+    use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+    use super::*;
+    use crate::Name;
+
+    /// Identification of a [`Swap`].
+    #[derive(
+        Debug,
+        Display,
+        FromStr,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        DeserializeFromStr,
+        SerializeDisplay,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}")]
+    #[getset(get = "pub")]
+    #[repr(transparent)]
+    #[ffi_type(opaque)]
+    pub struct SwapId {
+        /// Name given to the swap by its maker.
+        pub name: Name,
+    }
+
+    /// Quantity of assets of some definition exchanged in a [`Swap`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{quantity} of {definition}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SwapAsset {
+        /// Definition of the exchanged asset.
+        pub definition: AssetDefinitionId,
+        /// Exchanged quantity.
+        pub quantity: Numeric,
+    }
+
+    /// Holdings given away by a party of a [`Swap`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub enum SwapItem {
+        /// Quantity of a numeric asset.
+        #[display(fmt = "{_0}")]
+        Asset(SwapAsset),
+        /// Non-fungible asset.
+        #[display(fmt = "{_0}")]
+        Nft(NftId),
+    }
+
+    /// Offer to exchange holdings of the maker for holdings of any other account.
+    ///
+    /// Holdings offered by the maker are locked until the swap is accepted, cancelled or expired.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        IdEqOrdHash,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}: {offered} for {requested}")]
+    #[ffi_type]
+    pub struct Swap {
+        /// Identification of the swap.
+        pub id: SwapId,
+        /// Account which created the swap and gives away the offered holdings.
+        #[getset(get = "pub")]
+        pub maker: AccountId,
+        /// Holdings given by the maker to the taker.
+        #[getset(get = "pub")]
+        pub offered: SwapItem,
+        /// Holdings given by the taker to the maker.
+        #[getset(get = "pub")]
+        pub requested: SwapItem,
+        /// If specified, only this account can accept the swap.
+        #[getset(get = "pub")]
+        pub taker: Option<AccountId>,
+        /// Block creation time (in milliseconds since unix epoch) after which the swap can't be accepted.
+        #[getset(get = "pub")]
+        pub expires_at_ms: Option<u64>,
+    }
+}
+
+impl SwapAsset {
+    /// Id of the asset of `account` exchanged in the swap.
+    pub fn asset_of(&self, account: AccountId) -> AssetId {
+        AssetId::new(self.definition.clone(), account)
+    }
+}
+
+impl Swap {
+    /// Holdings exchanged by both parties of the swap.
+    pub fn items(&self) -> [&SwapItem; 2] {
+        [&self.offered, &self.requested]
+    }
+
+    /// Whether the swap can no longer be accepted in a block created at `block_time`.
+    pub fn is_expired(&self, block_time: Duration) -> bool {
+        self.expires_at_ms
+            .is_some_and(|expires_at_ms| Duration::from_millis(expires_at_ms) < block_time)
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this crate.
+pub mod prelude {
+    pub use super::{Swap, SwapAsset, SwapId, SwapItem};
+}
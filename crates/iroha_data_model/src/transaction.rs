@@ -152,6 +152,19 @@ mod model {
         pub(super) payload: TransactionPayload,
     }
 
+    /// Outcome of executing a transaction against the latest state without committing it
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct TransactionSimulation {
+        /// Reason the transaction would be rejected for, if it would be rejected
+        pub rejection_reason: Option<error::TransactionRejectionReason>,
+        /// Data events the transaction would emit. Empty if it would be rejected
+        pub events: Vec<crate::events::data::DataEvent>,
+        /// Fuel consumed by the WASM smart contract
+        pub fuel: u64,
+        /// Number of executed instructions
+        pub instructions: u64,
+    }
+
     /// Structure that represents the initial state of a transaction before the transaction receives any signatures.
     #[derive(Debug, Clone)]
     #[repr(transparent)]
@@ -430,8 +443,6 @@ mod candidate {
         fn validate(self) -> Result<SignedTransactionV1, &'static str> {
             #[cfg(not(target_family = "wasm"))]
             self.validate_instructions()?;
-            #[cfg(not(target_family = "wasm"))]
-            self.validate_signature()?;
 
             Ok(SignedTransactionV1 {
                 signature: self.signature,
@@ -449,17 +460,6 @@ mod candidate {
 
             Ok(())
         }
-
-        #[cfg(not(target_family = "wasm"))]
-        fn validate_signature(&self) -> Result<(), &'static str> {
-            let TransactionSignature(signature) = &self.signature;
-
-            signature
-                .verify(&self.payload.authority.signatory, &self.payload)
-                .map_err(|_| "Transaction signature is invalid")?;
-
-            Ok(())
-        }
     }
 
     impl Decode for SignedTransactionV1 {
@@ -645,6 +645,8 @@ pub mod error {
             InstructionExecution(#[cfg_attr(feature = "std", source)] InstructionExecutionFail),
             /// Failure in WebAssembly execution
             WasmExecution(#[cfg_attr(feature = "std", source)] WasmExecutionFail),
+            /// Transaction isn't signed by any of the authority's signatories
+            InvalidSignature,
         }
     }
 
@@ -665,6 +667,19 @@ pub mod error {
                 SetParameter(_) => "set parameter",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
+                EmitEvent(_) => "emit event",
+                AddSignatory(_) => "add signatory",
+                RemoveSignatory(_) => "remove signatory",
+                RotateAccountKey(_) => "rotate account key",
+                RegisterAlias(_) => "register alias",
+                UnregisterAlias(_) => "unregister alias",
+                Approve(_) => "approve",
+                TransferFrom(_) => "transfer from",
+                Freeze(_) => "freeze",
+                Unfreeze(_) => "unfreeze",
+                CreateSwap(_) => "create swap",
+                AcceptSwap(_) => "accept swap",
+                CancelSwap(_) => "cancel swap",
                 Custom(_) => "custom",
             };
             write!(
@@ -694,7 +709,8 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     pub use super::{
-        error::prelude::*, Executable, SignedTransaction, TransactionBuilder, WasmSmartContract,
+        error::prelude::*, Executable, SignedTransaction, TransactionBuilder,
+        TransactionSimulation, WasmSmartContract,
     };
 }
 
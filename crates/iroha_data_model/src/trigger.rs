@@ -115,7 +115,7 @@ pub mod action {
         /// triggers without gaps, the `Executable` wrapped in the action must
         /// be run before any of the ISIs are pushed into the queue of the
         /// next block.
-        #[derive(Debug, Clone, PartialEq, Eq, Encode, Serialize, IntoSchema)]
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Serialize, IntoSchema)]
         #[ffi_type]
         pub struct Action {
             /// The executable linked to this action
@@ -134,7 +134,18 @@ pub mod action {
 
         /// Enumeration of possible repetitions schemes.
         #[derive(
-            Debug, Copy, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+            Debug,
+            Copy,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
         )]
         #[ffi_type]
         pub enum Repeats {
@@ -44,22 +44,41 @@ pub trait Visit {
         visit_set_key_value(&SetKeyValueBox),
         visit_transfer(&TransferBox),
         visit_unregister(&UnregisterBox),
+        visit_freeze(&FreezeBox),
+        visit_unfreeze(&UnfreezeBox),
         visit_upgrade(&Upgrade),
 
         visit_execute_trigger(&ExecuteTrigger),
         visit_set_parameter(&SetParameter),
         visit_log(&Log),
+        visit_emit_event(&EmitEvent),
+        visit_add_signatory(&AddSignatory),
+        visit_remove_signatory(&RemoveSignatory),
+        visit_rotate_account_key(&RotateAccountKey),
+        visit_register_alias(&RegisterAlias),
+        visit_unregister_alias(&UnregisterAlias),
+        visit_approve(&Approve),
+        visit_transfer_from(&TransferFrom),
+        visit_create_swap(&CreateSwap),
+        visit_accept_swap(&AcceptSwap),
+        visit_cancel_swap(&CancelSwap),
         visit_custom_instruction(&CustomInstruction),
 
         // Visit SingularQueryBox
         visit_find_executor_data_model(&FindExecutorDataModel),
         visit_find_parameters(&FindParameters),
+        visit_find_transaction_proof(&FindTransactionProof),
+        visit_find_account_state_proof(&FindAccountStateProof),
+        visit_find_asset_state_proof(&FindAssetStateProof),
+        visit_find_account_by_alias(&FindAccountByAlias),
 
         // Visit IterableQueryBox
         visit_find_domains(&QueryWithFilter<FindDomains>),
         visit_find_accounts(&QueryWithFilter<FindAccounts>),
         visit_find_assets(&QueryWithFilter<FindAssets>),
         visit_find_assets_definitions(&QueryWithFilter<FindAssetsDefinitions>),
+        visit_find_asset_allowances(&QueryWithFilter<FindAssetAllowances>),
+        visit_find_swaps(&QueryWithFilter<FindSwaps>),
         visit_find_nfts(&QueryWithFilter<FindNfts>),
         visit_find_roles(&QueryWithFilter<FindRoles>),
         visit_find_role_ids(&QueryWithFilter<FindRoleIds>),
@@ -129,6 +148,16 @@ pub trait Visit {
         visit_revoke_account_permission(&Revoke<Permission, Account>),
         visit_revoke_account_role(&Revoke<RoleId, Account>),
         visit_revoke_role_permission(&Revoke<Permission, Role>),
+
+        // Visit FreezeBox
+        visit_freeze_account(&Freeze<Account>),
+        visit_freeze_asset_definition(&Freeze<AssetDefinition>),
+        visit_freeze_asset(&Freeze<Asset>),
+
+        // Visit UnfreezeBox
+        visit_unfreeze_account(&Unfreeze<Account>),
+        visit_unfreeze_asset_definition(&Unfreeze<AssetDefinition>),
+        visit_unfreeze_asset(&Unfreeze<Asset>),
     }
 }
 
@@ -155,6 +184,10 @@ pub fn visit_singular_query<V: Visit + ?Sized>(visitor: &mut V, query: &Singular
     singular_query_visitors! {
         visit_find_executor_data_model(FindExecutorDataModel),
         visit_find_parameters(FindParameters),
+        visit_find_transaction_proof(FindTransactionProof),
+        visit_find_account_state_proof(FindAccountStateProof),
+        visit_find_asset_state_proof(FindAssetStateProof),
+        visit_find_account_by_alias(FindAccountByAlias),
     }
 }
 
@@ -172,6 +205,8 @@ pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithPar
         visit_find_accounts(FindAccounts),
         visit_find_assets(FindAssets),
         visit_find_assets_definitions(FindAssetsDefinitions),
+        visit_find_asset_allowances(FindAssetAllowances),
+        visit_find_swaps(FindSwaps),
         visit_find_nfts(FindNfts),
         visit_find_roles(FindRoles),
         visit_find_role_ids(FindRoleIds),
@@ -208,6 +243,23 @@ pub fn visit_instruction<V: Visit + ?Sized>(visitor: &mut V, isi: &InstructionBo
             visitor.visit_execute_trigger(variant_value)
         }
         InstructionBox::Log(variant_value) => visitor.visit_log(variant_value),
+        InstructionBox::EmitEvent(variant_value) => visitor.visit_emit_event(variant_value),
+        InstructionBox::AddSignatory(variant_value) => visitor.visit_add_signatory(variant_value),
+        InstructionBox::RemoveSignatory(variant_value) => {
+            visitor.visit_remove_signatory(variant_value)
+        }
+        InstructionBox::RotateAccountKey(variant_value) => {
+            visitor.visit_rotate_account_key(variant_value)
+        }
+        InstructionBox::RegisterAlias(variant_value) => visitor.visit_register_alias(variant_value),
+        InstructionBox::UnregisterAlias(variant_value) => {
+            visitor.visit_unregister_alias(variant_value)
+        }
+        InstructionBox::Approve(variant_value) => visitor.visit_approve(variant_value),
+        InstructionBox::TransferFrom(variant_value) => visitor.visit_transfer_from(variant_value),
+        InstructionBox::CreateSwap(variant_value) => visitor.visit_create_swap(variant_value),
+        InstructionBox::AcceptSwap(variant_value) => visitor.visit_accept_swap(variant_value),
+        InstructionBox::CancelSwap(variant_value) => visitor.visit_cancel_swap(variant_value),
         InstructionBox::Burn(variant_value) => visitor.visit_burn(variant_value),
         InstructionBox::Grant(variant_value) => visitor.visit_grant(variant_value),
         InstructionBox::Mint(variant_value) => visitor.visit_mint(variant_value),
@@ -219,6 +271,8 @@ pub fn visit_instruction<V: Visit + ?Sized>(visitor: &mut V, isi: &InstructionBo
         InstructionBox::SetKeyValue(variant_value) => visitor.visit_set_key_value(variant_value),
         InstructionBox::Transfer(variant_value) => visitor.visit_transfer(variant_value),
         InstructionBox::Unregister(variant_value) => visitor.visit_unregister(variant_value),
+        InstructionBox::Freeze(variant_value) => visitor.visit_freeze(variant_value),
+        InstructionBox::Unfreeze(variant_value) => visitor.visit_unfreeze(variant_value),
         InstructionBox::Upgrade(variant_value) => visitor.visit_upgrade(variant_value),
         InstructionBox::Custom(custom) => visitor.visit_custom_instruction(custom),
     }
@@ -309,6 +363,22 @@ pub fn visit_revoke<V: Visit + ?Sized>(visitor: &mut V, isi: &RevokeBox) {
     }
 }
 
+pub fn visit_freeze<V: Visit + ?Sized>(visitor: &mut V, isi: &FreezeBox) {
+    match isi {
+        FreezeBox::Account(obj) => visitor.visit_freeze_account(obj),
+        FreezeBox::AssetDefinition(obj) => visitor.visit_freeze_asset_definition(obj),
+        FreezeBox::Asset(obj) => visitor.visit_freeze_asset(obj),
+    }
+}
+
+pub fn visit_unfreeze<V: Visit + ?Sized>(visitor: &mut V, isi: &UnfreezeBox) {
+    match isi {
+        UnfreezeBox::Account(obj) => visitor.visit_unfreeze_account(obj),
+        UnfreezeBox::AssetDefinition(obj) => visitor.visit_unfreeze_asset_definition(obj),
+        UnfreezeBox::Asset(obj) => visitor.visit_unfreeze_asset(obj),
+    }
+}
+
 macro_rules! leaf_visitors {
     ( $($visitor:ident($operation:ty)),+ $(,)? ) => { $(
         pub fn $visitor<V: Visit + ?Sized>(_visitor: &mut V, _operation: $operation) {
@@ -361,17 +431,40 @@ leaf_visitors! {
     visit_set_parameter(&SetParameter),
     visit_execute_trigger(&ExecuteTrigger),
     visit_log(&Log),
+    visit_emit_event(&EmitEvent),
+    visit_add_signatory(&AddSignatory),
+    visit_remove_signatory(&RemoveSignatory),
+    visit_rotate_account_key(&RotateAccountKey),
+    visit_register_alias(&RegisterAlias),
+    visit_unregister_alias(&UnregisterAlias),
+    visit_approve(&Approve),
+    visit_transfer_from(&TransferFrom),
+    visit_create_swap(&CreateSwap),
+    visit_accept_swap(&AcceptSwap),
+    visit_cancel_swap(&CancelSwap),
+    visit_freeze_account(&Freeze<Account>),
+    visit_freeze_asset_definition(&Freeze<AssetDefinition>),
+    visit_freeze_asset(&Freeze<Asset>),
+    visit_unfreeze_account(&Unfreeze<Account>),
+    visit_unfreeze_asset_definition(&Unfreeze<AssetDefinition>),
+    visit_unfreeze_asset(&Unfreeze<Asset>),
     visit_custom_instruction(&CustomInstruction),
 
     // Singular Query visitors
     visit_find_executor_data_model(&FindExecutorDataModel),
     visit_find_parameters(&FindParameters),
+    visit_find_transaction_proof(&FindTransactionProof),
+    visit_find_account_state_proof(&FindAccountStateProof),
+    visit_find_asset_state_proof(&FindAssetStateProof),
+    visit_find_account_by_alias(&FindAccountByAlias),
 
     // Iterable Query visitors
     visit_find_domains(&QueryWithFilter<FindDomains>),
     visit_find_accounts(&QueryWithFilter<FindAccounts>),
     visit_find_assets(&QueryWithFilter<FindAssets>),
     visit_find_assets_definitions(&QueryWithFilter<FindAssetsDefinitions>),
+    visit_find_asset_allowances(&QueryWithFilter<FindAssetAllowances>),
+    visit_find_swaps(&QueryWithFilter<FindSwaps>),
     visit_find_nfts(&QueryWithFilter<FindNfts>),
     visit_find_roles(&QueryWithFilter<FindRoles>),
     visit_find_role_ids(&QueryWithFilter<FindRoleIds>),
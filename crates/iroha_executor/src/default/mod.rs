@@ -4,19 +4,26 @@
 use alloc::format;
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
-    visit_unregister_account,
+    visit_add_signatory, visit_freeze_account, visit_register_account, visit_register_alias,
+    visit_remove_account_key_value, visit_remove_signatory, visit_rotate_account_key,
+    visit_set_account_key_value, visit_unfreeze_account, visit_unregister_account,
+    visit_unregister_alias,
+};
+pub use asset::{
+    visit_approve, visit_burn_asset_numeric, visit_freeze_asset, visit_mint_asset_numeric,
+    visit_transfer_asset_numeric, visit_transfer_from, visit_unfreeze_asset,
 };
-pub use asset::{visit_burn_asset_numeric, visit_mint_asset_numeric, visit_transfer_asset_numeric};
 pub use asset_definition::{
-    visit_register_asset_definition, visit_remove_asset_definition_key_value,
-    visit_set_asset_definition_key_value, visit_transfer_asset_definition,
+    visit_freeze_asset_definition, visit_register_asset_definition,
+    visit_remove_asset_definition_key_value, visit_set_asset_definition_key_value,
+    visit_transfer_asset_definition, visit_unfreeze_asset_definition,
     visit_unregister_asset_definition,
 };
 pub use domain::{
     visit_register_domain, visit_remove_domain_key_value, visit_set_domain_key_value,
     visit_transfer_domain, visit_unregister_domain,
 };
+pub use event::visit_emit_event;
 pub use executor::visit_upgrade;
 use iroha_smart_contract::data_model::{prelude::*, visit::Visit};
 pub use isi::visit_custom_instruction;
@@ -32,6 +39,7 @@ pub use role::{
     visit_grant_account_role, visit_grant_role_permission, visit_register_role,
     visit_revoke_account_role, visit_revoke_role_permission, visit_unregister_role,
 };
+pub use swap::{visit_accept_swap, visit_cancel_swap, visit_create_swap};
 pub use trigger::{
     visit_burn_trigger_repetitions, visit_execute_trigger, visit_mint_trigger_repetitions,
     visit_register_trigger, visit_remove_trigger_key_value, visit_set_trigger_key_value,
@@ -85,6 +93,45 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
         InstructionBox::Log(isi) => {
             executor.visit_log(isi);
         }
+        InstructionBox::EmitEvent(isi) => {
+            executor.visit_emit_event(isi);
+        }
+        InstructionBox::AddSignatory(isi) => {
+            executor.visit_add_signatory(isi);
+        }
+        InstructionBox::RemoveSignatory(isi) => {
+            executor.visit_remove_signatory(isi);
+        }
+        InstructionBox::RotateAccountKey(isi) => {
+            executor.visit_rotate_account_key(isi);
+        }
+        InstructionBox::RegisterAlias(isi) => {
+            executor.visit_register_alias(isi);
+        }
+        InstructionBox::UnregisterAlias(isi) => {
+            executor.visit_unregister_alias(isi);
+        }
+        InstructionBox::Approve(isi) => {
+            executor.visit_approve(isi);
+        }
+        InstructionBox::TransferFrom(isi) => {
+            executor.visit_transfer_from(isi);
+        }
+        InstructionBox::Freeze(isi) => {
+            executor.visit_freeze(isi);
+        }
+        InstructionBox::Unfreeze(isi) => {
+            executor.visit_unfreeze(isi);
+        }
+        InstructionBox::CreateSwap(isi) => {
+            executor.visit_create_swap(isi);
+        }
+        InstructionBox::AcceptSwap(isi) => {
+            executor.visit_accept_swap(isi);
+        }
+        InstructionBox::CancelSwap(isi) => {
+            executor.visit_cancel_swap(isi);
+        }
         InstructionBox::ExecuteTrigger(isi) => {
             executor.visit_execute_trigger(isi);
         }
@@ -329,6 +376,16 @@ pub mod domain {
                 permission.asset.definition().domain() == domain_id
                     || permission.asset.account().domain() == domain_id
             }
+            AnyPermission::CanFreezeAssetDefinition(permission) => {
+                permission.asset_definition.domain() == domain_id
+            }
+            AnyPermission::CanFreezeAssetWithDefinition(permission) => {
+                permission.asset_definition.domain() == domain_id
+            }
+            AnyPermission::CanFreezeAsset(permission) => {
+                permission.asset.definition().domain() == domain_id
+                    || permission.asset.account().domain() == domain_id
+            }
             AnyPermission::CanRegisterNft(permission) => &permission.domain == domain_id,
             AnyPermission::CanUnregisterNft(permission) => permission.nft.domain() == domain_id,
             AnyPermission::CanTransferNft(permission) => permission.nft.domain() == domain_id,
@@ -339,6 +396,10 @@ pub mod domain {
             AnyPermission::CanModifyAccountMetadata(permission) => {
                 permission.account.domain() == domain_id
             }
+            AnyPermission::CanManageAccountKeys(permission) => {
+                permission.account.domain() == domain_id
+            }
+            AnyPermission::CanFreezeAccount(permission) => permission.account.domain() == domain_id,
             AnyPermission::CanRegisterTrigger(permission) => {
                 permission.authority.domain() == domain_id
             }
@@ -357,7 +418,8 @@ pub mod domain {
 
 pub mod account {
     use iroha_executor_data_model::permission::account::{
-        CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
+        CanFreezeAccount, CanManageAccountKeys, CanModifyAccountMetadata, CanRegisterAccount,
+        CanUnregisterAccount,
     };
 
     use super::*;
@@ -483,6 +545,141 @@ pub mod account {
         );
     }
 
+    pub fn visit_add_signatory<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &AddSignatory) {
+        match can_manage_account_keys(executor, isi.account()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(executor, "Can't add signatories to another account"),
+        }
+    }
+
+    pub fn visit_remove_signatory<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RemoveSignatory,
+    ) {
+        match can_manage_account_keys(executor, isi.account()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(executor, "Can't remove signatories of another account"),
+        }
+    }
+
+    pub fn visit_rotate_account_key<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RotateAccountKey,
+    ) {
+        match can_manage_account_keys(executor, isi.account()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(executor, "Can't rotate keys of another account"),
+        }
+    }
+
+    pub fn visit_register_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RegisterAlias,
+    ) {
+        let account_id = isi.account();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(executor, "Can't register an alias for another account"),
+        }
+    }
+
+    pub fn visit_unregister_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &UnregisterAlias,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        let account_id = match executor
+            .host()
+            .query_single(FindAccountByAlias::new(isi.alias().clone()))
+        {
+            Err(err) => deny!(executor, err),
+            Ok(account_id) => account_id,
+        };
+        match is_account_owner(&account_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(executor, "Can't unregister an alias of another account"),
+        }
+    }
+
+    /// Keys of an account are managed by its owner, i.e. the account itself or the owner of its
+    /// domain, and by anyone granted [`CanManageAccountKeys`] for it.
+    fn can_manage_account_keys<V: Execute + Visit + ?Sized>(
+        executor: &V,
+        account_id: &AccountId,
+    ) -> Result<bool, ValidationFail> {
+        if executor.context().curr_block.is_genesis()
+            || is_account_owner(account_id, &executor.context().authority, executor.host())?
+        {
+            return Ok(true);
+        }
+
+        let can_manage_account_keys = CanManageAccountKeys {
+            account: account_id.clone(),
+        };
+        Ok(can_manage_account_keys.is_owned_by(&executor.context().authority, executor.host()))
+    }
+
+    pub fn visit_freeze_account<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Freeze<Account>,
+    ) {
+        match can_freeze_account(executor, isi.object()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't freeze accounts in a domain owned by another account"
+            ),
+        }
+    }
+
+    pub fn visit_unfreeze_account<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Unfreeze<Account>,
+    ) {
+        match can_freeze_account(executor, isi.object()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't unfreeze accounts in a domain owned by another account"
+            ),
+        }
+    }
+
+    /// Accounts are frozen by the owner of their domain and by anyone granted [`CanFreezeAccount`]
+    /// for them, but not by the account itself.
+    fn can_freeze_account<V: Execute + Visit + ?Sized>(
+        executor: &V,
+        account_id: &AccountId,
+    ) -> Result<bool, ValidationFail> {
+        if executor.context().curr_block.is_genesis()
+            || crate::permission::domain::is_domain_owner(
+                account_id.domain(),
+                &executor.context().authority,
+                executor.host(),
+            )?
+        {
+            return Ok(true);
+        }
+
+        let can_freeze_account = CanFreezeAccount {
+            account: account_id.clone(),
+        };
+        Ok(can_freeze_account.is_owned_by(&executor.context().authority, executor.host()))
+    }
+
     fn is_permission_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
@@ -492,9 +689,12 @@ pub mod account {
             AnyPermission::CanModifyAccountMetadata(permission) => {
                 permission.account == *account_id
             }
+            AnyPermission::CanManageAccountKeys(permission) => permission.account == *account_id,
             AnyPermission::CanMintAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanBurnAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanTransferAsset(permission) => permission.asset.account() == account_id,
+            AnyPermission::CanFreezeAccount(permission) => permission.account == *account_id,
+            AnyPermission::CanFreezeAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanRegisterTrigger(permission) => permission.authority == *account_id,
             AnyPermission::CanUnregisterTrigger(_)
             | AnyPermission::CanExecuteTrigger(_)
@@ -511,6 +711,8 @@ pub mod account {
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanBurnAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanFreezeAssetDefinition(_)
+            | AnyPermission::CanFreezeAssetWithDefinition(_)
             | AnyPermission::CanRegisterNft(_)
             | AnyPermission::CanUnregisterNft(_)
             | AnyPermission::CanTransferNft(_)
@@ -524,7 +726,8 @@ pub mod account {
 
 pub mod asset_definition {
     use iroha_executor_data_model::permission::asset_definition::{
-        CanModifyAssetDefinitionMetadata, CanRegisterAssetDefinition, CanUnregisterAssetDefinition,
+        CanFreezeAssetDefinition, CanModifyAssetDefinitionMetadata, CanRegisterAssetDefinition,
+        CanUnregisterAssetDefinition,
     };
     use iroha_smart_contract::data_model::asset::AssetDefinitionId;
 
@@ -699,6 +902,54 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_freeze_asset_definition<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Freeze<AssetDefinition>,
+    ) {
+        match can_freeze_asset_definition(executor, isi.object()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't freeze asset definitions owned by another account"
+            ),
+        }
+    }
+
+    pub fn visit_unfreeze_asset_definition<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Unfreeze<AssetDefinition>,
+    ) {
+        match can_freeze_asset_definition(executor, isi.object()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't unfreeze asset definitions owned by another account"
+            ),
+        }
+    }
+
+    fn can_freeze_asset_definition<V: Execute + Visit + ?Sized>(
+        executor: &V,
+        asset_definition_id: &AssetDefinitionId,
+    ) -> Result<bool, ValidationFail> {
+        if executor.context().curr_block.is_genesis()
+            || is_asset_definition_owner(
+                asset_definition_id,
+                &executor.context().authority,
+                executor.host(),
+            )?
+        {
+            return Ok(true);
+        }
+
+        let can_freeze_asset_definition = CanFreezeAssetDefinition {
+            asset_definition: asset_definition_id.clone(),
+        };
+        Ok(can_freeze_asset_definition.is_owned_by(&executor.context().authority, executor.host()))
+    }
+
     fn is_permission_asset_definition_associated(
         permission: &Permission,
         asset_definition_id: &AssetDefinitionId,
@@ -731,8 +982,19 @@ pub mod asset_definition {
             AnyPermission::CanTransferAsset(permission) => {
                 permission.asset.definition() == asset_definition_id
             }
+            AnyPermission::CanFreezeAssetDefinition(permission) => {
+                &permission.asset_definition == asset_definition_id
+            }
+            AnyPermission::CanFreezeAssetWithDefinition(permission) => {
+                &permission.asset_definition == asset_definition_id
+            }
+            AnyPermission::CanFreezeAsset(permission) => {
+                permission.asset.definition() == asset_definition_id
+            }
             AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanManageAccountKeys(_)
+            | AnyPermission::CanFreezeAccount(_)
             | AnyPermission::CanRegisterTrigger(_)
             | AnyPermission::CanUnregisterTrigger(_)
             | AnyPermission::CanExecuteTrigger(_)
@@ -757,8 +1019,8 @@ pub mod asset_definition {
 
 pub mod asset {
     use iroha_executor_data_model::permission::asset::{
-        CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanTransferAsset, CanTransferAssetWithDefinition,
+        CanBurnAsset, CanBurnAssetWithDefinition, CanFreezeAsset, CanFreezeAssetWithDefinition,
+        CanMintAsset, CanMintAssetWithDefinition, CanTransferAsset, CanTransferAssetWithDefinition,
     };
     use iroha_smart_contract::data_model::isi::BuiltInInstruction;
     use iroha_smart_contract_utils::Encode;
@@ -902,6 +1164,78 @@ pub mod asset {
 
         deny!(executor, "Can't transfer assets of another account");
     }
+
+    pub fn visit_approve<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Approve) {
+        let asset_id = isi.asset();
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_asset_owner(asset_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't approve spending of assets of another account"
+            ),
+        }
+    }
+
+    pub fn visit_transfer_from<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &TransferFrom) {
+        // The allowance approved for the authority is both checked and debited on execution
+        execute!(executor, isi);
+    }
+
+    pub fn visit_freeze_asset<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Freeze<Asset>) {
+        match can_freeze_asset(executor, isi.object()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't freeze assets with definitions owned by another account"
+            ),
+        }
+    }
+
+    pub fn visit_unfreeze_asset<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Unfreeze<Asset>,
+    ) {
+        match can_freeze_asset(executor, isi.object()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(
+                executor,
+                "Can't unfreeze assets with definitions owned by another account"
+            ),
+        }
+    }
+
+    /// Assets are frozen by the owner of their definition and by anyone granted
+    /// [`CanFreezeAssetWithDefinition`] or [`CanFreezeAsset`], but not by their holder.
+    fn can_freeze_asset<V: Execute + Visit + ?Sized>(
+        executor: &V,
+        asset_id: &AssetId,
+    ) -> Result<bool, ValidationFail> {
+        if executor.context().curr_block.is_genesis()
+            || is_asset_definition_owner(
+                asset_id.definition(),
+                &executor.context().authority,
+                executor.host(),
+            )?
+        {
+            return Ok(true);
+        }
+
+        let can_freeze_assets_with_definition = CanFreezeAssetWithDefinition {
+            asset_definition: asset_id.definition().clone(),
+        };
+        let can_freeze_asset = CanFreezeAsset {
+            asset: asset_id.clone(),
+        };
+        Ok(can_freeze_assets_with_definition
+            .is_owned_by(&executor.context().authority, executor.host())
+            || can_freeze_asset.is_owned_by(&executor.context().authority, executor.host()))
+    }
 }
 
 pub mod nft {
@@ -1515,6 +1849,8 @@ pub mod trigger {
             | AnyPermission::CanRegisterAssetDefinition(_)
             | AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanManageAccountKeys(_)
+            | AnyPermission::CanFreezeAccount(_)
             | AnyPermission::CanUnregisterAssetDefinition(_)
             | AnyPermission::CanModifyAssetDefinitionMetadata(_)
             | AnyPermission::CanMintAssetWithDefinition(_)
@@ -1523,6 +1859,9 @@ pub mod trigger {
             | AnyPermission::CanMintAsset(_)
             | AnyPermission::CanBurnAsset(_)
             | AnyPermission::CanTransferAsset(_)
+            | AnyPermission::CanFreezeAssetDefinition(_)
+            | AnyPermission::CanFreezeAssetWithDefinition(_)
+            | AnyPermission::CanFreezeAsset(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanRegisterNft(_)
@@ -1604,3 +1943,63 @@ pub mod log {
         execute!(executor, isi)
     }
 }
+
+pub mod event {
+    use super::*;
+
+    /// Events are always emitted on behalf of the authority, so anyone is allowed to emit them.
+    pub fn visit_emit_event<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &EmitEvent) {
+        execute!(executor, isi)
+    }
+}
+
+pub mod swap {
+    use iroha_smart_contract::Iroha;
+
+    use super::*;
+    use crate::data_model::{
+        isi::error::InstructionExecutionError,
+        query::{builder::SingleQueryError, error::FindError, swap::FindSwaps},
+    };
+
+    /// Swaps always offer holdings of the authority, which are checked and locked on execution.
+    pub fn visit_create_swap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &CreateSwap) {
+        execute!(executor, isi)
+    }
+
+    pub fn visit_accept_swap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &AcceptSwap) {
+        // The taker and the expiry of the swap are checked on execution
+        execute!(executor, isi)
+    }
+
+    pub fn visit_cancel_swap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &CancelSwap) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_swap_maker(isi.id(), &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => deny!(executor, "Can't cancel swap created by another account"),
+        }
+    }
+
+    fn is_swap_maker(
+        swap_id: &SwapId,
+        authority: &AccountId,
+        host: &Iroha,
+    ) -> Result<bool, ValidationFail> {
+        let swap = host
+            .query(FindSwaps::new())
+            .filter_with(|swap| swap.id.eq(swap_id.clone()))
+            .execute_single()
+            .map_err(|e| match e {
+                SingleQueryError::QueryError(e) => e,
+                SingleQueryError::ExpectedOneGotNone => ValidationFail::InstructionFailed(
+                    InstructionExecutionError::Find(FindError::Swap(swap_id.clone())),
+                ),
+                _ => unreachable!(),
+            })?;
+
+        Ok(swap.maker() == authority)
+    }
+}
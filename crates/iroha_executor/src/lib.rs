@@ -14,7 +14,7 @@ pub use iroha_executor_derive::{entrypoint, migrate};
 use iroha_schema::{Ident, MetaMap};
 pub use iroha_smart_contract as smart_contract;
 pub use iroha_smart_contract_utils::{dbg, dbg_panic, DebugExpectExt, DebugUnwrapExt};
-pub use smart_contract::{data_model, Iroha};
+pub use smart_contract::{data_model, Hash, Iroha, Signature};
 
 pub mod default;
 pub mod permission;
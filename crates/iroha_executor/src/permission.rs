@@ -97,10 +97,13 @@ declare_permissions! {
     iroha_executor_data_model::permission::account::{CanRegisterAccount},
     iroha_executor_data_model::permission::account::{CanUnregisterAccount},
     iroha_executor_data_model::permission::account::{CanModifyAccountMetadata},
+    iroha_executor_data_model::permission::account::{CanManageAccountKeys},
+    iroha_executor_data_model::permission::account::{CanFreezeAccount},
 
     iroha_executor_data_model::permission::asset_definition::{CanRegisterAssetDefinition},
     iroha_executor_data_model::permission::asset_definition::{CanUnregisterAssetDefinition},
     iroha_executor_data_model::permission::asset_definition::{CanModifyAssetDefinitionMetadata},
+    iroha_executor_data_model::permission::asset_definition::{CanFreezeAssetDefinition},
 
     iroha_executor_data_model::permission::asset::{CanMintAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanBurnAssetWithDefinition},
@@ -108,6 +111,8 @@ declare_permissions! {
     iroha_executor_data_model::permission::asset::{CanMintAsset},
     iroha_executor_data_model::permission::asset::{CanBurnAsset},
     iroha_executor_data_model::permission::asset::{CanTransferAsset},
+    iroha_executor_data_model::permission::asset::{CanFreezeAssetWithDefinition},
+    iroha_executor_data_model::permission::asset::{CanFreezeAsset},
 
     iroha_executor_data_model::permission::nft::{CanRegisterNft},
     iroha_executor_data_model::permission::nft::{CanUnregisterNft},
@@ -290,8 +295,8 @@ pub mod asset {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::asset::{
-        CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanTransferAsset, CanTransferAssetWithDefinition,
+        CanBurnAsset, CanBurnAssetWithDefinition, CanFreezeAsset, CanFreezeAssetWithDefinition,
+        CanMintAsset, CanMintAssetWithDefinition, CanTransferAsset, CanTransferAssetWithDefinition,
     };
 
     use super::*;
@@ -412,6 +417,34 @@ pub mod asset {
         }
     }
 
+    impl ValidateGrantRevoke for CanFreezeAssetWithDefinition {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CanFreezeAsset {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -423,13 +456,23 @@ pub mod asset {
     }
 
     impl_froms!(CanMintAsset, CanBurnAsset, CanTransferAsset);
+
+    // Granted by the issuer rather than by the holder of the asset
+    impl<'t> From<&'t CanFreezeAsset> for super::asset_definition::Owner<'t> {
+        fn from(value: &'t CanFreezeAsset) -> Self {
+            Self {
+                asset_definition: value.asset.definition(),
+            }
+        }
+    }
 }
 
 pub mod asset_definition {
     //! Module with pass conditions for asset definition related tokens
 
     use iroha_executor_data_model::permission::asset_definition::{
-        CanModifyAssetDefinitionMetadata, CanRegisterAssetDefinition, CanUnregisterAssetDefinition,
+        CanFreezeAssetDefinition, CanModifyAssetDefinitionMetadata, CanRegisterAssetDefinition,
+        CanUnregisterAssetDefinition,
     };
 
     use super::*;
@@ -541,6 +584,20 @@ pub mod asset_definition {
         }
     }
 
+    impl ValidateGrantRevoke for CanFreezeAssetDefinition {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -554,9 +611,11 @@ pub mod asset_definition {
     impl_froms!(
         CanUnregisterAssetDefinition,
         CanModifyAssetDefinitionMetadata,
+        CanFreezeAssetDefinition,
         iroha_executor_data_model::permission::asset::CanMintAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanBurnAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanTransferAssetWithDefinition,
+        iroha_executor_data_model::permission::asset::CanFreezeAssetWithDefinition,
     );
 }
 
@@ -712,7 +771,8 @@ pub mod account {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::account::{
-        CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
+        CanFreezeAccount, CanManageAccountKeys, CanModifyAccountMetadata, CanRegisterAccount,
+        CanUnregisterAccount,
     };
 
     use super::*;
@@ -799,6 +859,34 @@ pub mod account {
         }
     }
 
+    impl ValidateGrantRevoke for CanManageAccountKeys {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CanFreezeAccount {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::domain::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::domain::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -809,7 +897,20 @@ pub mod account {
         };
     }
 
-    impl_froms!(CanUnregisterAccount, CanModifyAccountMetadata,);
+    impl_froms!(
+        CanUnregisterAccount,
+        CanModifyAccountMetadata,
+        CanManageAccountKeys,
+    );
+
+    // Only the domain owner can freeze accounts, so that the account itself can't revoke the permission
+    impl<'t> From<&'t CanFreezeAccount> for super::domain::Owner<'t> {
+        fn from(value: &'t CanFreezeAccount) -> Self {
+            Self {
+                domain: value.account.domain(),
+            }
+        }
+    }
 }
 
 pub mod trigger {
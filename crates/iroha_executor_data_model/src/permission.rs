@@ -82,6 +82,12 @@ pub mod asset_definition {
             pub asset_definition: AssetDefinitionId,
         }
     }
+
+    permission! {
+        pub struct CanFreezeAssetDefinition {
+            pub asset_definition: AssetDefinitionId,
+        }
+    }
 }
 
 pub mod account {
@@ -103,6 +109,18 @@ pub mod account {
             pub account: AccountId,
         }
     }
+
+    permission! {
+        pub struct CanManageAccountKeys {
+            pub account: AccountId,
+        }
+    }
+
+    permission! {
+        pub struct CanFreezeAccount {
+            pub account: AccountId,
+        }
+    }
 }
 
 pub mod asset {
@@ -126,6 +144,12 @@ pub mod asset {
         }
     }
 
+    permission! {
+        pub struct CanFreezeAssetWithDefinition {
+            pub asset_definition: AssetDefinitionId,
+        }
+    }
+
     permission! {
         pub struct CanMintAsset {
             pub asset: AssetId,
@@ -143,6 +167,12 @@ pub mod asset {
             pub asset: AssetId,
         }
     }
+
+    permission! {
+        pub struct CanFreezeAsset {
+            pub asset: AssetId,
+        }
+    }
 }
 
 pub mod nft {
@@ -124,6 +124,22 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_unregister_account(operation: &Unregister<Account>)",
         "fn visit_set_account_key_value(operation: &SetKeyValue<Account>)",
         "fn visit_remove_account_key_value(operation: &RemoveKeyValue<Account>)",
+        "fn visit_add_signatory(operation: &AddSignatory)",
+        "fn visit_remove_signatory(operation: &RemoveSignatory)",
+        "fn visit_rotate_account_key(operation: &RotateAccountKey)",
+        "fn visit_register_alias(operation: &RegisterAlias)",
+        "fn visit_unregister_alias(operation: &UnregisterAlias)",
+        "fn visit_approve(operation: &Approve)",
+        "fn visit_transfer_from(operation: &TransferFrom)",
+        "fn visit_freeze_account(operation: &Freeze<Account>)",
+        "fn visit_freeze_asset_definition(operation: &Freeze<AssetDefinition>)",
+        "fn visit_freeze_asset(operation: &Freeze<Asset>)",
+        "fn visit_unfreeze_account(operation: &Unfreeze<Account>)",
+        "fn visit_unfreeze_asset_definition(operation: &Unfreeze<AssetDefinition>)",
+        "fn visit_unfreeze_asset(operation: &Unfreeze<Asset>)",
+        "fn visit_create_swap(operation: &CreateSwap)",
+        "fn visit_accept_swap(operation: &AcceptSwap)",
+        "fn visit_cancel_swap(operation: &CancelSwap)",
         "fn visit_register_nft(operation: &Register<Nft>)",
         "fn visit_unregister_nft(operation: &Unregister<Nft>)",
         "fn visit_mint_asset_numeric(operation: &Mint<Numeric, Asset>)",
@@ -155,6 +171,7 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_set_parameter(operation: &SetParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
+        "fn visit_emit_event(operation: &EmitEvent)",
         "fn visit_custom_instruction(operation: &CustomInstruction)",
     ]
     .into_iter()
@@ -0,0 +1,107 @@
+use std::{
+    collections::BTreeSet,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{Args as ClapArgs, Subcommand};
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha_core::snapshot::{read_manifests, verify_snapshot, Manifest};
+
+use crate::{Outcome, RunArgs};
+
+/// State snapshot inspector
+#[derive(Debug, ClapArgs, Clone)]
+pub struct Args {
+    /// Height of the snapshot to inspect.
+    /// Defaults to all the stored snapshots
+    #[clap(long, name = "SNAPSHOT_HEIGHT")]
+    height: Option<u64>,
+    /// Directory where snapshots are stored (`snapshot.store_dir` of the peer configuration)
+    #[clap()]
+    path_to_snapshot_store: PathBuf,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Command {
+    /// Check that snapshots are complete and their chunks match the manifest hashes
+    Verify,
+    /// Print the manifests of snapshots and how many chunks they share
+    Inspect,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let manifests = read_manifests(&self.path_to_snapshot_store)
+            .wrap_err("failed to read snapshot manifests")?;
+        let manifests = match self.height {
+            Some(height) => {
+                let manifest = manifests
+                    .into_iter()
+                    .find(|manifest| manifest.height == height)
+                    .ok_or_else(|| eyre!("There is no snapshot at height {height}"))?;
+                vec![manifest]
+            }
+            None => manifests,
+        };
+
+        match self.command {
+            Command::Verify => verify(writer, &self.path_to_snapshot_store, &manifests),
+            Command::Inspect => inspect(writer, &manifests),
+        }
+    }
+}
+
+fn verify(writer: &mut dyn Write, store_dir: &Path, manifests: &[Manifest]) -> Outcome {
+    let mut failed = 0;
+    for manifest in manifests {
+        match verify_snapshot(store_dir, manifest) {
+            Ok(()) => writeln!(writer, "Snapshot at height {}: ok", manifest.height)?,
+            Err(error) => {
+                failed += 1;
+                writeln!(writer, "Snapshot at height {}: {error}", manifest.height)?;
+            }
+        }
+    }
+    writer.flush()?;
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(eyre!(
+            "{failed} of {} snapshots are invalid",
+            manifests.len()
+        ))
+    }
+}
+
+fn inspect(writer: &mut dyn Write, manifests: &[Manifest]) -> Outcome {
+    let mut previous_chunks = BTreeSet::new();
+    for manifest in manifests {
+        let chunks = manifest
+            .chunks
+            .iter()
+            .map(|chunk| chunk.hash)
+            .collect::<BTreeSet<_>>();
+
+        writeln!(writer, "Snapshot at height {}", manifest.height)?;
+        match manifest.latest_block_hash {
+            Some(hash) => writeln!(writer, "  latest block: {hash}")?,
+            None => writeln!(writer, "  latest block: none")?,
+        }
+        writeln!(writer, "  size: {} bytes", manifest.len())?;
+        writeln!(
+            writer,
+            "  chunks: {} ({} shared with the previous snapshot)",
+            manifest.chunks.len(),
+            chunks.intersection(&previous_chunks).count()
+        )?;
+
+        previous_chunks = chunks;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
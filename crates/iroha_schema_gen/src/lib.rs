@@ -51,6 +51,7 @@ pub fn build_schemas() -> MetaMap {
         SignedTransaction,
         SignedQuery,
         QueryResponse,
+        TransactionSimulation,
 
         // Event stream
         EventMessage,
@@ -62,6 +63,7 @@ pub fn build_schemas() -> MetaMap {
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
+        MerkleTree<StateEntry>,
 
         // Default permissions
         permission::peer::CanManagePeers,
@@ -73,10 +75,13 @@ pub fn build_schemas() -> MetaMap {
         permission::account::CanRegisterAccount,
         permission::account::CanUnregisterAccount,
         permission::account::CanModifyAccountMetadata,
+        permission::account::CanManageAccountKeys,
+        permission::account::CanFreezeAccount,
 
         permission::asset_definition::CanRegisterAssetDefinition,
         permission::asset_definition::CanUnregisterAssetDefinition,
         permission::asset_definition::CanModifyAssetDefinitionMetadata,
+        permission::asset_definition::CanFreezeAssetDefinition,
 
         permission::asset::CanMintAssetWithDefinition,
         permission::asset::CanBurnAssetWithDefinition,
@@ -84,6 +89,8 @@ pub fn build_schemas() -> MetaMap {
         permission::asset::CanMintAsset,
         permission::asset::CanBurnAsset,
         permission::asset::CanTransferAsset,
+        permission::asset::CanFreezeAssetWithDefinition,
+        permission::asset::CanFreezeAsset,
 
         permission::nft::CanRegisterNft,
         permission::nft::CanUnregisterNft,
@@ -117,7 +124,13 @@ pub fn build_schemas() -> MetaMap {
 }
 
 types!(
+    AcceptSwap,
     Account,
+    AccountAlias,
+    AccountAliasChanged,
+    AccountAliasPredicateAtom,
+    AccountAliasProjection<PredicateMarker>,
+    AccountAliasProjection<SelectorMarker>,
     AccountEvent,
     AccountEventFilter,
     AccountEventSet,
@@ -126,16 +139,62 @@ types!(
     AccountIdProjection<PredicateMarker>,
     AccountIdProjection<SelectorMarker>,
     AccountPermissionChanged,
+    AccountPermissions,
     AccountPredicateAtom,
     AccountProjection<PredicateMarker>,
     AccountProjection<SelectorMarker>,
     AccountRoleChanged,
+    AccountRole,
+    AccountSignatoryChanged,
     Action,
     ActionPredicateAtom,
     ActionProjection<PredicateMarker>,
     ActionProjection<SelectorMarker>,
+    AddSignatory,
+    Aggregate<Account>,
+    Aggregate<AssetDefinition>,
+    Aggregate<Asset>,
+    Aggregate<AssetAllowance>,
+    Aggregate<BlockHeader>,
+    Aggregate<CommittedTransaction>,
+    Aggregate<Domain>,
+    Aggregate<Nft>,
+    Aggregate<PeerId>,
+    Aggregate<Permission>,
+    Aggregate<RoleId>,
+    Aggregate<Role>,
+    Aggregate<SignedBlock>,
+    Aggregate<TriggerId>,
+    Aggregate<Swap>,
+    Aggregate<Trigger>,
+    AggregateFunction<Account>,
+    AggregateFunction<AssetDefinition>,
+    AggregateFunction<Asset>,
+    AggregateFunction<AssetAllowance>,
+    AggregateFunction<BlockHeader>,
+    AggregateFunction<CommittedTransaction>,
+    AggregateFunction<Domain>,
+    AggregateFunction<Nft>,
+    AggregateFunction<PeerId>,
+    AggregateFunction<Permission>,
+    AggregateFunction<RoleId>,
+    AggregateFunction<Role>,
+    AggregateFunction<SignedBlock>,
+    AggregateFunction<TriggerId>,
+    AggregateFunction<Swap>,
+    AggregateFunction<Trigger>,
     Algorithm,
+    AliasedAccount,
+    Approve,
     Asset,
+    AssetAllowance,
+    AssetAllowanceId,
+    AssetAllowanceIdPredicateAtom,
+    AssetAllowanceIdProjection<PredicateMarker>,
+    AssetAllowanceIdProjection<SelectorMarker>,
+    AssetAllowancePredicateAtom,
+    AssetAllowanceProjection<PredicateMarker>,
+    AssetAllowanceProjection<SelectorMarker>,
     AssetChanged,
     AssetDefinition,
     AssetDefinitionEvent,
@@ -166,6 +225,7 @@ types!(
     BTreeMap<u64, TransactionRejectionReason>,
     BTreeSet<AccountId>,
     BTreeSet<Permission>,
+    BTreeSet<PublicKey>,
     BTreeSet<String>,
     BlockEvent,
     BlockEventFilter,
@@ -187,6 +247,7 @@ types!(
     Box<CompoundPredicate<Account>>,
     Box<CompoundPredicate<AssetDefinition>>,
     Box<CompoundPredicate<Asset>>,
+    Box<CompoundPredicate<AssetAllowance>>,
     Box<CompoundPredicate<BlockHeader>>,
     Box<CompoundPredicate<CommittedTransaction>>,
     Box<CompoundPredicate<Domain>>,
@@ -197,11 +258,13 @@ types!(
     Box<CompoundPredicate<Role>>,
     Box<CompoundPredicate<SignedBlock>>,
     Box<CompoundPredicate<TriggerId>>,
+    Box<CompoundPredicate<Swap>>,
     Box<CompoundPredicate<Trigger>>,
     Box<TransactionRejectionReason>,
     Burn<Numeric, Asset>,
     Burn<u32, Trigger>,
     BurnBox,
+    CancelSwap,
     ChainId,
     CommittedTransaction,
     CommittedTransactionPredicateAtom,
@@ -210,6 +273,7 @@ types!(
     CompoundPredicate<Account>,
     CompoundPredicate<AssetDefinition>,
     CompoundPredicate<Asset>,
+    CompoundPredicate<AssetAllowance>,
     CompoundPredicate<BlockHeader>,
     CompoundPredicate<CommittedTransaction>,
     CompoundPredicate<Domain>,
@@ -220,6 +284,7 @@ types!(
     CompoundPredicate<Role>,
     CompoundPredicate<SignedBlock>,
     CompoundPredicate<TriggerId>,
+    CompoundPredicate<Swap>,
     CompoundPredicate<Trigger>,
     ConfigurationEvent,
     ConfigurationEventFilter,
@@ -227,6 +292,11 @@ types!(
     ConstString,
     ConstVec<InstructionBox>,
     ConstVec<u8>,
+    CreateSwap,
+    CronExpression,
+    CronSchedule,
+    CustomEvent,
+    CustomEventFilter,
     CustomInstruction,
     CustomParameter,
     CustomParameterId,
@@ -244,6 +314,7 @@ types!(
     DomainPredicateAtom,
     DomainProjection<PredicateMarker>,
     DomainProjection<SelectorMarker>,
+    EmitEvent,
     EventBox,
     EventFilterBox,
     EventMessage,
@@ -261,9 +332,13 @@ types!(
     WasmPath,
     ExecutorUpgrade,
     FetchSize,
+    FindAccountByAlias,
+    FindAccountStateProof,
     FindAccounts,
     FindAccountsWithAsset,
     FindActiveTriggerIds,
+    FindAssetStateProof,
+    FindAssetAllowances,
     FindAssets,
     FindAssetsDefinitions,
     FindBlockHeaders,
@@ -278,9 +353,15 @@ types!(
     FindRoleIds,
     FindRoles,
     FindRolesByAccountId,
+    FindSwaps,
+    FindTransactionProof,
     FindTransactions,
     FindTriggers,
     ForwardCursor,
+    Freeze<Account>,
+    Freeze<Asset>,
+    Freeze<AssetDefinition>,
+    FreezeBox,
     GenesisWasmAction,
     GenesisWasmTrigger,
     Grant<Permission, Account>,
@@ -290,7 +371,9 @@ types!(
     Hash,
     HashOf<BlockHeader>,
     HashOf<MerkleTree<SignedTransaction>>,
+    HashOf<MerkleTree<StateEntry>>,
     HashOf<SignedTransaction>,
+    HashOf<StateEntry>,
     HashOf<Vec<InstructionBox>>,
     IdBox,
     InstructionBox,
@@ -307,9 +390,14 @@ types!(
     JsonProjection<PredicateMarker>,
     JsonProjection<SelectorMarker>,
     Level,
+    LockedAsset,
+    LockedNft,
     Log,
     MathError,
+    MerkleProof<SignedTransaction>,
+    MerkleProof<StateEntry>,
     MerkleTree<SignedTransaction>,
+    MerkleTree<StateEntry>,
     Metadata,
     MetadataChanged<AccountId>,
     MetadataChanged<AssetDefinitionId>,
@@ -354,16 +442,36 @@ types!(
     NumericPredicateAtom,
     NumericProjection<PredicateMarker>,
     NumericProjection<SelectorMarker>,
+    NumericRange,
     NumericSpec,
+    Option<Aggregate<Account>>,
+    Option<Aggregate<AssetDefinition>>,
+    Option<Aggregate<Asset>>,
+    Option<Aggregate<AssetAllowance>>,
+    Option<Aggregate<BlockHeader>>,
+    Option<Aggregate<CommittedTransaction>>,
+    Option<Aggregate<Domain>>,
+    Option<Aggregate<Nft>>,
+    Option<Aggregate<PeerId>>,
+    Option<Aggregate<Permission>>,
+    Option<Aggregate<RoleId>>,
+    Option<Aggregate<Role>>,
+    Option<Aggregate<SignedBlock>>,
+    Option<Aggregate<TriggerId>>,
+    Option<Aggregate<Swap>>,
+    Option<Aggregate<Trigger>>,
     Option<AccountId>,
     Option<AssetDefinitionId>,
     Option<AssetId>,
     Option<BlockStatus>,
     Option<DomainId>,
+    Option<Executor>,
     Option<ForwardCursor>,
     Option<HashOf<BlockHeader>>,
     Option<HashOf<MerkleTree<SignedTransaction>>>,
+    Option<HashOf<MerkleTree<StateEntry>>>,
     Option<HashOf<SignedTransaction>>,
+    Option<HashOf<StateEntry>>,
     Option<IpfsPath>,
     Option<Name>,
     Option<NftId>,
@@ -373,6 +481,23 @@ types!(
     Option<Parameters>,
     Option<PeerId>,
     Option<RoleId>,
+    Option<AccountAlias>,
+    Option<AccountProjection<SelectorMarker>>,
+    Option<AssetDefinitionProjection<SelectorMarker>>,
+    Option<AssetProjection<SelectorMarker>>,
+    Option<AssetAllowanceProjection<SelectorMarker>>,
+    Option<BlockHeaderProjection<SelectorMarker>>,
+    Option<CommittedTransactionProjection<SelectorMarker>>,
+    Option<DomainProjection<SelectorMarker>>,
+    Option<NftProjection<SelectorMarker>>,
+    Option<PeerIdProjection<SelectorMarker>>,
+    Option<PermissionProjection<SelectorMarker>>,
+    Option<RoleIdProjection<SelectorMarker>>,
+    Option<RoleProjection<SelectorMarker>>,
+    Option<SignedBlockProjection<SelectorMarker>>,
+    Option<TriggerIdProjection<SelectorMarker>>,
+    Option<SwapProjection<SelectorMarker>>,
+    Option<TriggerProjection<SelectorMarker>>,
     Option<TransactionRejectionReason>,
     Option<TransactionStatus>,
     Option<TriggerCompletedOutcomeType>,
@@ -416,6 +541,7 @@ types!(
     QueryWithFilter<FindAccountsWithAsset>,
     QueryWithFilter<FindActiveTriggerIds>,
     QueryWithFilter<FindAssets>,
+    QueryWithFilter<FindAssetAllowances>,
     QueryWithFilter<FindAssetsDefinitions>,
     QueryWithFilter<FindBlockHeaders>,
     QueryWithFilter<FindBlocks>,
@@ -426,6 +552,7 @@ types!(
     QueryWithFilter<FindRoleIds>,
     QueryWithFilter<FindRoles>,
     QueryWithFilter<FindRolesByAccountId>,
+    QueryWithFilter<FindSwaps>,
     QueryWithFilter<FindTransactions>,
     QueryWithFilter<FindTriggers>,
     QueryWithParams,
@@ -436,6 +563,7 @@ types!(
     Register<Peer>,
     Register<Role>,
     Register<Trigger>,
+    RegisterAlias,
     RegisterBox,
     RemoveKeyValue<Account>,
     RemoveKeyValue<AssetDefinition>,
@@ -443,6 +571,7 @@ types!(
     RemoveKeyValue<Nft>,
     RemoveKeyValue<Trigger>,
     RemoveKeyValueBox,
+    RemoveSignatory,
     Repeats,
     RepetitionError,
     Revoke<Permission, Account>,
@@ -461,9 +590,11 @@ types!(
     RolePredicateAtom,
     RoleProjection<PredicateMarker>,
     RoleProjection<SelectorMarker>,
+    RotateAccountKey,
     SelectorTuple<Account>,
     SelectorTuple<AssetDefinition>,
     SelectorTuple<Asset>,
+    SelectorTuple<AssetAllowance>,
     SelectorTuple<BlockHeader>,
     SelectorTuple<CommittedTransaction>,
     SelectorTuple<Domain>,
@@ -474,6 +605,7 @@ types!(
     SelectorTuple<Role>,
     SelectorTuple<SignedBlock>,
     SelectorTuple<TriggerId>,
+    SelectorTuple<Swap>,
     SelectorTuple<Trigger>,
     SetKeyValue<Account>,
     SetKeyValue<AssetDefinition>,
@@ -500,18 +632,53 @@ types!(
     SignedTransactionV1,
     SingularQueryBox,
     SingularQueryOutputBox,
+    SingularQueryWithParams,
     SmartContractParameter,
     SmartContractParameters,
     SocketAddr,
     SocketAddrHost,
     SocketAddrV4,
     SocketAddrV6,
+    SortKey<Account>,
+    SortKey<AssetDefinition>,
+    SortKey<Asset>,
+    SortKey<AssetAllowance>,
+    SortKey<BlockHeader>,
+    SortKey<CommittedTransaction>,
+    SortKey<Domain>,
+    SortKey<Nft>,
+    SortKey<PeerId>,
+    SortKey<Permission>,
+    SortKey<RoleId>,
+    SortKey<Role>,
+    SortKey<SignedBlock>,
+    SortKey<TriggerId>,
+    SortKey<Swap>,
+    SortKey<Trigger>,
+    SortOrder,
     Sorting,
+    StateEntry,
+    StateProof,
     Status,
     String,
     StringPredicateAtom,
     SumeragiParameter,
     SumeragiParameters,
+    Swap,
+    SwapAccepted,
+    SwapAsset,
+    SwapEvent,
+    SwapEventFilter,
+    SwapEventSet,
+    SwapExpiration,
+    SwapId,
+    SwapIdPredicateAtom,
+    SwapIdProjection<PredicateMarker>,
+    SwapIdProjection<SelectorMarker>,
+    SwapItem,
+    SwapPredicateAtom,
+    SwapProjection<PredicateMarker>,
+    SwapProjection<SelectorMarker>,
     TimeEvent,
     TimeEventFilter,
     TimeInterval,
@@ -528,14 +695,17 @@ types!(
     TransactionParameter,
     TransactionParameters,
     TransactionPayload,
+    TransactionProof,
     TransactionRejectionReason,
     TransactionSignature,
+    TransactionSimulation,
     TransactionStatus,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Account, DomainId, Account>,
     Transfer<Account, NftId, Account>,
     Transfer<Asset, Numeric, Account>,
     TransferBox,
+    TransferFrom,
     Trigger,
     TriggerCompletedEvent,
     TriggerCompletedEventFilter,
@@ -553,6 +723,13 @@ types!(
     TriggerProjection<PredicateMarker>,
     TriggerProjection<SelectorMarker>,
     TypeError,
+    U64PredicateAtom,
+    U64Projection<PredicateMarker>,
+    U64Projection<SelectorMarker>,
+    Unfreeze<Account>,
+    Unfreeze<Asset>,
+    Unfreeze<AssetDefinition>,
+    UnfreezeBox,
     Unregister<Account>,
     Unregister<AssetDefinition>,
     Unregister<Domain>,
@@ -560,6 +737,7 @@ types!(
     Unregister<Peer>,
     Unregister<Role>,
     Unregister<Trigger>,
+    UnregisterAlias,
     UnregisterBox,
     Upgrade,
     Uptime,
@@ -568,6 +746,8 @@ types!(
     Vec<AccountId>,
     Vec<Action>,
     Vec<Asset>,
+    Vec<AssetAllowance>,
+    Vec<AssetAllowanceId>,
     Vec<AssetId>,
     Vec<AssetDefinition>,
     Vec<AssetDefinitionId>,
@@ -577,6 +757,7 @@ types!(
     Vec<CompoundPredicate<Account>>,
     Vec<CompoundPredicate<AssetDefinition>>,
     Vec<CompoundPredicate<Asset>>,
+    Vec<CompoundPredicate<AssetAllowance>>,
     Vec<CompoundPredicate<BlockHeader>>,
     Vec<CompoundPredicate<CommittedTransaction>>,
     Vec<CompoundPredicate<Domain>>,
@@ -587,7 +768,9 @@ types!(
     Vec<CompoundPredicate<Role>>,
     Vec<CompoundPredicate<SignedBlock>>,
     Vec<CompoundPredicate<TriggerId>>,
+    Vec<CompoundPredicate<Swap>>,
     Vec<CompoundPredicate<Trigger>>,
+    Vec<DataEvent>,
     Vec<Domain>,
     Vec<DomainId>,
     Vec<EventFilterBox>,
@@ -605,9 +788,26 @@ types!(
     Vec<RoleId>,
     Vec<SignedBlock>,
     Vec<SignedTransaction>,
+    Vec<SortKey<Account>>,
+    Vec<SortKey<AssetDefinition>>,
+    Vec<SortKey<Asset>>,
+    Vec<SortKey<AssetAllowance>>,
+    Vec<SortKey<BlockHeader>>,
+    Vec<SortKey<CommittedTransaction>>,
+    Vec<SortKey<Domain>>,
+    Vec<SortKey<Nft>>,
+    Vec<SortKey<PeerId>>,
+    Vec<SortKey<Permission>>,
+    Vec<SortKey<RoleId>>,
+    Vec<SortKey<Role>>,
+    Vec<SortKey<SignedBlock>>,
+    Vec<SortKey<TriggerId>>,
+    Vec<SortKey<Swap>>,
+    Vec<SortKey<Trigger>>,
     Vec<AccountProjection<SelectorMarker>>,
     Vec<AssetDefinitionProjection<SelectorMarker>>,
     Vec<AssetProjection<SelectorMarker>>,
+    Vec<AssetAllowanceProjection<SelectorMarker>>,
     Vec<BlockHeaderProjection<SelectorMarker>>,
     Vec<CommittedTransactionProjection<SelectorMarker>>,
     Vec<DomainProjection<SelectorMarker>>,
@@ -616,6 +816,9 @@ types!(
     Vec<Metadata>,
     Vec<Name>,
     Vec<Numeric>,
+    Vec<Option<AccountAlias>>,
+    Vec<Option<HashOf<SignedTransaction>>>,
+    Vec<Option<HashOf<StateEntry>>>,
     Vec<Option<TransactionRejectionReason>>,
     Vec<PeerIdProjection<SelectorMarker>>,
     Vec<PermissionProjection<SelectorMarker>>,
@@ -625,9 +828,13 @@ types!(
     Vec<SignedBlockProjection<SelectorMarker>>,
     Vec<String>,
     Vec<TriggerIdProjection<SelectorMarker>>,
+    Vec<SwapProjection<SelectorMarker>>,
     Vec<TriggerProjection<SelectorMarker>>,
+    Vec<Swap>,
+    Vec<SwapId>,
     Vec<Trigger>,
     Vec<TriggerId>,
+    Vec<u64>,
     Vec<u8>,
     WasmExecutionFail,
     WasmSmartContract,
@@ -683,7 +890,10 @@ pub mod complete_data_model {
             parameters::{ForwardCursor, QueryParams},
             CommittedTransaction, QueryOutput, QueryOutputBatchBox, QueryOutputBatchBoxTuple,
             QueryRequestWithAuthority, QueryResponse, QuerySignature, QueryWithFilter,
-            QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
+            QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox, TransactionProof,
+        },
+        state::{
+            AccountPermissions, AccountRole, AliasedAccount, LockedAsset, LockedNft, SwapExpiration,
         },
         transaction::{
             error::TransactionLimitError, SignedTransactionV1, TransactionPayload,
@@ -745,6 +955,8 @@ mod tests {
         insert_into_test_map!(
             iroha_executor_data_model::permission::account::CanModifyAccountMetadata
         );
+        insert_into_test_map!(iroha_executor_data_model::permission::account::CanManageAccountKeys);
+        insert_into_test_map!(iroha_executor_data_model::permission::account::CanFreezeAccount);
         insert_into_test_map!(
             iroha_executor_data_model::permission::asset_definition::CanRegisterAssetDefinition
         );
@@ -752,6 +964,9 @@ mod tests {
             iroha_executor_data_model::permission::asset_definition::CanUnregisterAssetDefinition
         );
         insert_into_test_map!(iroha_executor_data_model::permission::asset_definition::CanModifyAssetDefinitionMetadata);
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::asset_definition::CanFreezeAssetDefinition
+        );
         insert_into_test_map!(
             iroha_executor_data_model::permission::asset::CanMintAssetWithDefinition
         );
@@ -764,6 +979,10 @@ mod tests {
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanMintAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanBurnAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanTransferAsset);
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::asset::CanFreezeAssetWithDefinition
+        );
+        insert_into_test_map!(iroha_executor_data_model::permission::asset::CanFreezeAsset);
 
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanRegisterNft);
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanUnregisterNft);
@@ -14,11 +14,12 @@ use data_model::{
     prelude::*,
     query::{parameters::ForwardCursor, Query},
 };
+pub use iroha_crypto::{Hash, Signature};
 pub use iroha_data_model as data_model;
 use iroha_data_model::query::{
     builder::{QueryBuilder, QueryExecutor},
     QueryOutputBatchBoxTuple, QueryRequest, QueryResponse, QueryWithParams, SingularQuery,
-    SingularQueryBox, SingularQueryOutputBox,
+    SingularQueryBox, SingularQueryOutputBox, SingularQueryWithParams,
 };
 pub use iroha_smart_contract_derive::main;
 pub use iroha_smart_contract_utils::{dbg, dbg_panic, DebugExpectExt, DebugUnwrapExt};
@@ -98,6 +99,68 @@ impl Iroha {
         Ok(())
     }
 
+    /// Emits a custom data event on behalf of the authority.
+    /// Subscribers and triggers can filter it by name and emitter with a `CustomEventFilter`.
+    ///
+    /// # Errors
+    /// Fails if the executor rejects the event or the instruction limit is reached
+    #[expect(clippy::unused_self)]
+    pub fn emit_event(&self, name: Name, payload: impl Into<Json>) -> Result<(), ValidationFail> {
+        #[cfg(not(test))]
+        use host::emit_event as host_emit_event;
+        #[cfg(test)]
+        use tests::_iroha_smart_contract_emit_event_mock as host_emit_event;
+
+        let event = EmitEvent::new(name, payload);
+        // Safety: - `host_emit_event` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(encode_and_execute(&event, host_emit_event)) }
+    }
+
+    /// Hash `bytes` with Blake2b on the host.
+    ///
+    /// Like any [`Hash`], the result has its least significant bit set to 1,
+    /// so it differs from a raw Blake2b-256 digest but matches hashes computed by Iroha.
+    ///
+    /// Much cheaper in fuel than hashing inside WASM.
+    #[expect(clippy::unused_self)]
+    pub fn hash(&self, bytes: &[u8]) -> Hash {
+        #[cfg(not(test))]
+        use host::hash as host_hash;
+        #[cfg(test)]
+        use tests::_iroha_smart_contract_hash_mock as host_hash;
+
+        // Safety: - `host_hash` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(encode_and_execute(&bytes, host_hash)) }
+    }
+
+    /// Check on the host that `signature` of `payload` was made with `public_key`.
+    /// Keys of all algorithms supported by Iroha are accepted.
+    ///
+    /// Much cheaper in fuel than verifying inside WASM.
+    #[expect(clippy::unused_self)]
+    pub fn verify_signature(
+        &self,
+        signature: &Signature,
+        public_key: &PublicKey,
+        payload: &[u8],
+    ) -> bool {
+        #[cfg(not(test))]
+        use host::verify_signature as host_verify_signature;
+        #[cfg(test)]
+        use tests::_iroha_smart_contract_verify_signature_mock as host_verify_signature;
+
+        // Safety: - `host_verify_signature` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(
+                &(public_key, signature, payload),
+                host_verify_signature,
+            ))
+        }
+    }
+
     /// Build an iterable query for execution in a smart contract.
     pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
     where
@@ -118,7 +181,7 @@ impl Iroha {
         Q::Output: TryFrom<SingularQueryOutputBox>,
         <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
     {
-        let query = SingularQueryBox::from(query);
+        let query = SingularQueryWithParams::new(SingularQueryBox::from(query), None);
 
         let result = self.execute_singular_query(query)?;
 
@@ -147,7 +210,7 @@ impl QueryExecutor for Iroha {
 
     fn execute_singular_query(
         &self,
-        query: SingularQueryBox,
+        query: SingularQueryWithParams,
     ) -> Result<SingularQueryOutputBox, Self::Error> {
         let QueryResponse::Singular(output) = Self::execute_query(&QueryRequest::Singular(query))?
         else {
@@ -233,6 +296,33 @@ mod host {
         /// This function doesn't take ownership of the provided allocation
         /// but it does transfer ownership of the result to the caller
         pub(super) fn execute_instruction(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Emit encoded custom event by providing offset and length
+        /// into WebAssembly's linear memory where event is stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn emit_event(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Hash encoded bytes by providing offset and length
+        /// into WebAssembly's linear memory where bytes are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn hash(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Verify encoded public key, signature and payload by providing offset and length
+        /// into WebAssembly's linear memory where they are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn verify_signature(ptr: *const u8, len: usize) -> *const u8;
     }
 }
 
@@ -246,7 +336,7 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
     use core::{mem::ManuallyDrop, slice};
 
     use iroha_data_model::query::{
@@ -259,12 +349,17 @@ mod tests {
     use super::*;
 
     const ISI_RESULT: Result<(), ValidationFail> = Ok(());
+    const TEST_PAYLOAD: &[u8] = b"payload";
 
     fn get_test_instruction() -> InstructionBox {
         let new_asset_id: AssetId = "tulip##ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland".parse().unwrap();
         Mint::asset_numeric(1_u32, new_asset_id).into()
     }
 
+    fn get_test_event() -> EmitEvent {
+        EmitEvent::new("order_filled".parse().unwrap(), Json::new(42_u32))
+    }
+
     fn get_test_query() -> QueryWithParams {
         let asset_id: AssetId = "rose##ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland".parse().unwrap();
 
@@ -293,6 +388,45 @@ mod tests {
         ManuallyDrop::new(encode_with_length_prefix(&ISI_RESULT)).as_ptr()
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_emit_event_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let event = EmitEvent::decode_all(&mut &*bytes);
+        assert_eq!(get_test_event(), event.unwrap());
+
+        ManuallyDrop::new(encode_with_length_prefix(&ISI_RESULT)).as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_hash_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let payload = Vec::<u8>::decode_all(&mut &*bytes).unwrap();
+        assert_eq!(payload, TEST_PAYLOAD);
+
+        ManuallyDrop::new(encode_with_length_prefix(&Hash::prehashed(
+            [1; Hash::LENGTH],
+        )))
+        .as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_verify_signature_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let (_public_key, _signature, payload) =
+            <(PublicKey, Signature, Vec<u8>)>::decode_all(&mut &*bytes).unwrap();
+
+        ManuallyDrop::new(encode_with_length_prefix(&(payload == TEST_PAYLOAD))).as_ptr()
+    }
+
     #[no_mangle]
     pub unsafe extern "C" fn _iroha_smart_contract_execute_query_mock(
         ptr: *const u8,
@@ -317,6 +451,31 @@ mod tests {
         host.submit(&get_test_instruction()).unwrap();
     }
 
+    #[webassembly_test]
+    fn emit_event() {
+        let host = Iroha;
+        let EmitEvent { name, payload } = get_test_event();
+        host.emit_event(name, payload).unwrap();
+    }
+
+    #[webassembly_test]
+    fn hash() {
+        let host = Iroha;
+        assert_eq!(host.hash(TEST_PAYLOAD), Hash::prehashed([1; Hash::LENGTH]));
+    }
+
+    #[webassembly_test]
+    fn verify_signature() {
+        let host = Iroha;
+        let public_key: PublicKey =
+            "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03"
+                .parse()
+                .unwrap();
+        let signature = Signature::from_bytes(&[0; 64]);
+        assert!(host.verify_signature(&signature, &public_key, TEST_PAYLOAD));
+        assert!(!host.verify_signature(&signature, &public_key, b"tampered"));
+    }
+
     #[webassembly_test]
     fn execute_query() {
         let host = Iroha;
@@ -157,6 +157,8 @@ pub struct Metrics {
     pub queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Number of Torii requests rejected due to rate limits
+    pub rate_limited_requests: IntCounterVec,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
@@ -244,6 +246,14 @@ impl Default for Metrics {
             .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let rate_limited_requests = IntCounterVec::new(
+            Opts::new(
+                "rate_limited_requests",
+                "Torii requests rejected due to rate limits",
+            ),
+            &["endpoint", "key"],
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -271,7 +281,8 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            dropped_messages,
+            rate_limited_requests
         );
 
         Self {
@@ -290,6 +301,7 @@ impl Default for Metrics {
             view_changes,
             queue_size,
             dropped_messages,
+            rate_limited_requests,
             registry,
         }
     }
@@ -15,7 +15,7 @@ use iroha_executor_data_model::permission::{
 };
 use iroha_genesis::{GenesisBlock, RawGenesisTransaction};
 use iroha_primitives::unique_vec::UniqueVec;
-use iroha_test_samples::{ALICE_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR};
+use iroha_test_samples::{ALICE_ID, ALICE_KEYPAIR, SAMPLE_GENESIS_ACCOUNT_KEYPAIR};
 use toml::Table;
 
 pub fn chain_id() -> ChainId {
@@ -34,6 +34,8 @@ pub fn base_iroha_config() -> Table {
         .write(["kura", "store_dir"], "./storage")
         .write(["network", "block_gossip_size"], 1)
         .write(["logger", "level"], "DEBUG")
+        // Operator endpoints, e.g. status, are used to monitor the peers
+        .write(["torii", "admin_public_keys"], [ALICE_KEYPAIR.public_key()])
 }
 
 pub fn genesis<T: Instruction>(
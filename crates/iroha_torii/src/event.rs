@@ -2,16 +2,42 @@
 //! This module contains descriptions of such an events and
 //! utility Iroha Special Instructions to work with them.
 
-use iroha_data_model::events::prelude::*;
+use std::{
+    collections::VecDeque,
+    num::NonZeroU64,
+    sync::{Arc, Mutex},
+};
+
+use iroha_core::{kura::Kura, query::history::StateHistory, EventsSender};
+use iroha_data_model::{
+    block::SignedBlock,
+    events::{
+        pipeline::{
+            BlockEvent, BlockStatus, PipelineEventBox, TransactionEvent, TransactionStatus,
+        },
+        prelude::*,
+    },
+};
+use iroha_futures::supervisor::ShutdownSignal;
+use tokio::sync::broadcast::{self, error::RecvError};
 
 use crate::stream::{self, WebSocketScale};
 
+/// Number of the latest blocks for which all the emitted events are kept for replaying.
+///
+/// Events of the older blocks are rebuilt from the blocks stored in [`Kura`]:
+/// pipeline events are derived from the blocks themselves, other events are emitted again by replaying the blocks with [`StateHistory`].
+pub const EVENT_JOURNAL_CAPACITY: usize = 256;
+
 /// Type of error for `Consumer`
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     /// Error from provided stream/websocket
     #[error("Stream error: {0}")]
     Stream(Box<stream::Error>),
+    /// Events of the block can't be rebuilt right now
+    #[error("Events of the block at height {0} can't be rebuilt right now, too many past states are being reconstructed")]
+    HistoryUnavailable(NonZeroU64),
 }
 
 impl From<stream::Error> for Error {
@@ -29,6 +55,8 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub struct Consumer<'ws> {
     pub stream: &'ws mut WebSocketScale,
     filters: Vec<EventFilterBox>,
+    /// Height of the next block to replay events of, if replay was requested.
+    next_height: Option<NonZeroU64>,
 }
 
 impl<'ws> Consumer<'ws> {
@@ -38,8 +66,20 @@ impl<'ws> Consumer<'ws> {
     /// Can fail due to timeout or without message at websocket or during decoding request
     #[iroha_futures::telemetry_future]
     pub async fn new(stream: &'ws mut WebSocketScale) -> Result<Self> {
-        let EventSubscriptionRequest(filters) = stream.recv::<EventSubscriptionRequest>().await?;
-        Ok(Consumer { stream, filters })
+        let EventSubscriptionRequest {
+            filters,
+            from_height,
+        } = stream.recv::<EventSubscriptionRequest>().await?;
+        Ok(Consumer {
+            stream,
+            filters,
+            next_height: from_height,
+        })
+    }
+
+    /// Whether the subscriber requested to replay the events of the committed blocks.
+    pub fn is_replaying(&self) -> bool {
+        self.next_height.is_some()
     }
 
     /// Forwards the `event` over the `stream` if it matches the `filter`.
@@ -48,13 +88,293 @@ impl<'ws> Consumer<'ws> {
     /// Can fail due to timeout or sending event. Also receiving might fail
     #[iroha_futures::telemetry_future]
     pub async fn consume(&mut self, event: EventBox) -> Result<()> {
+        self.send(event, None).await
+    }
+
+    /// Forwards the events of all the blocks up to `height` (inclusive) which were not sent yet.
+    ///
+    /// Events are taken from the `journal` if it still has them, otherwise they are rebuilt from the blocks in `kura`.
+    ///
+    /// # Errors
+    /// - Can fail due to timeout or sending event. Also receiving might fail
+    /// - Some of the requested events of a block can't be rebuilt right now
+    #[iroha_futures::telemetry_future]
+    pub async fn replay_until(
+        &mut self,
+        height: NonZeroU64,
+        journal: &EventJournal,
+        kura: &Kura,
+        history: &StateHistory,
+    ) -> Result<()> {
+        while let Some(next_height) = self.next_height.filter(|next| *next <= height) {
+            let events = match journal.get(next_height) {
+                Some(events) => events,
+                // the block is being applied right now, its events will be received from the journal
+                None if journal.latest().is_some_and(|latest| next_height > latest) => {
+                    return Ok(())
+                }
+                None => match kura.get_block(
+                    next_height
+                        .try_into()
+                        .expect("INTERNAL BUG: Number of blocks exceeds usize::MAX"),
+                ) {
+                    Some(block) => Arc::new(BlockEvents::pipeline_only(&block)),
+                    // the block is not committed yet
+                    None => return Ok(()),
+                },
+            };
+            self.consume_block(next_height, &events, history).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Forwards the events of the block at `height` and advances to the next one, unless they were already sent.
+    ///
+    /// If only pipeline events of the block are known, but others are requested, they are rebuilt with the state `history`.
+    ///
+    /// # Errors
+    /// - Can fail due to timeout or sending event. Also receiving might fail
+    /// - Some of the requested events of the block can't be rebuilt right now
+    #[iroha_futures::telemetry_future]
+    pub async fn consume_block(
+        &mut self,
+        height: NonZeroU64,
+        events: &BlockEvents,
+        history: &StateHistory,
+    ) -> Result<()> {
+        if self.next_height.is_some_and(|next| height < next) {
+            return Ok(());
+        }
+        let rebuilt;
+        let events = if events.pipeline_only
+            && self
+                .filters
+                .iter()
+                .any(|filter| !matches!(filter, EventFilterBox::Pipeline(_)))
+        {
+            rebuilt = history
+                .block_events(height)
+                .await
+                .ok()
+                .flatten()
+                .ok_or(Error::HistoryUnavailable(height))?;
+            &rebuilt
+        } else {
+            &events.events
+        };
+
+        for event in events {
+            self.send(event.clone(), Some(height)).await?;
+        }
+        self.next_height = Some(
+            height
+                .checked_add(1)
+                .expect("Maximum block height is achieved."),
+        );
+
+        Ok(())
+    }
+
+    async fn send(&mut self, event: EventBox, block_height: Option<NonZeroU64>) -> Result<()> {
         if !self.filters.iter().any(|filter| filter.matches(&event)) {
             return Ok(());
         }
 
         self.stream
-            .send(EventMessage(event))
+            .send(EventMessage {
+                event,
+                block_height,
+            })
             .await
             .map_err(Into::into)
     }
 }
+
+/// Events emitted while committing and applying a block.
+#[derive(Debug)]
+pub struct BlockEvents {
+    events: Vec<EventBox>,
+    /// Only pipeline events are kept, as they were derived from the stored block
+    pipeline_only: bool,
+}
+
+impl BlockEvents {
+    fn pipeline_only(block: &SignedBlock) -> Self {
+        Self {
+            events: pipeline_events(block),
+            pipeline_only: true,
+        }
+    }
+}
+
+/// Pipeline events of a committed block, in the order they are emitted during block commit.
+fn pipeline_events(block: &SignedBlock) -> Vec<EventBox> {
+    let header = block.header();
+    let tx_events = block.transactions().enumerate().map(|(idx, tx)| {
+        let status = block.error(idx).map_or_else(
+            || TransactionStatus::Approved,
+            |error| TransactionStatus::Rejected(Box::new(error.clone())),
+        );
+
+        PipelineEventBox::from(TransactionEvent {
+            block_height: Some(header.height()),
+            hash: tx.hash(),
+            status,
+        })
+    });
+    let block_events = [
+        BlockStatus::Approved,
+        BlockStatus::Committed,
+        BlockStatus::Applied,
+    ]
+    .into_iter()
+    .map(|status| PipelineEventBox::from(BlockEvent { header, status }));
+
+    tx_events.chain(block_events).map(Into::into).collect()
+}
+
+/// Entry of the [`EventJournal`] sent to its subscribers
+#[derive(Debug, Clone)]
+pub enum JournalEntry {
+    /// Events of a newly applied block at the given height
+    Block(NonZeroU64, Arc<BlockEvents>),
+    /// Event which isn't tied to any block, e.g. a transaction being queued or expired
+    Detached(EventBox),
+}
+
+/// Journal of all the events emitted by the latest blocks, used to replay them to subscribers.
+///
+/// Events are assigned to the block whose [`BlockStatus::Applied`] event follows them.
+/// Events which aren't tied to any block are forwarded to the subscribers right away and aren't kept.
+#[derive(Debug)]
+pub struct EventJournal {
+    blocks: Mutex<VecDeque<(NonZeroU64, Arc<BlockEvents>)>>,
+    entries: broadcast::Sender<JournalEntry>,
+}
+
+impl EventJournal {
+    /// Create an empty journal
+    pub fn new() -> Self {
+        Self {
+            blocks: Mutex::new(VecDeque::with_capacity(EVENT_JOURNAL_CAPACITY)),
+            entries: broadcast::Sender::new(EVENT_JOURNAL_CAPACITY),
+        }
+    }
+
+    /// Subscribe to the events of every newly applied block and to the events which aren't tied to any block
+    pub fn subscribe(&self) -> broadcast::Receiver<JournalEntry> {
+        self.entries.subscribe()
+    }
+
+    /// Height of the latest block whose events were journaled
+    pub fn latest(&self) -> Option<NonZeroU64> {
+        let blocks = self.blocks.lock().expect("lock is never poisoned");
+        blocks.back().map(|(height, _)| *height)
+    }
+
+    /// Events of the block at `height`, if they are still kept
+    pub fn get(&self, height: NonZeroU64) -> Option<Arc<BlockEvents>> {
+        let blocks = self.blocks.lock().expect("lock is never poisoned");
+        let (oldest, _) = blocks.front()?;
+        let idx = usize::try_from(height.get().checked_sub(oldest.get())?).ok()?;
+
+        blocks
+            .get(idx)
+            .filter(|(block_height, _)| *block_height == height)
+            .map(|(_, events)| Arc::clone(events))
+    }
+
+    fn push(&self, height: NonZeroU64, events: BlockEvents) {
+        let events = Arc::new(events);
+        {
+            let mut blocks = self.blocks.lock().expect("lock is never poisoned");
+            // a block replacing the top one makes the kept events non-contiguous
+            if blocks
+                .back()
+                .is_some_and(|(last, _)| last.checked_add(1) != Some(height))
+            {
+                blocks.clear();
+            }
+            if blocks.len() == EVENT_JOURNAL_CAPACITY {
+                blocks.pop_front();
+            }
+            blocks.push_back((height, Arc::clone(&events)));
+        }
+        let _ = self.entries.send(JournalEntry::Block(height, events));
+    }
+
+    /// Record the events emitted by the node until the shutdown
+    ///
+    /// If some of the events of a block were missed, only its pipeline events are journaled.
+    pub async fn record(
+        self: Arc<Self>,
+        events: EventsSender,
+        kura: Arc<Kura>,
+        shutdown_signal: ShutdownSignal,
+    ) {
+        let mut events = events.subscribe();
+        let mut pending = Vec::new();
+        // some events of the current block could have been emitted before subscribing
+        let mut incomplete = true;
+
+        loop {
+            tokio::select! {
+                event = events.recv() => match event {
+                    Ok(event) if is_detached(&event) => {
+                        let _ = self.entries.send(JournalEntry::Detached(event));
+                    }
+                    Ok(event) => {
+                        let applied = match &event {
+                            EventBox::Pipeline(PipelineEventBox::Block(block))
+                                if *block.status() == BlockStatus::Applied =>
+                            {
+                                Some(block.header().height())
+                            }
+                            _ => None,
+                        };
+                        pending.push(event);
+
+                        if let Some(height) = applied {
+                            let mut block_events = BlockEvents {
+                                events: core::mem::take(&mut pending),
+                                pipeline_only: false,
+                            };
+                            if core::mem::replace(&mut incomplete, false) {
+                                // the block is stored in kura before its events are emitted
+                                block_events = kura
+                                    .get_block(height.try_into().expect("INTERNAL BUG: Number of blocks exceeds usize::MAX"))
+                                    .map_or_else(
+                                        || BlockEvents { events: Vec::new(), pipeline_only: true },
+                                        |block| BlockEvents::pipeline_only(&block),
+                                    );
+                            }
+                            self.push(height, block_events);
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        iroha_logger::warn!(skipped, "Event journal lagged behind, a block will be replayed without data events");
+                        pending.clear();
+                        incomplete = true;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                () = shutdown_signal.receive() => break,
+            }
+        }
+    }
+}
+
+/// Whether the `event` isn't tied to any block, e.g. a transaction being queued or expired
+fn is_detached(event: &EventBox) -> bool {
+    matches!(
+        event,
+        EventBox::Pipeline(PipelineEventBox::Transaction(event)) if event.block_height().is_none()
+    )
+}
+
+impl Default for EventJournal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -10,14 +10,16 @@ use std::{collections::HashSet, fmt::Debug, sync::Arc, time::Duration};
 use axum::{
     extract::{DefaultBodyLimit, WebSocketUpgrade},
     http::StatusCode,
+    middleware,
     response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
 };
+use axum_server::tls_rustls::RustlsConfig;
 use error_stack::ResultExt;
 use iroha_config::{
     base::{util::Bytes, WithOrigin},
-    parameters::actual::Torii as Config,
+    parameters::actual::{Torii as Config, ToriiTls},
 };
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::Telemetry;
@@ -25,7 +27,7 @@ use iroha_core::{
     kiso::{Error as KisoError, KisoHandle},
     kura::Kura,
     prelude::*,
-    query::store::LiveQueryStoreHandle,
+    query::{history::StateHistory, store::LiveQueryStoreHandle},
     queue::{self, Queue},
     state::State,
     EventsSender,
@@ -34,7 +36,11 @@ use iroha_data_model::{peer::Peer, ChainId};
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_primitives::addr::SocketAddr;
 use iroha_torii_const::uri;
-use tokio::{net::TcpListener, sync::watch};
+use tokio::{
+    net::TcpListener,
+    sync::{watch, Semaphore},
+    task::JoinHandle,
+};
 use tower_http::{
     timeout::TimeoutLayer,
     trace::{DefaultMakeSpan, TraceLayer},
@@ -45,24 +51,42 @@ use utils::{extractors::ScaleVersioned, Scale};
 pub(crate) mod utils;
 mod block;
 mod event;
+mod operator;
+mod rate_limit;
 mod routing;
 mod stream;
+mod tls;
 
 const SERVER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
 
+/// Number of transactions which can be simulated at once, each on its own copy of the state
+const MAX_CONCURRENT_SIMULATIONS: usize = 4;
+
+/// How long clients are asked to wait before retrying a simulation once all of them are busy
+const SIMULATION_RETRY_AFTER: Duration = Duration::from_secs(1);
+
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
     chain_id: Arc<ChainId>,
     kiso: KisoHandle,
     queue: Arc<Queue>,
     events: EventsSender,
+    event_journal: Arc<event::EventJournal>,
     query_service: LiveQueryStoreHandle,
+    state_history: Arc<StateHistory>,
     kura: Arc<Kura>,
     transaction_max_content_len: Bytes<u64>,
+    operator_auth: operator::OperatorAuth,
+    rate_limiter: Arc<rate_limit::RateLimiter>,
+    /// Permits of the transaction simulations, separate from the other requests
+    simulations: Arc<Semaphore>,
     address: WithOrigin<SocketAddr>,
+    tls: Option<ToriiTls>,
     state: Arc<State>,
     #[cfg(feature = "telemetry")]
     telemetry: Telemetry,
+    #[cfg(feature = "telemetry")]
+    public_metrics: bool,
     online_peers: OnlinePeersProvider,
 }
 
@@ -76,6 +100,7 @@ impl Torii {
         queue: Arc<Queue>,
         events: EventsSender,
         query_service: LiveQueryStoreHandle,
+        state_history: Arc<StateHistory>,
         kura: Arc<Kura>,
         state: Arc<State>,
         online_peers: OnlinePeersProvider,
@@ -86,13 +111,32 @@ impl Torii {
             kiso,
             queue,
             events,
+            event_journal: Arc::new(event::EventJournal::new()),
             query_service,
+            state_history,
             kura,
             state,
             online_peers,
+            rate_limiter: Arc::new(rate_limit::RateLimiter::new(
+                config.rate_limits,
+                #[cfg(feature = "telemetry")]
+                telemetry.clone(),
+            )),
+            simulations: Arc::new(Semaphore::new(MAX_CONCURRENT_SIMULATIONS)),
             #[cfg(feature = "telemetry")]
             telemetry,
+            #[cfg(feature = "telemetry")]
+            public_metrics: config.public_metrics,
+            operator_auth: operator::OperatorAuth::new(
+                config.admin_public_keys,
+                config
+                    .max_content_len
+                    .get()
+                    .try_into()
+                    .expect("should't exceed usize"),
+            ),
             address: config.address,
+            tls: config.tls,
             transaction_max_content_len: config.max_content_len,
         }
     }
@@ -100,6 +144,15 @@ impl Torii {
     /// Helper function to create router. This router can be tested without starting up an HTTP server
     #[allow(clippy::too_many_lines)]
     fn create_api_router(&self) -> axum::Router {
+        let operator_auth =
+            middleware::from_fn_with_state(self.operator_auth.clone(), operator::authenticate);
+        let limit_ip = |endpoint| {
+            middleware::from_fn_with_state(
+                (self.rate_limiter.clone(), endpoint),
+                rate_limit::limit_ip,
+            )
+        };
+
         let router = Router::new()
             .route(uri::HEALTH, get(routing::handle_health))
             .route(
@@ -137,7 +190,8 @@ impl Torii {
                             Some(&tail),
                         ).await
                     }
-                }),
+                })
+                .layer(operator_auth.clone()),
             )
             .route(
                 uri::STATUS,
@@ -146,15 +200,20 @@ impl Torii {
                     move |accept: Option<utils::extractors::ExtractAccept>| async move {
                         routing::handle_status(&tel, accept.map(|extract| extract.0), None).await
                     }
-                }),
+                })
+                .layer(operator_auth.clone()),
             )
-            .route(
-                uri::METRICS,
-                get({
+            .route(uri::METRICS, {
+                let metrics = get({
                     let tel = self.telemetry.clone();
                     move || async move { routing::handle_metrics(&tel).await }
-                }),
-            );
+                });
+                if self.public_metrics {
+                    metrics
+                } else {
+                    metrics.layer(operator_auth.clone())
+                }
+            });
         #[cfg(not(feature = "telemetry"))]
         let router = router
             .route(uri::STATUS, get(routing::telemetry_not_implemented))
@@ -178,7 +237,8 @@ impl Torii {
                     let profiling_lock = Arc::clone(&profiling_lock);
                     routing::profiling::handle_profile(params, profiling_lock)
                 }
-            }),
+            })
+            .layer(operator_auth.clone()),
         );
         #[cfg(not(feature = "profiling"))]
         let router = router.route(uri::PROFILE, get(routing::profiling_not_implemented));
@@ -190,8 +250,40 @@ impl Torii {
                     let chain_id = self.chain_id.clone();
                     let queue = self.queue.clone();
                     let state = self.state.clone();
+                    let rate_limiter = self.rate_limiter.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_transaction(
+                            chain_id,
+                            queue,
+                            state,
+                            rate_limiter,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                ))
+                .layer(limit_ip(rate_limit::Endpoint::Transaction)),
+            )
+            .route(
+                uri::TRANSACTION_SIMULATE,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let state = self.state.clone();
+                    let rate_limiter = self.rate_limiter.clone();
+                    let simulations = self.simulations.clone();
                     move |ScaleVersioned(transaction): ScaleVersioned<_>| {
-                        routing::handle_transaction(chain_id, queue, state, transaction)
+                        routing::handle_simulate_transaction(
+                            chain_id,
+                            state,
+                            rate_limiter,
+                            simulations,
+                            transaction,
+                        )
                     }
                 })
                 .layer(DefaultBodyLimit::max(
@@ -199,24 +291,35 @@ impl Torii {
                         .get()
                         .try_into()
                         .expect("should't exceed usize"),
-                )),
+                ))
+                .layer(limit_ip(rate_limit::Endpoint::Transaction)),
             )
             .route(
                 uri::QUERY,
                 post({
                     let query_service = self.query_service.clone();
+                    let state_history = self.state_history.clone();
                     let state = self.state.clone();
+                    let rate_limiter = self.rate_limiter.clone();
                     move |ScaleVersioned(query_request): ScaleVersioned<_>| {
-                        routing::handle_queries(query_service, state, query_request)
+                        routing::handle_queries(
+                            query_service,
+                            state_history,
+                            state,
+                            rate_limiter,
+                            query_request,
+                        )
                     }
-                }),
+                })
+                .layer(limit_ip(rate_limit::Endpoint::Query)),
             )
             .route(
                 uri::CONFIGURATION,
                 post({
                     let kiso = self.kiso.clone();
                     move |Json(config): Json<_>| routing::handle_post_configuration(kiso, config)
-                }),
+                })
+                .layer(operator_auth),
             );
 
         let router = router
@@ -224,16 +327,26 @@ impl Torii {
                 uri::SUBSCRIPTION,
                 get({
                     let events = self.events.clone();
+                    let event_journal = self.event_journal.clone();
+                    let kura = self.kura.clone();
+                    let state_history = self.state_history.clone();
                     move |ws: WebSocketUpgrade| {
                         core::future::ready(ws.on_upgrade(|ws| async move {
-                            if let Err(error) =
-                                routing::event::handle_events_stream(events, ws).await
+                            if let Err(error) = routing::event::handle_events_stream(
+                                events,
+                                event_journal,
+                                kura,
+                                state_history,
+                                ws,
+                            )
+                            .await
                             {
                                 iroha_logger::error!(%error, "Failure during event streaming");
                             }
                         }))
                     }
-                }),
+                })
+                .layer(limit_ip(rate_limit::Endpoint::Subscription)),
             )
             .route(
                 uri::BLOCKS_STREAM,
@@ -247,7 +360,8 @@ impl Torii {
                             }
                         }))
                     }
-                }),
+                })
+                .layer(limit_ip(rate_limit::Endpoint::Subscription)),
             );
 
         router.layer((
@@ -259,6 +373,17 @@ impl Torii {
         ))
     }
 
+    /// Start journaling the events emitted by the node, so that they can be replayed to subscribers.
+    ///
+    /// Should be started along with [`Self::start`], under the same supervisor.
+    pub fn start_event_journal(&self, shutdown_signal: ShutdownSignal) -> JoinHandle<()> {
+        tokio::spawn(Arc::clone(&self.event_journal).record(
+            self.events.clone(),
+            Arc::clone(&self.kura),
+            shutdown_signal,
+        ))
+    }
+
     /// To handle incoming requests `Torii` should be started first.
     ///
     /// # Errors
@@ -275,12 +400,43 @@ impl Torii {
         .change_context(Error::StartServer)
         .attach_printable("failed to bind to the specified address")
         .attach_printable_lazy(|| self.address.clone().into_attachment())?;
+        let tls_config = self
+            .tls
+            .as_ref()
+            .map(tls::server_config)
+            .transpose()
+            .change_context(Error::StartServer)
+            .attach_printable("failed to configure TLS")?;
         let api_router = self.create_api_router();
 
-        axum::serve(listener, api_router)
-            .with_graceful_shutdown(async move { shutdown_signal.receive().await })
-            .await
-            .change_context(Error::FailedExit)
+        let make_service = api_router.into_make_service_with_connect_info::<std::net::SocketAddr>();
+        let Some(tls_config) = tls_config else {
+            return axum::serve(listener, make_service)
+                .with_graceful_shutdown(async move { shutdown_signal.receive().await })
+                .await
+                .change_context(Error::FailedExit);
+        };
+
+        iroha_logger::info!("Serving Torii over TLS");
+        let handle = axum_server::Handle::new();
+        tokio::spawn({
+            let handle = handle.clone();
+            async move {
+                shutdown_signal.receive().await;
+                handle.graceful_shutdown(Some(SERVER_SHUTDOWN_TIMEOUT));
+            }
+        });
+        axum_server::from_tcp_rustls(
+            listener
+                .into_std()
+                .change_context(Error::StartServer)
+                .attach_printable("failed to convert the listener")?,
+            RustlsConfig::from_config(Arc::new(tls_config)),
+        )
+        .handle(handle)
+        .serve(make_service)
+        .await
+        .change_context(Error::FailedExit)
     }
 }
 
@@ -308,6 +464,10 @@ pub enum Error {
     ConfigurationFailure(#[from] KisoError),
     /// Failed to find status segment by provided path
     StatusSegmentNotFound(#[source] eyre::Report),
+    /// Request to an operator endpoint is not authenticated
+    OperatorAuth(#[source] operator::AuthError),
+    /// Too many requests, retry after {0:?}
+    TooManyRequests(Duration),
     /// Failed to start Torii
     StartServer,
     /// Torii server terminated with an error
@@ -318,6 +478,16 @@ impl IntoResponse for Error {
     fn into_response(self) -> Response {
         match self {
             Self::Query(err) => (Self::query_status_code(&err), utils::Scale(err)).into_response(),
+            Self::TooManyRequests(retry_after) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(
+                    axum::http::header::RETRY_AFTER,
+                    // whole seconds, rounded up
+                    (retry_after.as_millis().div_ceil(1000)).to_string(),
+                )],
+                format!("{self:?}"),
+            )
+                .into_response(),
             _ => (self.status_code(), format!("{self:?}")).into_response(),
         }
     }
@@ -339,7 +509,14 @@ impl Error {
             Prometheus(_) | StatusFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "profiling")]
             Pprof(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ConfigurationFailure(KisoError::InvalidUpdate(_)) => StatusCode::BAD_REQUEST,
             ConfigurationFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            OperatorAuth(operator::AuthError::NoAdmins | operator::AuthError::NotAdmin) => {
+                StatusCode::FORBIDDEN
+            }
+            OperatorAuth(operator::AuthError::Body) => StatusCode::BAD_REQUEST,
+            OperatorAuth(_) => StatusCode::UNAUTHORIZED,
+            TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
             StartServer | FailedExit => unreachable!("these never occur during request handling"),
         }
     }
@@ -360,8 +537,11 @@ impl Error {
                 | CursorDone
                 | NotFound
                 | FetchSizeTooBig
-                | InvalidSingularParameters => StatusCode::BAD_REQUEST,
-                Find(_) => StatusCode::NOT_FOUND,
+                | InvalidSingularParameters
+                | AggregateOverflow
+                | SortedAggregate
+                | SelectedAggregate => StatusCode::BAD_REQUEST,
+                Find(_) | HeightUnavailable => StatusCode::NOT_FOUND,
                 CapacityLimit => StatusCode::TOO_MANY_REQUESTS,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
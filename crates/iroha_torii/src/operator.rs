@@ -0,0 +1,195 @@
+//! Authentication of requests to operator endpoints, e.g. configuration updates, status and metrics.
+//!
+//! Such requests must carry a signature of [`OperatorRequestPayload`] made by one of the admins.
+//! If no admins are configured, all of them are rejected.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::uri::PathAndQuery,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use iroha_crypto::{HashOf, Signature};
+use iroha_data_model::{operator::OperatorRequestPayload, prelude::PublicKey};
+use iroha_torii_const::header;
+
+use crate::Error;
+
+/// How far the timestamp of a request might be from the peer's clock.
+/// Accepted requests are remembered for this long so that they can't be replayed.
+const MAX_CLOCK_DRIFT: Duration = Duration::from_secs(60);
+
+/// Errors of operator request authentication.
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum AuthError {
+    /// Header `{0}` is missing
+    MissingHeader(&'static str),
+    /// Header `{0}` is malformed
+    MalformedHeader(&'static str),
+    /// No admins are configured for this peer
+    NoAdmins,
+    /// Signer isn't an admin of this peer
+    NotAdmin,
+    /// Request timestamp is too far from the peer's clock
+    Expired,
+    /// Failed to read the request body
+    Body,
+    /// Signature doesn't match the request
+    InvalidSignature,
+    /// Request was already accepted
+    Replayed,
+}
+
+/// State of the [`authenticate`] middleware.
+#[derive(Clone)]
+pub struct OperatorAuth {
+    admins: Arc<[PublicKey]>,
+    max_content_len: usize,
+    /// Requests accepted within [`MAX_CLOCK_DRIFT`], with their timestamps
+    accepted: Arc<Mutex<HashMap<HashOf<OperatorRequestPayload>, Duration>>>,
+}
+
+impl OperatorAuth {
+    /// Authenticate requests signed by any of `admins`.
+    /// If `admins` is empty, all requests are rejected.
+    pub fn new(admins: Vec<PublicKey>, max_content_len: usize) -> Self {
+        if admins.is_empty() {
+            iroha_logger::warn!(
+                "No admin public keys are configured; operator endpoints reject all requests"
+            );
+        }
+
+        Self {
+            admins: admins.into(),
+            max_content_len,
+            accepted: Arc::default(),
+        }
+    }
+
+    async fn check(&self, request: Request) -> Result<Request, AuthError> {
+        if self.admins.is_empty() {
+            return Err(AuthError::NoAdmins);
+        }
+
+        let public_key: PublicKey = read_header(&request, header::OPERATOR_PUBLIC_KEY)?
+            .parse()
+            .map_err(|_| AuthError::MalformedHeader(header::OPERATOR_PUBLIC_KEY))?;
+        let signature = Signature::from_hex(read_header(&request, header::OPERATOR_SIGNATURE)?)
+            .map_err(|_| AuthError::MalformedHeader(header::OPERATOR_SIGNATURE))?;
+        let timestamp_ms: u64 = read_header(&request, header::OPERATOR_TIMESTAMP)?
+            .parse()
+            .map_err(|_| AuthError::MalformedHeader(header::OPERATOR_TIMESTAMP))?;
+
+        if !self.admins.contains(&public_key) {
+            return Err(AuthError::NotAdmin);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("INTERNAL BUG: Failed to get the current system time");
+        let timestamp = Duration::from_millis(timestamp_ms);
+        if timestamp > now + MAX_CLOCK_DRIFT || now > timestamp + MAX_CLOCK_DRIFT {
+            return Err(AuthError::Expired);
+        }
+
+        let (parts, body) = request.into_parts();
+        let body = axum::body::to_bytes(body, self.max_content_len)
+            .await
+            .map_err(|_| AuthError::Body)?;
+        let path = parts
+            .uri
+            .path_and_query()
+            .map_or(parts.uri.path(), PathAndQuery::as_str);
+        let payload = OperatorRequestPayload {
+            method: parts.method.to_string(),
+            path: path.to_owned(),
+            body_hash: iroha_crypto::Hash::new(&body),
+            timestamp_ms,
+        };
+        let payload_hash = HashOf::new(&payload);
+        signature
+            .verify(&public_key, payload_hash.as_ref())
+            .map_err(|_| AuthError::InvalidSignature)?;
+        self.accept_once(payload_hash, timestamp, now)?;
+
+        Ok(Request::from_parts(parts, Body::from(body)))
+    }
+
+    fn accept_once(
+        &self,
+        payload_hash: HashOf<OperatorRequestPayload>,
+        timestamp: Duration,
+        now: Duration,
+    ) -> Result<(), AuthError> {
+        let mut accepted = self
+            .accepted
+            .lock()
+            .expect("INTERNAL BUG: Accepted requests lock is poisoned");
+        // expired requests are rejected anyway
+        accepted.retain(|_, timestamp| *timestamp + MAX_CLOCK_DRIFT >= now);
+        if accepted.insert(payload_hash, timestamp).is_some() {
+            return Err(AuthError::Replayed);
+        }
+
+        Ok(())
+    }
+}
+
+fn read_header<'req>(request: &'req Request, name: &'static str) -> Result<&'req str, AuthError> {
+    request
+        .headers()
+        .get(name)
+        .ok_or(AuthError::MissingHeader(name))?
+        .to_str()
+        .map_err(|_| AuthError::MalformedHeader(name))
+}
+
+/// Middleware rejecting requests which aren't signed by an admin.
+pub async fn authenticate(
+    State(auth): State<OperatorAuth>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match auth.check(request).await {
+        Ok(request) => next.run(request).await,
+        Err(error) => Error::OperatorAuth(error).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_are_rejected_without_admins() {
+        let auth = OperatorAuth::new(Vec::new(), 0);
+
+        let error = auth.check(Request::new(Body::empty())).await.unwrap_err();
+        assert!(matches!(error, AuthError::NoAdmins));
+    }
+
+    #[test]
+    fn accepted_request_is_not_accepted_again() {
+        let auth = OperatorAuth::new(vec![KeyPair::random().public_key().clone()], 0);
+        let now = Duration::from_secs(1_700_000_000);
+        let payload = OperatorRequestPayload::new("POST", "/configuration", b"{}", now);
+        let payload_hash = HashOf::new(&payload);
+
+        auth.accept_once(payload_hash, now, now).unwrap();
+        let error = auth.accept_once(payload_hash, now, now).unwrap_err();
+        assert!(matches!(error, AuthError::Replayed));
+
+        // the request is expired by then, so it's no longer remembered
+        let later = now + MAX_CLOCK_DRIFT + Duration::from_millis(1);
+        auth.accept_once(payload_hash, now, later).unwrap();
+    }
+}
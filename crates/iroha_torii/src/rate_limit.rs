@@ -0,0 +1,257 @@
+//! Token bucket rate limiting of requests to Torii.
+//!
+//! Limits apply separately to each authority account and each remote IP address.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use iroha_config::parameters::actual::{RateLimit, RateLimits};
+#[cfg(feature = "telemetry")]
+use iroha_core::telemetry::Telemetry;
+use iroha_data_model::account::AccountId;
+
+use crate::Error;
+
+/// Buckets are pruned when their number reaches this value at least
+const MIN_PRUNE_LEN: usize = 1024;
+
+/// Kind of rate limited requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint {
+    /// Transaction submission
+    Transaction,
+    /// Query execution or continuation
+    Query,
+    /// Event or block stream subscription
+    Subscription,
+}
+
+impl Endpoint {
+    #[cfg(feature = "telemetry")]
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Transaction => "transaction",
+            Self::Query => "query",
+            Self::Subscription => "subscription",
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self, limit: RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = elapsed
+            .mul_add(f64::from(limit.per_second.get()), self.tokens)
+            .min(f64::from(limit.burst.get()));
+        self.updated_at = now;
+    }
+
+    fn is_full(&self, limit: RateLimit) -> bool {
+        self.tokens >= f64::from(limit.burst.get())
+    }
+}
+
+struct BucketsInner<K> {
+    buckets: HashMap<K, Bucket>,
+    prune_at: usize,
+}
+
+/// Buckets of a single limit
+struct Buckets<K> {
+    limit: RateLimit,
+    inner: Mutex<BucketsInner<K>>,
+}
+
+impl<K: Eq + Hash> Buckets<K> {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            inner: Mutex::new(BucketsInner {
+                buckets: HashMap::new(),
+                prune_at: MIN_PRUNE_LEN,
+            }),
+        }
+    }
+
+    /// Take a token from the bucket of `key`.
+    ///
+    /// # Errors
+    /// If the bucket is empty, returns how long to wait until a token is available.
+    fn acquire(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let limit = self.limit;
+        let mut inner = self
+            .inner
+            .lock()
+            .expect("INTERNAL BUG: Rate limiter lock is poisoned");
+
+        if inner.buckets.len() >= inner.prune_at {
+            // full buckets are indistinguishable from absent ones
+            inner.buckets.retain(|_, bucket| {
+                bucket.refill(limit, now);
+                !bucket.is_full(limit)
+            });
+            inner.prune_at = MIN_PRUNE_LEN.max(inner.buckets.len() * 2);
+        }
+
+        let bucket = inner.buckets.entry(key).or_insert(Bucket {
+            tokens: f64::from(limit.burst.get()),
+            updated_at: now,
+        });
+        bucket.refill(limit, now);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / f64::from(limit.per_second.get()),
+            ))
+        }
+    }
+}
+
+struct Limit {
+    accounts: Buckets<AccountId>,
+    ips: Buckets<IpAddr>,
+}
+
+impl Limit {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            accounts: Buckets::new(limit),
+            ips: Buckets::new(limit),
+        }
+    }
+}
+
+/// Rate limiter of transactions, queries and subscriptions.
+pub struct RateLimiter {
+    transactions: Option<Limit>,
+    queries: Option<Limit>,
+    subscriptions: Option<Limit>,
+    #[cfg(feature = "telemetry")]
+    telemetry: Telemetry,
+}
+
+impl RateLimiter {
+    /// Construct [`RateLimiter`] from configuration.
+    pub fn new(limits: RateLimits, #[cfg(feature = "telemetry")] telemetry: Telemetry) -> Self {
+        Self {
+            transactions: limits.transactions.map(Limit::new),
+            queries: limits.queries.map(Limit::new),
+            subscriptions: limits.subscriptions.map(Limit::new),
+            #[cfg(feature = "telemetry")]
+            telemetry,
+        }
+    }
+
+    fn limit(&self, endpoint: Endpoint) -> Option<&Limit> {
+        match endpoint {
+            Endpoint::Transaction => self.transactions.as_ref(),
+            Endpoint::Query => self.queries.as_ref(),
+            Endpoint::Subscription => self.subscriptions.as_ref(),
+        }
+    }
+
+    /// Count a request to `endpoint` made by `account`.
+    ///
+    /// # Errors
+    /// [`Error::TooManyRequests`] if the account exceeded its limit.
+    pub fn check_account(&self, endpoint: Endpoint, account: &AccountId) -> Result<(), Error> {
+        let Some(limit) = self.limit(endpoint) else {
+            return Ok(());
+        };
+        limit
+            .accounts
+            .acquire(account.clone(), Instant::now())
+            .map_err(|retry_after| self.reject(endpoint, "account", retry_after))
+    }
+
+    /// Count a request to `endpoint` made from `ip`.
+    ///
+    /// # Errors
+    /// [`Error::TooManyRequests`] if the IP address exceeded its limit.
+    pub fn check_ip(&self, endpoint: Endpoint, ip: IpAddr) -> Result<(), Error> {
+        let Some(limit) = self.limit(endpoint) else {
+            return Ok(());
+        };
+        limit
+            .ips
+            .acquire(ip, Instant::now())
+            .map_err(|retry_after| self.reject(endpoint, "ip", retry_after))
+    }
+
+    #[allow(unused_variables)]
+    fn reject(&self, endpoint: Endpoint, key: &str, retry_after: Duration) -> Error {
+        #[cfg(feature = "telemetry")]
+        self.telemetry
+            .inc_rate_limited_requests(endpoint.as_str(), key);
+        Error::TooManyRequests(retry_after)
+    }
+}
+
+/// Middleware rejecting requests from remote IP addresses which exceeded the limit of the endpoint.
+///
+/// Requests are let through if the remote address is unknown.
+pub async fn limit_ip(
+    State((limiter, endpoint)): State<(Arc<RateLimiter>, Endpoint)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    if let Some(ip) = ip {
+        if let Err(error) = limiter.check_ip(endpoint, ip) {
+            return error.into_response();
+        }
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use nonzero_ext::nonzero;
+
+    use super::*;
+
+    #[test]
+    fn bucket_refills_over_time() {
+        let buckets = Buckets::new(RateLimit {
+            per_second: nonzero!(2_u32),
+            burst: nonzero!(3_u32),
+        });
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            buckets.acquire("alice", start).unwrap();
+        }
+        let retry_after = buckets.acquire("alice", start).unwrap_err();
+        assert_eq!(retry_after, Duration::from_millis(500));
+        // other keys aren't affected
+        buckets.acquire("bob", start).unwrap();
+
+        buckets
+            .acquire("alice", start + Duration::from_millis(500))
+            .unwrap();
+        buckets
+            .acquire("alice", start + Duration::from_millis(500))
+            .unwrap_err();
+    }
+}
@@ -8,23 +8,28 @@ use eyre::{eyre, WrapErr};
 use iroha_config::client_api::{ConfigGetDTO, ConfigUpdateDTO};
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::Telemetry;
-use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
+use iroha_core::{
+    query::{history::StateHistory, store::LiveQueryStoreHandle},
+    smartcontracts::query::ValidQueryRequest,
+};
 use iroha_data_model::{
     self,
     prelude::*,
-    query::{QueryRequestWithAuthority, QueryResponse, SignedQuery},
+    query::{QueryRequest, QueryRequestWithAuthority, QueryResponse, SignedQuery},
 };
 #[cfg(feature = "telemetry")]
 use iroha_telemetry::metrics::Status;
 use tokio::task;
 
 use super::*;
+use crate::rate_limit::{Endpoint, RateLimiter};
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     state: Arc<State>,
+    rate_limiter: Arc<RateLimiter>,
     tx: SignedTransaction,
 ) -> Result<()> {
     let (max_clock_drift, tx_limits) = {
@@ -35,6 +40,11 @@ pub async fn handle_transaction(
 
     let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
         .map_err(Error::AcceptTransaction)?;
+    accepted_tx
+        .verify_signature(&state.world.view())
+        .map_err(|err| Error::AcceptTransaction(err.into()))?;
+    // the authority is only trusted once the signature is verified
+    rate_limiter.check_account(Endpoint::Transaction, accepted_tx.as_ref().authority())?;
 
     queue
         .push(accepted_tx, state.view())
@@ -49,16 +59,95 @@ pub async fn handle_transaction(
         .map_err(Error::PushIntoQueue)
 }
 
+#[iroha_futures::telemetry_future]
+pub async fn handle_simulate_transaction(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    rate_limiter: Arc<RateLimiter>,
+    simulations: Arc<Semaphore>,
+    tx: SignedTransaction,
+) -> Result<Scale<TransactionSimulation>> {
+    let permit = simulations
+        .try_acquire_owned()
+        .map_err(|_| Error::TooManyRequests(SIMULATION_RETRY_AFTER))?;
+    let handle = task::spawn_blocking(move || {
+        // held until the simulation finishes
+        let _permit = permit;
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi.max_clock_drift(), params.transaction)
+        };
+
+        let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+            .map_err(Error::AcceptTransaction)?;
+        accepted_tx
+            .verify_signature(&state.world.view())
+            .map_err(|err| Error::AcceptTransaction(err.into()))?;
+        // the authority is only trusted once the signature is verified
+        rate_limiter.check_account(Endpoint::Transaction, accepted_tx.as_ref().authority())?;
+
+        Ok::<_, Error>(state.simulate_transaction(accepted_tx))
+    });
+    handle
+        .await
+        .expect("Failed to join transaction simulation task")
+        .map(Scale)
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
+    state_history: Arc<StateHistory>,
     state: Arc<State>,
+    rate_limiter: Arc<RateLimiter>,
     query: SignedQuery,
 ) -> Result<Scale<QueryResponse>> {
+    let SignedQuery::V1(query) = query;
+    if !state.world.view().verify_account_signature(
+        &query.payload.authority,
+        &query.signature.0,
+        &query.payload,
+    ) {
+        return Err(Error::Query(ValidationFail::NotPermitted(
+            "Query request signature is not valid".to_owned(),
+        )));
+    }
+    // the authority is only trusted once the signature is verified
+    rate_limiter.check_account(Endpoint::Query, &query.payload.authority)?;
+
+    let latest_height = state.view().height();
+    let historical_height = match &query.payload.request {
+        QueryRequest::Singular(singular_query) => singular_query.at_height,
+        QueryRequest::Start(iter_query) => iter_query.params.at_height,
+        QueryRequest::Continue(_) => None,
+    }
+    .filter(|height| height.get() != latest_height as u64);
+    if let Some(height) = historical_height {
+        let query: QueryRequestWithAuthority = query.payload;
+        let authority = query.authority.clone();
+        // the query is authorized by the current executor, like any other query,
+        // before spending time on reconstructing the state
+        let valid_query = task::spawn_blocking(move || {
+            ValidQueryRequest::validate_for_client(query, &state.view())
+        })
+        .await
+        .expect("Failed to join query validation task")?;
+
+        return state_history
+            .with_state_at(height, latest_height, move |state| {
+                valid_query.execute(&live_query_store, &state.view(), &authority)
+            })
+            .await
+            .map_err(ValidationFail::from)
+            .and_then(|response| response.map_err(ValidationFail::from))
+            .map(Scale)
+            .map_err(Into::into);
+    }
+
     let handle = task::spawn_blocking(move || {
         let state_view = state.view();
 
-        let SignedQuery::V1(query) = query;
         let query: QueryRequestWithAuthority = query.payload;
         let authority = query.authority.clone();
 
@@ -170,6 +259,8 @@ pub mod block {
 pub mod event {
     //! Events stream handler
 
+    use std::num::NonZeroU64;
+
     use stream::WebSocketScale;
 
     use super::*;
@@ -200,16 +291,32 @@ pub mod event {
     /// Subscribes `stream` for `events` filtered by filter that is
     /// received through the `stream`
     #[iroha_futures::telemetry_future]
-    pub async fn handle_events_stream(events: EventsSender, stream: WebSocket) -> eyre::Result<()> {
+    pub async fn handle_events_stream(
+        events: EventsSender,
+        journal: Arc<event::EventJournal>,
+        kura: Arc<Kura>,
+        history: Arc<StateHistory>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
         let mut stream = WebSocketScale(stream);
         let init_and_subscribe = async {
             let mut consumer = event::Consumer::new(&mut stream).await?;
-            subscribe_forever(events, &mut consumer).await
+            if consumer.is_replaying() {
+                replay_and_subscribe_forever(&journal, &kura, &history, &mut consumer).await
+            } else {
+                subscribe_forever(events, &mut consumer).await
+            }
         };
 
         match init_and_subscribe.await {
             Ok(()) => stream.close().await.map_err(Into::into),
             Err(Error::Close) => Ok(()),
+            Err(Error::Consumer(err)) if matches!(*err, event::Error::HistoryUnavailable(_)) => {
+                stream
+                    .close_with_reason(err.to_string())
+                    .await
+                    .map_err(Into::into)
+            }
             Err(err) => {
                 // NOTE: try close websocket and return initial error
                 let _ = stream.close().await;
@@ -218,6 +325,52 @@ pub mod event {
         }
     }
 
+    /// Replay events of the committed blocks, then make endless `consumer` subscription for events of the new blocks.
+    /// Events which aren't tied to any block are forwarded as soon as they are received.
+    ///
+    /// Ideally should return `Result<!>` cause it either runs forever or returns error
+    async fn replay_and_subscribe_forever(
+        journal: &event::EventJournal,
+        kura: &Kura,
+        history: &StateHistory,
+        consumer: &mut event::Consumer<'_>,
+    ) -> Result<()> {
+        // NOTE: subscribe before replaying, so that no block is missed in between
+        let mut entries = journal.subscribe();
+        consumer
+            .replay_until(NonZeroU64::MAX, journal, kura, history)
+            .await?;
+
+        loop {
+            tokio::select! {
+                // Wait for stream to be closed by client
+                closed = consumer.stream.closed() => {
+                    match closed {
+                        Ok(()) => return Err(Error::Close),
+                        Err(err) => return Err(event::Error::from(err).into())
+                    }
+                }
+                // This branch catches and sends events of the applied blocks and the detached events
+                entry = entries.recv() => match entry {
+                    Ok(event::JournalEntry::Block(height, events)) => {
+                        // fill the gap if some blocks were skipped
+                        if let Some(prev_height) = NonZeroU64::new(height.get() - 1) {
+                            consumer.replay_until(prev_height, journal, kura, history).await?;
+                        }
+                        consumer.consume_block(height, &events, history).await?;
+                    }
+                    Ok(event::JournalEntry::Detached(event)) => {
+                        consumer.consume(event).await?;
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                        consumer.replay_until(NonZeroU64::MAX, journal, kura, history).await?;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+    }
+
     /// Make endless `consumer` subscription for `events`
     ///
     /// Ideally should return `Result<!>` cause it either runs forever or returns error
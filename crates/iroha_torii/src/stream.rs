@@ -2,7 +2,7 @@
 
 use core::{result::Result, time::Duration};
 
-use axum::extract::ws::{Message, WebSocket};
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket};
 use futures::{SinkExt, StreamExt};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
@@ -94,6 +94,22 @@ impl WebSocketScale {
             Err(error) => Err(error),
         }
     }
+
+    /// Close websocket, telling the peer why the connection can't be served
+    pub async fn close_with_reason(mut self, reason: String) -> Result<(), Error> {
+        let frame = CloseFrame {
+            code: close_code::POLICY,
+            reason: reason.into(),
+        };
+        match tokio::time::timeout(TIMEOUT, self.0.send(Message::Close(Some(frame))))
+            .await
+            .map_err(|_err| Error::SendTimeout)?
+            .map_err(extract_ws_closed)
+        {
+            Err(Error::Closed) | Ok(()) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
 }
 
 /// Check if websocket was closed normally
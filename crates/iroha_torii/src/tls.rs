@@ -0,0 +1,100 @@
+//! TLS termination of Torii connections.
+
+use std::{fs::File, io::BufReader, path::Path, sync::Arc};
+
+use error_stack::{Report, ResultExt};
+use iroha_config::parameters::actual::ToriiTls;
+use rustls::{
+    crypto::ring,
+    pki_types::{CertificateDer, PrivateKeyDer},
+    server::WebPkiClientVerifier,
+    RootCertStore, ServerConfig,
+};
+
+/// Errors of TLS configuration.
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub enum TlsError {
+    /// Failed to read a PEM file
+    ReadFile,
+    /// PEM file contains no certificates
+    NoCertificates,
+    /// PEM file contains no private key
+    NoPrivateKey,
+    /// Client CA certificates are invalid
+    ClientCa,
+    /// Certificate chain or private key is invalid
+    ServerConfig,
+}
+
+/// Construct TLS server configuration from PEM files.
+///
+/// If client CA certificates are configured, clients must present a certificate signed by one of them.
+///
+/// # Errors
+/// If files can't be read or don't contain valid certificates and keys.
+pub fn server_config(config: &ToriiTls) -> error_stack::Result<ServerConfig, TlsError> {
+    let provider = Arc::new(ring::default_provider());
+
+    let cert_chain = read_certs(&config.cert_file.resolve_relative_path())?;
+    let key = read_private_key(&config.key_file.resolve_relative_path())?;
+
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()
+        .change_context(TlsError::ServerConfig)?;
+    let builder = match &config.client_ca_file {
+        Some(client_ca_file) => {
+            let path = client_ca_file.resolve_relative_path();
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(&path)? {
+                roots
+                    .add(cert)
+                    .change_context(TlsError::ClientCa)
+                    .attach_printable_lazy(|| path.display().to_string())?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .change_context(TlsError::ClientCa)
+                .attach_printable_lazy(|| path.display().to_string())?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder
+        .with_single_cert(cert_chain, key)
+        .change_context(TlsError::ServerConfig)?;
+    // WebSocket upgrades are only supported over HTTP/1.1
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    Ok(server_config)
+}
+
+fn open(path: &Path) -> error_stack::Result<BufReader<File>, TlsError> {
+    File::open(path)
+        .map(BufReader::new)
+        .change_context(TlsError::ReadFile)
+        .attach_printable_lazy(|| path.display().to_string())
+}
+
+fn read_certs(path: &Path) -> error_stack::Result<Vec<CertificateDer<'static>>, TlsError> {
+    let certs = rustls_pemfile::certs(&mut open(path)?)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(TlsError::ReadFile)
+        .attach_printable_lazy(|| path.display().to_string())?;
+    if certs.is_empty() {
+        return Err(
+            Report::new(TlsError::NoCertificates).attach_printable(path.display().to_string())
+        );
+    }
+
+    Ok(certs)
+}
+
+fn read_private_key(path: &Path) -> error_stack::Result<PrivateKeyDer<'static>, TlsError> {
+    rustls_pemfile::private_key(&mut open(path)?)
+        .change_context(TlsError::ReadFile)
+        .attach_printable_lazy(|| path.display().to_string())?
+        .ok_or_else(|| {
+            Report::new(TlsError::NoPrivateKey).attach_printable(path.display().to_string())
+        })
+}
@@ -7,6 +7,8 @@ pub mod uri {
     pub const QUERY: &str = "/query";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "/transaction";
+    /// Transaction simulation URI is used to execute transactions without submitting them.
+    pub const TRANSACTION_SIMULATE: &str = "/transaction/simulate";
     /// Health URI is used to handle incoming Healthcheck requests.
     pub const HEALTH: &str = "/health";
     /// Peers URI is used to find all peers in the network
@@ -29,3 +31,14 @@ pub mod uri {
     /// URI for getting cpu profile
     pub const PROFILE: &str = "/debug/pprof/profile";
 }
+
+pub mod header {
+    //! HTTP headers that Torii uses to authenticate requests to operator endpoints.
+
+    /// Public key of the admin who signed the request
+    pub const OPERATOR_PUBLIC_KEY: &str = "x-iroha-operator-public-key";
+    /// Hex-encoded signature of the request payload
+    pub const OPERATOR_SIGNATURE: &str = "x-iroha-operator-signature";
+    /// Time the request was created at, as milliseconds since the Unix epoch
+    pub const OPERATOR_TIMESTAMP: &str = "x-iroha-operator-timestamp";
+}
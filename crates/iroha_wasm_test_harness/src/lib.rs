@@ -0,0 +1,498 @@
+//! Harness to unit test WASM smart contracts, triggers and executors without running a network.
+//!
+//! [`Harness`] keeps an in-memory [`State`] seeded from a genesis block built by
+//! [`GenesisBuilder`]. WASM modules are executed against this state on behalf of any
+//! account, and each call reports its [`Outcome`]: the result, emitted data events and consumed fuel.
+//! Successful calls are applied to the state, so their effects can be observed with [`Harness::state`]
+//! and by the following calls.
+//!
+//! ```ignore
+//! let genesis = GenesisBuilder::new(chain, "executor.wasm", "libs")
+//!     .domain("wonderland".parse()?)
+//!     .account(alice_key_pair.public_key().clone())
+//!     .finish_domain();
+//! let mut harness = Harness::new(genesis)?;
+//!
+//! let outcome = harness.execute_smart_contract(&alice_id, smart_contract);
+//! outcome.result?;
+//! assert!(outcome.fuel > 0);
+//! ```
+
+use std::{
+    num::NonZeroU64,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use eyre::{eyre, Result, WrapErr};
+use iroha_core::{
+    block::ValidBlock,
+    kura::Kura,
+    query::store::LiveQueryStore,
+    smartcontracts::{
+        wasm::{self, cache::WasmCache, RuntimeBuilder},
+        Execute as _,
+    },
+    state::{State, StateReadOnly, StateTransaction, World, WorldReadOnly},
+    sumeragi::network_topology::Topology,
+};
+use iroha_crypto::KeyPair;
+use iroha_data_model::{block::BlockHeader, prelude::*, query::QueryRequest};
+use iroha_genesis::{GenesisBuilder, GENESIS_DOMAIN_ID};
+
+/// In-memory Iroha state to execute WASM modules against
+pub struct Harness {
+    state: State,
+    chain_id: ChainId,
+    genesis_account: AccountId,
+    genesis_key_pair: KeyPair,
+    /// Creation time of the following blocks. The current system time if unset
+    block_time: Option<Duration>,
+    /// Runtime to spawn the query store in, if the harness isn't created inside of one
+    _runtime: Option<tokio::runtime::Runtime>,
+}
+
+/// Outcome of a call made through the [`Harness`]
+#[derive(Debug)]
+pub struct Outcome<E> {
+    /// Result of the call. The state is only changed if it is `Ok`
+    pub result: Result<(), E>,
+    /// Data events emitted by the call. Empty if it has failed
+    pub events: Vec<DataEvent>,
+    /// Fuel consumed by WASM smart contracts and triggers
+    pub fuel: u64,
+    /// Number of executed instructions
+    pub instructions: u64,
+}
+
+impl Harness {
+    /// Construct [`Harness`] with the state after committing the genesis block built by `genesis`.
+    ///
+    /// The genesis block is signed with a random key pair and its topology is overwritten
+    /// with a single peer.
+    ///
+    /// # Errors
+    ///
+    /// - if the genesis block can't be built, e.g. the executor file is missing
+    /// - if the genesis block is invalid
+    pub fn new(genesis: GenesisBuilder) -> Result<Self> {
+        let runtime = tokio::runtime::Handle::try_current()
+            .is_err()
+            .then(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_time()
+                    .build()
+            })
+            .transpose()
+            .wrap_err("failed to create runtime for the query store")?;
+        let query_handle = {
+            let _guard = runtime.as_ref().map(tokio::runtime::Runtime::enter);
+            LiveQueryStore::start_test()
+        };
+
+        let genesis_key_pair = KeyPair::random();
+        let genesis_account_id = AccountId::new(
+            GENESIS_DOMAIN_ID.clone(),
+            genesis_key_pair.public_key().clone(),
+        );
+        let genesis_account = Account::new(genesis_account_id.clone()).build(&genesis_account_id);
+        let genesis_domain = Domain::new(GENESIS_DOMAIN_ID.clone()).build(&genesis_account_id);
+
+        let peer_key_pair = KeyPair::random();
+        let topology = Topology::new(vec![PeerId::new(peer_key_pair.public_key().clone())]);
+        let genesis = genesis
+            .set_topology(topology.as_ref().to_owned())
+            .build_and_sign(&genesis_key_pair)?;
+        let chain_id = genesis
+            .0
+            .transactions()
+            .next()
+            .ok_or_else(|| eyre!("genesis block has no transactions"))?
+            .chain()
+            .clone();
+
+        let kura = Kura::blank_kura_for_testing();
+        let state = State::new(
+            World::with([genesis_domain], [genesis_account], []),
+            Arc::clone(&kura),
+            query_handle,
+        );
+
+        {
+            let mut state_block = state.block(genesis.0.header());
+            let block = ValidBlock::validate(
+                genesis.0,
+                &topology,
+                &chain_id,
+                &genesis_account_id,
+                &mut state_block,
+            )
+            .unpack(|_| {})
+            .map_err(|(_, error)| error)
+            .wrap_err("invalid genesis block")?
+            .commit(&topology)
+            .unpack(|_| {})
+            .map_err(|(_, error)| error)
+            .wrap_err("genesis block can't be committed")?;
+
+            let _events = state_block.apply_without_execution(&block, topology.as_ref().to_owned());
+            state_block.commit();
+            kura.store_block(block);
+        }
+
+        Ok(Self {
+            state,
+            chain_id,
+            genesis_account: genesis_account_id,
+            genesis_key_pair,
+            block_time: None,
+            _runtime: runtime,
+        })
+    }
+
+    /// State changed by the successful calls
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Id of the chain, required to sign transactions
+    pub fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    /// Account which has signed the genesis block
+    pub fn genesis_account(&self) -> &AccountId {
+        &self.genesis_account
+    }
+
+    /// Key pair of the [`genesis account`](Self::genesis_account)
+    pub fn genesis_key_pair(&self) -> &KeyPair {
+        &self.genesis_key_pair
+    }
+
+    /// Execute the following calls in blocks created at `time` since the Unix epoch
+    /// instead of the current system time, e.g. to test time-dependent logic.
+    pub fn set_block_time(&mut self, time: Duration) {
+        self.block_time = Some(time);
+    }
+
+    /// Execute `_iroha_smart_contract_main` of the given smart contract on behalf of `authority`.
+    ///
+    /// Instructions are validated by the executor, but the smart contract
+    /// itself isn't passed through `execute_transaction()`.
+    pub fn execute_smart_contract(
+        &mut self,
+        authority: &AccountId,
+        smart_contract: impl AsRef<[u8]>,
+    ) -> Outcome<wasm::error::Error> {
+        self.transact(|state_transaction| {
+            RuntimeBuilder::<wasm::state::SmartContract>::new()
+                .with_engine(state_transaction.engine.clone())
+                .with_config(state_transaction.world.parameters().smart_contract)
+                .build()?
+                .execute(state_transaction, authority.clone(), smart_contract)
+        })
+    }
+
+    /// Execute `_iroha_trigger_main` of the given trigger module as if trigger `id`
+    /// owned by `authority` was activated by `event`.
+    ///
+    /// The trigger doesn't need to be registered.
+    pub fn execute_trigger(
+        &mut self,
+        id: &TriggerId,
+        authority: &AccountId,
+        trigger: impl AsRef<[u8]>,
+        event: impl Into<EventBox>,
+    ) -> Outcome<wasm::error::Error> {
+        let event = event.into();
+
+        self.transact(|state_transaction| {
+            let module = wasm::load_module(state_transaction.engine, trigger)?;
+
+            RuntimeBuilder::<wasm::state::Trigger>::new()
+                .with_engine(state_transaction.engine.clone())
+                .with_config(state_transaction.world.parameters().smart_contract)
+                .build()?
+                .execute_trigger_module(state_transaction, id, authority.clone(), &module, event)
+        })
+    }
+
+    /// Pass `transaction` through `execute_transaction()` of the current executor.
+    ///
+    /// Signatures and limits of the transaction aren't checked.
+    pub fn execute_transaction(
+        &mut self,
+        transaction: SignedTransaction,
+    ) -> Outcome<ValidationFail> {
+        let authority = transaction.authority().clone();
+
+        self.transact(|state_transaction| {
+            let executor = state_transaction.world.executor().clone();
+            executor.execute_transaction(
+                state_transaction,
+                &authority,
+                transaction,
+                &mut WasmCache::new(),
+            )
+        })
+    }
+
+    /// Pass `instruction` through `execute_instruction()` of the current executor
+    /// on behalf of `authority`.
+    ///
+    /// Use [`Upgrade`] to test `migrate()` of an executor.
+    pub fn execute_instruction(
+        &mut self,
+        authority: &AccountId,
+        instruction: impl Into<InstructionBox>,
+    ) -> Outcome<ValidationFail> {
+        let instruction = instruction.into();
+
+        self.transact(|state_transaction| {
+            let executor = state_transaction.world.executor().clone();
+            executor.execute_instruction(state_transaction, authority, instruction)
+        })
+    }
+
+    /// Pass `query` through `validate_query()` of the current executor on behalf of `authority`.
+    ///
+    /// # Errors
+    ///
+    /// If the executor denies the query
+    pub fn validate_query(
+        &self,
+        authority: &AccountId,
+        query: &QueryRequest,
+    ) -> Result<(), ValidationFail> {
+        let state_view = self.state.view();
+        state_view
+            .world
+            .executor()
+            .validate_query(&state_view, authority, query)
+    }
+
+    /// Execute `instruction` on behalf of `authority` bypassing the executor,
+    /// e.g. to set up the state before the test.
+    ///
+    /// # Errors
+    ///
+    /// If the instruction fails
+    pub fn force_execute(
+        &mut self,
+        authority: &AccountId,
+        instruction: impl Into<InstructionBox>,
+    ) -> Result<(), ValidationFail> {
+        let instruction = instruction.into();
+
+        self.transact(|state_transaction| {
+            instruction
+                .execute(authority, state_transaction)
+                .map_err(ValidationFail::from)
+        })
+        .result
+    }
+
+    /// Run `f` in a transaction of the next block, applying it if `f` succeeds
+    fn transact<E>(
+        &mut self,
+        f: impl FnOnce(&mut StateTransaction<'_, '_>) -> Result<(), E>,
+    ) -> Outcome<E> {
+        let mut state_block = self.state.block(self.next_block_header());
+        let mut state_transaction = state_block.transaction();
+
+        let result = f(&mut state_transaction);
+        let fuel = state_transaction.consumed_fuel();
+        let instructions = state_transaction.executed_instructions();
+        if result.is_err() {
+            return Outcome {
+                result,
+                events: Vec::new(),
+                fuel,
+                instructions,
+            };
+        }
+
+        let events = state_transaction.data_events();
+        state_transaction.apply();
+        state_block.commit();
+
+        Outcome {
+            result,
+            events,
+            fuel,
+            instructions,
+        }
+    }
+
+    fn next_block_header(&self) -> BlockHeader {
+        let state_view = self.state.view();
+        let creation_time_ms = self
+            .block_time
+            .unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .expect("INTERNAL BUG: Failed to get the current system time")
+            })
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Unix time exceeds u64::MAX");
+
+        BlockHeader {
+            height: NonZeroU64::new(state_view.height() as u64 + 1)
+                .expect("INTERNAL BUG: Blockchain height overflow"),
+            prev_block_hash: state_view.latest_block_hash(),
+            transactions_hash: None,
+            prev_state_root: state_view.state_roots().last().copied(),
+            creation_time_ms,
+            view_change_index: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::{load_sample_wasm, ALICE_ID, ALICE_KEYPAIR, BOB_ID, BOB_KEYPAIR};
+    use parity_scale_codec::Encode;
+
+    use super::*;
+
+    fn harness() -> Harness {
+        let wasm_dir = tempfile::tempdir().unwrap();
+        let executor_path = wasm_dir.path().join("executor.wasm");
+        std::fs::write(&executor_path, load_sample_wasm("default_executor")).unwrap();
+
+        let genesis = GenesisBuilder::new(
+            ChainId::from("00000000-0000-0000-0000-000000000000"),
+            executor_path,
+            wasm_dir.path(),
+        )
+        .domain("wonderland".parse().unwrap())
+        .account(ALICE_KEYPAIR.public_key().clone())
+        .account(BOB_KEYPAIR.public_key().clone())
+        .finish_domain();
+
+        Harness::new(genesis).unwrap()
+    }
+
+    fn set_answer() -> InstructionBox {
+        SetKeyValue::account(ALICE_ID.clone(), "answer".parse().unwrap(), 42_u32).into()
+    }
+
+    fn answer(harness: &Harness) -> Option<Json> {
+        harness
+            .state()
+            .view()
+            .world
+            .account(&ALICE_ID)
+            .unwrap()
+            .metadata()
+            .get("answer")
+            .cloned()
+    }
+
+    /// WASM module which executes `isi` in its `main_fn_name` entrypoint
+    fn module_executing(main_fn_name: &str, isi: &InstructionBox) -> String {
+        let isi_hex = hex::encode(isi.encode())
+            .as_bytes()
+            .chunks(2)
+            .map(|byte| format!("\\{}", std::str::from_utf8(byte).unwrap()))
+            .collect::<String>();
+
+        format!(
+            r#"
+            (module
+                (import "iroha" "execute_instruction"
+                    (func $exec_isi (param i32 i32) (result i32)))
+
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{isi_hex}")
+
+                (global $mem_size (mut i32) i32.const {isi_len})
+
+                (func (export "_iroha_smart_contract_alloc") (param $size i32) (result i32)
+                    global.get $mem_size
+
+                    (global.set $mem_size
+                        (i32.add (global.get $mem_size) (local.get $size))))
+
+                (func (export "_iroha_smart_contract_dealloc") (param $size i32) (param $len i32)
+                    nop)
+
+                (func (export "{main_fn_name}") (param i32)
+                    (call $exec_isi (i32.const 0) (i32.const {isi_len}))
+                    drop))
+            "#,
+            isi_len = isi_hex.len() / 3,
+        )
+    }
+
+    #[test]
+    fn smart_contract_changes_state() {
+        let mut harness = harness();
+        let smart_contract = module_executing("_iroha_smart_contract_main", &set_answer());
+
+        let outcome = harness.execute_smart_contract(&ALICE_ID, smart_contract);
+
+        outcome.result.unwrap();
+        assert!(outcome.fuel > 0);
+        assert_eq!(outcome.instructions, 1);
+        assert!(matches!(
+            outcome.events.as_slice(),
+            [DataEvent::Domain(DomainEvent::Account(
+                AccountEvent::MetadataInserted(_)
+            ))]
+        ));
+        assert_eq!(answer(&harness), Some(Json::new(42_u32)));
+    }
+
+    #[test]
+    fn trigger_changes_state() {
+        let mut harness = harness();
+        let trigger = module_executing("_iroha_trigger_main", &set_answer());
+        let id: TriggerId = "answer".parse().unwrap();
+        let event = ExecuteTriggerEvent {
+            trigger_id: id.clone(),
+            authority: ALICE_ID.clone(),
+            args: Json::default(),
+        };
+
+        let outcome = harness.execute_trigger(&id, &ALICE_ID, trigger, event);
+
+        outcome.result.unwrap();
+        assert!(outcome.fuel > 0);
+        assert_eq!(answer(&harness), Some(Json::new(42_u32)));
+    }
+
+    #[test]
+    fn executor_denies_instruction() {
+        let mut harness = harness();
+
+        let outcome = harness.execute_instruction(&BOB_ID, set_answer());
+
+        assert!(matches!(
+            outcome.result,
+            Err(ValidationFail::NotPermitted(_))
+        ));
+        assert!(outcome.events.is_empty());
+        assert_eq!(answer(&harness), None);
+
+        harness
+            .execute_instruction(&ALICE_ID, set_answer())
+            .result
+            .unwrap();
+        assert_eq!(answer(&harness), Some(Json::new(42_u32)));
+    }
+
+    #[test]
+    fn block_time_is_set_by_caller() {
+        let mut harness = harness();
+
+        harness.set_block_time(Duration::from_secs(1_700_000_000));
+
+        assert_eq!(
+            harness.next_block_header().creation_time_ms,
+            1_700_000_000_000
+        );
+    }
+}
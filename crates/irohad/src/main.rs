@@ -22,11 +22,15 @@ use iroha_core::{
     kiso::KisoHandle,
     kura::Kura,
     peers_gossiper::{PeersGossiper, PeersGossiperHandle},
-    query::store::LiveQueryStore,
+    query::{
+        history::StateHistory,
+        store::{LiveQueryStore, LiveQueryStoreHandle},
+    },
     queue::Queue,
     smartcontracts::isi::Registrable as _,
     snapshot::{try_read_snapshot, SnapshotMaker, TryReadError as TryReadSnapshotError},
     state::{State, StateReadOnly, World},
+    state_sync::{Bootstrap, StateSynchronizer, StateSynchronizerHandle},
     sumeragi::{GenesisWithPubKey, SumeragiHandle, SumeragiStartArgs},
     IrohaNetwork,
 };
@@ -129,11 +133,14 @@ pub enum StartError {
     ListenOsSignal,
     #[error("Unable to start Torii (Iroha HTTP API Gateway)")]
     StartTorii,
+    #[error("Unable to subscribe on configuration updates")]
+    SubscribeConfigUpdates,
 }
 
 struct NetworkRelay {
     sumeragi: SumeragiHandle,
     block_sync: BlockSynchronizerHandle,
+    state_sync: StateSynchronizerHandle,
     tx_gossiper: TransactionGossiperHandle,
     peers_gossiper: PeersGossiperHandle,
     network: IrohaNetwork,
@@ -160,6 +167,7 @@ impl NetworkRelay {
                 self.sumeragi.incoming_control_flow_message(*data);
             }
             BlockSync(data) => self.block_sync.message(*data).await,
+            StateSync(data) => self.state_sync.message(*data).await,
             TransactionGossiper(data) => self.tx_gossiper.gossip(*data).await,
             PeersGossiper(data) => self.peers_gossiper.gossip(*data, peer).await,
             Health => {}
@@ -192,6 +200,9 @@ impl Iroha {
     > {
         let mut supervisor = Supervisor::new();
 
+        let (kiso, child) = KisoHandle::start(config.clone());
+        supervisor.monitor(child);
+
         let (kura, block_count) = Kura::new(&config.kura).change_context(StartError::InitKura)?;
         let child = Kura::start(kura.clone(), supervisor.shutdown_signal());
         supervisor.monitor(child);
@@ -207,7 +218,37 @@ impl Iroha {
             (metrics.clone(), StateTelemetry::new(metrics))
         };
 
-        let state = match try_read_snapshot(
+        let (network, child) = IrohaNetwork::start(
+            config.common.key_pair.clone(),
+            config.network.clone(),
+            supervisor.shutdown_signal(),
+        )
+        .await
+        .attach_printable_lazy(|| config.network.address.clone().into_attachment())
+        .change_context(StartError::StartP2p)?;
+        supervisor.monitor(child);
+
+        let synced_state = if config.state_sync.enabled && block_count.0 == 0 && genesis.is_none() {
+            Bootstrap {
+                config: config.state_sync,
+                peer: config.common.peer.clone(),
+                trusted_peers: config.common.trusted_peers.value().clone(),
+                network: network.clone(),
+                kura: Arc::clone(&kura),
+                query_handle: live_query_store.clone(),
+                store_dir: config.snapshot.store_dir.resolve_relative_path(),
+                #[cfg(feature = "telemetry")]
+                telemetry: state_telemetry.clone(),
+            }
+            .run()
+            .await
+        } else {
+            None
+        };
+        // State sync might have filled the block store
+        let block_count = kura.block_count();
+
+        let state = synced_state.or_else(|| match try_read_snapshot(
             config.snapshot.store_dir.resolve_relative_path(),
             &kura,
             || live_query_store.clone(),
@@ -230,7 +271,7 @@ impl Iroha {
                 iroha_logger::warn!(%error, "Failed to load the state from a snapshot; creating an empty state");
                 None
             }
-        }.unwrap_or_else(|| {
+        }).unwrap_or_else(|| {
             let world = World::with(
                 [genesis_domain(config.genesis.public_key.clone())],
                 [genesis_account(config.genesis.public_key.clone())],
@@ -247,21 +288,32 @@ impl Iroha {
         });
         let state = Arc::new(state);
 
+        let state_history = Arc::new(StateHistory::new(
+            config.common.chain.clone(),
+            AccountId::new(
+                iroha_genesis::GENESIS_DOMAIN_ID.clone(),
+                config.genesis.public_key.clone(),
+            ),
+            config
+                .common
+                .trusted_peers
+                .value()
+                .clone()
+                .into_non_empty_vec()
+                .into_iter()
+                .collect(),
+            Arc::clone(&kura),
+            Arc::clone(&state),
+            live_query_store.clone(),
+            config.snapshot.store_dir.resolve_relative_path(),
+            config.live_query_store.history_depth,
+        ));
+
         let (events_sender, _) = broadcast::channel(EVENTS_BUFFER_CAPACITY);
         let queue = Arc::new(Queue::from_config(config.queue, events_sender.clone()));
 
-        let (network, child) = IrohaNetwork::start(
-            config.common.key_pair.clone(),
-            config.network.clone(),
-            supervisor.shutdown_signal(),
-        )
-        .await
-        .attach_printable_lazy(|| config.network.address.clone().into_attachment())
-        .change_context(StartError::StartP2p)?;
-        supervisor.monitor(child);
-
         #[cfg(feature = "telemetry")]
-        start_telemetry(&logger, &config, &mut supervisor).await?;
+        start_telemetry(&logger, &config, &kiso, &mut supervisor).await?;
 
         #[cfg(feature = "telemetry")]
         let telemetry = {
@@ -314,6 +366,21 @@ impl Iroha {
             network.clone(),
             Arc::clone(&state),
         )
+        .start(
+            kiso.subscribe_on_block_sync_updates()
+                .await
+                .change_context(StartError::SubscribeConfigUpdates)?,
+            supervisor.shutdown_signal(),
+        );
+        supervisor.monitor(child);
+
+        let (state_sync, child) = StateSynchronizer::new(
+            kura.clone(),
+            config.common.peer.clone(),
+            network.clone(),
+            config.snapshot.store_dir.resolve_relative_path(),
+            config.block_sync.gossip_size,
+        )
         .start(supervisor.shutdown_signal());
         supervisor.monitor(child);
 
@@ -324,32 +391,50 @@ impl Iroha {
             Arc::clone(&queue),
             Arc::clone(&state),
         )
-        .start(supervisor.shutdown_signal());
+        .start(
+            kiso.subscribe_on_transaction_gossiper_updates()
+                .await
+                .change_context(StartError::SubscribeConfigUpdates)?,
+            supervisor.shutdown_signal(),
+        );
         supervisor.monitor(child);
 
         if let Some(snapshot_maker) =
             SnapshotMaker::from_config(&config.snapshot, Arc::clone(&state))
         {
-            supervisor.monitor(snapshot_maker.start(supervisor.shutdown_signal()));
+            supervisor.monitor(
+                snapshot_maker.start(
+                    kiso.subscribe_on_snapshot_updates()
+                        .await
+                        .change_context(StartError::SubscribeConfigUpdates)?,
+                    supervisor.shutdown_signal(),
+                ),
+            );
         }
 
-        let (kiso, child) = KisoHandle::start(config.clone());
-        supervisor.monitor(child);
+        supervisor.monitor(tokio::task::spawn(config_updates_relay(
+            kiso.clone(),
+            logger,
+            Arc::clone(&queue),
+            live_query_store.clone(),
+        )));
 
-        let torii_run = Torii::new(
+        let torii = Torii::new(
             config.common.chain.clone(),
             kiso.clone(),
             config.torii,
             queue,
             events_sender,
             live_query_store,
+            state_history,
             kura.clone(),
             state.clone(),
             iroha_torii::OnlinePeersProvider::new(network.online_peers_receiver()),
             #[cfg(feature = "telemetry")]
             telemetry,
-        )
-        .start(supervisor.shutdown_signal());
+        );
+        supervisor.monitor(torii.start_event_journal(supervisor.shutdown_signal()));
+        let torii_run = torii.start(supervisor.shutdown_signal());
         supervisor.monitor(Child::new(
             tokio::spawn(async move {
                 if let Err(err) = torii_run.await {
@@ -366,6 +451,7 @@ impl Iroha {
             NetworkRelay {
                 sumeragi,
                 block_sync,
+                state_sync,
                 tx_gossiper,
                 peers_gossiper,
                 network,
@@ -373,8 +459,6 @@ impl Iroha {
             .run(),
         ));
 
-        supervisor.monitor(tokio::task::spawn(config_updates_relay(kiso, logger)));
-
         supervisor
             .setup_shutdown_on_os_signals()
             .change_context(StartError::ListenOsSignal)?;
@@ -403,9 +487,12 @@ impl Iroha {
 async fn start_telemetry(
     logger: &LoggerHandle,
     config: &Config,
+    kiso: &KisoHandle,
     supervisor: &mut Supervisor,
 ) -> Result<(), StartError> {
+    #[cfg(feature = "dev-telemetry")]
     const MSG_SUBSCRIBE: &str = "unable to subscribe to the channel";
+    #[cfg(feature = "dev-telemetry")]
     const MSG_START_TASK: &str = "unable to start the task";
 
     #[cfg(feature = "dev-telemetry")]
@@ -426,35 +513,109 @@ async fn start_telemetry(
         }
     }
 
-    if let Some(config) = &config.telemetry {
-        let receiver = logger
-            .subscribe_on_telemetry(iroha_logger::telemetry::Channel::Regular)
-            .await
-            .change_context(StartError::StartTelemetry)
-            .attach_printable(MSG_SUBSCRIBE)?;
-        let handle = iroha_telemetry::ws::start(config.clone(), receiver)
-            .await
-            .into_report()
-            .map_err(|report| report.change_context(StartError::StartTelemetry))
-            .attach_printable(MSG_START_TASK)?;
-        supervisor.monitor(handle);
-        iroha_logger::info!("Telemetry started");
-        Ok(())
-    } else {
-        iroha_logger::info!("Telemetry not started due to absent configuration");
-        Ok(())
+    let handle = match &config.telemetry {
+        Some(config) => {
+            let handle = start_ws_telemetry(logger, config).await?;
+            iroha_logger::info!("Telemetry started");
+            Some(handle)
+        }
+        None => {
+            iroha_logger::info!("Telemetry not started due to absent configuration");
+            None
+        }
+    };
+    let updates = kiso
+        .subscribe_on_telemetry_updates()
+        .await
+        .change_context(StartError::SubscribeConfigUpdates)?;
+    supervisor.monitor(tokio::task::spawn(telemetry_updates_relay(
+        logger.clone(),
+        handle,
+        updates,
+    )));
+
+    Ok(())
+}
+
+#[cfg(feature = "telemetry")]
+async fn start_ws_telemetry(
+    logger: &LoggerHandle,
+    config: &iroha_config::parameters::actual::Telemetry,
+) -> Result<task::JoinHandle<()>, StartError> {
+    const MSG_SUBSCRIBE: &str = "unable to subscribe to the channel";
+    const MSG_START_TASK: &str = "unable to start the task";
+
+    let receiver = logger
+        .subscribe_on_telemetry(iroha_logger::telemetry::Channel::Regular)
+        .await
+        .change_context(StartError::StartTelemetry)
+        .attach_printable(MSG_SUBSCRIBE)?;
+    iroha_telemetry::ws::start(config.clone(), receiver)
+        .await
+        .into_report()
+        .map_err(|report| report.change_context(StartError::StartTelemetry))
+        .attach_printable(MSG_START_TASK)
+}
+
+/// Restarts the telemetry task whenever its configuration changes.
+///
+/// The task itself isn't monitored by the supervisor since it is replaced at runtime.
+#[cfg(feature = "telemetry")]
+async fn telemetry_updates_relay(
+    logger: LoggerHandle,
+    mut handle: Option<task::JoinHandle<()>>,
+    mut updates: tokio::sync::watch::Receiver<Option<iroha_config::parameters::actual::Telemetry>>,
+) {
+    while updates.changed().await.is_ok() {
+        let config = updates.borrow_and_update().clone();
+        if let Some(handle) = handle.take() {
+            handle.abort();
+        }
+        let Some(config) = config else {
+            iroha_logger::info!("Telemetry stopped due to absent configuration");
+            continue;
+        };
+        match start_ws_telemetry(&logger, &config).await {
+            Ok(new_handle) => {
+                iroha_logger::info!("Telemetry restarted");
+                handle = Some(new_handle);
+            }
+            Err(report) => {
+                iroha_logger::error!(?report, "Failed to restart telemetry");
+            }
+        }
+    }
+
+    if let Some(handle) = handle {
+        handle.abort();
     }
+    iroha_logger::debug!("Exiting telemetry updates relay");
 }
 
 /// Spawns a task which subscribes on updates from the configuration actor
 /// and broadcasts them further to interested actors. This way, neither the config actor nor other ones know
 /// about each other, achieving loose coupling of code and system.
-async fn config_updates_relay(kiso: KisoHandle, logger: LoggerHandle) {
+async fn config_updates_relay(
+    kiso: KisoHandle,
+    logger: LoggerHandle,
+    queue: Arc<Queue>,
+    live_query_store: LiveQueryStoreHandle,
+) {
+    // FIXME: don't like neither the message nor inability to throw Result to the outside
+    const MSG_SUBSCRIBE: &str = "Cannot proceed without working subscriptions";
+
     let mut log_level_update = kiso
         .subscribe_on_logger_updates()
         .await
-        // FIXME: don't like neither the message nor inability to throw Result to the outside
-        .expect("Cannot proceed without working subscriptions");
+        .expect(MSG_SUBSCRIBE);
+    let mut queue_update = kiso
+        .subscribe_on_queue_updates()
+        .await
+        .expect(MSG_SUBSCRIBE);
+    let mut live_query_store_update = kiso
+        .subscribe_on_live_query_store_updates()
+        .await
+        .expect(MSG_SUBSCRIBE);
 
     // See https://github.com/tokio-rs/tokio/issues/5616 and
     // https://github.com/rust-lang/rust-clippy/issues/10636
@@ -467,6 +628,14 @@ async fn config_updates_relay(kiso: KisoHandle, logger: LoggerHandle) {
                     iroha_logger::error!("Failed to reload log level: {error}");
                 };
             }
+            Ok(()) = queue_update.changed() => {
+                let value = *queue_update.borrow_and_update();
+                queue.update_config(value);
+            }
+            Ok(()) = live_query_store_update.changed() => {
+                let value = *live_query_store_update.borrow_and_update();
+                live_query_store.update_config(value);
+            }
             else => {
                 iroha_logger::debug!("Exiting config updates relay");
                 break;
@@ -0,0 +1,67 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn alias_resolves_to_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    let alias: AccountAlias = "alice@wonderland".parse()?;
+
+    client.submit_blocking(RegisterAlias::new(ALICE_ID.clone(), alias.clone()))?;
+
+    assert_eq!(
+        client.query_single(FindAccountByAlias::new(alias.clone()))?,
+        *ALICE_ID
+    );
+    let account = client
+        .query(FindAccounts::new())
+        .filter_with(|account| account.alias.eq(alias.clone()))
+        .execute_single()?;
+    assert_eq!(account.id(), &*ALICE_ID);
+
+    // an alias resolves into the account ID, so it can be used anywhere the ID is expected
+    let rose: AssetId = format!(
+        "rose#wonderland#{}",
+        client.query_single(FindAccountByAlias::new(alias.clone()))?
+    )
+    .parse()?;
+    client.submit_blocking(Transfer::asset_numeric(rose, 1_u32, BOB_ID.clone()))?;
+
+    client.submit_blocking(UnregisterAlias::new(alias.clone()))?;
+    let _err = client
+        .query_single(FindAccountByAlias::new(alias))
+        .expect_err("alias should be unregistered");
+
+    Ok(())
+}
+
+#[test]
+fn alias_is_unique() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice = network.client();
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    let alias: AccountAlias = "rabbit@wonderland".parse()?;
+
+    alice.submit_blocking(RegisterAlias::new(ALICE_ID.clone(), alias.clone()))?;
+    let _err = bob
+        .submit_blocking(RegisterAlias::new(BOB_ID.clone(), alias.clone()))
+        .expect_err("alias should be taken by alice");
+    let _err = bob
+        .submit_blocking(UnregisterAlias::new(alias.clone()))
+        .expect_err("bob shouldn't unregister the alias of alice");
+
+    // the alias is released along with the account
+    let (carol_id, _) = gen_account_in("wonderland");
+    alice.submit_blocking(Register::account(Account::new(carol_id.clone())))?;
+    alice.submit_blocking(UnregisterAlias::new(alias.clone()))?;
+    alice.submit_blocking(RegisterAlias::new(carol_id.clone(), alias.clone()))?;
+    alice.submit_blocking(Unregister::account(carol_id))?;
+    bob.submit_blocking(RegisterAlias::new(BOB_ID.clone(), alias.clone()))?;
+    assert_eq!(alice.query_single(FindAccountByAlias::new(alias))?, *BOB_ID);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use eyre::Result;
+use iroha::{
+    client::{Client, QueryError},
+    crypto::{KeyPair, PublicKey},
+    data_model::{prelude::*, Level},
+};
+use iroha_executor_data_model::permission::account::CanManageAccountKeys;
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+fn alice_signatories(client: &Client) -> Result<Vec<PublicKey>> {
+    let alice = client
+        .query(FindAccounts::new())
+        .filter_with(|account| account.id.eq(ALICE_ID.clone()))
+        .execute_single()?;
+
+    Ok(alice.signatories().cloned().collect())
+}
+
+#[test]
+fn rotated_key_controls_the_same_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let mut alice = network.client();
+    let new_key_pair = KeyPair::random();
+
+    alice.submit_blocking(RotateAccountKey::new(
+        ALICE_ID.clone(),
+        alice.key_pair.public_key().clone(),
+        new_key_pair.public_key().clone(),
+    ))?;
+
+    // the old key can't sign on behalf of the account anymore
+    let _err = alice
+        .submit(Log::new(Level::INFO, "signed by the old key".to_owned()))
+        .expect_err("old key should be rotated out");
+    let _err = alice
+        .query_single(FindParameters::new())
+        .expect_err("old key should be rotated out");
+
+    // while the new key controls the same account along with its assets and permissions
+    alice.key_pair = new_key_pair.clone();
+    alice.submit_blocking(Register::domain(Domain::new("rotated".parse()?)))?;
+    let rose: AssetId = format!("rose#wonderland#{}", *ALICE_ID).parse()?;
+    alice.submit_blocking(Transfer::asset_numeric(rose, 1_u32, BOB_ID.clone()))?;
+    assert_eq!(
+        alice_signatories(&alice)?,
+        [new_key_pair.public_key().clone()]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn keys_of_another_account_require_permission() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice = network.client();
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    let (extra_signatory, _) = KeyPair::random().into_parts();
+    let add_signatory = AddSignatory::new(ALICE_ID.clone(), extra_signatory.clone());
+
+    let _err = bob
+        .submit_blocking(add_signatory.clone())
+        .expect_err("bob shouldn't manage keys of alice");
+
+    alice.submit_blocking(Grant::account_permission(
+        CanManageAccountKeys {
+            account: ALICE_ID.clone(),
+        },
+        BOB_ID.clone(),
+    ))?;
+    bob.submit_blocking(add_signatory)?;
+    assert!(alice_signatories(&alice)?.contains(&extra_signatory));
+
+    // the original key is still allowed to sign, so it can take the extra one back
+    alice.submit_blocking(RemoveSignatory::new(
+        ALICE_ID.clone(),
+        extra_signatory.clone(),
+    ))?;
+    assert!(!alice_signatories(&alice)?.contains(&extra_signatory));
+
+    Ok(())
+}
+
+#[test]
+fn query_signed_by_another_key_is_rejected() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    // the key pair doesn't belong to bob
+    let impostor = network
+        .peer()
+        .client_for(&BOB_ID, KeyPair::random().private_key().clone());
+
+    let err = impostor
+        .query_single(FindParameters::new())
+        .expect_err("query signature should be rejected");
+    let QueryError::Validation(ValidationFail::NotPermitted(_)) = err else {
+        panic!("Unexpected error: {err:?}");
+    };
+
+    // while the same query signed by bob is served
+    network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone())
+        .query_single(FindParameters::new())?;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use eyre::Result;
+use iroha::{client::Client, data_model::prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn spender_transfers_within_allowance() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice = network.client();
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    let (carol_id, _) = gen_account_in("wonderland");
+    let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+    let alice_rose = AssetId::new(rose.clone(), ALICE_ID.clone());
+    let carol_rose = AssetId::new(rose, carol_id.clone());
+    alice.submit_blocking(Register::account(Account::new(carol_id.clone())))?;
+    let initial = rose_quantity(&alice, &alice_rose)?;
+
+    let _err = bob
+        .submit_blocking(TransferFrom::new(
+            alice_rose.clone(),
+            1_u32,
+            carol_id.clone(),
+        ))
+        .expect_err("bob has no allowance yet");
+    let _err = bob
+        .submit_blocking(Approve::new(alice_rose.clone(), BOB_ID.clone(), 5_u32))
+        .expect_err("bob can't approve spending of assets of alice");
+
+    alice.submit_blocking(Approve::new(alice_rose.clone(), BOB_ID.clone(), 5_u32))?;
+    let _err = bob
+        .submit_blocking(TransferFrom::new(
+            alice_rose.clone(),
+            0_u32,
+            carol_id.clone(),
+        ))
+        .expect_err("transfers of zero are rejected");
+    bob.submit_blocking(TransferFrom::new(
+        alice_rose.clone(),
+        3_u32,
+        carol_id.clone(),
+    ))?;
+    let _err = bob
+        .submit_blocking(TransferFrom::new(alice_rose.clone(), 3_u32, carol_id))
+        .expect_err("bob can spend only what is left of the allowance");
+
+    let allowance = alice
+        .query(FindAssetAllowances::new())
+        .filter_with(|allowance| allowance.id.spender.eq(BOB_ID.clone()))
+        .execute_single()?;
+    assert_eq!(allowance.id().asset(), &alice_rose);
+    assert_eq!(*allowance.amount(), numeric!(2));
+    assert_eq!(
+        rose_quantity(&alice, &alice_rose)?,
+        initial.checked_sub(numeric!(3)).unwrap()
+    );
+    assert_eq!(rose_quantity(&alice, &carol_rose)?, numeric!(3));
+
+    // approving zero revokes the allowance
+    alice.submit_blocking(Approve::new(alice_rose, BOB_ID.clone(), 0_u32))?;
+    assert!(alice
+        .query(FindAssetAllowances::new())
+        .execute_all()?
+        .is_empty());
+
+    Ok(())
+}
+
+fn rose_quantity(client: &Client, asset_id: &AssetId) -> Result<Numeric> {
+    let asset = client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id.clone()))
+        .execute_single()?;
+    Ok(*asset.value())
+}
@@ -0,0 +1,47 @@
+use eyre::Result;
+use iroha::{client::Client, data_model::prelude::*};
+use iroha_executor_data_model::permission::asset::CanFreezeAsset;
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn frozen_asset_cannot_be_transferred() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice = network.client();
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    let alice_rose = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let transfer = Transfer::asset_numeric(alice_rose.clone(), 1_u32, BOB_ID.clone());
+
+    let _err = bob
+        .submit_blocking(Freeze::asset(alice_rose.clone()))
+        .expect_err("bob can't freeze assets of a definition owned by alice");
+    alice.submit_blocking(Freeze::asset(alice_rose.clone()))?;
+    assert!(find_asset(&alice, &alice_rose)?.is_frozen());
+    let _err = alice
+        .submit_blocking(transfer.clone())
+        .expect_err("frozen asset can't be transferred");
+    let _err = bob
+        .submit_blocking(Unfreeze::asset(alice_rose.clone()))
+        .expect_err("bob can't unfreeze assets without permission");
+
+    alice.submit_blocking(Grant::account_permission(
+        CanFreezeAsset {
+            asset: alice_rose.clone(),
+        },
+        BOB_ID.clone(),
+    ))?;
+    bob.submit_blocking(Unfreeze::asset(alice_rose.clone()))?;
+    assert!(!find_asset(&alice, &alice_rose)?.is_frozen());
+    alice.submit_blocking(transfer)?;
+
+    Ok(())
+}
+
+fn find_asset(client: &Client, asset_id: &AssetId) -> Result<Asset> {
+    Ok(client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id.clone()))
+        .execute_single()?)
+}
@@ -0,0 +1,97 @@
+use eyre::Result;
+use iroha::{client::Client, data_model::prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn taker_exchanges_holdings_with_maker() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice = network.client();
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+    let tulip: AssetDefinitionId = "tulip#wonderland".parse()?;
+    let alice_rose = AssetId::new(rose.clone(), ALICE_ID.clone());
+    let bob_tulip = AssetId::new(tulip.clone(), BOB_ID.clone());
+    alice.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(tulip.clone())).into(),
+        Mint::asset_numeric(5_u32, bob_tulip.clone()).into(),
+    ])?;
+    let initial = quantity(&alice, &alice_rose)?;
+
+    let swap_id: SwapId = "rose_for_tulips".parse()?;
+    alice.submit_blocking(
+        CreateSwap::new(
+            swap_id.clone(),
+            SwapItem::Asset(SwapAsset::new(rose.clone(), numeric!(1))),
+            SwapItem::Asset(SwapAsset::new(tulip.clone(), numeric!(2))),
+        )
+        .with_taker(BOB_ID.clone()),
+    )?;
+    let _err = alice
+        .submit_blocking(Transfer::asset_numeric(
+            alice_rose.clone(),
+            initial,
+            BOB_ID.clone(),
+        ))
+        .expect_err("offered rose is locked until the swap is closed");
+
+    bob.submit_blocking(AcceptSwap::new(swap_id))?;
+    assert!(alice.query(FindSwaps::new()).execute_all()?.is_empty());
+    assert_eq!(
+        quantity(&alice, &alice_rose)?,
+        initial.checked_sub(numeric!(1)).unwrap()
+    );
+    assert_eq!(
+        quantity(&alice, &AssetId::new(rose, BOB_ID.clone()))?,
+        numeric!(1)
+    );
+    assert_eq!(quantity(&alice, &bob_tulip)?, numeric!(3));
+    assert_eq!(
+        quantity(&alice, &AssetId::new(tulip, ALICE_ID.clone()))?,
+        numeric!(2)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn only_maker_cancels_swap() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice = network.client();
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+    let tulip: AssetDefinitionId = "tulip#wonderland".parse()?;
+    let alice_rose = AssetId::new(rose.clone(), ALICE_ID.clone());
+    alice.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        tulip.clone(),
+    )))?;
+    let initial = quantity(&alice, &alice_rose)?;
+
+    let swap_id: SwapId = "all_roses".parse()?;
+    alice.submit_blocking(CreateSwap::new(
+        swap_id.clone(),
+        SwapItem::Asset(SwapAsset::new(rose, initial)),
+        SwapItem::Asset(SwapAsset::new(tulip, numeric!(1))),
+    ))?;
+    let _err = bob
+        .submit_blocking(CancelSwap::new(swap_id.clone()))
+        .expect_err("bob can't cancel swap created by alice");
+
+    alice.submit_blocking(CancelSwap::new(swap_id))?;
+    assert!(alice.query(FindSwaps::new()).execute_all()?.is_empty());
+    alice.submit_blocking(Transfer::asset_numeric(alice_rose, initial, BOB_ID.clone()))?;
+
+    Ok(())
+}
+
+fn quantity(client: &Client, asset_id: &AssetId) -> Result<Numeric> {
+    let asset = client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id.clone()))
+        .execute_single()?;
+    Ok(*asset.value())
+}
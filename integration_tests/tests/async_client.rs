@@ -0,0 +1,72 @@
+use eyre::Result;
+use futures_util::TryStreamExt;
+use iroha::{
+    async_client::{AsyncClient, AsyncQueryBuilderExt},
+    data_model::{prelude::*, query::parameters::FetchSize},
+};
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+use nonzero_ext::nonzero;
+use tokio::task::spawn_blocking;
+
+#[tokio::test]
+async fn async_client_submits_and_queries() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = AsyncClient::new(network.client());
+
+    let definitions = (0..10)
+        .map(|i| format!("xor{i}#wonderland").parse::<AssetDefinitionId>())
+        .collect::<Result<Vec<_>, _>>()?;
+    client
+        .submit_all_blocking(
+            definitions
+                .iter()
+                .cloned()
+                .map(|id| Register::asset_definition(AssetDefinition::numeric(id))),
+        )
+        .await?;
+
+    // several batches are fetched lazily
+    let mut fetched = client
+        .query(FindAssetsDefinitions::new())
+        .with_fetch_size(FetchSize::new(Some(nonzero!(3_u64))))
+        .execute_stream()
+        .map_ok(|definition| definition.id().clone())
+        .try_collect::<Vec<_>>()
+        .await?;
+    fetched.retain(|id| definitions.contains(id));
+    fetched.sort();
+    assert_eq!(fetched, definitions);
+
+    let parameters = client.query_single(FindParameters::new()).await?;
+    let blocking_client = client.client().clone();
+    assert_eq!(
+        parameters,
+        spawn_blocking(move || blocking_client.query_single(FindParameters::new())).await??
+    );
+
+    let status = client.get_status().await?;
+    assert_eq!(status.blocks_non_empty, 2);
+
+    let config = client.get_config().await?;
+    assert_eq!(config.public_key, *network.peer().peer_id().public_key());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_client_reports_rejection() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = AsyncClient::new(network.client());
+
+    let missing_definition = "missing#wonderland".parse::<AssetDefinitionId>()?;
+    let result = client
+        .submit_blocking(Mint::asset_numeric(
+            1u32,
+            AssetId::new(missing_definition, ALICE_ID.clone()),
+        ))
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
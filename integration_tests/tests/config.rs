@@ -1,4 +1,4 @@
-use iroha_config::client_api::{ConfigUpdateDTO, Logger};
+use iroha_config::client_api::{ConfigUpdateDTO, Logger, Queue};
 use iroha_data_model::Level;
 use iroha_test_network::NetworkBuilder;
 use nonzero_ext::nonzero;
@@ -21,10 +21,11 @@ fn retrieve_update_config() -> eyre::Result<()> {
     assert_eq!(config.logger.filter, None);
 
     client.set_config(&ConfigUpdateDTO {
-        logger: Logger {
+        logger: Some(Logger {
             level: Level::ERROR,
             filter: Some("iroha_p2p=trace".parse()?),
-        },
+        }),
+        ..ConfigUpdateDTO::default()
     })?;
 
     let config = client.get_config()?;
@@ -34,5 +35,21 @@ fn retrieve_update_config() -> eyre::Result<()> {
     assert_eq!(config.logger.level, Level::ERROR);
     assert_eq!(config.logger.filter, Some("iroha_p2p=trace".parse()?),);
 
+    client.set_config(&ConfigUpdateDTO {
+        queue: Some(Queue {
+            capacity: nonzero!(10_usize),
+            capacity_per_user: nonzero!(5_usize),
+            transaction_time_to_live_ms: 60_000,
+        }),
+        ..ConfigUpdateDTO::default()
+    })?;
+
+    let config = client.get_config()?;
+
+    assert_eq!(config.queue.capacity, nonzero!(10_usize));
+    assert_eq!(config.queue.capacity_per_user, nonzero!(5_usize));
+    assert_eq!(config.queue.transaction_time_to_live_ms, 60_000);
+    assert_eq!(config.logger.level, Level::ERROR);
+
     Ok(())
 }
@@ -1,4 +1,4 @@
-use std::fmt::Write as _;
+use std::{fmt::Write as _, time::Duration};
 
 use assert_matches::assert_matches;
 use eyre::Result;
@@ -9,8 +9,9 @@ use iroha_executor_data_model::permission::{
 };
 use iroha_test_network::*;
 use iroha_test_samples::{ALICE_ID, BOB_ID};
+use nonzero_ext::nonzero;
 use parity_scale_codec::Encode as _;
-use tokio::task::spawn_blocking;
+use tokio::{task::spawn_blocking, time::timeout};
 
 /// Return string containing exported memory, dummy allocator, and
 /// host function imports which you can embed into your wasm module.
@@ -265,3 +266,98 @@ async fn produce_multiple_events() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[allow(clippy::integer_division)]
+async fn custom_events_are_filtered_by_name_and_emitter() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let order_filled: Name = "order_filled".parse()?;
+    let mut events_stream = network
+        .client()
+        .listen_for_events_async([CustomEventFilter::new()
+            .for_emitter(ALICE_ID.clone())
+            .for_name(order_filled.clone())])
+        .await?;
+
+    {
+        let client = network.client();
+        spawn_blocking(move || {
+            client.submit_all_blocking([
+                EmitEvent::new("order_cancelled".parse().unwrap(), 1_u32),
+                EmitEvent::new("order_filled".parse().unwrap(), 2_u32),
+            ])
+        })
+        .await??;
+    }
+
+    // emitted from a smart contract through the dedicated host function
+    let event_hex = hex::encode(EmitEvent::new(order_filled.clone(), 3_u32).encode());
+    let wat = format!(
+        r#"
+        (module
+            (import "iroha" "emit_event"
+                (func $emit_event (param i32 i32) (result i32)))
+            {wasm_template}
+
+            (func (export "{main_fn_name}") (param i32)
+                (call $emit_event (i32.const 0) (i32.const {event_len}))
+                drop))
+        "#,
+        main_fn_name = "_iroha_smart_contract_main",
+        wasm_template = wasm_template(&event_hex),
+        event_len = event_hex.len() / 2,
+    );
+    {
+        let client = network.client();
+        let tx = client.build_transaction(
+            WasmSmartContract::from_compiled(wat.into_bytes()),
+            <_>::default(),
+        );
+        spawn_blocking(move || client.submit_transaction_blocking(&tx)).await??;
+    }
+
+    for expected_payload in [2_u32, 3] {
+        let event = events_stream.next().await.unwrap()?;
+        let event = assert_matches!(event, EventBox::Data(DataEvent::Custom(event)) => event);
+        assert_eq!(*event.emitter(), *ALICE_ID);
+        assert_eq!(*event.name(), order_filled);
+        assert_eq!(*event.payload(), Json::new(expected_payload));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn data_events_are_replayed_after_restart() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    let register = Register::domain(Domain::new("replayed".parse()?));
+    spawn_blocking(move || client.submit_blocking(register)).await??;
+    network.ensure_blocks(2).await?;
+
+    // events of the committed blocks are no longer kept by the restarted peer
+    network.shutdown().await;
+    let peer = network.peer();
+    timeout(network.peer_startup_timeout(), async {
+        peer.start(network.config(), None).await;
+        peer.once_block(2).await;
+    })
+    .await?;
+
+    let mut events = network
+        .client()
+        .replay_events_async([DataEventFilter::Any], nonzero!(2_u64))
+        .await?;
+    let message = timeout(Duration::from_secs(5), events.next())
+        .await?
+        .expect("the block is replayed")?;
+
+    let domain = assert_matches!(
+        message.event,
+        EventBox::Data(DataEvent::Domain(DomainEvent::Created(domain))) => domain
+    );
+    assert_eq!(domain.id().name().as_ref(), "replayed");
+    assert_eq!(message.block_height, Some(nonzero!(2_u64)));
+
+    Ok(())
+}
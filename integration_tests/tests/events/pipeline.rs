@@ -11,6 +11,7 @@ use iroha::data_model::{
     ValidationFail,
 };
 use iroha_test_network::*;
+use nonzero_ext::nonzero;
 use tokio::{task::spawn_blocking, time::timeout};
 
 #[tokio::test]
@@ -78,3 +79,79 @@ async fn test_with_instruction_and_status(
 fn applied_block_must_be_available_in_kura() {
     unimplemented!("Take a look at previous implementation and restore this test");
 }
+
+#[tokio::test]
+async fn events_are_replayed_from_block_height() -> Result<()> {
+    // Given
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    let register = Register::domain(Domain::new("replayed".parse()?));
+    let hash = spawn_blocking({
+        let client = client.clone();
+        move || client.submit_blocking(register)
+    })
+    .await??;
+
+    // When
+    let mut events = client
+        .replay_events_async(
+            [TransactionEventFilter::default().for_hash(hash)],
+            nonzero!(1_u64),
+        )
+        .await?;
+
+    // Then
+    timeout(Duration::from_secs(5), async move {
+        let message = events.next().await.unwrap().unwrap();
+        let EventBox::Pipeline(PipelineEventBox::Transaction(event)) = message.event else {
+            panic!("Expected transaction event");
+        };
+        assert_eq!(*event.status(), TransactionStatus::Approved);
+        assert_eq!(message.block_height, event.block_height());
+    })
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn queued_transaction_is_forwarded_while_replaying() -> Result<()> {
+    // Given
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+    let transaction = client.build_transaction(
+        [Log::new(Level::INFO, "queued".to_owned())],
+        Metadata::default(),
+    );
+    let hash = transaction.hash();
+
+    // When
+    let mut events = client
+        .replay_events_async(
+            [TransactionEventFilter::default().for_hash(hash)],
+            nonzero!(1_u64),
+        )
+        .await?;
+    spawn_blocking(move || client.submit_transaction(&transaction)).await??;
+
+    // Then
+    timeout(Duration::from_secs(5), async move {
+        // isn't held back until the transaction is committed
+        let message = events.next().await.unwrap().unwrap();
+        let EventBox::Pipeline(PipelineEventBox::Transaction(event)) = message.event else {
+            panic!("Expected transaction event");
+        };
+        assert_eq!(*event.status(), TransactionStatus::Queued);
+        assert_eq!(message.block_height, None);
+
+        let message = events.next().await.unwrap().unwrap();
+        let EventBox::Pipeline(PipelineEventBox::Transaction(event)) = message.event else {
+            panic!("Expected transaction event");
+        };
+        assert_eq!(*event.status(), TransactionStatus::Approved);
+        assert_eq!(message.block_height, event.block_height());
+    })
+    .await?;
+
+    Ok(())
+}
@@ -4,4 +4,5 @@ mod multiple_blocks_created;
 mod normal;
 mod offline_peers;
 mod restart_peer;
+mod state_sync;
 mod unregister_peer;
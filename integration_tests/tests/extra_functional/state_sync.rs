@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_config_base::toml::WriteExt;
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+use tokio::{task::spawn_blocking, time::timeout};
+
+#[tokio::test]
+async fn new_peer_should_bootstrap_from_snapshot() -> Result<()> {
+    let asset_definition_id = "xor#wonderland".parse::<AssetDefinitionId>()?;
+    let quantity = numeric!(200);
+
+    let network = NetworkBuilder::new()
+        .with_peers(4)
+        .with_config(|c| {
+            c.write(["snapshot", "mode"], "read_write")
+                .write(["snapshot", "create_every_ms"], 100);
+        })
+        .start()
+        .await?;
+
+    let client = network.client();
+    let asset_definition_clone = asset_definition_id.clone();
+    spawn_blocking(move || {
+        client.submit_all_blocking::<InstructionBox>([
+            Register::asset_definition(AssetDefinition::numeric(asset_definition_clone.clone()))
+                .into(),
+            Mint::asset_numeric(
+                quantity,
+                AssetId::new(asset_definition_clone, ALICE_ID.clone()),
+            )
+            .into(),
+        ])
+    })
+    .await??;
+    network.ensure_blocks(2).await?;
+    // let peers snapshot the state at height 2
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    // the block following the snapshot commits to its state root
+    let peer = NetworkPeer::generate();
+    let register = Register::peer(peer.peer_id());
+    let client = network.client();
+    spawn_blocking(move || client.submit_blocking(register)).await??;
+    network.ensure_blocks(3).await?;
+
+    // start without a genesis; only the snapshot and the blocks preceding it are downloaded
+    peer.start(
+        network.config().write(["network", "state_sync"], true),
+        None,
+    )
+    .await;
+    timeout(network.sync_timeout(), peer.once_block(3)).await?;
+
+    let client = peer.client();
+    let asset = spawn_blocking(move || {
+        client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.account.eq(ALICE_ID.clone()))
+            .execute_all()
+    })
+    .await??
+    .into_iter()
+    .find(|asset| *asset.id().definition() == asset_definition_id)
+    .expect("Asset not found");
+    assert_eq!(quantity, *asset.value());
+
+    Ok(())
+}
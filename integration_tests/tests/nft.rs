@@ -148,3 +148,40 @@ fn nft_owner_can_transfer_nft() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn nft_cant_be_transferred_from_or_to_frozen_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let (account_id, account_keypair) = gen_account_in("wonderland");
+    let nft_id: NftId = "nft$wonderland".parse()?;
+    client.submit_blocking(Register::account(Account::new(account_id.clone())))?;
+    client.submit_blocking(Register::nft(Nft::new(nft_id.clone(), Metadata::default())))?;
+
+    client.submit_blocking(Freeze::account(account_id.clone()))?;
+    let _err = client
+        .submit_blocking(Transfer::nft(
+            ALICE_ID.clone(),
+            nft_id.clone(),
+            account_id.clone(),
+        ))
+        .expect_err("NFT can't be transferred to a frozen account");
+
+    client.submit_blocking(Unfreeze::account(account_id.clone()))?;
+    client.submit_blocking(Transfer::nft(
+        ALICE_ID.clone(),
+        nft_id.clone(),
+        account_id.clone(),
+    ))?;
+
+    client.submit_blocking(Freeze::account(account_id.clone()))?;
+    let transfer_back = TransactionBuilder::new(network.chain_id(), account_id.clone())
+        .with_instructions([Transfer::nft(account_id, nft_id, ALICE_ID.clone())])
+        .sign(account_keypair.private_key());
+    let _err = client
+        .submit_transaction_blocking(&transfer_back)
+        .expect_err("NFT can't be transferred from a frozen account");
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use eyre::Result;
+use iroha::{async_client::AsyncClient, data_model::Level};
+use iroha_config::client_api::{ConfigUpdateDTO, Logger};
+use iroha_test_network::*;
+use iroha_test_samples::{BOB_ID, BOB_KEYPAIR};
+use reqwest::StatusCode;
+
+fn logger_update() -> ConfigUpdateDTO {
+    ConfigUpdateDTO {
+        logger: Some(Logger {
+            level: Level::DEBUG,
+            filter: None,
+        }),
+        ..ConfigUpdateDTO::default()
+    }
+}
+
+#[tokio::test]
+async fn operator_endpoints_require_admin_signature() -> Result<()> {
+    // alice is an admin of the test network peers
+    let network = NetworkBuilder::new().start().await?;
+
+    let admin = AsyncClient::new(network.client());
+    admin.get_status().await?;
+    admin.set_config(&logger_update()).await?;
+
+    let stranger = AsyncClient::new(
+        network
+            .peer()
+            .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone()),
+    );
+    assert!(stranger.get_status().await.is_err());
+    assert!(stranger.set_config(&logger_update()).await.is_err());
+    // configuration is still readable by anyone
+    stranger.get_config().await?;
+
+    let torii_url = network.client().torii_url;
+    let unsigned = reqwest::get(torii_url.join("metrics")?).await?;
+    assert_eq!(unsigned.status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn metrics_might_be_public() -> Result<()> {
+    let network = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "public_metrics"], true);
+        })
+        .start()
+        .await?;
+
+    let torii_url = network.client().torii_url;
+    let unsigned = reqwest::get(torii_url.join("metrics")?).await?;
+    assert_eq!(unsigned.status(), StatusCode::OK);
+    // other operator endpoints are still protected
+    let stranger = AsyncClient::new(
+        network
+            .peer()
+            .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone()),
+    );
+    assert!(stranger.get_status().await.is_err());
+
+    Ok(())
+}
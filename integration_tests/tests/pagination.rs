@@ -71,6 +71,7 @@ fn fetch_size_should_work() -> Result<()> {
             Pagination::new(Some(nonzero!(7_u64)), 1),
             Sorting::default(),
             FetchSize::new(Some(nonzero!(3_u64))),
+            None,
         ),
     );
     let (first_batch, remaining_items, _continue_cursor) = client.start_query(query)?;
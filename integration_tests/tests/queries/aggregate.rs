@@ -0,0 +1,95 @@
+use eyre::Result;
+use iroha::data_model::{prelude::*, query::parameters::FetchSize};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID};
+use nonzero_ext::nonzero;
+
+#[test]
+fn aggregate_asset_values() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let definition_a: AssetDefinitionId = "agg_a#wonderland".parse()?;
+    let definition_b: AssetDefinitionId = "agg_b#wonderland".parse()?;
+    let holders = [ALICE_ID.clone(), gen_account_in("wonderland").0];
+
+    let mut instructions: Vec<InstructionBox> = vec![
+        Register::asset_definition(AssetDefinition::numeric(definition_a.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(definition_b.clone())).into(),
+        Register::account(Account::new(holders[1].clone())).into(),
+    ];
+    for (definition, values) in [(&definition_a, [5_u32, 7]), (&definition_b, [10, 1])] {
+        for (holder, value) in holders.iter().zip(values) {
+            instructions.push(
+                Mint::asset_numeric(value, AssetId::new(definition.clone(), holder.clone())).into(),
+            );
+        }
+    }
+    client.submit_all_blocking(instructions)?;
+
+    let accounts = client
+        .query(FindAccounts::new())
+        .filter_with(|account| account.id.domain.name.eq("wonderland"))
+        .execute_all()?;
+    let count = client
+        .query(FindAccounts::new())
+        .filter_with(|account| account.id.domain.name.eq("wonderland"))
+        .count()
+        .execute_single()?;
+    assert_eq!(count, Numeric::from(accounts.len() as u64));
+
+    let sums = client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.name.starts_with("agg_"))
+        .sum_with(|asset| asset.value)
+        .group_by_with(|asset| asset.id.definition)
+        .execute_all()?;
+    assert_eq!(
+        sums,
+        [
+            (definition_a.clone(), Numeric::from(12_u32)),
+            (definition_b, Numeric::from(11_u32)),
+        ]
+    );
+    // groups are returned in batches of the requested size like any other results
+    let batched_sums = client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.name.starts_with("agg_"))
+        .with_fetch_size(FetchSize::new(Some(nonzero!(1_u64))))
+        .sum_with(|asset| asset.value)
+        .group_by_with(|asset| asset.id.definition)
+        .execute_all()?;
+    assert_eq!(batched_sums, sums);
+
+    // groups are always ordered by their keys
+    let _err = client
+        .query(FindAssets::new())
+        .sort_by_with(|asset| asset.value, SortOrder::Descending)
+        .count()
+        .execute_single()
+        .expect_err("aggregated queries can't be sorted");
+
+    let only_a = || {
+        client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(definition_a.clone()))
+    };
+    assert_eq!(
+        only_a().min_with(|asset| asset.value).execute_single()?,
+        Numeric::from(5_u32)
+    );
+    assert_eq!(
+        only_a().max_with(|asset| asset.value).execute_single()?,
+        Numeric::from(7_u32)
+    );
+
+    // min and max are not defined when nothing matches
+    let nothing = client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.name.eq("agg_none"))
+        .max_with(|asset| asset.value)
+        .execute_single_opt()?;
+    assert_eq!(nothing, None);
+
+    Ok(())
+}
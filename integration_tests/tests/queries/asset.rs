@@ -1,7 +1,12 @@
+use std::collections::BTreeSet;
+
 use eyre::Result;
 use iroha::{
     client::{Client, QueryError},
-    data_model::{prelude::*, query::builder::SingleQueryError},
+    data_model::{
+        prelude::*,
+        query::{builder::SingleQueryError, error::QueryExecutionFail},
+    },
 };
 use iroha_test_network::*;
 use iroha_test_samples::{gen_account_in, ALICE_ID};
@@ -142,3 +147,147 @@ fn test_total_quantity(
 
     Ok(())
 }
+
+#[test]
+fn find_assets_by_numeric_value() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "coin#wonderland".parse()?;
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id.clone(),
+    )))?;
+
+    let accounts = [
+        gen_account_in("wonderland").0,
+        gen_account_in("wonderland").0,
+        gen_account_in("wonderland").0,
+    ];
+    test_client.submit_all_blocking(
+        accounts
+            .iter()
+            .cloned()
+            .map(|account_id| Register::account(Account::new(account_id))),
+    )?;
+
+    let amounts = [numeric!(10), numeric!(1000), numeric!(5000.5)];
+    test_client.submit_all_blocking(accounts.iter().zip(amounts).map(|(account_id, amount)| {
+        Mint::asset_numeric(
+            amount,
+            AssetId::new(definition_id.clone(), account_id.clone()),
+        )
+    }))?;
+
+    let find_accounts = |predicate: CompoundPredicate<Asset>| -> Result<BTreeSet<AccountId>> {
+        Ok(test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(definition_id.clone()))
+            .filter(predicate)
+            .select_with(|asset| asset.id.account)
+            .execute_all()?
+            .into_iter()
+            .collect())
+    };
+    let expected = |indices: &[usize]| -> BTreeSet<AccountId> {
+        indices.iter().map(|&i| accounts[i].clone()).collect()
+    };
+
+    assert_eq!(
+        find_accounts(CompoundPredicate::build(|asset| asset.value.gt(1000_u32)))?,
+        expected(&[2])
+    );
+    assert_eq!(
+        find_accounts(CompoundPredicate::build(|asset| asset.value.lt(1000_u32)))?,
+        expected(&[0])
+    );
+    // comparison is done by value, regardless of the scale
+    assert_eq!(
+        find_accounts(CompoundPredicate::build(|asset| asset
+            .value
+            .eq(numeric!(1000.00))))?,
+        expected(&[1])
+    );
+    assert_eq!(
+        find_accounts(CompoundPredicate::build(|asset| asset
+            .value
+            .in_range(numeric!(10)..numeric!(5000.5))))?,
+        expected(&[0, 1])
+    );
+    assert_eq!(
+        find_accounts(CompoundPredicate::build(|asset| asset.value.is_zero()))?,
+        expected(&[])
+    );
+
+    let definitions = test_client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|asset_definition| asset_definition.total_quantity.gt(6000_u32))
+        .select_with(|asset_definition| asset_definition.id)
+        .execute_all()?;
+    assert_eq!(definitions, vec![definition_id]);
+
+    Ok(())
+}
+
+#[test]
+fn numeric_predicates_respect_spec_of_asset_definition() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "cent#wonderland".parse()?;
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::new(
+        definition_id.clone(),
+        NumericSpec::fractional(2),
+    )))?;
+    let asset_id = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+    test_client.submit_blocking(Mint::asset_numeric(numeric!(1.25), asset_id.clone()))?;
+
+    let find_cents = |predicate: CompoundPredicate<Asset>| {
+        test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(definition_id.clone()))
+            .filter(predicate)
+            .select_with(|asset| asset.id)
+            .execute_all()
+    };
+
+    // trailing zeros don't make the value more precise than the spec
+    assert_eq!(
+        find_cents(CompoundPredicate::build(|asset| asset
+            .value
+            .eq(numeric!(1.2500))))?,
+        vec![asset_id.clone()]
+    );
+    assert_eq!(
+        find_cents(CompoundPredicate::build(|asset| asset
+            .value
+            .gt(numeric!(1.2))))?,
+        vec![asset_id]
+    );
+    let err = find_cents(CompoundPredicate::build(|asset| {
+        asset.value.lt(numeric!(1.255))
+    }))
+    .expect_err("value is more precise than the spec");
+    assert!(matches!(
+        err,
+        QueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Conversion(
+            _
+        )))
+    ));
+    let err = test_client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|asset_definition| {
+            asset_definition
+                .total_quantity
+                .in_range(numeric!(0.001)..numeric!(2))
+        })
+        .execute_all()
+        .expect_err("range bound is more precise than the spec");
+    assert!(matches!(
+        err,
+        QueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Conversion(
+            _
+        )))
+    ));
+
+    Ok(())
+}
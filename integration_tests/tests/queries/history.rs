@@ -0,0 +1,160 @@
+use eyre::Result;
+use iroha::{
+    client::{Client, QueryError},
+    data_model::{
+        parameter::{BlockParameter, Parameter},
+        prelude::*,
+        query::error::QueryExecutionFail,
+    },
+};
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+use nonzero_ext::nonzero;
+
+fn latest_height(client: &Client) -> Result<NonZeroU64> {
+    Ok(NonZeroU64::new(client.get_status()?.blocks).expect("genesis is committed"))
+}
+
+fn rose_at(client: &Client, height: NonZeroU64) -> Result<Numeric> {
+    let rose_id: AssetId = format!("rose##{}", *ALICE_ID).parse()?;
+    let rose = client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(rose_id))
+        .at_height(height)
+        .execute_single()?;
+
+    Ok(*rose.value())
+}
+
+fn assert_height_unavailable(err: QueryError) {
+    let QueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::HeightUnavailable)) =
+        err
+    else {
+        panic!("Unexpected error: {err:?}");
+    };
+}
+
+#[test]
+fn asset_is_queried_at_past_height() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    let rose_id: AssetId = format!("rose##{}", *ALICE_ID).parse()?;
+
+    let before = latest_height(&client)?;
+    let initial = rose_at(&client, before)?;
+
+    client.submit_blocking(Mint::asset_numeric(10_u32, rose_id.clone()))?;
+    let after_first = latest_height(&client)?;
+    client.submit_blocking(Mint::asset_numeric(5_u32, rose_id))?;
+    let after_second = latest_height(&client)?;
+
+    assert_eq!(rose_at(&client, before)?, initial);
+    assert_eq!(
+        rose_at(&client, after_first)?,
+        initial.checked_add(numeric!(10)).unwrap()
+    );
+    assert_eq!(
+        rose_at(&client, after_second)?,
+        initial.checked_add(numeric!(15)).unwrap()
+    );
+    // previously reconstructed state is not reused for an earlier height
+    assert_eq!(rose_at(&client, before)?, initial);
+
+    Ok(())
+}
+
+#[test]
+fn singular_query_is_executed_at_past_height() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let before = latest_height(&client)?;
+    let initial = client.query_single(FindParameters::new())?;
+    let max_transactions = initial
+        .block()
+        .max_transactions()
+        .checked_add(1)
+        .expect("doesn't overflow");
+    client.submit_blocking(SetParameter::new(Parameter::Block(
+        BlockParameter::MaxTransactions(max_transactions),
+    )))?;
+
+    let params = client.query_single(FindParameters::new())?;
+    assert_eq!(params.block().max_transactions(), max_transactions);
+    let params = client.query_single_at_height(FindParameters::new(), before)?;
+    assert_eq!(params, initial);
+
+    let future = latest_height(&client)?.checked_add(10).unwrap();
+    let err = client
+        .query_single_at_height(FindParameters::new(), future)
+        .expect_err("height is not committed yet");
+    assert_height_unavailable(err);
+
+    Ok(())
+}
+
+#[test]
+fn future_height_is_unavailable() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let future = latest_height(&client)?.checked_add(10).unwrap();
+    let err = client
+        .query(FindDomains::new())
+        .at_height(future)
+        .execute_all()
+        .expect_err("height is not committed yet");
+    assert_height_unavailable(err);
+
+    Ok(())
+}
+
+#[test]
+fn height_beyond_history_depth_is_unavailable() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "query_history_depth"], 1);
+        })
+        .start_blocking()?;
+    let client = network.client();
+
+    for _ in 0..2 {
+        client.submit_blocking(Log::new(Level::INFO, "next block".to_owned()))?;
+    }
+    let latest = latest_height(&client)?;
+
+    client
+        .query(FindDomains::new())
+        .at_height(NonZeroU64::new(latest.get() - 1).unwrap())
+        .execute_all()?;
+    let err = client
+        .query(FindDomains::new())
+        .at_height(nonzero!(1_u64))
+        .execute_all()
+        .expect_err("height is too old");
+    assert_height_unavailable(err);
+
+    Ok(())
+}
+
+#[test]
+fn past_height_is_served_to_any_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    let stranger = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    let before = latest_height(&client)?;
+    let domains_before = client.query(FindDomains::new()).execute_all()?;
+    client.submit_blocking(Register::domain(Domain::new("garden".parse()?)))?;
+
+    // historical queries are authorized by the executor like any other query
+    let domains = stranger
+        .query(FindDomains::new())
+        .at_height(before)
+        .execute_all()?;
+    assert_eq!(domains, domains_before);
+
+    Ok(())
+}
@@ -8,11 +8,15 @@ use iroha::{
 use iroha_test_network::*;
 
 mod account;
+mod aggregate;
 mod asset;
+mod history;
 mod metadata;
 mod query_errors;
 mod role;
 mod smart_contract;
+mod state_proof;
+mod transaction_proof;
 
 #[test]
 fn too_big_fetch_size_is_not_allowed() {
@@ -0,0 +1,61 @@
+use eyre::Result;
+use iroha::{
+    client::Client,
+    data_model::{prelude::*, state::StateEntry},
+};
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+
+/// State root committed to by the header of the block following the one at `height`
+fn state_root_after(client: &Client, height: u64) -> Result<StateRoot> {
+    let header = client
+        .query(FindBlockHeaders::new())
+        .execute_all()?
+        .into_iter()
+        .find(|header| header.height().get() == height + 1)
+        .expect("next block should be committed");
+
+    Ok(header
+        .prev_state_root()
+        .expect("only genesis doesn't commit to the state root"))
+}
+
+#[test]
+fn account_state_is_proven() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let proof = client.query_single(FindAccountStateProof::new(ALICE_ID.clone()))?;
+    let StateEntry::Account(account) = proof.entry() else {
+        panic!("Unexpected entry: {:?}", proof.entry());
+    };
+    assert_eq!(account.id(), &*ALICE_ID);
+
+    client.submit_blocking(Log::new(Level::INFO, "next block".to_owned()))?;
+    let root = state_root_after(&client, proof.height().get())?;
+    assert!(proof.verify(&root));
+
+    Ok(())
+}
+
+#[test]
+fn changed_asset_state_is_not_proven() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let asset_id: AssetId = format!("rose##{}", *ALICE_ID).parse()?;
+    let proof = client.query_single(FindAssetStateProof::new(asset_id.clone()))?;
+    let StateEntry::Asset(asset) = proof.entry() else {
+        panic!("Unexpected entry: {:?}", proof.entry());
+    };
+    assert_eq!(asset.id(), &asset_id);
+    let height = proof.height().get();
+
+    client.submit_blocking(Mint::asset_numeric(1_u32, asset_id))?;
+    client.submit_blocking(Log::new(Level::INFO, "next block".to_owned()))?;
+
+    assert!(proof.verify(&state_root_after(&client, height)?));
+    assert!(!proof.verify(&state_root_after(&client, height + 1)?));
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use eyre::Result;
+use iroha::{
+    client::QueryError,
+    data_model::{
+        prelude::*,
+        query::error::{FindError, QueryExecutionFail},
+    },
+};
+use iroha_test_network::*;
+
+#[test]
+fn transaction_inclusion_is_proven() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let hashes = ["proof_a", "proof_b", "proof_c"]
+        .into_iter()
+        .map(|name| {
+            let register = Register::domain(Domain::new(name.parse()?));
+            Ok(client.submit_blocking(register)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let headers = client.query(FindBlockHeaders::new()).execute_all()?;
+    for hash in &hashes {
+        let proof = client.get_transaction_proof(*hash)?;
+        assert!(proof.verify(*hash));
+        assert!(headers.contains(proof.block_header()));
+        assert!(client.verify_transaction_inclusion(*hash, proof.block_header())?);
+
+        for other in hashes.iter().filter(|other| *other != hash) {
+            assert!(!proof.verify(*other));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn proof_of_unknown_transaction_is_not_found() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let transaction = client.build_transaction(
+        [Log::new(Level::INFO, "never".to_owned())],
+        Metadata::default(),
+    );
+    let err = client
+        .get_transaction_proof(transaction.hash())
+        .expect_err("transaction is not committed");
+
+    let QueryError::Validation(ValidationFail::QueryFailed(QueryExecutionFail::Find(
+        FindError::Transaction(hash),
+    ))) = err
+    else {
+        panic!("Unexpected error: {err:?}");
+    };
+    assert_eq!(hash, transaction.hash());
+
+    Ok(())
+}
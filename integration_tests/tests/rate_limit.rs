@@ -0,0 +1,73 @@
+use eyre::Result;
+use iroha::data_model::{prelude::*, Level};
+use iroha_test_network::*;
+use iroha_test_samples::{BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn transactions_are_limited_per_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "rate_limit", "transactions_per_second"], 1)
+                .write(["torii", "rate_limit", "transactions_burst"], 2);
+        })
+        .start_blocking()?;
+    let alice = network.client();
+    let register_domain = |name: &str| Register::domain(Domain::new(name.parse().unwrap()));
+
+    alice.submit(register_domain("first"))?;
+    alice.submit(register_domain("second"))?;
+    let error = alice
+        .submit(register_domain("third"))
+        .expect_err("burst is exhausted");
+    assert!(format!("{error:?}").contains("429"), "{error:?}");
+
+    // other accounts aren't affected
+    let bob = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+    bob.submit(Log::new(Level::INFO, "still accepted".to_owned()))?;
+
+    Ok(())
+}
+
+#[test]
+fn queries_are_limited_per_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "rate_limit", "queries_per_second"], 1)
+                .write(["torii", "rate_limit", "queries_burst"], 1);
+        })
+        .start_blocking()?;
+    let client = network.client();
+
+    client.query_single(FindParameters::new())?;
+    let error = client
+        .query_single(FindParameters::new())
+        .expect_err("burst is exhausted");
+    assert!(format!("{error:?}").contains("429"), "{error:?}");
+
+    Ok(())
+}
+
+#[test]
+fn simulations_share_the_transaction_limit() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "rate_limit", "transactions_per_second"], 1)
+                .write(["torii", "rate_limit", "transactions_burst"], 1);
+        })
+        .start_blocking()?;
+    let alice = network.client();
+    let transaction = alice.build_transaction(
+        [Log::new(Level::INFO, "simulated".to_owned())],
+        Metadata::default(),
+    );
+
+    alice.simulate_transaction(&transaction)?;
+    let error = alice
+        .simulate_transaction(&transaction)
+        .expect_err("burst is exhausted");
+    assert!(format!("{error:?}").contains("429"), "{error:?}");
+
+    Ok(())
+}
@@ -340,3 +340,53 @@ fn sort_only_elements_which_have_sorting_key() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn sort_by_multiple_projected_keys() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let values = [3_u32, 1, 3, 2, 1, 3];
+    let mut instructions: Vec<InstructionBox> = vec![];
+    for (i, value) in values.iter().enumerate() {
+        let definition_id = format!("sort_{i}#wonderland").parse::<AssetDefinitionId>()?;
+        instructions.push(
+            Register::asset_definition(AssetDefinition::numeric(definition_id.clone())).into(),
+        );
+        instructions.push(
+            Mint::asset_numeric(*value, AssetId::new(definition_id, ALICE_ID.clone())).into(),
+        );
+    }
+    test_client
+        .submit_all_blocking(instructions)
+        .wrap_err("Failed to register assets")?;
+
+    let query = || {
+        test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.name.starts_with("sort_"))
+            .sort_by_with(|asset| asset.value, SortOrder::Descending)
+            .sort_by_with(|asset| asset.id, SortOrder::Ascending)
+    };
+
+    let mut expected = query().execute_all()?;
+    assert_eq!(expected.len(), values.len());
+    // verify the server-side order against the same ordering computed locally
+    let mut sorted = expected.clone();
+    sorted.sort_by(|left, right| {
+        right
+            .value()
+            .cmp(left.value())
+            .then_with(|| left.id().cmp(right.id()))
+    });
+    assert_eq!(expected, sorted);
+
+    // sorting is applied before pagination
+    let page = query()
+        .with_pagination(Pagination::new(Some(nonzero!(2_u64)), 1))
+        .execute_all()?;
+    expected.truncate(3);
+    assert_eq!(page, expected[1..]);
+
+    Ok(())
+}
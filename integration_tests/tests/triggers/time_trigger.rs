@@ -232,6 +232,57 @@ fn mint_nft_for_every_user_every_1_sec() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn cron_trigger_with_end_in_the_past_is_rejected() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let register_trigger = |id: &str, schedule: CronSchedule| {
+        Register::trigger(Trigger::new(
+            id.parse().expect("Valid"),
+            Action::new(
+                vec![Log::new(Level::INFO, "Happy new month".to_string())],
+                Repeats::Indefinitely,
+                ALICE_ID.clone(),
+                TimeEventFilter::new(ExecutionTime::Cron(schedule)),
+            ),
+        ))
+    };
+    let schedule = CronSchedule::new("@monthly".parse()?);
+
+    let _err = test_client
+        .submit_blocking(register_trigger(
+            "ended",
+            schedule.ending_at(curr_time() - Duration::from_secs(60)),
+        ))
+        .expect_err("Trigger whose schedule has ended should be rejected");
+
+    let _err = test_client
+        .submit_blocking(register_trigger(
+            "limited",
+            schedule.with_max_occurrences(12),
+        ))
+        .expect_err("Trigger with limited occurrences since the Unix epoch should be rejected");
+
+    let schedule = schedule
+        .starting_at(curr_time())
+        .ending_at(curr_time() + Duration::from_secs(365 * 24 * 60 * 60))
+        .with_max_occurrences(12);
+    test_client.submit_blocking(register_trigger("monthly", schedule))?;
+    let trigger_id: TriggerId = "monthly".parse()?;
+    let trigger = test_client
+        .query(FindTriggers::new())
+        .filter_with(|trigger| trigger.id.eq(trigger_id))
+        .execute_single()?;
+    assert_eq!(
+        *trigger.action().filter(),
+        EventFilterBox::from(TimeEventFilter::new(ExecutionTime::Cron(schedule)))
+    );
+    assert_eq!(trigger.action().repeats(), Repeats::Exactly(12));
+
+    Ok(())
+}
+
 /// Get block committed event listener
 fn get_block_committed_event_listener(
     client: &Client,
@@ -0,0 +1,71 @@
+use eyre::Result;
+use iroha::data_model::{prelude::*, transaction::error::TransactionRejectionReason};
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+
+#[test]
+fn simulated_transaction_is_not_committed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let asset_definition_id = "xor#wonderland".parse::<AssetDefinitionId>()?;
+    let instructions: [InstructionBox; 2] = [
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone())).into(),
+        Mint::asset_numeric(
+            200u32,
+            AssetId::new(asset_definition_id.clone(), ALICE_ID.clone()),
+        )
+        .into(),
+    ];
+    let transaction = client.build_transaction(instructions, Metadata::default());
+
+    let simulation = client.simulate_transaction(&transaction)?;
+    assert_eq!(simulation.rejection_reason, None);
+    assert_eq!(simulation.instructions, 2);
+    assert_eq!(simulation.fuel, 0);
+    assert!(simulation.events.iter().any(|event| matches!(
+        event,
+        DataEvent::Domain(DomainEvent::AssetDefinition(AssetDefinitionEvent::Created(definition)))
+            if *definition.id() == asset_definition_id
+    )));
+
+    let definitions = client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|definition| definition.id.eq(asset_definition_id.clone()))
+        .execute_all()?;
+    assert!(definitions.is_empty());
+
+    // the simulation doesn't consume the transaction
+    client.submit_transaction_blocking(&transaction)?;
+    let definitions = client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|definition| definition.id.eq(asset_definition_id))
+        .execute_all()?;
+    assert_eq!(definitions.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn simulated_transaction_reports_rejection() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let missing_asset_definition_id = "ksor#wonderland".parse::<AssetDefinitionId>()?;
+    let transaction = client.build_transaction(
+        [Mint::asset_numeric(
+            200u32,
+            AssetId::new(missing_asset_definition_id, ALICE_ID.clone()),
+        )],
+        Metadata::default(),
+    );
+
+    let simulation = client.simulate_transaction(&transaction)?;
+    assert!(matches!(
+        simulation.rejection_reason,
+        Some(TransactionRejectionReason::Validation(_))
+    ));
+    assert!(simulation.events.is_empty());
+
+    Ok(())
+}